@@ -24,11 +24,28 @@
 //! # Ok::<(), beads::Error>(())
 //! ```
 
+mod priority;
+mod stream;
+mod version;
+
+pub use priority::Priority;
+pub use semver::Version;
+pub use stream::IssueStream;
+pub use version::{
+    is_supported_version, parse_version, unsupported_version_warning, version_warning,
+    MIN_SUPPORTED_VERSION,
+};
+
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
-use std::process::Command;
+use std::process::{Command, Stdio};
+use std::sync::OnceLock;
 use thiserror::Error;
 
+/// `bd list --help` output, fetched once per process
+static LIST_HELP: OnceLock<String> = OnceLock::new();
+
 /// Errors that can occur when interacting with beads
 #[derive(Error, Debug)]
 pub enum Error {
@@ -52,6 +69,18 @@ pub enum Error {
 
     #[error("JSON parse error: {0}")]
     Json(#[from] serde_json::Error),
+
+    /// `bd` printed something other than the expected JSON, e.g. a warning
+    /// banner ahead of the output
+    #[error(
+        "Failed to parse `bd {command}` output as JSON ({source}); output began with: {snippet}"
+    )]
+    UnexpectedOutput {
+        command: String,
+        snippet: String,
+        #[source]
+        source: serde_json::Error,
+    },
 }
 
 /// Result type for beads operations
@@ -113,6 +142,7 @@ impl std::fmt::Display for IssueType {
 
 /// A dependency reference (used in bd show --json output)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DependencyRef {
     pub id: String,
     #[serde(default)]
@@ -125,6 +155,7 @@ pub struct DependencyRef {
 
 /// A beads issue
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Issue {
     pub id: String,
     pub title: String,
@@ -157,6 +188,11 @@ pub struct Issue {
 }
 
 impl Issue {
+    /// Priority as a typed [`Priority`], if set
+    pub fn priority_typed(&self) -> Option<Priority> {
+        self.priority.map(Priority::from)
+    }
+
     /// Get all blocker IDs (from either dependencies or depends_on)
     pub fn blocker_ids(&self) -> Vec<String> {
         if !self.dependencies.is_empty() {
@@ -284,6 +320,60 @@ impl CommandOutput {
     }
 }
 
+/// How much of unparseable output to quote in [`Error::UnexpectedOutput`]
+const SNIPPET_CHARS: usize = 200;
+
+/// Parse `bd <args>` JSON output, quoting the output on failure
+///
+/// Unless the `strict-json` feature is enabled, lines printed before the
+/// JSON (such as a deprecation or update notice) are skipped.
+fn parse_json<T: DeserializeOwned>(args: &[&str], stdout: &str) -> Result<T> {
+    let source = match serde_json::from_str(stdout) {
+        Ok(value) => return Ok(value),
+        Err(e) => e,
+    };
+
+    if !cfg!(feature = "strict-json") {
+        let value = json_start(stdout).and_then(|i| serde_json::from_str(&stdout[i..]).ok());
+        if let Some(value) = value {
+            return Ok(value);
+        }
+    }
+
+    Err(unexpected_output(args.join(" "), stdout, source))
+}
+
+/// Build [`Error::UnexpectedOutput`], quoting the start of `output`
+fn unexpected_output(command: String, output: &str, source: serde_json::Error) -> Error {
+    let trimmed = output.trim();
+    let mut snippet: String = trimmed.chars().take(SNIPPET_CHARS).collect();
+    if snippet.len() < trimmed.len() {
+        snippet.push_str("...");
+    }
+    Error::UnexpectedOutput {
+        command,
+        snippet: if snippet.is_empty() {
+            "(empty)".to_string()
+        } else {
+            format!("{:?}", snippet)
+        },
+        source,
+    }
+}
+
+/// Byte offset of the first line after the first that opens a JSON array or object
+fn json_start(stdout: &str) -> Option<usize> {
+    let mut offset = 0;
+    for (i, line) in stdout.split_inclusive('\n').enumerate() {
+        let content = line.trim_start();
+        if i > 0 && content.starts_with(['[', '{']) {
+            return Some(offset + line.len() - content.len());
+        }
+        offset += line.len();
+    }
+    None
+}
+
 /// Status info for display
 #[derive(Debug, Clone, Default)]
 pub struct StatusInfo {
@@ -293,6 +383,38 @@ pub struct StatusInfo {
     pub ready: usize,
 }
 
+/// Filters passed through to `bd list`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ListFilter<'a> {
+    pub status: Option<&'a str>,
+    pub issue_type: Option<&'a str>,
+    pub assignee: Option<&'a str>,
+    pub label: Option<&'a str>,
+}
+
+impl<'a> ListFilter<'a> {
+    /// Build `bd list --json` arguments for the set filters
+    fn args(&self) -> Vec<&'a str> {
+        let mut args = vec!["list"];
+
+        let flags = [
+            ("--status", self.status),
+            ("--type", self.issue_type),
+            ("--assignee", self.assignee),
+            ("--label", self.label),
+        ];
+        for (flag, value) in flags {
+            if let Some(v) = value {
+                args.push(flag);
+                args.push(v);
+            }
+        }
+
+        args.push("--json");
+        args
+    }
+}
+
 /// Beads CLI wrapper
 #[derive(Debug, Clone, Default)]
 pub struct Beads {
@@ -304,9 +426,12 @@ pub struct Beads {
 
 impl Beads {
     /// Create a new Beads instance
+    ///
+    /// Checks the installed bd version (once per process); see
+    /// [`version_warning`] for the verdict.
     pub fn new() -> Result<Self> {
         let bd = Self::default();
-        if !bd.is_available() {
+        if version::probe_version(&bd).is_none() {
             return Err(Error::NotInstalled);
         }
         Ok(bd)
@@ -348,6 +473,12 @@ impl Beads {
         self.run_command(&["--version"]).is_ok()
     }
 
+    /// Get the installed bd version, parsed from `bd --version`
+    pub fn version(&self) -> Result<Version> {
+        let output = self.run_command(&["--version"])?;
+        parse_version(&output.stdout)
+    }
+
     /// Check if current directory has beads initialized
     pub fn is_repo(&self) -> bool {
         self.run_command(&["stats"]).is_ok()
@@ -371,8 +502,44 @@ impl Beads {
 
         args.push("--json");
 
-        let output = self.run_command(&args)?;
-        serde_json::from_str(&output.stdout).map_err(Error::from)
+        self.run_json(&args)
+    }
+
+    /// List issues, passing every set filter to `bd list`
+    ///
+    /// Use [`Beads::supports_list_flag`] first to only set filters the
+    /// installed `bd` understands.
+    pub fn list_filtered(&self, filter: &ListFilter) -> Result<Vec<Issue>> {
+        let args = filter.args();
+
+        self.run_json(&args)
+    }
+
+    /// Stream issues from `bd list --json` as they are parsed
+    ///
+    /// Unlike [`Beads::list_filtered`], neither the raw JSON nor the full
+    /// list is held in memory, and callers may stop iterating early.
+    pub fn list_stream(&self, filter: &ListFilter) -> Result<IssueStream> {
+        let args = filter.args();
+
+        let child = self
+            .command(&args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        IssueStream::new(child, args.join(" "))
+    }
+
+    /// Check whether `bd list` accepts a flag (e.g. "assignee")
+    ///
+    /// Parses `bd list --help` once per process.
+    pub fn supports_list_flag(&self, flag: &str) -> bool {
+        let help = LIST_HELP.get_or_init(|| {
+            self.run_command(&["list", "--help"])
+                .map(|output| output.combined())
+                .unwrap_or_default()
+        });
+        help_has_flag(help, flag)
     }
 
     /// List all open issues
@@ -402,23 +569,20 @@ impl Beads {
 
     /// Get ready issues (no blockers)
     pub fn ready(&self) -> Result<Vec<Issue>> {
-        let output = self.run_command(&["ready", "--json"])?;
-        serde_json::from_str(&output.stdout).map_err(Error::from)
+        self.run_json(&["ready", "--json"])
     }
 
     /// Get blocked issues
     pub fn blocked(&self) -> Result<Vec<Issue>> {
-        let output = self.run_command(&["blocked", "--json"])?;
-        serde_json::from_str(&output.stdout).map_err(Error::from)
+        self.run_json(&["blocked", "--json"])
     }
 
     // --- Issue details ---
 
     /// Show a specific issue
     pub fn show(&self, id: &str) -> Result<Issue> {
-        let output = self.run_command(&["show", id, "--json"])?;
         // bd show returns an array with a single issue
-        let issues: Vec<Issue> = serde_json::from_str(&output.stdout)?;
+        let issues: Vec<Issue> = self.run_json(&["show", id, "--json"])?;
         issues
             .into_iter()
             .next()
@@ -427,8 +591,7 @@ impl Beads {
 
     /// Search for issues by query
     pub fn search(&self, query: &str) -> Result<Vec<Issue>> {
-        let output = self.run_command(&["search", query, "--json"])?;
-        serde_json::from_str(&output.stdout).map_err(Error::from)
+        self.run_json(&["search", query, "--json"])
     }
 
     // --- Issue creation ---
@@ -531,6 +694,7 @@ impl Beads {
     }
 
     /// Update an issue with various options
+    #[allow(clippy::too_many_arguments)]
     pub fn update(
         &self,
         id: &str,
@@ -538,6 +702,8 @@ impl Beads {
         priority: Option<u8>,
         assignee: Option<&str>,
         title: Option<&str>,
+        description: Option<&str>,
+        notes: Option<&str>,
     ) -> Result<CommandOutput> {
         let mut args = vec!["update".to_string(), id.to_string()];
 
@@ -557,10 +723,23 @@ impl Beads {
             args.push(format!("--title={}", t));
         }
 
+        if let Some(d) = description {
+            args.push(format!("--description={}", d));
+        }
+
+        if let Some(n) = notes {
+            args.push(format!("--notes={}", n));
+        }
+
         let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
         self.run_command(&args_refs)
     }
 
+    /// Set an issue's parent epic
+    pub fn set_parent(&self, id: &str, parent: &str) -> Result<CommandOutput> {
+        self.run_command(&["update", id, &format!("--parent={}", parent)])
+    }
+
     /// Close an issue
     pub fn close(&self, id: &str) -> Result<CommandOutput> {
         self.run_command(&["close", id])
@@ -658,8 +837,7 @@ impl Beads {
 
     /// Get comments for an issue
     pub fn comments(&self, issue_id: &str) -> Result<Vec<Comment>> {
-        let output = self.run_command(&["comments", issue_id, "--json"])?;
-        serde_json::from_str(&output.stdout).map_err(Error::from)
+        self.run_json(&["comments", issue_id, "--json"])
     }
 
     /// Add a comment to an issue
@@ -717,8 +895,7 @@ impl Beads {
 
     /// Get project statistics
     pub fn stats(&self) -> Result<Stats> {
-        let output = self.run_command(&["stats", "--json"])?;
-        serde_json::from_str(&output.stdout).map_err(Error::from)
+        self.run_json(&["stats", "--json"])
     }
 
     /// Get raw stats output
@@ -751,8 +928,7 @@ impl Beads {
             args.extend(["--limit", &limit_str]);
         }
 
-        let output = self.run_command(&args)?;
-        serde_json::from_str(&output.stdout).map_err(Error::from)
+        self.run_json(&args)
     }
 
     /// Get activity for a specific issue
@@ -769,8 +945,7 @@ impl Beads {
             args.extend(["--limit", &limit_str]);
         }
 
-        let output = self.run_command(&args)?;
-        serde_json::from_str(&output.stdout).map_err(Error::from)
+        self.run_json(&args)
     }
 
     // --- Sync and admin ---
@@ -809,7 +984,7 @@ impl Beads {
 
     // --- Private helpers ---
 
-    fn run_command(&self, args: &[&str]) -> Result<CommandOutput> {
+    fn command(&self, args: &[&str]) -> Command {
         let mut cmd = Command::new("bd");
 
         // Add global flags first (they apply to all commands)
@@ -824,7 +999,22 @@ impl Beads {
             cmd.current_dir(dir);
         }
 
-        let output = cmd.output()?;
+        cmd
+    }
+
+    /// Run a `--json` command and parse its output
+    fn run_json<T: DeserializeOwned>(&self, args: &[&str]) -> Result<T> {
+        let output = self.run_command(args)?;
+        // An unsupported bd is the usual cause of unparseable output
+        let parsed = parse_json(args, &output.stdout);
+        if parsed.is_err() {
+            version::probe_version(self);
+        }
+        parsed
+    }
+
+    fn run_command(&self, args: &[&str]) -> Result<CommandOutput> {
+        let output = self.command(args).output()?;
 
         let stdout = String::from_utf8_lossy(&output.stdout).to_string();
         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
@@ -865,6 +1055,15 @@ impl Beads {
     }
 }
 
+/// Check whether help text documents `--<flag>` (not just a longer flag)
+fn help_has_flag(help: &str, flag: &str) -> bool {
+    let needle = format!("--{}", flag);
+    help.match_indices(&needle).any(|(i, _)| {
+        let next = help[i + needle.len()..].chars().next();
+        !matches!(next, Some(c) if c.is_ascii_alphanumeric() || c == '-')
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -900,6 +1099,48 @@ mod tests {
         assert_eq!(output_with_err.combined(), "out\nerr");
     }
 
+    #[test]
+    fn test_parse_json_quotes_unexpected_output() {
+        let stdout = "Warning: bd 0.30 is available\nError: database is locked";
+        let err = parse_json::<Vec<Issue>>(&["list", "--json"], stdout).unwrap_err();
+        assert!(
+            matches!(err, Error::UnexpectedOutput { ref command, .. } if command == "list --json")
+        );
+        let message = err.to_string();
+        assert!(message.contains("`bd list --json`"), "{}", message);
+        assert!(
+            message.contains("Warning: bd 0.30 is available"),
+            "{}",
+            message
+        );
+
+        let long = "x".repeat(500);
+        match parse_json::<Vec<Issue>>(&["ready", "--json"], &long).unwrap_err() {
+            Error::UnexpectedOutput { snippet, .. } => {
+                assert_eq!(snippet, format!("\"{}...\"", "x".repeat(SNIPPET_CHARS)))
+            }
+            other => panic!("unexpected error: {}", other),
+        }
+
+        let issues: Vec<Issue> = parse_json(&["list", "--json"], "[]").unwrap();
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    #[cfg(not(feature = "strict-json"))]
+    fn test_parse_json_skips_leading_banner() {
+        let stdout = "⚠ bd is deprecated [use bd2]\nRun `bd upgrade` to update\n  [\n  {\"id\": \"PROJ-1\", \"title\": \"Test\", \"status\": \"open\", \"type\": \"task\"}\n]\n";
+        let issues: Vec<Issue> = parse_json(&["list", "--json"], stdout).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].id, "PROJ-1");
+
+        let stats: Stats = parse_json(&["stats", "--json"], "notice\n{}").unwrap();
+        assert_eq!(stats.total, 0);
+
+        assert_eq!(json_start("[1]"), None);
+        assert!(parse_json::<Vec<Issue>>(&["ready", "--json"], "no json here").is_err());
+    }
+
     #[test]
     fn test_status_display() {
         assert_eq!(Status::Open.to_string(), "open");
@@ -979,6 +1220,15 @@ mod tests {
         assert_eq!(activity.issue_id, Some("PROJ-123".to_string()));
     }
 
+    #[test]
+    fn test_help_has_flag() {
+        let help =
+            "Flags:\n  -a, --assignee string   Filter by assignee\n      --type-filter string\n";
+        assert!(help_has_flag(help, "assignee"));
+        assert!(!help_has_flag(help, "type"));
+        assert!(!help_has_flag(help, "label"));
+    }
+
     #[test]
     fn test_extract_issue_id() {
         let bd = Beads::default();
@@ -0,0 +1,148 @@
+//! Typed issue priority
+//!
+//! `bd` stores priorities as integers 0-4 but users write them as `P0`-`P4`.
+//! [`Priority`] accepts either form when parsing or deserializing and always
+//! serializes as the integer `bd` expects.
+
+use crate::Error;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Issue priority (P0 = critical, P4 = backlog)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    P0,
+    P1,
+    #[default]
+    P2,
+    P3,
+    P4,
+}
+
+impl Priority {
+    /// Numeric priority as used by `bd` (0-4)
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+impl From<u8> for Priority {
+    /// Values above 4 are treated as backlog
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::P0,
+            1 => Self::P1,
+            2 => Self::P2,
+            3 => Self::P3,
+            _ => Self::P4,
+        }
+    }
+}
+
+impl From<Priority> for u8 {
+    fn from(priority: Priority) -> u8 {
+        priority.as_u8()
+    }
+}
+
+impl FromStr for Priority {
+    type Err = Error;
+
+    /// Parse `P0`-`P4` (case-insensitive) or `0`-`4`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let digits = trimmed
+            .strip_prefix('P')
+            .or_else(|| trimmed.strip_prefix('p'))
+            .unwrap_or(trimmed);
+        match digits.parse::<u8>() {
+            Ok(n) if n <= 4 => Ok(Self::from(n)),
+            _ => Err(Error::ParseError(format!(
+                "Invalid priority '{}': expected P0-P4 or 0-4",
+                s
+            ))),
+        }
+    }
+}
+
+impl TryFrom<&str> for Priority {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "P{}", self.as_u8())
+    }
+}
+
+impl Serialize for Priority {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.as_u8())
+    }
+}
+
+impl<'de> Deserialize<'de> for Priority {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PriorityVisitor;
+
+        impl<'de> Visitor<'de> for PriorityVisitor {
+            type Value = Priority;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a priority from 0-4 or \"P0\"-\"P4\"")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Priority, E> {
+                u8::try_from(value)
+                    .ok()
+                    .filter(|n| *n <= 4)
+                    .map(Priority::from)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Priority, E> {
+                u64::try_from(value)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+                    .and_then(|n| self.visit_u64(n))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Priority, E> {
+                value
+                    .parse()
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+            }
+        }
+
+        deserializer.deserialize_any(PriorityVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("P1".parse::<Priority>().unwrap(), Priority::P1);
+        assert_eq!("p0".parse::<Priority>().unwrap(), Priority::P0);
+        assert_eq!(Priority::try_from("3").unwrap(), Priority::P3);
+        assert!("P5".parse::<Priority>().is_err());
+        assert!("high".parse::<Priority>().is_err());
+        assert_eq!(Priority::from(9), Priority::P4);
+        assert_eq!(Priority::P2.to_string(), "P2");
+    }
+
+    #[test]
+    fn test_serde() {
+        let parsed: Vec<Priority> = serde_json::from_str(r#"[1, "P3", "0"]"#).unwrap();
+        assert_eq!(parsed, vec![Priority::P1, Priority::P3, Priority::P0]);
+        assert!(serde_json::from_str::<Priority>("7").is_err());
+        assert_eq!(serde_json::to_string(&Priority::P4).unwrap(), "4");
+    }
+}
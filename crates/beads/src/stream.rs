@@ -0,0 +1,337 @@
+//! Streaming parser for `bd list --json` output
+//!
+//! Reads issues straight from the child's stdout pipe so large repositories
+//! never hold the full JSON text (or the full `Vec<Issue>`) in memory.
+//!
+//! As with the non-streaming commands, lines printed before the array (such
+//! as an update notice) are skipped unless the `strict-json` feature is
+//! enabled.
+
+use crate::{unexpected_output, Error, Issue, Result, SNIPPET_CHARS};
+use serde_json::de::IoRead;
+use serde_json::StreamDeserializer;
+use std::io::{self, BufRead, BufReader, Read};
+use std::process::{Child, ChildStdout};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+/// Where the reader is relative to the opening `[` of the array
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Prelude {
+    /// At the start of a line, before any non-whitespace
+    LineStart,
+    /// After a `[` that opens either the array or a banner like `[notice] ...`
+    Bracket,
+    /// Inside a non-JSON line, skipped up to the newline
+    Banner,
+    /// Inside the array
+    Array,
+}
+
+/// What the stream has seen so far, for error reporting
+#[derive(Debug, Default)]
+struct Seen {
+    /// Start of the raw output, for [`Error::UnexpectedOutput`]
+    head: Vec<u8>,
+    /// Whether the opening `[` of the array was found
+    in_array: bool,
+}
+
+/// Adapts a JSON array byte stream into whitespace-separated values
+///
+/// Rewrites leading non-JSON lines, the outer `[`, `]` and top-level commas
+/// to spaces so that `serde_json`'s stream deserializer can yield the
+/// elements one at a time.
+struct ArrayElements<R> {
+    inner: R,
+    prelude: Prelude,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    /// Bytes read from `inner` so far
+    consumed: usize,
+    seen: Arc<Mutex<Seen>>,
+}
+
+impl<R: BufRead> ArrayElements<R> {
+    fn new(inner: R) -> Self {
+        let prelude = if cfg!(feature = "strict-json") {
+            Prelude::Array
+        } else {
+            Prelude::LineStart
+        };
+        Self {
+            inner,
+            prelude,
+            depth: 0,
+            in_string: false,
+            escaped: false,
+            consumed: 0,
+            seen: Arc::default(),
+        }
+    }
+
+    /// Advance the prelude state; returns false while the byte is before the array
+    ///
+    /// A line counts as the array once its `[` is followed by `{` or `]`,
+    /// possibly after whitespace, so `[notice]` banners are skipped too.
+    fn enter_array(&mut self, byte: u8) -> bool {
+        self.prelude = match (self.prelude, byte) {
+            (Prelude::Array, _) => return true,
+            (Prelude::Bracket, b'{' | b']') => {
+                // The `[` itself was already blanked
+                self.prelude = Prelude::Array;
+                self.depth = 1;
+                return true;
+            }
+            (Prelude::Bracket, b) if b.is_ascii_whitespace() => Prelude::Bracket,
+            (_, b'\n') => Prelude::LineStart,
+            (Prelude::LineStart, b'[') => Prelude::Bracket,
+            (Prelude::LineStart, b) if b.is_ascii_whitespace() => Prelude::LineStart,
+            _ => Prelude::Banner,
+        };
+        false
+    }
+}
+
+impl<R: BufRead> Read for ArrayElements<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.inner.fill_buf()?;
+        {
+            // Record buffered bytes too: serde_json reads one byte at a time,
+            // so an early parse error would otherwise quote a single character
+            let mut seen = self.seen.lock().unwrap_or_else(|e| e.into_inner());
+            let start = seen.head.len().saturating_sub(self.consumed);
+            let room = (SNIPPET_CHARS * 4).saturating_sub(seen.head.len());
+            if room > 0 && start < available.len() {
+                let end = available.len().min(start + room);
+                seen.head.extend_from_slice(&available[start..end]);
+            }
+        }
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.inner.consume(n);
+        self.consumed += n;
+
+        for byte in &mut buf[..n] {
+            if !self.enter_array(*byte) {
+                *byte = b' ';
+                continue;
+            }
+            if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if *byte == b'\\' {
+                    self.escaped = true;
+                } else if *byte == b'"' {
+                    self.in_string = false;
+                }
+                continue;
+            }
+
+            match *byte {
+                b'"' => self.in_string = true,
+                b'[' | b'{' => {
+                    if self.depth == 0 && *byte == b'[' {
+                        *byte = b' ';
+                    }
+                    self.depth += 1;
+                }
+                b']' | b'}' => {
+                    self.depth = self.depth.saturating_sub(1);
+                    if self.depth == 0 && *byte == b']' {
+                        *byte = b' ';
+                    }
+                }
+                b',' if self.depth == 1 => *byte = b' ',
+                _ => {}
+            }
+        }
+        if self.prelude == Prelude::Array {
+            self.seen.lock().unwrap_or_else(|e| e.into_inner()).in_array = true;
+        }
+        Ok(n)
+    }
+}
+
+type Elements = StreamDeserializer<'static, IoRead<ArrayElements<BufReader<ChildStdout>>>, Issue>;
+
+/// Iterator over issues parsed incrementally from a running `bd list --json`
+///
+/// Dropping the stream early kills the `bd` process.
+pub struct IssueStream {
+    child: Child,
+    elements: Elements,
+    /// `bd` arguments, for error messages
+    command: String,
+    seen: Arc<Mutex<Seen>>,
+    /// Reads stderr while stdout streams, so a chatty `bd` can't fill the
+    /// pipe and block
+    stderr: Option<JoinHandle<String>>,
+    done: bool,
+}
+
+impl IssueStream {
+    pub(crate) fn new(mut child: Child, command: String) -> Result<Self> {
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| Error::CommandFailed("bd stdout was not captured".to_string()))?;
+        let stderr = child.stderr.take().map(|mut pipe| {
+            std::thread::spawn(move || {
+                let mut stderr = String::new();
+                let _ = pipe.read_to_string(&mut stderr);
+                stderr
+            })
+        });
+        let reader = ArrayElements::new(BufReader::new(stdout));
+        let seen = Arc::clone(&reader.seen);
+        Ok(Self {
+            child,
+            elements: serde_json::Deserializer::from_reader(reader).into_iter(),
+            command,
+            seen,
+            stderr,
+            done: false,
+        })
+    }
+
+    /// Quote the start of the output in an [`Error::UnexpectedOutput`]
+    fn unexpected(&self, source: serde_json::Error) -> Error {
+        let seen = self.seen.lock().unwrap_or_else(|e| e.into_inner());
+        let output = String::from_utf8_lossy(&seen.head);
+        unexpected_output(self.command.clone(), &output, source)
+    }
+
+    /// Wait for `bd` to exit, turning a failed exit into an error
+    fn finish(&mut self) -> Option<Result<Issue>> {
+        self.done = true;
+        let status = match self.child.wait() {
+            Ok(status) => status,
+            Err(e) => return Some(Err(e.into())),
+        };
+        if status.success() {
+            // Only text and no array: report it rather than an empty list
+            let no_array = {
+                let seen = self.seen.lock().unwrap_or_else(|e| e.into_inner());
+                !seen.in_array && !seen.head.iter().all(u8::is_ascii_whitespace)
+            };
+            if no_array {
+                let source = <serde_json::Error as serde::de::Error>::custom("no JSON array");
+                return Some(Err(self.unexpected(source)));
+            }
+            return None;
+        }
+
+        let stderr = self
+            .stderr
+            .take()
+            .and_then(|reader| reader.join().ok())
+            .unwrap_or_default();
+        if stderr.contains("not initialized") || stderr.contains("No .beads") {
+            return Some(Err(Error::NotInRepo));
+        }
+        Some(Err(Error::CommandFailed(stderr)))
+    }
+}
+
+impl Iterator for IssueStream {
+    type Item = Result<Issue>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.elements.next() {
+            Some(Ok(issue)) => Some(Ok(issue)),
+            Some(Err(e)) => {
+                self.done = true;
+                Some(Err(self.unexpected(e)))
+            }
+            None => self.finish(),
+        }
+    }
+}
+
+impl Drop for IssueStream {
+    fn drop(&mut self) {
+        // No-op if bd already exited; otherwise stop it writing to a closed pipe
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> Vec<String> {
+        let reader = ArrayElements::new(json.as_bytes());
+        serde_json::Deserializer::from_reader(reader)
+            .into_iter::<Issue>()
+            .map(|issue| issue.unwrap().id)
+            .collect()
+    }
+
+    #[test]
+    fn test_array_elements() {
+        let json = r#"[
+            {"id": "bd-1", "title": "Has [brackets], and \"quotes\"", "status": "open", "issue_type": "task", "labels": ["a", "b"]},
+            {"id": "bd-2", "title": "Second", "status": "closed", "issue_type": "bug", "dependencies": [{"id": "bd-1"}]}
+        ]"#;
+        assert_eq!(parse(json), vec!["bd-1", "bd-2"]);
+    }
+
+    #[test]
+    fn test_array_elements_empty() {
+        assert!(parse("[]\n").is_empty());
+    }
+
+    #[test]
+    #[cfg(not(feature = "strict-json"))]
+    fn test_array_elements_skips_leading_banner() {
+        let json = "[notice] bd 0.30 is available {see [changelog]}\nRun `bd upgrade`\n  [\n  {\"id\": \"bd-1\", \"title\": \"Test\", \"status\": \"open\", \"issue_type\": \"task\"}\n]\n";
+        assert_eq!(parse(json), vec!["bd-1"]);
+        assert!(parse("Update available\n[]").is_empty());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_issue_stream_drains_stderr() {
+        // More stderr than a pipe buffer holds, written before any stdout
+        let child = std::process::Command::new("sh")
+            .args([
+                "-c",
+                "head -c 300000 /dev/zero | tr '\\0' x >&2; echo '[{\"id\": \"bd-1\", \"title\": \"T\", \"status\": \"open\", \"issue_type\": \"task\"}]'",
+            ])
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .unwrap();
+        let stream = IssueStream::new(child, "list --json".to_string()).unwrap();
+        let ids: Vec<String> = stream.map(|issue| issue.unwrap().id).collect();
+        assert_eq!(ids, vec!["bd-1"]);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_issue_stream_quotes_unexpected_output() {
+        let child = std::process::Command::new("sh")
+            .args(["-c", "echo 'Error: database is locked'"])
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .unwrap();
+        let mut stream = IssueStream::new(child, "list --json".to_string()).unwrap();
+        match stream.next() {
+            Some(Err(Error::UnexpectedOutput {
+                command, snippet, ..
+            })) => {
+                assert_eq!(command, "list --json");
+                assert!(snippet.contains("database is locked"), "{}", snippet);
+            }
+            other => panic!("unexpected result: {:?}", other.map(|r| r.map(|i| i.id))),
+        }
+        assert!(stream.next().is_none());
+    }
+}
@@ -0,0 +1,100 @@
+//! `bd` version detection
+//!
+//! `bd` has changed its JSON output between releases (e.g. `type` became
+//! `issue_type`), so an untested version tends to fail later with a confusing
+//! parse error. The crate checks the version at most once per process, in
+//! [`Beads::new`] or when bd output fails to parse, and
+//! [`version_warning`] reports the verdict for the caller to print.
+
+use crate::{Beads, Error, Result};
+use semver::Version;
+use std::sync::OnceLock;
+
+/// Oldest `bd` release this crate is tested against
+pub const MIN_SUPPORTED_VERSION: Version = Version::new(0, 29, 0);
+
+/// `bd --version` output, or `None` if bd couldn't be run
+static VERSION_PROBE: OnceLock<Option<String>> = OnceLock::new();
+
+/// Parse the version out of `bd --version` output, e.g. `bd version 0.29.0 (dev)`
+pub fn parse_version(output: &str) -> Result<Version> {
+    output
+        .split_whitespace()
+        .find_map(|word| Version::parse(word.trim_start_matches('v')).ok())
+        .ok_or_else(|| {
+            Error::ParseError(format!(
+                "no version in `bd --version` output: {}",
+                output.trim()
+            ))
+        })
+}
+
+/// Whether `version` is at least [`MIN_SUPPORTED_VERSION`] and in the same major series
+pub fn is_supported_version(version: &Version) -> bool {
+    *version >= MIN_SUPPORTED_VERSION && version.major == MIN_SUPPORTED_VERSION.major
+}
+
+/// Warning text for an unsupported `version`, or `None` if it is supported
+pub fn unsupported_version_warning(version: &Version) -> Option<String> {
+    if is_supported_version(version) {
+        return None;
+    }
+    Some(format!(
+        "bd {} is outside the supported range (>= {}, < {}.0.0); \
+         commands may fail to parse its output",
+        version,
+        MIN_SUPPORTED_VERSION,
+        MIN_SUPPORTED_VERSION.major + 1
+    ))
+}
+
+/// Run `bd --version` the first time it is needed, returning the cached output
+pub(crate) fn probe_version(bd: &Beads) -> Option<&'static str> {
+    VERSION_PROBE
+        .get_or_init(|| bd.run(&["--version"]).ok().map(|output| output.stdout))
+        .as_deref()
+}
+
+/// Warning about the installed bd, if its version was checked and is unsupported
+///
+/// Never runs bd itself, so it is cheap to ask before exiting.
+pub fn version_warning() -> Option<String> {
+    let output = VERSION_PROBE.get()?.as_deref()?;
+    unsupported_version_warning(&parse_version(output).ok()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        let cases = [
+            ("bd version 0.29.0", "0.29.0"),
+            ("bd version 0.32.1 (dev)\n", "0.32.1"),
+            ("bd v1.2.3", "1.2.3"),
+            ("0.30.0-rc.1", "0.30.0-rc.1"),
+        ];
+        for (output, expected) in cases {
+            assert_eq!(
+                parse_version(output).unwrap(),
+                Version::parse(expected).unwrap()
+            );
+        }
+        assert!(parse_version("bd version dev").is_err());
+    }
+
+    #[test]
+    fn test_is_supported_version() {
+        let supported = |v: &str| is_supported_version(&Version::parse(v).unwrap());
+        assert!(supported("0.29.0"));
+        assert!(supported("0.47.2"));
+        assert!(!supported("0.28.9"));
+        assert!(!supported("0.29.0-rc.1"));
+        assert!(!supported("1.0.0"));
+
+        let warning = unsupported_version_warning(&Version::new(0, 28, 0)).unwrap();
+        assert!(warning.contains("bd 0.28.0"), "{}", warning);
+        assert!(unsupported_version_warning(&Version::new(0, 30, 0)).is_none());
+    }
+}
@@ -5,7 +5,10 @@ use crate::git::BossRepo;
 use crate::graph::{FederatedGraph, Rig, RigAuthStrategy};
 use crate::storage::JsonlReader;
 use crate::{AllBeadsError, Result};
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::Mutex as TokioMutex;
 
@@ -32,6 +35,13 @@ pub enum RefreshProgress {
     ClonedRepo { name: String },
     /// Failed to fetch/clone a repository (non-fatal with skip_errors)
     RepoError { name: String, error: String },
+    /// Loaded a repository's beads into the graph
+    LoadedContext {
+        name: String,
+        beads: usize,
+        index: usize,
+        total: usize,
+    },
     /// All refreshes complete
     Complete {
         succeeded: usize,
@@ -162,7 +172,11 @@ impl Aggregator {
             // Check if repo needs cloning (doesn't exist)
             let needs_clone = repo.status()? == crate::git::RepoStatus::NotCloned;
             if needs_clone {
-                eprintln!("  📦 Cloning {} from {}...", name, repo.context().url);
+                eprintln!(
+                    "  📦 Cloning {} from {}...",
+                    name,
+                    repo.context().clone_url()
+                );
             }
 
             if let Err(e) = repo.clone_if_needed() {
@@ -202,7 +216,13 @@ impl Aggregator {
         let mut errors = Vec::new();
 
         for (name, repo) in &mut self.repos {
-            if let Err(e) = repo.fetch() {
+            // Managed clones have no local edits, so bring the working tree up to date
+            let result = if repo.context().is_remote() {
+                repo.pull()
+            } else {
+                repo.fetch()
+            };
+            if let Err(e) = result {
                 let err_msg = format!("Failed to fetch {}: {}", name, e);
                 tracing::error!("{}", err_msg);
                 errors.push(err_msg);
@@ -337,7 +357,7 @@ impl Aggregator {
                             if let Some(ref cb) = *callback {
                                 cb(RefreshProgress::CloningRepo {
                                     name: name.clone(),
-                                    url: repo.context().url.clone(),
+                                    url: repo.context().clone_url().to_string(),
                                 });
                             }
                             repo.clone_if_needed()?;
@@ -346,8 +366,9 @@ impl Aggregator {
                             }
                         }
 
-                        // Fetch or pull
-                        if is_pull {
+                        // Fetch or pull. Managed clones of remote-only contexts are
+                        // always fast-forwarded so .beads/ on disk reflects the remote.
+                        if is_pull || repo.context().is_remote() {
                             repo.pull()?;
                         } else {
                             repo.fetch()?;
@@ -450,61 +471,14 @@ impl Aggregator {
     }
 
     /// Aggregate all Boss repositories into a FederatedGraph
-    pub fn aggregate(&mut self) -> Result<FederatedGraph> {
+    ///
+    /// With a `previous` graph (usually the expired cache), contexts whose
+    /// `issues.jsonl` is unchanged reuse its beads instead of being re-read.
+    pub fn aggregate(&mut self, previous: Option<&FederatedGraph>) -> Result<FederatedGraph> {
         // Ensure repos are synced
         self.sync_repos()?;
 
-        let mut graph = FederatedGraph::new();
-
-        // Load beads from each Boss repository
-        for (context_name, repo) in &self.repos {
-            if !repo.has_issues_jsonl() {
-                tracing::debug!(
-                    context = %context_name,
-                    "No issues.jsonl found, skipping"
-                );
-                continue;
-            }
-
-            tracing::info!(
-                context = %context_name,
-                path = %repo.issues_jsonl_path().display(),
-                "Loading beads from Boss repository"
-            );
-
-            // Read beads from issues.jsonl
-            let mut reader = JsonlReader::open(repo.issues_jsonl_path())?;
-            let beads: Vec<crate::graph::Bead> = reader.read_all()?;
-
-            tracing::debug!(
-                context = %context_name,
-                count = beads.len(),
-                "Loaded beads"
-            );
-
-            // Add beads to graph with context information
-            for bead in beads {
-                let mut bead = bead;
-                // Tag bead with context
-                let label = format!("@{}", context_name);
-                bead.add_label(label);
-
-                graph.add_bead(bead);
-            }
-
-            // Create a Rig for this Boss repository
-            let rig = Rig::builder()
-                .id(format!("boss-{}", context_name))
-                .path(repo.path().to_string_lossy().to_string())
-                .remote(repo.context().url.clone())
-                .auth_strategy(RigAuthStrategy::SshAgent) // TODO: Map from BossContext auth
-                .prefix("beads")
-                .context(context_name.clone())
-                .build()?;
-
-            // Add rig to graph
-            graph.add_rig(rig);
-        }
+        let graph = self.load_beads_with_progress(None, previous)?;
 
         tracing::info!(
             total_beads = graph.stats().total_beads,
@@ -521,28 +495,56 @@ impl Aggregator {
     ///
     /// # Arguments
     /// * `progress_callback` - Optional callback to receive progress updates during sync
+    /// * `previous` - Earlier graph whose beads are reused for unchanged contexts
     pub async fn aggregate_parallel<F>(
         &mut self,
         progress_callback: Option<F>,
+        previous: Option<&FederatedGraph>,
     ) -> Result<FederatedGraph>
     where
         F: Fn(RefreshProgress) + Send + Sync + 'static,
     {
+        // The callback is shared between the sync and load phases
+        let callback = progress_callback.map(Arc::new);
+        let sync_callback = callback
+            .clone()
+            .map(|cb| move |event: RefreshProgress| (*cb)(event));
+
         // Sync repos in parallel
-        self.sync_repos_parallel(progress_callback, None).await?;
+        self.sync_repos_parallel(sync_callback, None).await?;
 
         // Load beads (this part is fast, no need to parallelize)
-        self.load_beads_into_graph()
+        match callback {
+            Some(cb) => self.load_beads_with_progress(Some(&*cb), previous),
+            None => self.load_beads_with_progress(None, previous),
+        }
     }
 
     /// Load beads from all repos into a FederatedGraph (no sync)
     ///
     /// This is useful when you've already synced and just want to load.
     pub fn load_beads_into_graph(&self) -> Result<FederatedGraph> {
+        self.load_beads_with_progress(None, None)
+    }
+
+    /// Load beads from all repos, reporting each loaded context
+    ///
+    /// Emits [`RefreshProgress::LoadedContext`] as each repository finishes.
+    /// Contexts whose `issues.jsonl` hash matches `previous` take their beads
+    /// from it rather than parsing the file again.
+    pub fn load_beads_with_progress(
+        &self,
+        progress_callback: Option<&dyn Fn(RefreshProgress)>,
+        previous: Option<&FederatedGraph>,
+    ) -> Result<FederatedGraph> {
         let mut graph = FederatedGraph::new();
+        let total = self.repos.len();
 
-        // Load beads from each Boss repository
-        for (context_name, repo) in &self.repos {
+        // Load beads from each Boss repository, in name order so the same
+        // context wins every time two of them share a bead ID
+        let mut repos: Vec<_> = self.repos.iter().collect();
+        repos.sort_by(|a, b| a.0.cmp(b.0));
+        for (i, (context_name, repo)) in repos.into_iter().enumerate() {
             if !repo.has_issues_jsonl() {
                 tracing::debug!(
                     context = %context_name,
@@ -551,30 +553,54 @@ impl Aggregator {
                 continue;
             }
 
-            tracing::debug!(
-                context = %context_name,
-                path = %repo.issues_jsonl_path().display(),
-                "Loading beads from Boss repository"
-            );
+            let hash = content_hash(&repo.issues_jsonl_path())?;
+            let beads: Vec<crate::graph::Bead> =
+                match previous.filter(|p| p.content_hashes.get(context_name) == Some(&hash)) {
+                    Some(previous) => {
+                        tracing::debug!(
+                            context = %context_name,
+                            "issues.jsonl unchanged, reusing previous beads"
+                        );
+                        let label = format!("@{}", context_name);
+                        previous
+                            .beads
+                            .values()
+                            .filter(|b| b.labels.contains(&label))
+                            .cloned()
+                            .collect()
+                    }
+                    None => {
+                        tracing::debug!(
+                            context = %context_name,
+                            path = %repo.issues_jsonl_path().display(),
+                            "Loading beads from Boss repository"
+                        );
 
-            // Read beads from issues.jsonl
-            let mut reader = JsonlReader::open(repo.issues_jsonl_path())?;
-            let beads: Vec<crate::graph::Bead> = reader.read_all()?;
+                        // Read beads from issues.jsonl
+                        let mut reader = JsonlReader::open(repo.issues_jsonl_path())?;
+                        let beads: Vec<crate::graph::Bead> = reader.read_all()?;
 
-            tracing::debug!(
-                context = %context_name,
-                count = beads.len(),
-                "Loaded beads"
-            );
+                        tracing::debug!(
+                            context = %context_name,
+                            count = beads.len(),
+                            "Loaded beads"
+                        );
+                        beads
+                    }
+                };
+            graph.content_hashes.insert(context_name.clone(), hash);
 
             // Add beads to graph with context information
+            let loaded = beads.len();
             for bead in beads {
-                let mut bead = bead;
-                // Tag bead with context
-                let label = format!("@{}", context_name);
-                bead.add_label(label);
-
-                graph.add_bead(bead);
+                let id = bead.id.clone();
+                if !graph.add_context_bead(bead, context_name) {
+                    tracing::warn!(
+                        context = %context_name,
+                        id = %id,
+                        "Bead ID already loaded from another context, skipping"
+                    );
+                }
             }
 
             // Create a Rig for this Boss repository
@@ -589,6 +615,15 @@ impl Aggregator {
 
             // Add rig to graph
             graph.add_rig(rig);
+
+            if let Some(cb) = progress_callback {
+                cb(RefreshProgress::LoadedContext {
+                    name: context_name.clone(),
+                    beads: loaded,
+                    index: i + 1,
+                    total,
+                });
+            }
         }
 
         tracing::info!(
@@ -615,6 +650,17 @@ impl Aggregator {
     }
 }
 
+/// Hash of a context's issues.jsonl, to tell whether it changed since the last load
+///
+/// Uses std's hasher, which may change between Rust releases; that only costs
+/// one full re-read.
+fn content_hash(path: &Path) -> Result<String> {
+    let bytes = std::fs::read(path)?;
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    Ok(format!("{:016x}", hasher.finish()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -673,4 +719,47 @@ mod tests {
         assert_eq!(SyncMode::LocalOnly, SyncMode::LocalOnly);
         assert_ne!(SyncMode::LocalOnly, SyncMode::Fetch);
     }
+
+    #[test]
+    fn test_incremental_load_reuses_unchanged_contexts() {
+        use crate::graph::{Bead, BeadId};
+
+        let dir = tempfile::tempdir().unwrap();
+        git2::Repository::init(dir.path()).unwrap();
+        std::fs::create_dir(dir.path().join(".beads")).unwrap();
+        let issues = dir.path().join(".beads/issues.jsonl");
+        crate::storage::write_beads(&issues, &[Bead::new("ab-1", "From file", "alice")]).unwrap();
+
+        let mut config = AllBeadsConfig::new();
+        config.add_context(
+            BossContext::new("work", "", AuthStrategy::SshAgent).with_path(dir.path()),
+        );
+        let agg_config = AggregatorConfig {
+            sync_mode: SyncMode::LocalOnly,
+            ..Default::default()
+        };
+        let aggregator = Aggregator::new(config, agg_config).unwrap();
+
+        let mut previous = aggregator.load_beads_into_graph().unwrap();
+        assert!(previous.content_hashes.contains_key("work"));
+        let id = BeadId::new("ab-1");
+        previous.beads.get_mut(&id).unwrap().title = "From cache".to_string();
+
+        // Unchanged file: beads come from the previous graph
+        let graph = aggregator
+            .load_beads_with_progress(None, Some(&previous))
+            .unwrap();
+        assert_eq!(graph.get_bead(&id).unwrap().title, "From cache");
+
+        // Changed file: beads are read again
+        crate::storage::write_beads(&issues, &[Bead::new("ab-1", "Edited", "alice")]).unwrap();
+        let graph = aggregator
+            .load_beads_with_progress(None, Some(&previous))
+            .unwrap();
+        assert_eq!(graph.get_bead(&id).unwrap().title, "Edited");
+        assert_ne!(
+            graph.content_hashes["work"],
+            previous.content_hashes["work"]
+        );
+    }
 }
@@ -2,11 +2,22 @@
 
 use crate::graph::{Bead, BeadId, FederatedGraph, Priority, Rig, Status};
 use crate::Result;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use rusqlite::types::{Type, Value, ValueRef};
 use rusqlite::{params, Connection, OptionalExtension};
 use std::collections::HashMap;
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+/// Version of the cache schema
+///
+/// Bump this whenever the tables change. A cache written by a different
+/// version is discarded and rebuilt from the contexts on next load.
+pub const CACHE_VERSION: u32 = 4;
+
 /// Cache configuration
 #[derive(Debug, Clone)]
 pub struct CacheConfig {
@@ -18,6 +29,12 @@ pub struct CacheConfig {
 
     /// Enable WAL mode for better concurrency
     pub wal_mode: bool,
+
+    /// Gzip large text fields (descriptions, notes) on disk
+    ///
+    /// Fields are tagged by their gzip magic bytes, so caches written with
+    /// either setting load correctly.
+    pub compress: bool,
 }
 
 impl Default for CacheConfig {
@@ -32,6 +49,7 @@ impl Default for CacheConfig {
             path,
             ttl: Duration::from_secs(300), // 5 minutes
             wal_mode: true,
+            compress: true,
         }
     }
 }
@@ -62,12 +80,52 @@ impl Cache {
         // Create cache instance
         let cache = Self { conn, config };
 
-        // Initialize schema
+        // Discard caches from other versions, then initialize schema
+        cache.migrate()?;
         cache.init_schema()?;
 
         Ok(cache)
     }
 
+    /// Drop tables written by a different cache version so they are rebuilt
+    fn migrate(&self) -> Result<()> {
+        let version = self.stored_version();
+        if version == Some(CACHE_VERSION) {
+            return Ok(());
+        }
+
+        tracing::debug!(
+            found = ?version,
+            expected = CACHE_VERSION,
+            "Cache version mismatch, discarding cached data"
+        );
+        self.conn.execute_batch(
+            r#"
+            DROP TABLE IF EXISTS dependencies;
+            DROP TABLE IF EXISTS blocks;
+            DROP TABLE IF EXISTS parents;
+            DROP TABLE IF EXISTS beads;
+            DROP TABLE IF EXISTS rigs;
+            DROP TABLE IF EXISTS content_hashes;
+            DROP TABLE IF EXISTS cache_metadata;
+            "#,
+        )?;
+
+        Ok(())
+    }
+
+    /// Cache version recorded in the database, if any
+    fn stored_version(&self) -> Option<u32> {
+        self.conn
+            .query_row(
+                "SELECT value FROM cache_metadata WHERE key = 'version'",
+                [],
+                |row| row.get::<_, String>(0),
+            )
+            .ok()
+            .and_then(|v| v.parse().ok())
+    }
+
     /// Initialize database schema
     fn init_schema(&self) -> Result<()> {
         self.conn.execute_batch(
@@ -91,6 +149,8 @@ impl Cache {
                 assignee TEXT,
                 labels TEXT NOT NULL,
                 notes TEXT,
+                aiki_tasks TEXT NOT NULL,
+                handoff TEXT,
                 context TEXT NOT NULL,
                 cached_at INTEGER NOT NULL
             );
@@ -109,6 +169,12 @@ impl Cache {
                 FOREIGN KEY (bead_id) REFERENCES beads(id) ON DELETE CASCADE
             );
 
+            CREATE TABLE IF NOT EXISTS parents (
+                bead_id TEXT PRIMARY KEY,
+                parent_id TEXT NOT NULL,
+                FOREIGN KEY (bead_id) REFERENCES beads(id) ON DELETE CASCADE
+            );
+
             CREATE TABLE IF NOT EXISTS rigs (
                 id TEXT PRIMARY KEY,
                 path TEXT NOT NULL,
@@ -119,6 +185,11 @@ impl Cache {
                 cached_at INTEGER NOT NULL
             );
 
+            CREATE TABLE IF NOT EXISTS content_hashes (
+                context TEXT PRIMARY KEY,
+                hash TEXT NOT NULL
+            );
+
             CREATE INDEX IF NOT EXISTS idx_beads_status ON beads(status);
             CREATE INDEX IF NOT EXISTS idx_beads_context ON beads(context);
             CREATE INDEX IF NOT EXISTS idx_beads_priority ON beads(priority);
@@ -127,6 +198,11 @@ impl Cache {
             "#,
         )?;
 
+        self.conn.execute(
+            "INSERT OR IGNORE INTO cache_metadata (key, value, updated_at) VALUES ('version', ?, 0)",
+            params![CACHE_VERSION.to_string()],
+        )?;
+
         Ok(())
     }
 
@@ -145,8 +221,10 @@ impl Cache {
         // Clear existing data
         tx.execute("DELETE FROM dependencies", [])?;
         tx.execute("DELETE FROM blocks", [])?;
+        tx.execute("DELETE FROM parents", [])?;
         tx.execute("DELETE FROM beads", [])?;
         tx.execute("DELETE FROM rigs", [])?;
+        tx.execute("DELETE FROM content_hashes", [])?;
 
         // Store all beads
         for bead in graph.beads.values() {
@@ -158,6 +236,14 @@ impl Cache {
             self.store_rig_tx(&tx, rig, now)?;
         }
 
+        // Store issues.jsonl hashes so the next refresh can skip unchanged contexts
+        for (context, hash) in &graph.content_hashes {
+            tx.execute(
+                "INSERT INTO content_hashes (context, hash) VALUES (?, ?)",
+                params![context, hash],
+            )?;
+        }
+
         // Update metadata
         tx.execute(
             "INSERT OR REPLACE INTO cache_metadata (key, value, updated_at) VALUES (?, ?, ?)",
@@ -188,19 +274,26 @@ impl Cache {
             .collect::<Vec<_>>()
             .join(",");
 
+        // Handoff info is stored as JSON
+        let handoff = bead
+            .handoff
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()?;
+
         // Insert bead
         tx.execute(
             r#"
             INSERT INTO beads (
                 id, title, description, status, priority, issue_type,
                 created_at, updated_at, created_by, assignee,
-                labels, notes, context, cached_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                labels, notes, aiki_tasks, handoff, context, cached_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
             params![
                 bead.id.as_str(),
                 &bead.title,
-                bead.description.as_deref(),
+                self.encode_text(bead.description.as_deref())?,
                 status_to_str(bead.status),
                 priority_to_int(bead.priority),
                 issue_type_to_str(bead.issue_type),
@@ -209,7 +302,9 @@ impl Cache {
                 &bead.created_by,
                 bead.assignee.as_deref(),
                 labels_str,
-                bead.notes.as_deref(),
+                self.encode_text(bead.notes.as_deref())?,
+                bead.aiki_tasks.join(","),
+                handoff,
                 context,
                 timestamp,
             ],
@@ -231,9 +326,31 @@ impl Cache {
             )?;
         }
 
+        // Store parent relationship
+        if let Some(parent) = &bead.parent {
+            tx.execute(
+                "INSERT INTO parents (bead_id, parent_id) VALUES (?, ?)",
+                params![bead.id.as_str(), parent.as_str()],
+            )?;
+        }
+
         Ok(())
     }
 
+    /// Encode a text field for storage, gzipping it when worthwhile
+    fn encode_text(&self, text: Option<&str>) -> Result<Value> {
+        let Some(text) = text else {
+            return Ok(Value::Null);
+        };
+        if !self.config.compress || text.len() < COMPRESS_MIN_LEN {
+            return Ok(Value::Text(text.to_string()));
+        }
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+        encoder.write_all(text.as_bytes())?;
+        Ok(Value::Blob(encoder.finish()?))
+    }
+
     /// Store a single rig within a transaction
     fn store_rig_tx(&self, tx: &Connection, rig: &Rig, timestamp: i64) -> Result<()> {
         tx.execute(
@@ -268,6 +385,26 @@ impl Cache {
             return Ok(None);
         }
 
+        self.load_previous()
+    }
+
+    /// Load the cached graph even if it has expired
+    ///
+    /// Used as the starting point for an incremental refresh: contexts whose
+    /// `issues.jsonl` hash still matches [`FederatedGraph::content_hashes`]
+    /// reuse these beads instead of being parsed again.
+    pub fn load_previous(&self) -> Result<Option<FederatedGraph>> {
+        // Another version may have rewritten the cache since it was opened
+        let version = self.stored_version();
+        if version != Some(CACHE_VERSION) {
+            tracing::debug!(
+                found = ?version,
+                expected = CACHE_VERSION,
+                "Cache version mismatch"
+            );
+            return Ok(None);
+        }
+
         tracing::debug!("Loading graph from cache");
 
         // Step 1: Load all beads in one query
@@ -277,7 +414,7 @@ impl Cache {
             r#"
             SELECT id, title, description, status, priority, issue_type,
                    created_at, updated_at, created_by, assignee,
-                   labels, notes
+                   labels, notes, aiki_tasks, handoff
             FROM beads
             "#,
         )?;
@@ -290,11 +427,19 @@ impl Cache {
                 .filter(|s| !s.is_empty())
                 .map(|s| s.to_string())
                 .collect();
+            let aiki_tasks: String = row.get(12)?;
+            let handoff = row
+                .get::<_, Option<String>>(13)?
+                .map(|json| serde_json::from_str(&json))
+                .transpose()
+                .map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(13, Type::Text, Box::new(e))
+                })?;
 
             Ok(Bead {
                 id: BeadId::new(id),
                 title: row.get(1)?,
-                description: row.get(2)?,
+                description: decode_text(row.get_ref(2)?, 2)?,
                 status: str_to_status(row.get::<_, String>(3)?.as_str()),
                 priority: int_to_priority(row.get(4)?),
                 issue_type: str_to_issue_type(row.get::<_, String>(5)?.as_str()),
@@ -303,11 +448,16 @@ impl Cache {
                 created_by: row.get(8)?,
                 assignee: row.get(9)?,
                 labels,
-                notes: row.get(11)?,
+                notes: decode_text(row.get_ref(11)?, 11)?,
                 dependencies: Vec::new(),
+                parent: None,
                 blocks: Vec::new(),
-                aiki_tasks: Vec::new(),
-                handoff: None,
+                aiki_tasks: aiki_tasks
+                    .split(',')
+                    .filter(|s| !s.is_empty())
+                    .map(|s| s.to_string())
+                    .collect(),
+                handoff,
             })
         })?;
 
@@ -344,12 +494,34 @@ impl Cache {
             }
         }
 
-        // Step 4: Build the graph
+        // Step 4: Load all parent relationships in one query
+        let mut parents_stmt = self
+            .conn
+            .prepare("SELECT bead_id, parent_id FROM parents")?;
+        let parents = parents_stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+
+        for parent_result in parents {
+            let (bead_id, parent_id) = parent_result?;
+            if let Some(bead) = beads_map.get_mut(&bead_id) {
+                bead.parent = Some(BeadId::new(parent_id));
+            }
+        }
+
+        // Step 5: Build the graph
         let mut graph = FederatedGraph::new();
         for bead in beads_map.into_values() {
             graph.add_bead(bead);
         }
 
+        let mut hashes_stmt = self
+            .conn
+            .prepare("SELECT context, hash FROM content_hashes")?;
+        graph.content_hashes = hashes_stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<rusqlite::Result<_>>()?;
+
         tracing::info!(beads = graph.stats().total_beads, "Graph loaded from cache");
         Ok(Some(graph))
     }
@@ -385,9 +557,12 @@ impl Cache {
 
         self.conn.execute("DELETE FROM dependencies", [])?;
         self.conn.execute("DELETE FROM blocks", [])?;
+        self.conn.execute("DELETE FROM parents", [])?;
         self.conn.execute("DELETE FROM beads", [])?;
         self.conn.execute("DELETE FROM rigs", [])?;
-        self.conn.execute("DELETE FROM cache_metadata", [])?;
+        self.conn.execute("DELETE FROM content_hashes", [])?;
+        self.conn
+            .execute("DELETE FROM cache_metadata WHERE key != 'version'", [])?;
 
         Ok(())
     }
@@ -447,6 +622,35 @@ pub struct CacheStats {
 
 // Helper functions for type conversions
 
+/// Leading bytes of every gzip stream
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Text shorter than this is stored as-is; gzip overhead would outweigh savings
+const COMPRESS_MIN_LEN: usize = 256;
+
+/// Decode a text field written by [`Cache::encode_text`] (or by older caches)
+fn decode_text(value: ValueRef<'_>, column: usize) -> rusqlite::Result<Option<String>> {
+    let conversion_err = |e: std::io::Error| {
+        rusqlite::Error::FromSqlConversionFailure(column, Type::Blob, Box::new(e))
+    };
+
+    match value {
+        ValueRef::Null => Ok(None),
+        ValueRef::Blob(bytes) if bytes.starts_with(&GZIP_MAGIC) => {
+            let mut text = String::new();
+            GzDecoder::new(bytes)
+                .read_to_string(&mut text)
+                .map_err(conversion_err)?;
+            Ok(Some(text))
+        }
+        ValueRef::Text(bytes) | ValueRef::Blob(bytes) => {
+            Ok(Some(String::from_utf8_lossy(bytes).into_owned()))
+        }
+        ValueRef::Integer(i) => Ok(Some(i.to_string())),
+        ValueRef::Real(f) => Ok(Some(f.to_string())),
+    }
+}
+
 fn status_to_str(status: Status) -> &'static str {
     match status {
         Status::Open => "open",
@@ -552,6 +756,7 @@ mod tests {
         bead1.add_label("@work");
         let mut bead2 = Bead::new("ab-456", "Test Issue 2", "bob");
         bead2.add_label("@personal");
+        bead2.parent = Some(BeadId::new("ab-123"));
 
         graph.add_bead(bead1);
         graph.add_bead(bead2);
@@ -565,6 +770,71 @@ mod tests {
 
         let loaded_graph = loaded.unwrap();
         assert_eq!(loaded_graph.stats().total_beads, 2);
+        assert_eq!(
+            loaded_graph
+                .get_bead(&BeadId::new("ab-456"))
+                .unwrap()
+                .parent,
+            Some(BeadId::new("ab-123"))
+        );
+    }
+
+    #[test]
+    fn test_cache_keeps_aiki_tasks_and_handoff() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let config = CacheConfig {
+            path: temp_file.path().to_path_buf(),
+            ttl: Duration::from_secs(3600),
+            ..Default::default()
+        };
+        let cache = Cache::new(config).unwrap();
+
+        let mut graph = FederatedGraph::new();
+        let mut bead = Bead::new("ab-1", "Test", "alice");
+        bead.add_aiki_task("task-1");
+        bead.add_aiki_task("task-2");
+        bead.handoff = Some(crate::handoff::AgentHandoff::cli(
+            crate::handoff::AgentType::Claude,
+            PathBuf::from("/tmp/work"),
+        ));
+        graph.add_context_bead(bead, "work");
+        graph.add_context_bead(Bead::new("ab-2", "Plain", "bob"), "work");
+        cache.store_graph(&graph).unwrap();
+
+        let loaded = cache.load_previous().unwrap().unwrap();
+        let bead = loaded.get_bead(&BeadId::new("ab-1")).unwrap();
+        assert_eq!(bead.aiki_tasks, vec!["task-1", "task-2"]);
+        let handoff = bead.handoff.as_ref().unwrap();
+        assert_eq!(handoff.agent_type, crate::handoff::AgentType::Claude);
+        assert_eq!(handoff.workdir, Some(PathBuf::from("/tmp/work")));
+
+        let plain = loaded.get_bead(&BeadId::new("ab-2")).unwrap();
+        assert!(plain.aiki_tasks.is_empty());
+        assert!(plain.handoff.is_none());
+    }
+
+    #[test]
+    fn test_load_previous_ignores_expiry() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let config = CacheConfig {
+            path: temp_file.path().to_path_buf(),
+            ttl: Duration::from_millis(50),
+            ..Default::default()
+        };
+        let cache = Cache::new(config).unwrap();
+
+        let mut graph = FederatedGraph::new();
+        graph.add_context_bead(Bead::new("ab-1", "Test", "alice"), "work");
+        graph
+            .content_hashes
+            .insert("work".to_string(), "abc123".to_string());
+        cache.store_graph(&graph).unwrap();
+
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(cache.load_graph().unwrap().is_none());
+        let previous = cache.load_previous().unwrap().unwrap();
+        assert_eq!(previous.stats().total_beads, 1);
+        assert_eq!(previous.content_hashes["work"], "abc123");
     }
 
     #[test]
@@ -619,4 +889,89 @@ mod tests {
         let stats = cache.stats().unwrap();
         assert_eq!(stats.bead_count, 0);
     }
+
+    #[test]
+    fn test_cache_compression() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let config = CacheConfig {
+            path: temp_file.path().to_path_buf(),
+            ttl: Duration::from_secs(3600),
+            ..Default::default()
+        };
+        let long_text = "a long description ".repeat(100);
+
+        let mut graph = FederatedGraph::new();
+        let mut bead = Bead::new("ab-1", "Big", "alice");
+        bead.description = Some(long_text.clone());
+        bead.notes = Some("short".to_string());
+        graph.add_bead(bead);
+
+        // Written compressed, then read back by an uncompressed cache
+        let cache = Cache::new(config.clone()).unwrap();
+        cache.store_graph(&graph).unwrap();
+        let stored: Vec<u8> = cache
+            .conn
+            .query_row("SELECT description FROM beads", [], |row| row.get(0))
+            .unwrap();
+        assert!(stored.starts_with(&GZIP_MAGIC));
+        assert!(stored.len() < long_text.len());
+        drop(cache);
+
+        let cache = Cache::new(CacheConfig {
+            compress: false,
+            ..config
+        })
+        .unwrap();
+        let loaded = cache.load_graph().unwrap().unwrap();
+        let bead = loaded.get_bead(&BeadId::new("ab-1")).unwrap();
+        assert_eq!(bead.description.as_deref(), Some(long_text.as_str()));
+        assert_eq!(bead.notes.as_deref(), Some("short"));
+
+        // Plain text written without compression still loads
+        cache.store_graph(&graph).unwrap();
+        let loaded = cache.load_graph().unwrap().unwrap();
+        let bead = loaded.get_bead(&BeadId::new("ab-1")).unwrap();
+        assert_eq!(bead.description.as_deref(), Some(long_text.as_str()));
+    }
+
+    #[test]
+    fn test_cache_version_mismatch() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let config = CacheConfig {
+            path: temp_file.path().to_path_buf(),
+            ttl: Duration::from_secs(3600),
+            ..Default::default()
+        };
+
+        // An unversioned cache with an incompatible schema is discarded on open
+        {
+            let conn = Connection::open(&config.path).unwrap();
+            conn.execute_batch(
+                "CREATE TABLE beads (id TEXT PRIMARY KEY, data BLOB);
+                 INSERT INTO beads VALUES ('ab-1', x'00');",
+            )
+            .unwrap();
+        }
+        let cache = Cache::new(config.clone()).unwrap();
+        assert_eq!(cache.stats().unwrap().bead_count, 0);
+
+        let mut graph = FederatedGraph::new();
+        graph.add_bead(Bead::new("ab-123", "Test", "alice"));
+        cache.store_graph(&graph).unwrap();
+        assert!(cache.load_graph().unwrap().is_some());
+
+        // A cache stamped by another version loads as empty, not as an error
+        cache
+            .conn
+            .execute(
+                "UPDATE cache_metadata SET value = '999' WHERE key = 'version'",
+                [],
+            )
+            .unwrap();
+        assert!(cache.load_graph().unwrap().is_none());
+        drop(cache);
+
+        let cache = Cache::new(config).unwrap();
+        assert_eq!(cache.stats().unwrap().bead_count, 0);
+    }
 }
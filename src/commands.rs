@@ -2,6 +2,8 @@
 //!
 //! All CLI structs and subcommand enums are defined here.
 
+use allbeads::graph::LinkType;
+use allbeads::style::ColorChoice;
 use clap::{Parser, Subcommand, ValueEnum};
 
 /// Output format for scan results
@@ -29,11 +31,53 @@ impl std::fmt::Display for OutputFormat {
     }
 }
 
+/// Output format for bead exports
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ExportFormat {
+    /// Comma-separated values
+    Csv,
+    /// Markdown tables grouped by context
+    #[value(alias = "md")]
+    Markdown,
+}
+
+/// Output format for `ab show`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ShowFormat {
+    /// Colored terminal output
+    Text,
+    /// Markdown section, e.g. for pasting into a PR description
+    #[value(alias = "md")]
+    Markdown,
+    /// The bead as JSON
+    Json,
+}
+
+/// Input format for bead imports
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ImportFormat {
+    /// One bd issue JSON object per line
+    Jsonl,
+}
+
+/// Type to print a JSON Schema for (`ab schema`)
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum SchemaType {
+    /// A bead as aggregated by AllBeads (`ab list --json`, `ab show --json`)
+    #[default]
+    Bead,
+    /// An issue as reported by bd (`bd show --json`)
+    Issue,
+}
+
 /// Generate the custom help output matching bd's style
 pub fn custom_help() -> String {
     // ANSI codes for cyan (like bd uses)
-    let cyan = "\x1b[36m";
-    let reset = "\x1b[0m";
+    let (cyan, reset) = if allbeads::style::color_enabled() {
+        ("\x1b[36m", "\x1b[0m")
+    } else {
+        ("", "")
+    };
 
     format!(
         r#"Multi-context bead aggregator and orchestrator
@@ -45,31 +89,50 @@ Usage:
 {cyan}Aggregation:{reset}
   list               List beads with optional filters
   show               Show detailed information about a bead
+  tree               Show the dependency tree of a bead
   ready              Show beads that are ready to work on (no blockers)
+  next               Pick the highest-value ready bead to work on
   blocked            Show all blocked beads
-  open               Open a bead or external issue (JIRA/GitHub) in browser
+  open               Open a bead's file:line in $EDITOR, or a JIRA/GitHub issue in browser
   search             Search beads by text (title, description, notes)
-  duplicates         Find potential duplicate beads
+  duplicates         Find potential duplicate beads (merge to resolve)
   stats              Show aggregated statistics
+  summary            Ready/blocked counts per context (--oneline for shell prompts)
+  export             Export beads to CSV or Markdown
+  import             Import beads from a JSON Lines file into a context
+  stale              Show open beads not updated recently
+  workload           Show active beads per assignee
+  activity           Show the bd activity log (--all merges every context)
+  history            Show a bead's status/priority/assignee changes from git history
+  watch              Wait until a bead reaches a status (for supervising scripts)
+  doctor             Check bd, config, contexts and cache
 
 {cyan}Wrapper Commands:{reset}
   create             Create a bead in a specific context (delegates to bd)
+  clone              Create a bead by copying an existing one
   update             Update a bead (delegates to bd in the bead's context)
   close              Close bead(s) (delegates to bd in the bead's context)
   reopen             Reopen closed bead(s)
+  reassign           Move all of one assignee's open beads to another
+  move               Move a bead under a different parent epic
   dep                Manage dependencies (add/remove)
+  link               Link related beads without blocking (relates-to, ...)
   label              Manage labels (add/remove/list)
   comments           Manage comments (list/add)
   q                  Quick capture - create and output only ID
-  epic               Epic management (list/create/show)
+  epic               Epic management (list/create/show/stats)
   edit               Edit a bead in $EDITOR
+  triage             Walk untriaged beads and set priority, assignee, labels
   delete             Delete bead(s)
   duplicate          Mark a bead as duplicate of another
+  undo               Undo the last close, reopen, label, update or rename-prefix
 
 {cyan}Context Management:{reset}
   init               Initialize AllBeads configuration or clone a remote repo
   setup              Setup wizard for configuration
   quickstart         Quickstart guide for AllBeads
+  completion         Generate shell completions (bash, zsh, fish)
+  schema             Print the JSON Schema for bead or issue JSON output
   context            Manage contexts (Boss repositories)
                        - new: Create a new GitHub repo with AllBeads configured
                        - onboarding: Track repo adoption and onboarding status
@@ -86,6 +149,7 @@ Usage:
 {cyan}Daemon & Sync:{reset}
   sync               Sync AllBeads state (config and/or context beads)
   sheriff            Run the Sheriff daemon (background sync)
+  manifest           Generate and validate manifests for the Sheriff
   mail               Agent Mail commands
 
 {cyan}Agent Support:{reset}
@@ -126,11 +190,13 @@ Flags:
   -c, --config string        Path to config file (default: ~/.config/allbeads/config.yaml)
   -C, --contexts string      Filter to specific contexts (comma-separated)
       --cached               Use cached data only (don't fetch updates)
+      --here                 Run against this repo's beads only (like bd)
 
 {cyan}Output Control:{reset}
       --json                 Output in JSON format
-  -q, --quiet                Suppress non-essential output (errors only)
-  -v, --verbose              Enable verbose/debug output
+  -q, --quiet                Suppress non-essential output (results and errors only)
+  -v, --verbose              Enable verbose output (-vv for debug logging)
+      --color string         Colorize output: auto, always, never (default auto)
 
 {cyan}Database/Storage:{reset}
       --db string            Database path (default: auto-discover .beads/*.db)
@@ -174,6 +240,12 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub cached: bool,
 
+    /// Run against the current directory's beads only (no config, no aggregation)
+    ///
+    /// Changes made with --here are not recorded for `ab undo`.
+    #[arg(long, global = true)]
+    pub here: bool,
+
     // =========================================================================
     // OUTPUT CONTROL FLAGS (bd-compatible)
     // =========================================================================
@@ -181,13 +253,17 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub json: bool,
 
-    /// Suppress non-essential output (errors only)
-    #[arg(short, long, global = true)]
+    /// Suppress non-essential output (command results and errors only)
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
     pub quiet: bool,
 
-    /// Enable verbose/debug output
-    #[arg(short, long, global = true)]
-    pub verbose: bool,
+    /// Enable verbose output (-v for info logging, -vv for debug)
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+
+    /// When to use colors: auto (terminal without NO_COLOR), always, never
+    #[arg(long, global = true, value_enum, default_value_t = ColorChoice::Auto)]
+    pub color: ColorChoice,
 
     // =========================================================================
     // DATABASE/STORAGE FLAGS (bd-compatible)
@@ -259,7 +335,7 @@ impl Cli {
         if self.quiet {
             flags.push("--quiet".to_string());
         }
-        if self.verbose {
+        if self.verbose > 0 {
             flags.push("--verbose".to_string());
         }
 
@@ -325,6 +401,10 @@ pub enum Commands {
         #[arg(long)]
         context: Option<String>,
 
+        /// Filter to contexts in a named group (e.g., backend)
+        #[arg(short, long)]
+        group: Option<String>,
+
         /// Filter by label/tag
         #[arg(short, long)]
         label: Option<String>,
@@ -337,6 +417,10 @@ pub enum Commands {
         #[arg(short, long)]
         assignee: Option<String>,
 
+        /// Only show children of this epic
+        #[arg(long)]
+        parent: Option<String>,
+
         /// Show only ready issues (no blockers, not closed)
         #[arg(long)]
         ready: bool,
@@ -345,13 +429,32 @@ pub enum Commands {
         #[arg(long)]
         all: bool,
 
+        /// Only show beads updated since a duration (24h, 7d, 2w) or date (YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Sort by field: priority, created, updated, status, id, title, type (default: priority)
+        #[arg(long, conflicts_with = "local")]
+        sort: Option<String>,
+
+        /// Reverse sort order
+        #[arg(short = 'r', long, conflicts_with = "local")]
+        reverse: bool,
+
         /// Limit number of results (default: 50)
         #[arg(short = 'n', long, default_value = "50")]
         limit: usize,
 
         /// Only show beads from current directory (skip aggregation)
+        ///
+        /// Filters are passed to bd list where it supports them, so
+        /// --assignee matches the assignee exactly, as bd does.
         #[arg(long)]
         local: bool,
+
+        /// Stable tab-separated output for scripts: id, status, priority, type, title
+        #[arg(long, conflicts_with = "local")]
+        porcelain: bool,
     },
 
     /// Show detailed information about a bead
@@ -366,15 +469,78 @@ pub enum Commands {
         /// Show linked Aiki tasks
         #[arg(long)]
         tasks: bool,
+
+        /// Output format (text, markdown, json); --provenance and --tasks are text only
+        #[arg(long, value_enum, default_value = "text")]
+        format: ShowFormat,
+    },
+
+    /// Show the transitive dependency tree of a bead
+    Tree {
+        /// Bead ID (e.g., ab-123)
+        id: String,
+
+        /// Walk upward through the beads this one is blocking
+        #[arg(long)]
+        up: bool,
     },
 
     /// Show beads that are ready to work on (no blockers)
-    Ready,
+    Ready {
+        /// Stable tab-separated output for scripts: id, status, priority, type, title
+        #[arg(long)]
+        porcelain: bool,
+
+        /// Only ready beads nothing else waits on (quick wins)
+        #[arg(long, conflicts_with = "unblockers")]
+        leaves: bool,
+
+        /// Only ready beads that unblock other work, most unblocked first
+        #[arg(long)]
+        unblockers: bool,
+    },
+
+    /// Walk untriaged beads one at a time, setting priority, assignee and labels
+    ///
+    /// Untriaged means open, unassigned and still at the default priority (P2).
+    /// Keys: 0-4 set priority and move on, a assign, l add label,
+    /// n/Enter skip, q/Esc quit.
+    Triage {
+        /// Only triage beads in this context
+        #[arg(long)]
+        context: Option<String>,
+
+        /// Walk every open bead, not just untriaged ones
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// Pick the single highest-value ready bead (priority, then beads it unblocks)
+    Next {
+        /// Only consider beads in this context
+        #[arg(long)]
+        context: Option<String>,
+
+        /// Print the bead's full detail
+        #[arg(long)]
+        detail: bool,
+
+        /// Print the handoff prompt for the bead
+        #[arg(long)]
+        prompt: bool,
+    },
 
     /// Show all blocked beads
-    Blocked,
+    Blocked {
+        /// Stable tab-separated output for scripts: id, status, priority, type, title
+        #[arg(long)]
+        porcelain: bool,
+    },
 
-    /// Open a bead or linked external issue in the browser
+    /// Open a bead's file:line in $EDITOR, or an external issue in the browser
+    ///
+    /// Bead IDs open the first `file:line` in the description or notes (as
+    /// written by the janitor), falling back to editing the bead.
     Open {
         /// Bead ID (e.g., ab-123) or external issue reference (PROJ-123, owner/repo#123)
         id: String,
@@ -409,32 +575,59 @@ pub enum Commands {
         #[arg(short = 'l', long)]
         label: Option<Vec<String>>,
 
-        /// Filter by assignee
+        /// Filter by assignee ("me" for your git user.email)
         #[arg(short = 'a', long)]
         assignee: Option<String>,
 
-        /// Sort by field: priority, created, updated, status, id, title, type
-        #[arg(long, default_value = "priority")]
-        sort: String,
+        /// Sort by field: priority, created, updated, status, id, title, type (default: priority)
+        #[arg(long)]
+        sort: Option<String>,
 
         /// Reverse sort order
         #[arg(short = 'r', long)]
         reverse: bool,
 
         /// Limit results (default: 50)
-        #[arg(short = 'n', long, default_value = "50")]
-        limit: usize,
+        #[arg(short = 'n', long)]
+        limit: Option<usize>,
+
+        /// Fuzzy-match the query against title, ID and description, ranked by score
+        #[arg(long)]
+        fuzzy: bool,
+
+        /// Save this query and its filters under NAME instead of searching
+        #[arg(long, value_name = "NAME", conflicts_with_all = ["load", "list_saved"])]
+        save: Option<String>,
+
+        /// Run a saved search; filters given on the command line take precedence
+        #[arg(long, value_name = "NAME", conflicts_with = "list_saved")]
+        load: Option<String>,
+
+        /// List saved searches
+        #[arg(long)]
+        list_saved: bool,
     },
 
     /// Find potential duplicate beads
     Duplicates {
-        /// Similarity threshold (0.0-1.0, default: 0.8)
-        #[arg(short, long, default_value = "0.8")]
-        threshold: f64,
+        /// Similarity threshold (0.0-1.0, default: duplicates.threshold in config, else 0.8)
+        #[arg(short, long)]
+        threshold: Option<f64>,
+
+        /// Skip beads with titles shorter than this (default: duplicates.min_title_len in config)
+        #[arg(long)]
+        min_title_len: Option<usize>,
 
         /// Include closed beads (default: open only)
         #[arg(long)]
         include_closed: bool,
+
+        /// Save --threshold and --min-title-len as the config defaults
+        #[arg(long)]
+        save_default: bool,
+
+        #[command(subcommand)]
+        action: Option<DuplicatesCommands>,
     },
 
     /// Show aggregated statistics
@@ -442,6 +635,166 @@ pub enum Commands {
         /// Fetch stats from remote web API instead of local
         #[arg(long)]
         remote: bool,
+
+        /// Show open/closed counts over time from issues.jsonl git history
+        #[arg(long, conflicts_with = "remote")]
+        history: bool,
+
+        /// Number of days of history to show (with --history)
+        #[arg(long, default_value = "30")]
+        days: u32,
+
+        /// Bucket open beads by age (since created) per status
+        #[arg(long, conflicts_with_all = ["remote", "history"])]
+        age: bool,
+    },
+
+    /// Show ready and blocked counts per context from the cache
+    ///
+    /// Reads the cached graph even when it has expired; only aggregates when
+    /// there is no cache yet, so it is fast enough for a shell prompt.
+    Summary {
+        /// Print a single compact line, e.g. `@work: 3 ready, 2 blocked`
+        #[arg(long)]
+        oneline: bool,
+    },
+
+    /// Wait until a bead reaches a status
+    ///
+    /// Polls `bd show` in the context matching the bead's ID prefix. Exits 0
+    /// once the status is reached and non-zero on timeout.
+    Watch {
+        /// Bead ID
+        id: String,
+
+        /// Status to wait for (open, in_progress, blocked, deferred, closed)
+        #[arg(long, default_value = "closed")]
+        until: String,
+
+        /// Give up after this long, e.g. 30s, 10m, 2h (default: wait forever)
+        #[arg(long)]
+        timeout: Option<String>,
+
+        /// Time between polls
+        #[arg(long, default_value = "5s")]
+        interval: String,
+    },
+
+    /// List open/in-progress beads not updated recently, oldest first
+    Stale {
+        /// Minimum days since last update
+        #[arg(long, default_value = "30")]
+        days: u32,
+
+        /// Add a `stale` label to each stale bead
+        #[arg(long)]
+        add_label: bool,
+    },
+
+    /// Show open and in-progress beads per assignee across contexts
+    Workload,
+
+    /// Show when a bead's status, priority and assignee changed, from git history
+    ///
+    /// Walks the commits touching the bead's line in its context's
+    /// `.beads/issues.jsonl`, so it only sees changes that were committed.
+    History {
+        /// Bead ID
+        id: String,
+    },
+
+    /// Show the bd activity log for the current repo, or every context with --all
+    Activity {
+        /// Merge activity from all contexts into one time-sorted stream
+        #[arg(long)]
+        all: bool,
+
+        /// Maximum number of entries to show
+        #[arg(short = 'n', long)]
+        limit: Option<usize>,
+    },
+
+    /// Run health checks (bd, config, contexts, cache); exits non-zero on failure
+    Doctor {
+        /// Report shadow beads whose target bead is missing
+        #[arg(long)]
+        shadows: bool,
+    },
+
+    /// Export beads to CSV or Markdown (accepts the same filters as list)
+    Export {
+        /// Output format (csv, markdown)
+        #[arg(short, long, value_enum, default_value = "csv")]
+        format: ExportFormat,
+
+        /// Write to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// Filter by status (open, in_progress, blocked, closed)
+        #[arg(short, long)]
+        status: Option<String>,
+
+        /// Filter by priority (P0-P4 or 0-4)
+        #[arg(short, long)]
+        priority: Option<String>,
+
+        /// Filter by context (@work, @personal)
+        #[arg(long)]
+        context: Option<String>,
+
+        /// Filter to contexts in a named group (e.g., backend)
+        #[arg(short, long)]
+        group: Option<String>,
+
+        /// Filter by label/tag
+        #[arg(short, long)]
+        label: Option<String>,
+
+        /// Filter by type (epic, task, bug, feature, chore)
+        #[arg(short = 't', long = "type")]
+        issue_type: Option<String>,
+
+        /// Filter by assignee
+        #[arg(short, long)]
+        assignee: Option<String>,
+
+        /// Export only ready issues (no blockers, not closed)
+        #[arg(long)]
+        ready: bool,
+
+        /// Export all issues including closed
+        #[arg(long)]
+        all: bool,
+
+        /// Only export beads updated since a duration (24h, 7d, 2w) or date (YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+    },
+
+    /// Import beads from a file into a context
+    ///
+    /// IDs are kept when they already use the context's prefix; other beads
+    /// get a new ID from bd.
+    ///
+    /// Examples:
+    ///   ab import --context work issues.jsonl
+    ///   ab import --context work --format jsonl export.jsonl --dry-run
+    Import {
+        /// File to import
+        file: String,
+
+        /// Context to create the beads in
+        #[arg(long)]
+        context: String,
+
+        /// Input format
+        #[arg(short, long, value_enum, default_value = "jsonl")]
+        format: ImportFormat,
+
+        /// Dry run: parse the file and show what would be created
+        #[arg(long)]
+        dry_run: bool,
     },
 
     // =========================================================================
@@ -453,23 +806,58 @@ pub enum Commands {
         #[arg(short, long)]
         title: String,
 
-        /// Type (bug, feature, task, epic, chore)
-        #[arg(short = 'T', long = "type", default_value = "task")]
-        issue_type: String,
+        /// Type (bug, feature, task, epic, chore) [default: task]
+        #[arg(short = 'T', long = "type")]
+        issue_type: Option<String>,
 
-        /// Priority (P0-P4 or 0-4)
-        #[arg(short, long, default_value = "2")]
-        priority: String,
+        /// Priority (P0-P4 or 0-4) [default: 2]
+        #[arg(short, long)]
+        priority: Option<String>,
+
+        /// Assignee ("me" for your git user.email)
+        #[arg(short, long)]
+        assignee: Option<String>,
 
-        /// Context to create in (defaults to current directory's context)
+        /// Context to create in (default: the current directory's context, then default_context)
         #[arg(long)]
         context: Option<String>,
+
+        /// Start from a named template in config (bead_templates)
+        #[arg(long)]
+        template: Option<String>,
+
+        /// Description (overrides the template's)
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Read the description from standard input
+        #[arg(long, conflicts_with = "description")]
+        stdin: bool,
+    },
+
+    /// Create a new bead from an existing one (copies type, priority, labels, description)
+    Clone {
+        /// Bead ID to copy
+        id: String,
+
+        /// Title of the new bead
+        #[arg(short, long)]
+        title: String,
     },
 
     /// Update a bead (delegates to bd in the bead's context)
+    ///
+    /// --description and --notes set the text directly, for scripts where
+    /// `ab edit` can't open an editor.
+    ///
+    /// Examples:
+    ///   ab update ab-12 --status in_progress --assignee me
+    ///   ab update ab-12 --description "Steps to reproduce: ..."
+    ///   cat notes.md | ab update ab-12 --stdin
     Update {
-        /// Bead ID (e.g., ab-123, rk-456)
-        id: String,
+        /// Bead ID(s) to update (e.g., ab-123 rk-456)
+        #[arg(required = true)]
+        ids: Vec<String>,
 
         /// Set status (open, in_progress, blocked, closed)
         #[arg(long)]
@@ -479,9 +867,21 @@ pub enum Commands {
         #[arg(long)]
         priority: Option<String>,
 
-        /// Set assignee
+        /// Set assignee ("me" for your git user.email)
         #[arg(long)]
         assignee: Option<String>,
+
+        /// Set the description, replacing the current one (no editor)
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Set the notes, replacing the current ones (no editor)
+        #[arg(long)]
+        notes: Option<String>,
+
+        /// Read the description from standard input
+        #[arg(long, conflicts_with = "description")]
+        stdin: bool,
     },
 
     /// Close a bead (delegates to bd in the bead's context)
@@ -492,18 +892,80 @@ pub enum Commands {
         /// Reason for closing
         #[arg(long)]
         reason: Option<String>,
+
+        /// Dry run: show which beads would be affected without calling bd
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Move a bead under a different parent epic
+    Move {
+        /// Bead ID to move
+        id: String,
+
+        /// New parent epic ID
+        #[arg(long)]
+        parent: String,
     },
 
     /// Reopen closed bead(s) (delegates to bd in the bead's context)
     Reopen {
         /// Bead ID(s) to reopen
         ids: Vec<String>,
+
+        /// Dry run: show which beads would be affected without calling bd
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Move every open bead assigned to one person to another
+    Reassign {
+        /// Current assignee
+        #[arg(long)]
+        from: String,
+
+        /// New assignee
+        #[arg(long)]
+        to: String,
+
+        /// Only reassign in this context
+        #[arg(long)]
+        context: Option<String>,
+
+        /// Dry run: show which beads would be affected without calling bd
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Manage dependencies between beads
     #[command(subcommand)]
     Dep(DepCommands),
 
+    /// Link two beads without making one block the other
+    ///
+    /// Links are stored as `<type>:<id>` labels on the first bead and shown
+    /// under their own heading in `ab show`.
+    ///
+    /// Examples:
+    ///   ab link ab-12 ab-7                       # ab-12 relates to ab-7
+    ///   ab link ab-12 ab-7 --type duplicate-of   # ab-12 duplicates ab-7
+    ///   ab link ab-12 ab-7 --remove              # Remove the relates-to link
+    Link {
+        /// Bead the link is recorded on
+        from: String,
+
+        /// Bead being linked to
+        to: String,
+
+        /// Relationship from the first bead to the second
+        #[arg(long = "type", value_enum, default_value = "relates-to")]
+        link_type: LinkType,
+
+        /// Remove the link instead of adding it
+        #[arg(long)]
+        remove: bool,
+    },
+
     /// Manage labels on beads
     #[command(subcommand)]
     Label(LabelCommands),
@@ -525,7 +987,7 @@ pub enum Commands {
         #[arg(short, long)]
         priority: Option<String>,
 
-        /// Context to create in (defaults to current directory's context)
+        /// Context to create in (default: the current directory's context, then default_context)
         #[arg(long)]
         context: Option<String>,
     },
@@ -556,6 +1018,10 @@ pub enum Commands {
         /// Skip confirmation
         #[arg(long, short)]
         yes: bool,
+
+        /// Dry run: show which beads would be affected without calling bd
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Mark a bead as duplicate of another
@@ -568,6 +1034,22 @@ pub enum Commands {
         of: String,
     },
 
+    /// Undo the last close, reopen, label, update or rename-prefix
+    ///
+    /// Examples:
+    ///   ab undo            # Reverse the most recent operation
+    ///   ab undo --list     # Show the undo log, newest first
+    ///   ab undo --skip     # Drop the last entry without reversing it
+    Undo {
+        /// List recorded operations instead of undoing
+        #[arg(long)]
+        list: bool,
+
+        /// Drop the last entry without reversing it (e.g. an irreversible delete)
+        #[arg(long, conflicts_with = "list")]
+        skip: bool,
+    },
+
     /// Rename the issue prefix for a context (delegates to bd rename-prefix)
     ///
     /// Examples:
@@ -586,6 +1068,10 @@ pub enum Commands {
         /// Path to repository (default: current directory, ignored if --from is set)
         #[arg(short, long, default_value = ".")]
         path: String,
+
+        /// Rename even if another context already uses the new prefix
+        #[arg(long)]
+        force: bool,
     },
 
     // =========================================================================
@@ -612,6 +1098,33 @@ pub enum Commands {
     /// Quickstart guide for AllBeads
     Quickstart,
 
+    /// Generate a shell completion script
+    ///
+    /// Examples:
+    ///   ab completion bash > ~/.local/share/bash-completion/completions/ab
+    ///   source <(ab completion zsh)             # in ~/.zshrc
+    ///   ab completion fish > ~/.config/fish/completions/ab.fish
+    Completion {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+
+    /// Print the JSON Schema of bead or issue JSON output
+    Schema {
+        /// Which type to describe
+        #[arg(long = "type", value_enum, default_value_t)]
+        schema_type: SchemaType,
+    },
+
+    /// List context names, one per line (used by shell completion)
+    #[command(name = "__complete_contexts", hide = true)]
+    CompleteContexts,
+
+    /// List known labels, one per line (used by shell completion)
+    #[command(name = "__complete_labels", hide = true)]
+    CompleteLabels,
+
     /// Manage contexts (Boss repositories)
     #[command(subcommand)]
     Context(ContextCommands),
@@ -724,13 +1237,17 @@ pub enum Commands {
         #[arg(short, long)]
         message: Option<String>,
 
-        /// Show status only, don't sync
+        /// Show status only, don't sync (includes ahead/behind per context; add --json for JSON)
         #[arg(long)]
         status: bool,
 
         /// Also sync beads to web platform (allbeads.co)
         #[arg(long)]
         web: bool,
+
+        /// Auto-merge a conflicted .beads/issues.jsonl, leaving only genuinely conflicting beads
+        #[arg(long)]
+        resolve: bool,
     },
 
     /// Run the Sheriff daemon (background sync)
@@ -754,6 +1271,10 @@ pub enum Commands {
         /// Mail poll interval in seconds (default: 60)
         #[arg(long, default_value = "60")]
         mail_interval: u64,
+
+        /// Maximum number of rigs to sync at the same time (default: 4)
+        #[arg(long, default_value = "4")]
+        max_concurrent: usize,
     },
 
     /// Agent Mail commands
@@ -819,6 +1340,22 @@ pub enum Commands {
         /// Queue work for a running agent via Agent Mail instead of spawning new
         #[arg(long)]
         queue: bool,
+
+        /// Assign the bead to the agent (overrides assign_on_handoff in config)
+        #[arg(long, conflicts_with = "no_assign")]
+        assign: bool,
+
+        /// Don't assign the bead to the agent (overrides assign_on_handoff in config)
+        #[arg(long)]
+        no_assign: bool,
+
+        /// Print the agent prompt and exit without launching or changing the bead
+        /// (with --json: prompt and bead metadata as separate fields)
+        #[arg(long, conflicts_with_all = ["ready", "list", "agents", "worktree", "queue"])]
+        print_prompt: bool,
+
+        #[command(subcommand)]
+        action: Option<HandoffCommands>,
     },
 
     // =========================================================================
@@ -831,19 +1368,49 @@ pub enum Commands {
         path: String,
 
         /// Include verbose analysis details
-        #[arg(short, long)]
-        verbose: bool,
+        #[arg(short, long, action = clap::ArgAction::Count)]
+        verbose: u8,
 
         /// Only scan, don't create beads (dry run)
         #[arg(long)]
         dry_run: bool,
+
+        /// Description template for a finding category, as CATEGORY=TEMPLATE
+        /// or CATEGORY=@FILE (repeatable; overrides janitor_templates in config).
+        /// Placeholders: {file}, {line}, {text}, {title}, {category}, {description}
+        #[arg(long, value_name = "CATEGORY=TEMPLATE")]
+        template: Vec<String>,
+
+        /// YAML file of extra security rules (a list of `name` and `pattern`
+        /// regexes) added to the built-in ones
+        #[arg(long, value_name = "FILE")]
+        rules: Option<String>,
+
+        /// Only create beads for findings at this priority or more urgent (P0-P4 or 0-4)
+        #[arg(long, value_name = "PRIORITY")]
+        min_priority: Option<String>,
+
+        /// Only scan files matching this glob, relative to the repo (repeatable)
+        #[arg(long, value_name = "GLOB")]
+        include: Vec<String>,
+
+        /// Skip files and directories matching this glob (repeatable)
+        #[arg(long, value_name = "GLOB")]
+        exclude: Vec<String>,
     },
 
     // =========================================================================
     // UI COMMANDS - User interface
     // =========================================================================
     /// Launch Terminal UI (Kanban + Mail + Graph + Swarm)
-    Tui,
+    Tui {
+        /// Enable mouse support (click to select, wheel to scroll)
+        ///
+        /// Also enabled by `visualization.mouse` in the config. Mouse capture
+        /// disables the terminal's own text selection.
+        #[arg(long)]
+        mouse: bool,
+    },
 
     // =========================================================================
     // GOVERNANCE COMMANDS - Policy enforcement and compliance
@@ -870,9 +1437,13 @@ pub enum Commands {
         #[arg(long)]
         bead: Option<String>,
 
-        /// Output format (text, json, yaml)
+        /// Output format (text, json, yaml); json/yaml list violations with bead, policy, message and severity
         #[arg(long, default_value = "text")]
         format: String,
+
+        /// Only check for dependency cycles and dependencies on missing beads (reads .beads/issues.jsonl directly, no network)
+        #[arg(long, conflicts_with_all = ["policy", "bead"])]
+        cycles: bool,
     },
 
     /// Manage git hooks for policy enforcement
@@ -905,6 +1476,10 @@ pub enum Commands {
     #[command(subcommand)]
     Config(ConfigCommands),
 
+    /// Generate and validate Sheriff manifests
+    #[command(subcommand)]
+    Manifest(ManifestCommands),
+
     // =========================================================================
     // WEB APP COMMANDS - AllBeads web platform integration
     // =========================================================================
@@ -953,6 +1528,23 @@ pub enum MailCommands {
         /// Sender name (default: current context or "cli")
         #[arg(short, long)]
         from: Option<String>,
+
+        /// Attach a file (repeatable); copied into the managed attachments directory
+        #[arg(short, long = "attach", value_name = "FILE")]
+        attach: Vec<String>,
+    },
+
+    /// Reply to a message; goes to the original sender in the same thread
+    Reply {
+        /// ID of the message being replied to
+        id: String,
+
+        /// Reply content
+        message: String,
+
+        /// Sender name (default: human inbox)
+        #[arg(short, long)]
+        from: Option<String>,
     },
 
     /// Send a test notification message
@@ -962,8 +1554,12 @@ pub enum MailCommands {
         message: String,
     },
 
-    /// Show inbox messages
-    Inbox,
+    /// Show unread messages for the current project
+    Inbox {
+        /// Include read messages and messages from other projects
+        #[arg(long)]
+        all: bool,
+    },
 
     /// Show unread message count
     Unread,
@@ -1012,8 +1608,8 @@ pub enum JiraCommands {
         label: String,
 
         /// Show raw issue data
-        #[arg(long)]
-        verbose: bool,
+        #[arg(long, action = clap::ArgAction::Count)]
+        verbose: u8,
     },
 
     /// Show JIRA configuration status
@@ -1037,8 +1633,8 @@ pub enum GitHubCommands {
         label: String,
 
         /// Show raw issue data
-        #[arg(long)]
-        verbose: bool,
+        #[arg(long, action = clap::ArgAction::Count)]
+        verbose: u8,
     },
 
     /// Show GitHub configuration status
@@ -1065,7 +1661,7 @@ pub enum SwarmCommands {
     /// List all swarm molecules
     List,
 
-    /// Show current swarm status
+    /// Show beads handed off to agents, with their worktrees and last activity
     Status,
 
     /// Validate epic structure for swarming
@@ -1075,9 +1671,27 @@ pub enum SwarmCommands {
     },
 }
 
+#[derive(Subcommand, Debug)]
+pub enum ManifestCommands {
+    /// Generate a manifest XML from the configured contexts
+    Generate {
+        /// Write to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+
+    /// Validate a manifest file, reporting problems with line numbers
+    Validate {
+        /// Path to manifest file
+        path: String,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 pub enum ConfigCommands {
     /// Initialize distributed config sync with a git remote
+    ///
+    /// With --force, the config files are backed up first (see `ab config restore`).
     Init {
         /// Remote repository URL for config sync
         #[arg(long, conflicts_with = "gist")]
@@ -1094,7 +1708,7 @@ pub enum ConfigCommands {
 
     /// Pull config changes from remote
     Pull {
-        /// Force pull, discarding local changes
+        /// Force pull, discarding local changes (backed up first)
         #[arg(short, long)]
         force: bool,
     },
@@ -1116,6 +1730,39 @@ pub enum ConfigCommands {
     /// Show config diff with remote
     Diff,
 
+    /// Resolve a config that has diverged from the remote
+    ///
+    /// Without a strategy, fetches and shows what differs. Examples:
+    ///   ab config resolve
+    ///   ab config resolve --merge
+    Resolve {
+        /// Take the remote config, discarding local commits (kept on a backup branch
+        /// and in a file backup)
+        #[arg(long, conflicts_with_all = ["ours", "merge"])]
+        theirs: bool,
+
+        /// Keep the local config and mark the remote as merged
+        #[arg(long, conflicts_with = "merge")]
+        ours: bool,
+
+        /// Merge both sides, field by field for YAML files
+        #[arg(long)]
+        merge: bool,
+    },
+
+    /// Check contexts for problems (duplicate names, missing paths, bad prefixes)
+    Check,
+
+    /// Restore config files from a backup taken before a forced operation
+    ///
+    /// Without a name, lists the backups, newest first. Examples:
+    ///   ab config restore
+    ///   ab config restore 20260301T120000-pull
+    Restore {
+        /// Backup name as listed
+        backup: Option<String>,
+    },
+
     /// Clone config from a remote to a new machine
     Clone {
         /// Remote repository URL or Gist ID
@@ -1142,6 +1789,10 @@ pub enum HooksCommands {
         /// Dry run (show what would be installed)
         #[arg(long)]
         dry_run: bool,
+
+        /// Install a pre-commit hook that only blocks dependency cycles and missing dependencies
+        #[arg(long, conflicts_with_all = ["hook", "all"])]
+        cycles: bool,
     },
 
     /// Uninstall git hooks
@@ -1153,6 +1804,10 @@ pub enum HooksCommands {
         /// Uninstall all hooks
         #[arg(long)]
         all: bool,
+
+        /// Uninstall the dependency cycle pre-commit hook
+        #[arg(long, conflicts_with_all = ["hook", "all"])]
+        cycles: bool,
     },
 
     /// List installed hooks
@@ -1514,7 +2169,7 @@ pub enum PluginCommands {
         all: bool,
 
         /// Filter by category (claude, beads, prose, etc.)
-        #[arg(short, long)]
+        #[arg(long)]
         category: Option<String>,
 
         /// Output as JSON
@@ -1541,8 +2196,8 @@ pub enum PluginCommands {
         path: String,
 
         /// Show verbose detection output
-        #[arg(short, long)]
-        verbose: bool,
+        #[arg(short, long, action = clap::ArgAction::Count)]
+        verbose: u8,
     },
 
     /// Install a plugin
@@ -1579,6 +2234,16 @@ pub enum PluginCommands {
         yes: bool,
     },
 
+    /// Register a local plugin directory for testing its onboarding
+    Dev {
+        /// Plugin directory containing .claude-plugin/allbeads-onboarding.yaml (lists registered dev plugins if omitted)
+        path: Option<String>,
+
+        /// Unregister all local dev plugins
+        #[arg(long, conflicts_with = "path")]
+        clear: bool,
+    },
+
     /// Recommend plugins for current project
     Recommend {
         /// Path to project (default: current directory)
@@ -1641,12 +2306,19 @@ pub enum DepCommands {
 #[derive(Subcommand, Debug)]
 pub enum LabelCommands {
     /// Add a label to an issue
+    ///
+    /// If the label looks like a typo of one already used in the context,
+    /// asks whether you meant the existing one (interactive terminals only).
     Add {
         /// Issue ID
         issue: String,
 
         /// Label to add
         label: String,
+
+        /// Add the label as given, without checking for similar labels
+        #[arg(short, long)]
+        force: bool,
     },
 
     /// Remove a label from an issue
@@ -1660,6 +2332,19 @@ pub enum LabelCommands {
 
     /// List all labels in the project
     List,
+
+    /// Rename a label on every bead that carries it
+    Rename {
+        /// Label to rename
+        old: String,
+
+        /// New label name
+        new: String,
+
+        /// Only rename in this context
+        #[arg(long)]
+        context: Option<String>,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -1675,12 +2360,21 @@ pub enum CommentCommands {
     },
 
     /// Add a comment to an issue
+    ///
+    /// Examples:
+    ///   ab comments add ab-12 "Fixed in the latest build"
+    ///   cat notes.md | ab comments add ab-12 --stdin
     Add {
         /// Issue ID
         issue: String,
 
         /// Comment content
-        content: String,
+        #[arg(required_unless_present = "stdin")]
+        content: Option<String>,
+
+        /// Read the comment from standard input
+        #[arg(long, conflicts_with = "content")]
+        stdin: bool,
 
         /// Post to remote web API instead of local bd
         #[arg(long)]
@@ -1688,6 +2382,37 @@ pub enum CommentCommands {
     },
 }
 
+#[derive(Subcommand, Debug)]
+pub enum DuplicatesCommands {
+    /// Close a duplicate, moving its dependents and labels to the bead it duplicates
+    Merge {
+        /// Bead to keep
+        keep: String,
+
+        /// Duplicate bead to close
+        dup: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum HandoffCommands {
+    /// List handoff worktrees; with --merged, remove those whose branch is
+    /// merged into the default branch
+    Prune {
+        /// Remove worktrees and branches already merged
+        #[arg(long)]
+        merged: bool,
+
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Show what would be removed without removing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 pub enum EpicCommands {
     /// List all epics
@@ -1707,7 +2432,7 @@ pub enum EpicCommands {
         #[arg(short, long, default_value = "2")]
         priority: String,
 
-        /// Context to create in (defaults to current directory's context)
+        /// Context to create in (default: the current directory's context, then default_context)
         #[arg(long)]
         context: Option<String>,
     },
@@ -1717,6 +2442,12 @@ pub enum EpicCommands {
         /// Epic ID
         id: String,
     },
+
+    /// Roll up child bead status and completion, including sub-epics
+    Stats {
+        /// Epic ID
+        id: String,
+    },
 }
 
 /// Milestone commands - machine tags for release tracking
@@ -1770,7 +2501,7 @@ pub enum MilestoneCommands {
         #[arg(short, long)]
         description: Option<String>,
 
-        /// Context to create in (defaults to current directory)
+        /// Context to create in (default: the current directory's context, then default_context)
         #[arg(long)]
         context: Option<String>,
     },
@@ -1821,6 +2552,14 @@ pub enum ContextCommands {
         /// Options: ssh_agent, personal_access_token, gh_enterprise_token
         #[arg(short, long)]
         auth: Option<String>,
+
+        /// Group to add this context to (e.g., backend)
+        #[arg(short, long)]
+        group: Option<String>,
+
+        /// Remote-only context: AllBeads manages its own clone of --url
+        #[arg(long)]
+        remote: bool,
     },
 
     /// List all contexts
@@ -1844,6 +2583,27 @@ pub enum ContextCommands {
         name: String,
     },
 
+    /// Point a context at a repository that moved on disk
+    Move {
+        /// Context name
+        name: String,
+
+        /// New path to the repository (must contain .beads/)
+        path: String,
+    },
+
+    /// Rename a context (existing @old labels are not migrated)
+    Rename {
+        /// Current context name
+        old: String,
+
+        /// New context name
+        new: String,
+    },
+
+    /// List context groups and their members
+    Groups,
+
     /// Show onboarding status for all contexts
     Onboarding {
         /// Show detailed onboarding guide for each repo
@@ -1983,8 +2743,8 @@ pub enum FolderCommands {
         json: bool,
 
         /// Show detailed info
-        #[arg(short, long)]
-        verbose: bool,
+        #[arg(short, long, action = clap::ArgAction::Count)]
+        verbose: u8,
     },
 
     /// Remove a folder from tracking
@@ -2247,3 +3007,22 @@ pub enum SkillCommands {
         path: String,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli_definition() {
+        // Catches clashing flags anywhere in the tree, which would otherwise
+        // only panic when that subcommand (or `ab completion`) is built.
+        // The tree is deeper than a test thread's default stack allows.
+        std::thread::Builder::new()
+            .stack_size(16 * 1024 * 1024)
+            .spawn(|| Cli::command().debug_assert())
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+}
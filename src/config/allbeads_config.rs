@@ -5,7 +5,9 @@
 use super::boss_context::BossContext;
 use crate::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
+use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
 
 /// Agent Mail configuration
@@ -52,6 +54,13 @@ pub struct VisualizationConfig {
     /// Refresh interval in seconds
     #[serde(default = "default_refresh_interval")]
     pub refresh_interval: u32,
+
+    /// Capture mouse events in the TUI (click to select, wheel to scroll)
+    ///
+    /// Off by default: mouse capture disables the terminal's own text
+    /// selection, and some tmux setups forward events badly.
+    #[serde(default)]
+    pub mouse: bool,
 }
 
 fn default_view_mode() -> String {
@@ -72,6 +81,7 @@ impl Default for VisualizationConfig {
             default_view: default_view_mode(),
             theme: default_theme(),
             refresh_interval: default_refresh_interval(),
+            mouse: false,
         }
     }
 }
@@ -135,6 +145,37 @@ impl WebAuthConfig {
     }
 }
 
+/// Defaults for `ab duplicates`; command-line flags override them
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DuplicatesConfig {
+    /// Similarity threshold (0.0-1.0)
+    #[serde(default = "default_duplicate_threshold")]
+    pub threshold: f64,
+
+    /// Skip beads whose title is shorter than this many characters
+    #[serde(default)]
+    pub min_title_len: usize,
+}
+
+fn default_duplicate_threshold() -> f64 {
+    0.8
+}
+
+impl Default for DuplicatesConfig {
+    fn default() -> Self {
+        Self {
+            threshold: default_duplicate_threshold(),
+            min_title_len: 0,
+        }
+    }
+}
+
+impl DuplicatesConfig {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 /// Onboarding configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OnboardingConfig {
@@ -178,6 +219,145 @@ impl Default for OnboardingConfig {
     }
 }
 
+/// A named `ab search` query, stored as the filter arguments it was saved with
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedSearch {
+    /// Text query
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+
+    /// Context filter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+
+    /// Status filter (may be negated with ^)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Minimum priority (inclusive)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_min: Option<String>,
+
+    /// Maximum priority (inclusive)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_max: Option<String>,
+
+    /// Type filter (may be negated with ^)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_type: Option<String>,
+
+    /// Required labels
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+
+    /// Assignee filter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+
+    /// Sort field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+
+    /// Reverse sort order
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub reverse: bool,
+
+    /// Result limit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+
+    /// Fuzzy text matching
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub fuzzy: bool,
+}
+
+impl SavedSearch {
+    /// Render as `ab search` arguments, for display
+    pub fn to_args(&self) -> String {
+        let mut args = Vec::new();
+        if let Some(ref query) = self.query {
+            args.push(format!("{:?}", query));
+        }
+        let options = [
+            ("--context", &self.context),
+            ("--status", &self.status),
+            ("--priority-min", &self.priority_min),
+            ("--priority-max", &self.priority_max),
+            ("--type", &self.issue_type),
+            ("--assignee", &self.assignee),
+            ("--sort", &self.sort),
+        ];
+        for (flag, value) in options {
+            if let Some(value) = value {
+                args.push(format!("{} {}", flag, value));
+            }
+        }
+        for label in &self.labels {
+            args.push(format!("--label {}", label));
+        }
+        if self.reverse {
+            args.push("--reverse".to_string());
+        }
+        if let Some(limit) = self.limit {
+            args.push(format!("--limit {}", limit));
+        }
+        if self.fuzzy {
+            args.push("--fuzzy".to_string());
+        }
+        args.join(" ")
+    }
+}
+
+/// A named `ab create --template` preset
+///
+/// Flags given on the command line override the template's values.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BeadTemplate {
+    /// Type (bug, feature, task, epic, chore)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_type: Option<String>,
+
+    /// Priority (P0-P4 or 0-4)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+
+    /// Labels added to the new bead
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+
+    /// Description for the new bead
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Look up the janitor description template for a finding category
+///
+/// Code-comment findings (Bug, Tech Debt, Task) fall back to the `TODO` key.
+pub fn janitor_template<'a>(
+    templates: &'a BTreeMap<String, String>,
+    category: &str,
+) -> Option<&'a str> {
+    templates
+        .get(category)
+        .or_else(|| match category {
+            "Bug" | "Tech Debt" | "Task" => templates.get("TODO"),
+            _ => None,
+        })
+        .map(String::as_str)
+}
+
+/// Fill `{name}` placeholders in a janitor template
+///
+/// Janitor provides `{category}`, `{title}`, `{file}`, `{line}`, `{text}` and
+/// `{description}` (the default description). Unknown placeholders are kept.
+pub fn render_janitor_template(template: &str, vars: &[(&str, &str)]) -> String {
+    let mut rendered = template.to_string();
+    for (name, value) in vars {
+        rendered = rendered.replace(&format!("{{{}}}", name), value);
+    }
+    rendered
+}
+
 /// AllBeads configuration
 ///
 /// Represents the complete ~/.config/allbeads/config.yaml file with multiple
@@ -207,6 +387,49 @@ pub struct AllBeadsConfig {
     /// Defaults to ~/Workspace if not specified
     #[serde(default = "default_workspace_dir")]
     pub workspace_directory: PathBuf,
+
+    /// Saved `ab search` queries by name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub saved_searches: BTreeMap<String, SavedSearch>,
+
+    /// Bead templates for `ab create --template` by name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub bead_templates: BTreeMap<String, BeadTemplate>,
+
+    /// Description templates for janitor-created beads by finding category
+    ///
+    /// Keys are Documentation, Configuration, Security, Testing and TODO
+    /// (code comments); see [`render_janitor_template`] for placeholders.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub janitor_templates: BTreeMap<String, String>,
+
+    /// Defaults for `ab duplicates`
+    #[serde(default, skip_serializing_if = "DuplicatesConfig::is_default")]
+    pub duplicates: DuplicatesConfig,
+
+    /// Who `--assignee me` means when git has no user.email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_assignee: Option<String>,
+
+    /// Context for new beads when `--context` isn't given and the current
+    /// directory isn't inside any context; see [`Self::resolve_context`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_context: Option<String>,
+
+    /// Agent for `ab handoff` when `--agent` isn't given, e.g. `claude`
+    ///
+    /// Takes precedence over the per-repo saved preference in `.beads/config.yaml`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_agent: Option<String>,
+
+    /// Assign handed-off beads to the agent (its display name), so `ab workload`
+    /// shows who is working on them
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub assign_on_handoff: bool,
+
+    /// Directory of the file this configuration was loaded from (set at runtime)
+    #[serde(skip)]
+    config_dir: Option<PathBuf>,
 }
 
 fn default_workspace_dir() -> PathBuf {
@@ -225,6 +448,15 @@ impl AllBeadsConfig {
             onboarding: OnboardingConfig::default(),
             web_auth: WebAuthConfig::default(),
             workspace_directory: default_workspace_dir(),
+            saved_searches: BTreeMap::new(),
+            bead_templates: BTreeMap::new(),
+            janitor_templates: BTreeMap::new(),
+            duplicates: DuplicatesConfig::default(),
+            default_assignee: None,
+            default_context: None,
+            default_agent: None,
+            assign_on_handoff: false,
+            config_dir: None,
         }
     }
 
@@ -248,7 +480,13 @@ impl AllBeadsConfig {
         tracing::info!(path = %path.display(), "Loading AllBeads configuration");
 
         let content = fs::read_to_string(path)?;
-        let config: Self = serde_yaml::from_str(&content)?;
+        let mut config: Self = serde_yaml::from_str(&content)?;
+        config.config_dir = path.parent().map(Path::to_path_buf);
+        let remotes_dir = config.remotes_dir();
+        for context in &mut config.contexts {
+            context.remotes_dir = Some(remotes_dir.clone());
+            context.load_prefix();
+        }
 
         tracing::debug!(
             contexts = config.contexts.len(),
@@ -256,6 +494,15 @@ impl AllBeadsConfig {
             "Configuration loaded successfully"
         );
 
+        // Surface problems early; `ab config check` gives the full report
+        for issue in config.validate() {
+            if issue.is_error() {
+                tracing::warn!("Config issue: {}", issue);
+            } else {
+                tracing::debug!("Config issue: {}", issue);
+            }
+        }
+
         Ok(config)
     }
 
@@ -302,8 +549,59 @@ impl AllBeadsConfig {
         self.contexts.iter_mut().find(|c| c.name == name)
     }
 
+    /// Find the context whose beads use the given ID prefix
+    ///
+    /// Matches each context's `issue-prefix` (case-insensitively) first, then
+    /// falls back to the prefix of the first issue in `.beads/issues.jsonl`
+    /// for repositories that don't configure one.
+    pub fn context_for_prefix(&self, prefix: &str) -> Option<&BossContext> {
+        let matches = |p: &str| p.eq_ignore_ascii_case(prefix);
+        self.contexts
+            .iter()
+            .find(|c| c.issue_prefix().is_some_and(matches))
+            .or_else(|| {
+                self.contexts
+                    .iter()
+                    .find(|c| first_issue_prefix(c).is_some_and(|p| matches(&p)))
+            })
+    }
+
+    /// All contexts whose beads use the given ID prefix
+    ///
+    /// Unlike [`Self::context_for_prefix`] this reports every match, so
+    /// prefix collisions across the federation can be listed.
+    pub fn contexts_using_prefix(&self, prefix: &str) -> Vec<&BossContext> {
+        self.contexts
+            .iter()
+            .filter(|c| {
+                c.issue_prefix()
+                    .map(str::to_string)
+                    .or_else(|| first_issue_prefix(c))
+                    .is_some_and(|p| p.eq_ignore_ascii_case(prefix))
+            })
+            .collect()
+    }
+
+    /// Find the context a bead belongs to from its ID (e.g. `ab-123`)
+    ///
+    /// Prefixes may themselves contain `-`, so the longest candidate prefix
+    /// is tried first.
+    pub fn context_for_bead_id(&self, id: &str) -> Option<&BossContext> {
+        let candidates: Vec<&str> = id.match_indices('-').map(|(i, _)| &id[..i]).collect();
+        candidates
+            .into_iter()
+            .rev()
+            .find_map(|prefix| self.context_for_prefix(prefix))
+    }
+
     /// Add a new context
-    pub fn add_context(&mut self, context: BossContext) {
+    pub fn add_context(&mut self, mut context: BossContext) {
+        if context.remotes_dir.is_none() {
+            context.remotes_dir = Some(self.remotes_dir());
+        }
+        if context.prefix.is_none() {
+            context.load_prefix();
+        }
         self.contexts.push(context);
     }
 
@@ -316,11 +614,244 @@ impl AllBeadsConfig {
         }
     }
 
+    /// Point a context at a new local path
+    ///
+    /// The path must contain a `.beads/` directory. The cached issue prefix
+    /// is re-read from the new location.
+    pub fn move_context(&mut self, name: &str, path: PathBuf) -> Result<()> {
+        if !path.join(".beads").is_dir() {
+            return Err(crate::AllBeadsError::Config(format!(
+                "No .beads/ directory in {} (run `bd init` there first)",
+                path.display()
+            )));
+        }
+        let context = self
+            .get_context_mut(name)
+            .ok_or_else(|| crate::AllBeadsError::Config(format!("Context '{}' not found", name)))?;
+        context.path = Some(path);
+        context.load_prefix();
+        Ok(())
+    }
+
+    /// Directory of the loaded config file
+    ///
+    /// Falls back to the default config directory for a configuration that
+    /// wasn't loaded from disk. State that belongs with the config (managed
+    /// clones, the undo log, dev plugins) lives here.
+    pub fn config_dir(&self) -> PathBuf {
+        match &self.config_dir {
+            Some(dir) => dir.clone(),
+            None => {
+                let mut path = Self::default_path();
+                path.pop();
+                path
+            }
+        }
+    }
+
+    /// Directory holding the managed clones of remote-only contexts
+    pub fn remotes_dir(&self) -> PathBuf {
+        self.config_dir().join("remotes")
+    }
+
+    /// Rename a context, updating saved searches that filter on it
+    ///
+    /// A remote-only context's managed clone is moved to match the new name.
+    /// `@old` labels on beads live in each repository and are not changed.
+    pub fn rename_context(&mut self, old: &str, new: &str) -> Result<()> {
+        if new.is_empty() || new.starts_with('@') || new.contains(char::is_whitespace) {
+            return Err(crate::AllBeadsError::Config(format!(
+                "Invalid context name '{}'",
+                new
+            )));
+        }
+        if self.get_context(new).is_some() {
+            return Err(crate::AllBeadsError::Config(format!(
+                "Context '{}' already exists",
+                new
+            )));
+        }
+        let context = self
+            .get_context_mut(old)
+            .ok_or_else(|| crate::AllBeadsError::Config(format!("Context '{}' not found", old)))?;
+        if context.is_remote() {
+            let old_clone = context.remote_cache_path();
+            if old_clone.exists() {
+                let mut new_clone = old_clone.clone();
+                new_clone.set_file_name(new);
+                if new_clone.exists() {
+                    return Err(crate::AllBeadsError::Config(format!(
+                        "Cannot move clone of '{}': {} already exists",
+                        old,
+                        new_clone.display()
+                    )));
+                }
+                fs::rename(&old_clone, &new_clone)?;
+            }
+        }
+        context.name = new.to_string();
+
+        for search in self.saved_searches.values_mut() {
+            if search.context.as_deref() == Some(old) {
+                search.context = Some(new.to_string());
+            }
+        }
+        if self.default_context.as_deref() == Some(old) {
+            self.default_context = Some(new.to_string());
+        }
+        Ok(())
+    }
+
+    /// Pick the context a new bead goes into
+    ///
+    /// In order: the explicit `--context` name, the context whose local path
+    /// contains `cwd` (the deepest one if they nest), then `default_context`.
+    /// If none applies, or a named context doesn't exist, the error lists the
+    /// available contexts.
+    pub fn resolve_context(&self, explicit: Option<&str>, cwd: &Path) -> Result<&BossContext> {
+        let named = |name: &str, source: &str| {
+            let name = name.trim_start_matches('@');
+            self.get_context(name).ok_or_else(|| {
+                crate::AllBeadsError::Config(format!(
+                    "Context '{}'{} not found. {}",
+                    name,
+                    source,
+                    self.available_contexts()
+                ))
+            })
+        };
+
+        if let Some(name) = explicit {
+            return named(name, "");
+        }
+
+        if let Some(context) = self.context_for_path(cwd) {
+            return Ok(context);
+        }
+
+        if let Some(ref name) = self.default_context {
+            return named(name, " (default_context)");
+        }
+
+        Err(crate::AllBeadsError::Config(format!(
+            "No context contains {} and no default_context is set; \
+             pass --context or set default_context in the config. {}",
+            cwd.display(),
+            self.available_contexts()
+        )))
+    }
+
+    /// The context whose local path contains `path`, the deepest if they nest
+    pub fn context_for_path(&self, path: &Path) -> Option<&BossContext> {
+        self.contexts
+            .iter()
+            .filter_map(|c| c.local_path().map(|p| (c, p)))
+            .filter(|(_, p)| path.starts_with(p))
+            .max_by_key(|(_, p)| p.components().count())
+            .map(|(c, _)| c)
+    }
+
+    /// Expand a bare bead number like `1234` into a full ID like `proj-1234`
+    ///
+    /// Anything that isn't all digits is returned unchanged. Inside a context
+    /// with a known prefix that prefix is used. Elsewhere every configured
+    /// prefix is tried and `exists` picks out real beads; no match, or more
+    /// than one, is an error naming what was tried.
+    pub fn resolve_bead_id(
+        &self,
+        input: &str,
+        cwd: &Path,
+        exists: impl Fn(&str) -> bool,
+    ) -> Result<String> {
+        if input.is_empty() || !input.chars().all(|c| c.is_ascii_digit()) {
+            return Ok(input.to_string());
+        }
+
+        if let Some(prefix) = self.context_for_path(cwd).and_then(|c| c.issue_prefix()) {
+            return Ok(format!("{}-{}", prefix, input));
+        }
+
+        let mut candidates: Vec<String> = Vec::new();
+        for prefix in self.contexts.iter().filter_map(|c| c.issue_prefix()) {
+            let id = format!("{}-{}", prefix, input);
+            if !candidates.contains(&id) {
+                candidates.push(id);
+            }
+        }
+        let tried = candidates.join(", ");
+        candidates.retain(|id| exists(id));
+        match candidates.len() {
+            1 => Ok(candidates.remove(0)),
+            0 => Err(crate::AllBeadsError::IssueNotFound(format!(
+                "No bead numbered {} in any context (tried: {})",
+                input,
+                if tried.is_empty() {
+                    "no context has a known prefix".to_string()
+                } else {
+                    tried
+                }
+            ))),
+            _ => Err(crate::AllBeadsError::Config(format!(
+                "Bead number {} is ambiguous; use the full ID: {}",
+                input,
+                candidates.join(", ")
+            ))),
+        }
+    }
+
+    /// "Available contexts: a, b" for error messages
+    fn available_contexts(&self) -> String {
+        if self.contexts.is_empty() {
+            "No contexts are configured (see 'ab context add')".to_string()
+        } else {
+            format!("Available contexts: {}", self.context_names().join(", "))
+        }
+    }
+
     /// Get all context names
     pub fn context_names(&self) -> Vec<&str> {
         self.contexts.iter().map(|c| c.name.as_str()).collect()
     }
 
+    /// Get all context groups, mapping each group name to its member context names
+    ///
+    /// Group names are case-insensitive, as in [`Self::group_members`]; each
+    /// group is listed under the first spelling seen.
+    pub fn groups(&self) -> BTreeMap<&str, Vec<&str>> {
+        let mut groups: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for context in &self.contexts {
+            if let Some(ref group) = context.group {
+                let key = groups
+                    .keys()
+                    .find(|k| k.eq_ignore_ascii_case(group))
+                    .copied()
+                    .unwrap_or(group.as_str());
+                groups.entry(key).or_default().push(context.name.as_str());
+            }
+        }
+        groups
+    }
+
+    /// Get the names of contexts belonging to a group (case-insensitive)
+    pub fn group_members(&self, group: &str) -> Vec<&str> {
+        self.contexts
+            .iter()
+            .filter(|c| {
+                c.group
+                    .as_deref()
+                    .is_some_and(|g| g.eq_ignore_ascii_case(group))
+            })
+            .map(|c| c.name.as_str())
+            .collect()
+    }
+
+    /// Check contexts for problems like duplicate names or missing paths
+    ///
+    /// See `ab config check` for the user-facing report.
+    pub fn validate(&self) -> Vec<super::validation::ConfigIssue> {
+        super::validation::check_config(self)
+    }
+
     /// Get the workspace directory for cloning repositories
     pub fn workspace_directory(&self) -> &Path {
         &self.workspace_directory
@@ -333,6 +864,16 @@ impl Default for AllBeadsConfig {
     }
 }
 
+/// ID prefix of the first issue in a context's `.beads/issues.jsonl`
+fn first_issue_prefix(context: &BossContext) -> Option<String> {
+    let file = fs::File::open(context.local_path()?.join(".beads/issues.jsonl")).ok()?;
+    let mut first_line = String::new();
+    BufReader::new(file).read_line(&mut first_line).ok()?;
+    let issue: serde_json::Value = serde_json::from_str(&first_line).ok()?;
+    let id = issue.get("id")?.as_str()?;
+    id.rfind('-').map(|dash| id[..dash].to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -404,6 +945,221 @@ mod tests {
         assert_eq!(config.contexts.len(), 0);
     }
 
+    #[test]
+    fn test_move_context() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = AllBeadsConfig::new();
+        config.add_context(BossContext::new(
+            "test",
+            "https://github.com/test.git",
+            AuthStrategy::SshAgent,
+        ));
+
+        let err = config
+            .move_context("test", dir.path().to_path_buf())
+            .unwrap_err();
+        assert!(err.to_string().contains("No .beads/"), "{}", err);
+
+        fs::create_dir(dir.path().join(".beads")).unwrap();
+        fs::write(dir.path().join(".beads/config.yaml"), "issue-prefix: mv\n").unwrap();
+        assert!(config
+            .move_context("missing", dir.path().to_path_buf())
+            .is_err());
+        config
+            .move_context("test", dir.path().to_path_buf())
+            .unwrap();
+
+        let context = config.get_context("test").unwrap();
+        assert_eq!(context.path.as_deref(), Some(dir.path()));
+        assert_eq!(context.issue_prefix(), Some("mv"));
+    }
+
+    #[test]
+    fn test_resolve_context() {
+        let mut config = AllBeadsConfig::new();
+        for (name, path) in [
+            ("work", "/src/work"),
+            ("nested", "/src/work/nested"),
+            ("personal", "/src/personal"),
+        ] {
+            let mut context =
+                BossContext::new(name, "https://github.com/test.git", AuthStrategy::SshAgent);
+            context.path = Some(PathBuf::from(path));
+            config.contexts.push(context);
+        }
+        let resolve = |config: &AllBeadsConfig, explicit: Option<&str>, cwd: &str| {
+            config
+                .resolve_context(explicit, Path::new(cwd))
+                .map(|c| c.name.clone())
+        };
+
+        // Flag wins over the current directory
+        assert_eq!(
+            resolve(&config, Some("@personal"), "/src/work").unwrap(),
+            "personal"
+        );
+        assert!(resolve(&config, Some("missing"), "/src/work").is_err());
+
+        // Deepest context containing the current directory
+        assert_eq!(resolve(&config, None, "/src/work/lib").unwrap(), "work");
+        assert_eq!(
+            resolve(&config, None, "/src/work/nested/x").unwrap(),
+            "nested"
+        );
+
+        // Outside every context: default_context, or an error listing contexts
+        let err = resolve(&config, None, "/tmp").unwrap_err().to_string();
+        assert!(err.contains("work, nested, personal"), "{}", err);
+        config.default_context = Some("personal".to_string());
+        assert_eq!(resolve(&config, None, "/tmp").unwrap(), "personal");
+        config.default_context = Some("gone".to_string());
+        assert!(resolve(&config, None, "/tmp").is_err());
+    }
+
+    #[test]
+    fn test_resolve_bead_id() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = AllBeadsConfig::new();
+        for (name, prefix) in [("work", "proj"), ("personal", "me")] {
+            let path = dir.path().join(name);
+            std::fs::create_dir_all(path.join(".beads")).unwrap();
+            std::fs::write(
+                path.join(".beads/config.yaml"),
+                format!("issue-prefix: {}\n", prefix),
+            )
+            .unwrap();
+            config.add_context(
+                BossContext::new(name, "https://github.com/test.git", AuthStrategy::SshAgent)
+                    .with_path(&path),
+            );
+        }
+        let outside = Path::new("/");
+        let none = |_: &str| false;
+
+        // Full IDs pass through untouched
+        assert_eq!(
+            config.resolve_bead_id("me-7", outside, none).unwrap(),
+            "me-7"
+        );
+        // Inside a context its prefix is used
+        assert_eq!(
+            config
+                .resolve_bead_id("12", &dir.path().join("work/src"), none)
+                .unwrap(),
+            "proj-12"
+        );
+        // Outside, the number must match exactly one existing bead
+        assert_eq!(
+            config
+                .resolve_bead_id("12", outside, |id| id == "me-12")
+                .unwrap(),
+            "me-12"
+        );
+        let err = config
+            .resolve_bead_id("12", outside, |_| true)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("proj-12, me-12"), "{}", err);
+        assert!(config.resolve_bead_id("12", outside, none).is_err());
+    }
+
+    #[test]
+    fn test_rename_context() {
+        let mut config = AllBeadsConfig::new();
+        for name in ["work", "personal"] {
+            config.add_context(BossContext::new(
+                name,
+                "https://github.com/test.git",
+                AuthStrategy::SshAgent,
+            ));
+        }
+        config.saved_searches.insert(
+            "mine".to_string(),
+            SavedSearch {
+                context: Some("work".to_string()),
+                ..Default::default()
+            },
+        );
+        config.default_context = Some("work".to_string());
+
+        assert!(config.rename_context("work", "personal").is_err());
+        assert!(config.rename_context("missing", "other").is_err());
+        assert!(config.rename_context("work", "@job").is_err());
+
+        config.rename_context("work", "job").unwrap();
+        assert!(config.get_context("work").is_none());
+        assert!(config.get_context("job").is_some());
+        assert_eq!(
+            config.saved_searches["mine"].context.as_deref(),
+            Some("job")
+        );
+        assert_eq!(config.default_context.as_deref(), Some("job"));
+    }
+
+    #[test]
+    fn test_rename_remote_context_moves_clone() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.yaml");
+        let mut config = AllBeadsConfig::new();
+        config.add_context(
+            BossContext::new(
+                "work",
+                "https://github.com/test.git",
+                AuthStrategy::SshAgent,
+            )
+            .with_remote("https://github.com/test.git"),
+        );
+        config.save(&config_path).unwrap();
+
+        let mut config = AllBeadsConfig::load(&config_path).unwrap();
+        let clone = dir.path().join("remotes").join("work");
+        assert_eq!(
+            config.get_context("work").unwrap().remote_cache_path(),
+            clone
+        );
+        fs::create_dir_all(clone.join(".git")).unwrap();
+
+        config.rename_context("work", "job").unwrap();
+        let moved = dir.path().join("remotes").join("job");
+        assert_eq!(
+            config.get_context("job").unwrap().remote_cache_path(),
+            moved
+        );
+        assert!(!clone.exists());
+        assert!(moved.join(".git").is_dir());
+    }
+
+    #[test]
+    fn test_context_groups() {
+        let mut config = AllBeadsConfig::new();
+        config.add_context(
+            BossContext::new("api", "git@github.com:org/api.git", AuthStrategy::SshAgent)
+                .with_group("backend"),
+        );
+        config.add_context(
+            BossContext::new("web", "git@github.com:org/web.git", AuthStrategy::SshAgent)
+                .with_group("frontend"),
+        );
+        config.add_context(
+            BossContext::new("db", "git@github.com:org/db.git", AuthStrategy::SshAgent)
+                .with_group("Backend"),
+        );
+        config.add_context(BossContext::new(
+            "notes",
+            "git@github.com:user/notes.git",
+            AuthStrategy::SshAgent,
+        ));
+
+        assert_eq!(config.group_members("backend"), vec!["api", "db"]);
+        assert_eq!(config.group_members("Backend"), vec!["api", "db"]);
+        assert!(config.group_members("missing").is_empty());
+
+        let groups = config.groups();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups["frontend"], vec!["web"]);
+        assert_eq!(groups["backend"], vec!["api", "db"]);
+    }
+
     #[test]
     fn test_save_and_load() {
         let temp_file = NamedTempFile::new().unwrap();
@@ -427,6 +1183,109 @@ mod tests {
         assert_eq!(loaded.contexts[0].name, "test");
     }
 
+    #[test]
+    fn test_load_caches_prefix() {
+        let repo = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(repo.path().join(".beads")).unwrap();
+        std::fs::write(
+            repo.path().join(".beads/config.yaml"),
+            "issue-prefix: 'wk'\n",
+        )
+        .unwrap();
+
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut config = AllBeadsConfig::new();
+        config.add_context(
+            BossContext::new(
+                "work",
+                "https://github.com/test.git",
+                AuthStrategy::SshAgent,
+            )
+            .with_path(repo.path()),
+        );
+        config.save(temp_file.path()).unwrap();
+
+        let loaded = AllBeadsConfig::load(temp_file.path()).unwrap();
+        assert_eq!(loaded.contexts[0].issue_prefix(), Some("wk"));
+    }
+
+    #[test]
+    fn test_context_for_bead_id() {
+        let dir = tempfile::tempdir().unwrap();
+        let beads = |name: &str| {
+            let path = dir.path().join(name);
+            std::fs::create_dir_all(path.join(".beads")).unwrap();
+            path
+        };
+
+        let web = beads("web");
+        std::fs::write(web.join(".beads/config.yaml"), "issue-prefix: \"web\"\n").unwrap();
+        let web_api = beads("web-api");
+        std::fs::write(
+            web_api.join(".beads/config.yaml"),
+            "issue-prefix: \"web-api\"\n",
+        )
+        .unwrap();
+        let legacy = beads("legacy");
+        std::fs::write(
+            legacy.join(".beads/issues.jsonl"),
+            "{\"id\":\"old-7\",\"title\":\"x\"}\n",
+        )
+        .unwrap();
+
+        let mut config = AllBeadsConfig::new();
+        for (name, path) in [("web", web), ("web-api", web_api), ("legacy", legacy)] {
+            config.add_context(
+                BossContext::new(name, "https://github.com/test.git", AuthStrategy::SshAgent)
+                    .with_path(path),
+            );
+        }
+
+        let name = |id: &str| config.context_for_bead_id(id).map(|c| c.name.as_str());
+        assert_eq!(name("web-12"), Some("web"));
+        assert_eq!(name("WEB-12"), Some("web"));
+        assert_eq!(name("web-api-3.1"), Some("web-api"));
+        assert_eq!(name("old-9"), Some("legacy"));
+        assert_eq!(name("nope-1"), None);
+        assert_eq!(name("noprefix"), None);
+        assert_eq!(
+            config
+                .context_for_prefix("web-api")
+                .map(|c| c.name.as_str()),
+            Some("web-api")
+        );
+    }
+
+    #[test]
+    fn test_contexts_using_prefix() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = AllBeadsConfig::new();
+        for (name, prefix) in [("work", "ab"), ("fork", "AB"), ("home", "hm")] {
+            let path = dir.path().join(name);
+            std::fs::create_dir_all(path.join(".beads")).unwrap();
+            std::fs::write(
+                path.join(".beads/config.yaml"),
+                format!("issue-prefix: \"{}\"\n", prefix),
+            )
+            .unwrap();
+            config.add_context(
+                BossContext::new(name, "https://github.com/test.git", AuthStrategy::SshAgent)
+                    .with_path(path),
+            );
+        }
+
+        let names = |prefix: &str| {
+            config
+                .contexts_using_prefix(prefix)
+                .iter()
+                .map(|c| c.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names("ab"), vec!["work", "fork"]);
+        assert_eq!(names("hm"), vec!["home"]);
+        assert!(names("new").is_empty());
+    }
+
     #[test]
     fn test_default_path() {
         let path = AllBeadsConfig::default_path();
@@ -483,5 +1342,101 @@ mod tests {
 
         // workspace_directory should be in the YAML
         assert!(yaml.contains("workspace_directory:"));
+        assert!(!yaml.contains("saved_searches:"));
+    }
+
+    #[test]
+    fn test_saved_searches() {
+        let mut config = AllBeadsConfig::new();
+        let search = SavedSearch {
+            status: Some("^closed".to_string()),
+            priority_min: Some("P0".to_string()),
+            priority_max: Some("P1".to_string()),
+            labels: vec!["foo".to_string()],
+            ..Default::default()
+        };
+        config
+            .saved_searches
+            .insert("urgent".to_string(), search.clone());
+
+        let file = NamedTempFile::new().unwrap();
+        config.save(file.path()).unwrap();
+        let loaded = AllBeadsConfig::load(file.path()).unwrap();
+        assert_eq!(loaded.saved_searches["urgent"], search);
+
+        assert_eq!(
+            search.to_args(),
+            "--status ^closed --priority-min P0 --priority-max P1 --label foo"
+        );
+    }
+
+    #[test]
+    fn test_bead_templates() {
+        let yaml = r#"
+contexts: []
+bead_templates:
+  deploy:
+    issue_type: chore
+    priority: P1
+    labels: [deploy, ops]
+"#;
+        let config: AllBeadsConfig = serde_yaml::from_str(yaml).unwrap();
+        let deploy = &config.bead_templates["deploy"];
+        assert_eq!(deploy.issue_type.as_deref(), Some("chore"));
+        assert_eq!(deploy.priority.as_deref(), Some("P1"));
+        assert_eq!(deploy.labels, vec!["deploy", "ops"]);
+        assert!(deploy.description.is_none());
+
+        let yaml = serde_yaml::to_string(&AllBeadsConfig::new()).unwrap();
+        assert!(!yaml.contains("bead_templates:"));
+    }
+
+    #[test]
+    fn test_duplicates_config() {
+        let config: AllBeadsConfig = serde_yaml::from_str("contexts: []\n").unwrap();
+        assert_eq!(config.duplicates, DuplicatesConfig::default());
+        assert_eq!(config.duplicates.threshold, 0.8);
+
+        let yaml = "contexts: []\nduplicates:\n  threshold: 0.6\n";
+        let mut config: AllBeadsConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.duplicates.threshold, 0.6);
+        assert_eq!(config.duplicates.min_title_len, 0);
+
+        config.duplicates.min_title_len = 8;
+        let yaml = serde_yaml::to_string(&config).unwrap();
+        assert!(yaml.contains("min_title_len: 8"));
+        assert!(!serde_yaml::to_string(&AllBeadsConfig::new())
+            .unwrap()
+            .contains("duplicates:"));
+    }
+
+    #[test]
+    fn test_janitor_templates() {
+        let yaml = r#"
+contexts: []
+janitor_templates:
+  TODO: "From {file}:{line}\n\n> {text}\n\n- [ ] Fix\n- [ ] Remove comment"
+"#;
+        let config: AllBeadsConfig = serde_yaml::from_str(yaml).unwrap();
+        let templates = &config.janitor_templates;
+        assert!(janitor_template(templates, "Security").is_none());
+        assert_eq!(
+            janitor_template(templates, "Bug"),
+            janitor_template(templates, "TODO")
+        );
+
+        let rendered = render_janitor_template(
+            janitor_template(templates, "Task").unwrap(),
+            &[
+                ("file", "src/lib.rs"),
+                ("line", "12"),
+                ("text", "TODO: tidy"),
+            ],
+        );
+        assert_eq!(
+            rendered,
+            "From src/lib.rs:12\n\n> TODO: tidy\n\n- [ ] Fix\n- [ ] Remove comment"
+        );
+        assert_eq!(render_janitor_template("{other}", &[]), "{other}");
     }
 }
@@ -0,0 +1,211 @@
+//! Timestamped config backups
+//!
+//! Forced config operations (`ab config init --force`, `ab config pull
+//! --force`, `ab config resolve --theirs`) can throw away local edits, so the
+//! YAML files in the config directory are copied to `backups/<timestamp>-<reason>/`
+//! first. `ab config restore` copies a backup back. Backups are excluded from
+//! config sync through `.git/info/exclude`, which is never pushed.
+
+use crate::{AllBeadsError, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory under the config directory holding backups
+pub const BACKUP_DIR: &str = "backups";
+
+/// A saved copy of the config files
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigBackup {
+    /// Directory name, e.g. `20260301T120000-pull`, used by `ab config restore`
+    pub name: String,
+    pub path: PathBuf,
+    /// Files in the backup, by name
+    pub files: Vec<String>,
+}
+
+/// YAML files directly in `dir`, sorted by name
+fn yaml_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    if !dir.is_dir() {
+        return Ok(files);
+    }
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_yaml = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e == "yaml" || e == "yml");
+        if is_yaml && path.is_file() {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Copy the config files in `config_dir` into a new backup
+///
+/// `reason` (e.g. `pull`) is appended to the backup name. Returns `None` when
+/// there are no config files to back up.
+pub fn backup_config(config_dir: &Path, reason: &str) -> Result<Option<ConfigBackup>> {
+    let files = yaml_files(config_dir)?;
+    if files.is_empty() {
+        return Ok(None);
+    }
+
+    let stamp = chrono::Local::now().format("%Y%m%dT%H%M%S");
+    let backups = config_dir.join(BACKUP_DIR);
+    let mut name = format!("{}-{}", stamp, reason);
+    // Two backups in the same second get a counter rather than overwriting
+    let mut n = 1;
+    while backups.join(&name).exists() {
+        n += 1;
+        name = format!("{}-{}-{}", stamp, reason, n);
+    }
+    let path = backups.join(&name);
+    fs::create_dir_all(&path)?;
+
+    let mut copied = Vec::new();
+    for file in files {
+        let file_name = file.file_name().unwrap_or_default();
+        fs::copy(&file, path.join(file_name))?;
+        copied.push(file_name.to_string_lossy().into_owned());
+    }
+    exclude_from_sync(config_dir)?;
+
+    Ok(Some(ConfigBackup {
+        name,
+        path,
+        files: copied,
+    }))
+}
+
+/// Keep `backups/` out of config sync without touching the synced `.gitignore`
+fn exclude_from_sync(config_dir: &Path) -> Result<()> {
+    let info = config_dir.join(".git").join("info");
+    if !config_dir.join(".git").is_dir() {
+        return Ok(());
+    }
+    let exclude = info.join("exclude");
+    let pattern = format!("/{}/", BACKUP_DIR);
+    let current = fs::read_to_string(&exclude).unwrap_or_default();
+    if current.lines().any(|l| l.trim() == pattern) {
+        return Ok(());
+    }
+    fs::create_dir_all(&info)?;
+    let separator = if current.is_empty() || current.ends_with('\n') {
+        ""
+    } else {
+        "\n"
+    };
+    fs::write(&exclude, format!("{}{}{}\n", current, separator, pattern))?;
+    Ok(())
+}
+
+/// All backups in `config_dir`, newest first
+pub fn list_backups(config_dir: &Path) -> Result<Vec<ConfigBackup>> {
+    let dir = config_dir.join(BACKUP_DIR);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut backups = Vec::new();
+    for entry in fs::read_dir(&dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let files = yaml_files(&path)?
+            .iter()
+            .filter_map(|f| f.file_name().map(|n| n.to_string_lossy().into_owned()))
+            .collect();
+        backups.push(ConfigBackup {
+            name: path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            path,
+            files,
+        });
+    }
+    // Names start with a sortable timestamp
+    backups.sort_by(|a, b| b.name.cmp(&a.name));
+    Ok(backups)
+}
+
+/// Copy a backup's files back into `config_dir`
+///
+/// The current files are backed up first (as `<timestamp>-restore`), so a
+/// restore can itself be undone. Files not in the backup are left alone.
+/// Returns the safety backup, if there was anything to save.
+pub fn restore_backup(config_dir: &Path, name: &str) -> Result<Option<ConfigBackup>> {
+    let backup = list_backups(config_dir)?
+        .into_iter()
+        .find(|b| b.name == name)
+        .ok_or_else(|| {
+            AllBeadsError::Config(format!(
+                "Backup '{}' not found (list them with 'ab config restore')",
+                name
+            ))
+        })?;
+    if backup.files.is_empty() {
+        return Err(AllBeadsError::Config(format!(
+            "Backup '{}' has no config files",
+            name
+        )));
+    }
+
+    let safety = backup_config(config_dir, "restore")?;
+    for file in &backup.files {
+        fs::copy(backup.path.join(file), config_dir.join(file))?;
+    }
+    Ok(safety)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backup_and_restore() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_dir = dir.path();
+        fs::create_dir_all(config_dir.join(".git/info")).unwrap();
+        fs::write(config_dir.join("config.yaml"), "contexts: []\n").unwrap();
+        fs::write(config_dir.join("notes.txt"), "not config").unwrap();
+
+        let first = backup_config(config_dir, "pull").unwrap().unwrap();
+        assert!(first.name.ends_with("-pull"));
+        assert_eq!(first.files, vec!["config.yaml"]);
+        let second = backup_config(config_dir, "pull").unwrap().unwrap();
+        assert_ne!(first.name, second.name);
+
+        let exclude = fs::read_to_string(config_dir.join(".git/info/exclude")).unwrap();
+        assert_eq!(exclude.matches("/backups/").count(), 1);
+
+        fs::write(config_dir.join("config.yaml"), "contexts: [lost]\n").unwrap();
+        let safety = restore_backup(config_dir, &first.name).unwrap().unwrap();
+        assert_eq!(
+            fs::read_to_string(config_dir.join("config.yaml")).unwrap(),
+            "contexts: []\n"
+        );
+        assert_eq!(
+            fs::read_to_string(safety.path.join("config.yaml")).unwrap(),
+            "contexts: [lost]\n"
+        );
+
+        let names: Vec<String> = list_backups(config_dir)
+            .unwrap()
+            .into_iter()
+            .map(|b| b.name)
+            .collect();
+        assert_eq!(names.len(), 3);
+        assert!(restore_backup(config_dir, "missing").is_err());
+    }
+
+    #[test]
+    fn test_backup_without_config() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(backup_config(dir.path(), "init").unwrap().is_none());
+        assert!(list_backups(dir.path()).unwrap().is_empty());
+    }
+}
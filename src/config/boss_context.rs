@@ -83,9 +83,28 @@ pub struct BossContext {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<PathBuf>,
 
+    /// Git URL for a remote-only context
+    ///
+    /// When set and `path` is not, AllBeads keeps its own clone under
+    /// `~/.config/allbeads/remotes/<name>` and treats it like a local context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
+
     /// Authentication strategy
     pub auth_strategy: AuthStrategy,
 
+    /// Optional group name for viewing related contexts together (e.g., "backend")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+
+    /// Type for beads created here when none is given (e.g., "chore")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_type: Option<String>,
+
+    /// Priority for beads created here when none is given (P0-P4 or 0-4)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_priority: Option<String>,
+
     /// Environment variables required for this context
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub env_vars: HashMap<String, String>,
@@ -97,6 +116,15 @@ pub struct BossContext {
     /// Member Rig repositories (loaded at runtime)
     #[serde(skip)]
     pub rigs: Vec<Rig>,
+
+    /// Issue prefix from `.beads/config.yaml` (loaded at runtime)
+    #[serde(skip)]
+    pub prefix: Option<String>,
+
+    /// Directory holding managed clones, next to the loaded config file
+    /// (set at runtime; falls back to the default config directory)
+    #[serde(skip)]
+    pub remotes_dir: Option<PathBuf>,
 }
 
 fn is_default_integrations(integrations: &Integrations) -> bool {
@@ -115,10 +143,16 @@ impl BossContext {
             repo_type: "git".to_string(),
             url: url.into(),
             path: None,
+            remote: None,
             auth_strategy,
+            group: None,
+            default_type: None,
+            default_priority: None,
             env_vars: HashMap::new(),
             integrations: Integrations::default(),
             rigs: Vec::new(),
+            prefix: None,
+            remotes_dir: None,
         }
     }
 
@@ -128,6 +162,18 @@ impl BossContext {
         self
     }
 
+    /// Make this a remote-only context cloned from the given URL
+    pub fn with_remote(mut self, remote: impl Into<String>) -> Self {
+        self.remote = Some(remote.into());
+        self
+    }
+
+    /// Set the group this context belongs to
+    pub fn with_group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
     /// Add an environment variable
     pub fn with_env_var(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.env_vars.insert(key.into(), value.into());
@@ -159,10 +205,65 @@ impl BossContext {
         self.rigs.push(rig);
     }
 
+    /// Whether this is a remote-only context with a managed clone
+    pub fn is_remote(&self) -> bool {
+        self.path.is_none() && self.remote.is_some()
+    }
+
+    /// URL to clone from (the `remote` URL if set, otherwise `url`)
+    pub fn clone_url(&self) -> &str {
+        self.remote.as_deref().unwrap_or(&self.url)
+    }
+
+    /// Directory holding the managed clone of a remote-only context
+    pub fn remote_cache_path(&self) -> PathBuf {
+        let mut path = self.remotes_dir.clone().unwrap_or_else(|| {
+            let mut path = crate::config::AllBeadsConfig::default_path();
+            path.pop();
+            path.push("remotes");
+            path
+        });
+        path.push(&self.name);
+        path
+    }
+
+    /// Get the working directory for running `bd` in this context
+    ///
+    /// Returns the configured path, or the managed clone of a remote-only
+    /// context once it has been cloned. `None` means there is nothing local.
+    pub fn local_path(&self) -> Option<PathBuf> {
+        if let Some(ref path) = self.path {
+            return Some(path.clone());
+        }
+        if self.remote.is_some() {
+            let cache = self.remote_cache_path();
+            if cache.join(".git").exists() {
+                return Some(cache);
+            }
+        }
+        None
+    }
+
+    /// Issue prefix from the context's `.beads/config.yaml`, if available locally
+    ///
+    /// Cached when the configuration is loaded; see [`Self::load_prefix`].
+    pub fn issue_prefix(&self) -> Option<&str> {
+        self.prefix.as_deref()
+    }
+
+    /// Read and cache the issue prefix from the context's `.beads/config.yaml`
+    pub fn load_prefix(&mut self) {
+        self.prefix = self
+            .local_path()
+            .and_then(|path| crate::config::validation::read_issue_prefix(&path));
+    }
+
     /// Get the local path, computing it if not set
     pub fn get_path(&self) -> PathBuf {
         if let Some(ref path) = self.path {
             path.clone()
+        } else if self.remote.is_some() {
+            self.remote_cache_path()
         } else {
             // Default to ~/.config/allbeads/{context_name}
             let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
@@ -266,6 +367,47 @@ mod tests {
         assert!(yaml.contains("auth_strategy: gh_enterprise_token"));
     }
 
+    #[test]
+    fn test_boss_context_group_serialization() {
+        let context = BossContext::new(
+            "api",
+            "https://github.com/org/api.git",
+            AuthStrategy::SshAgent,
+        );
+        let yaml = serde_yaml::to_string(&context).unwrap();
+        assert!(!yaml.contains("group:"));
+
+        let context = context.with_group("backend");
+        let yaml = serde_yaml::to_string(&context).unwrap();
+        assert!(yaml.contains("group: backend"));
+
+        let parsed: BossContext = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed.group.as_deref(), Some("backend"));
+    }
+
+    #[test]
+    fn test_boss_context_create_defaults() {
+        let yaml = r#"
+name: docs
+type: git
+url: https://github.com/org/docs.git
+auth_strategy: ssh_agent
+default_type: chore
+default_priority: P3
+"#;
+        let context: BossContext = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(context.default_type.as_deref(), Some("chore"));
+        assert_eq!(context.default_priority.as_deref(), Some("P3"));
+
+        let yaml = serde_yaml::to_string(&BossContext::new(
+            "api",
+            "https://github.com/org/api.git",
+            AuthStrategy::SshAgent,
+        ))
+        .unwrap();
+        assert!(!yaml.contains("default_type:"));
+    }
+
     #[test]
     fn test_get_path_default() {
         let context = BossContext::new(
@@ -291,6 +433,24 @@ mod tests {
         assert_eq!(path, PathBuf::from("/custom/path"));
     }
 
+    #[test]
+    fn test_remote_context() {
+        let context = BossContext::new(
+            "mirror",
+            "https://github.com/org/boss.git",
+            AuthStrategy::SshAgent,
+        )
+        .with_remote("git@github.com:org/boss.git");
+
+        assert!(context.is_remote());
+        assert_eq!(context.clone_url(), "git@github.com:org/boss.git");
+        assert!(context.get_path().ends_with("allbeads/remotes/mirror"));
+
+        let local = context.with_path("/custom/path");
+        assert!(!local.is_remote());
+        assert_eq!(local.local_path(), Some(PathBuf::from("/custom/path")));
+    }
+
     #[test]
     fn test_add_rig() {
         let mut context = BossContext::new(
@@ -0,0 +1,246 @@
+//! Three-way merge of YAML config files
+//!
+//! Used by `ab config resolve --merge` when the synced config has diverged.
+//! Values are merged field by field against the common ancestor, so two
+//! machines that each changed a different setting (or added a different
+//! context) merge cleanly. Lists of mappings with a `name` key, like
+//! `contexts`, are matched by name rather than by position.
+
+use crate::{AllBeadsError, Result};
+use serde_yaml::{Mapping, Value};
+
+/// Result of merging two edits of a YAML document
+#[derive(Debug, Clone)]
+pub struct YamlMerge {
+    /// Merged document; conflicting fields keep our value
+    pub merged: Value,
+    /// Paths of fields changed differently on both sides, e.g. `contexts[work].path`
+    pub conflicts: Vec<String>,
+}
+
+impl YamlMerge {
+    /// Render the merged document
+    pub fn to_yaml(&self) -> Result<String> {
+        serde_yaml::to_string(&self.merged)
+            .map_err(|e| AllBeadsError::Config(format!("Failed to serialize merged config: {}", e)))
+    }
+}
+
+/// Merge `ours` and `theirs` against their common ancestor `base`
+///
+/// An empty `base` means the file was added on both sides.
+pub fn merge_yaml(base: &str, ours: &str, theirs: &str) -> Result<YamlMerge> {
+    let parse = |side: &str, text: &str| -> Result<Option<Value>> {
+        if text.trim().is_empty() {
+            return Ok(None);
+        }
+        serde_yaml::from_str(text)
+            .map(Some)
+            .map_err(|e| AllBeadsError::Parse(format!("Invalid YAML in {} version: {}", side, e)))
+    };
+    let base = parse("base", base)?;
+    let ours = parse("our", ours)?;
+    let theirs = parse("their", theirs)?;
+
+    let mut conflicts = Vec::new();
+    let merged = merge_value(
+        "",
+        base.as_ref(),
+        ours.as_ref(),
+        theirs.as_ref(),
+        &mut conflicts,
+    )
+    .unwrap_or(Value::Null);
+    Ok(YamlMerge { merged, conflicts })
+}
+
+fn merge_value(
+    path: &str,
+    base: Option<&Value>,
+    ours: Option<&Value>,
+    theirs: Option<&Value>,
+    conflicts: &mut Vec<String>,
+) -> Option<Value> {
+    if ours == theirs {
+        return ours.cloned();
+    }
+    if ours == base {
+        return theirs.cloned();
+    }
+    if theirs == base {
+        return ours.cloned();
+    }
+
+    match (ours, theirs) {
+        (Some(Value::Mapping(o)), Some(Value::Mapping(t))) => {
+            let b = base.and_then(Value::as_mapping);
+            Some(Value::Mapping(merge_mapping(path, b, o, t, conflicts)))
+        }
+        (Some(Value::Sequence(o)), Some(Value::Sequence(t))) => {
+            let b = base.and_then(Value::as_sequence);
+            match merge_named_sequence(path, b, o, t, conflicts) {
+                Some(merged) => Some(Value::Sequence(merged)),
+                None => {
+                    conflicts.push(display_path(path));
+                    ours.cloned()
+                }
+            }
+        }
+        _ => {
+            conflicts.push(display_path(path));
+            ours.cloned()
+        }
+    }
+}
+
+fn merge_mapping(
+    path: &str,
+    base: Option<&Mapping>,
+    ours: &Mapping,
+    theirs: &Mapping,
+    conflicts: &mut Vec<String>,
+) -> Mapping {
+    // Our key order first, then keys only they have
+    let keys = ours
+        .keys()
+        .chain(theirs.keys().filter(|k| !ours.contains_key(*k)));
+
+    let mut merged = Mapping::new();
+    for key in keys {
+        let child = match key {
+            Value::String(s) if path.is_empty() => s.clone(),
+            Value::String(s) => format!("{}.{}", path, s),
+            other => format!("{}.{:?}", path, other),
+        };
+        if let Some(value) = merge_value(
+            &child,
+            base.and_then(|b| b.get(key)),
+            ours.get(key),
+            theirs.get(key),
+            conflicts,
+        ) {
+            merged.insert(key.clone(), value);
+        }
+    }
+    merged
+}
+
+/// Merge lists whose items are all mappings with a unique `name`
+///
+/// Returns `None` for any other list, which is then treated as one value.
+fn merge_named_sequence(
+    path: &str,
+    base: Option<&Vec<Value>>,
+    ours: &[Value],
+    theirs: &[Value],
+    conflicts: &mut Vec<String>,
+) -> Option<Vec<Value>> {
+    let base = base.map(Vec::as_slice).unwrap_or_default();
+    let ours = by_name(ours)?;
+    let theirs = by_name(theirs)?;
+    let base = by_name(base)?;
+
+    let names = ours.iter().map(|(n, _)| *n).chain(
+        theirs
+            .iter()
+            .map(|(n, _)| *n)
+            .filter(|n| find(&ours, n).is_none()),
+    );
+
+    let mut merged = Vec::new();
+    for name in names {
+        let child = format!("{}[{}]", path, name);
+        if let Some(value) = merge_value(
+            &child,
+            find(&base, name),
+            find(&ours, name),
+            find(&theirs, name),
+            conflicts,
+        ) {
+            merged.push(value);
+        }
+    }
+    Some(merged)
+}
+
+fn find<'a>(items: &[(&str, &'a Value)], name: &str) -> Option<&'a Value> {
+    items.iter().find(|(n, _)| *n == name).map(|(_, v)| *v)
+}
+
+fn by_name(items: &[Value]) -> Option<Vec<(&str, &Value)>> {
+    let mut named: Vec<(&str, &Value)> = Vec::with_capacity(items.len());
+    for item in items {
+        let name = item.get("name")?.as_str()?;
+        if named.iter().any(|(n, _)| *n == name) {
+            return None;
+        }
+        named.push((name, item));
+    }
+    Some(named)
+}
+
+fn display_path(path: &str) -> String {
+    if path.is_empty() {
+        "(document)".to_string()
+    } else {
+        path.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str = "\
+cache_ttl: 300
+contexts:
+  - name: work
+    path: ~/work
+  - name: oss
+    path: ~/oss
+";
+
+    #[test]
+    fn test_merge_yaml_disjoint_edits() {
+        let ours = "\
+cache_ttl: 600
+contexts:
+  - name: work
+    path: ~/work
+  - name: oss
+    path: ~/oss
+  - name: home
+    path: ~/home
+";
+        let theirs = "\
+cache_ttl: 300
+contexts:
+  - name: work
+    path: ~/src/work
+";
+
+        let merge = merge_yaml(BASE, ours, theirs).unwrap();
+        assert!(merge.conflicts.is_empty());
+
+        let merged = &merge.merged;
+        assert_eq!(merged["cache_ttl"], 600);
+        let contexts = merged["contexts"].as_sequence().unwrap();
+        let names: Vec<&str> = contexts
+            .iter()
+            .map(|c| c["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["work", "home"]);
+        assert_eq!(contexts[0]["path"], "~/src/work");
+    }
+
+    #[test]
+    fn test_merge_yaml_conflict_keeps_ours() {
+        let ours = BASE.replace("~/oss", "~/code/oss");
+        let theirs = BASE.replace("~/oss", "~/projects/oss");
+
+        let merge = merge_yaml(BASE, &ours, &theirs).unwrap();
+        assert_eq!(merge.conflicts, vec!["contexts[oss].path".to_string()]);
+        assert_eq!(merge.merged["contexts"][1]["path"], "~/code/oss");
+        assert!(merge.to_yaml().unwrap().contains("~/code/oss"));
+    }
+}
@@ -10,13 +10,21 @@
 //! - Visualization preferences
 
 mod allbeads_config;
+mod backup;
 mod boss_context;
+mod merge;
 pub mod validation;
 
 pub use allbeads_config::{
-    AgentMailConfig, AllBeadsConfig, OnboardingConfig, VisualizationConfig, WebAuthConfig,
+    janitor_template, render_janitor_template, AgentMailConfig, AllBeadsConfig, BeadTemplate,
+    DuplicatesConfig, OnboardingConfig, SavedSearch, VisualizationConfig, WebAuthConfig,
 };
+pub use backup::{backup_config, list_backups, restore_backup, ConfigBackup, BACKUP_DIR};
 pub use boss_context::{
     AuthStrategy, BossContext, GitHubIntegration, Integrations, JiraIntegration,
 };
-pub use validation::{validate_config, validate_config_result, ValidationError};
+pub use merge::{merge_yaml, YamlMerge};
+pub use validation::{
+    check_config, read_issue_prefix, validate_config, validate_config_result, ConfigIssue,
+    IssueSeverity, ValidationError,
+};
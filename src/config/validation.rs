@@ -9,7 +9,8 @@
 use super::allbeads_config::AllBeadsConfig;
 use super::boss_context::{AuthStrategy, BossContext};
 use crate::AllBeadsError;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 
 /// Validation error details
 #[derive(Debug, Clone)]
@@ -47,6 +48,168 @@ impl std::fmt::Display for ValidationError {
 /// Validation result
 pub type ValidationResult = std::result::Result<(), Vec<ValidationError>>;
 
+/// Severity of a configuration issue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IssueSeverity {
+    /// Something looks off but AllBeads can still work
+    Warning,
+    /// The context cannot be used until this is fixed
+    Error,
+}
+
+impl std::fmt::Display for IssueSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IssueSeverity::Warning => write!(f, "warning"),
+            IssueSeverity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A problem found in the on-disk state of a configuration
+///
+/// Unlike [`ValidationError`], which checks the config file's own fields,
+/// these checks look at what each context points to (paths, `.beads/`, prefixes).
+#[derive(Debug, Clone)]
+pub struct ConfigIssue {
+    pub severity: IssueSeverity,
+    pub context: Option<String>,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    fn error(context: &str, message: impl Into<String>) -> Self {
+        Self {
+            severity: IssueSeverity::Error,
+            context: Some(context.to_string()),
+            message: message.into(),
+        }
+    }
+
+    fn warning(context: &str, message: impl Into<String>) -> Self {
+        Self {
+            severity: IssueSeverity::Warning,
+            context: Some(context.to_string()),
+            message: message.into(),
+        }
+    }
+
+    /// Whether this issue is an error (as opposed to a warning)
+    pub fn is_error(&self) -> bool {
+        self.severity == IssueSeverity::Error
+    }
+}
+
+impl std::fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(ref ctx) = self.context {
+            write!(f, "[{}] {}", ctx, self.message)
+        } else {
+            write!(f, "{}", self.message)
+        }
+    }
+}
+
+/// Check contexts for duplicate names, unusable paths, and bad issue prefixes
+pub fn check_config(config: &AllBeadsConfig) -> Vec<ConfigIssue> {
+    let mut issues = Vec::new();
+
+    let mut seen_names = HashSet::new();
+    for context in &config.contexts {
+        if !seen_names.insert(context.name.as_str()) {
+            issues.push(ConfigIssue::error(&context.name, "Duplicate context name"));
+        }
+    }
+
+    if let Some(ref name) = config.default_context {
+        if !seen_names.contains(name.trim_start_matches('@')) {
+            issues.push(ConfigIssue {
+                severity: IssueSeverity::Error,
+                context: None,
+                message: format!("default_context '{}' is not a configured context", name),
+            });
+        }
+    }
+
+    let mut seen_prefixes: HashMap<String, &str> = HashMap::new();
+    for context in &config.contexts {
+        let Some(ref path) = context.path else {
+            // URL-only contexts have nothing on disk to check
+            continue;
+        };
+
+        if !path.exists() {
+            issues.push(ConfigIssue::error(
+                &context.name,
+                format!("Path does not exist: {}", path.display()),
+            ));
+            continue;
+        }
+
+        if !path.is_dir() {
+            issues.push(ConfigIssue::error(
+                &context.name,
+                format!("Path is not a directory: {}", path.display()),
+            ));
+            continue;
+        }
+
+        if !path.join(".beads").is_dir() {
+            issues.push(ConfigIssue::warning(
+                &context.name,
+                format!(
+                    "No .beads/ directory in {} (run 'bd init' there)",
+                    path.display()
+                ),
+            ));
+            continue;
+        }
+
+        if let Some(prefix) = context.issue_prefix() {
+            if !is_valid_prefix(prefix) {
+                issues.push(ConfigIssue::error(
+                    &context.name,
+                    format!(
+                        "Invalid issue prefix '{}' (use letters, digits, '-' or '_')",
+                        prefix
+                    ),
+                ));
+            } else if let Some(other) = seen_prefixes.get(&prefix.to_lowercase()) {
+                issues.push(ConfigIssue::warning(
+                    &context.name,
+                    format!("Issue prefix '{}' is also used by '{}'", prefix, other),
+                ));
+            } else {
+                seen_prefixes.insert(prefix.to_lowercase(), &context.name);
+            }
+        }
+    }
+
+    issues
+}
+
+/// Read the `issue-prefix` from a repository's `.beads/config.yaml`
+pub fn read_issue_prefix(repo_path: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(repo_path.join(".beads/config.yaml")).ok()?;
+    content.lines().find_map(|line| {
+        line.strip_prefix("issue-prefix:").map(|value| {
+            value
+                .trim()
+                .trim_matches('"')
+                .trim_matches('\'')
+                .to_string()
+        })
+    })
+}
+
+/// Check that an issue prefix is usable in bead IDs
+fn is_valid_prefix(prefix: &str) -> bool {
+    !prefix.is_empty()
+        && prefix
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
 /// Validate an AllBeads configuration
 pub fn validate_config(config: &AllBeadsConfig) -> ValidationResult {
     let mut errors = Vec::new();
@@ -373,6 +536,84 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_check_config_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let with_beads = dir.path().join("with-beads");
+        let without_beads = dir.path().join("without-beads");
+        std::fs::create_dir_all(with_beads.join(".beads")).unwrap();
+        std::fs::create_dir_all(&without_beads).unwrap();
+
+        let mut config = AllBeadsConfig::new();
+        config.add_context(
+            BossContext::new("ok", "git@github.com:a/ok.git", AuthStrategy::SshAgent)
+                .with_path(&with_beads),
+        );
+        config.add_context(
+            BossContext::new("nobeads", "git@github.com:a/nb.git", AuthStrategy::SshAgent)
+                .with_path(&without_beads),
+        );
+        config.add_context(
+            BossContext::new("gone", "git@github.com:a/gone.git", AuthStrategy::SshAgent)
+                .with_path(dir.path().join("missing")),
+        );
+        config.add_context(BossContext::new(
+            "ok",
+            "git@github.com:a/ok2.git",
+            AuthStrategy::SshAgent,
+        ));
+
+        let issues = check_config(&config);
+        assert_eq!(issues.len(), 3);
+        assert!(issues
+            .iter()
+            .any(|i| i.is_error() && i.message.contains("Duplicate")));
+        assert!(issues
+            .iter()
+            .any(|i| i.is_error() && i.message.contains("does not exist")));
+        assert!(issues
+            .iter()
+            .any(|i| !i.is_error() && i.message.contains("No .beads/")));
+
+        config.default_context = Some("@ok".to_string());
+        assert_eq!(check_config(&config).len(), 3);
+        config.default_context = Some("missing".to_string());
+        assert!(check_config(&config)
+            .iter()
+            .any(|i| i.is_error() && i.message.contains("default_context")));
+    }
+
+    #[test]
+    fn test_check_config_prefixes() {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, prefix) in [("a", "ab"), ("b", "AB"), ("c", "bad prefix")] {
+            let beads = dir.path().join(name).join(".beads");
+            std::fs::create_dir_all(&beads).unwrap();
+            std::fs::write(
+                beads.join("config.yaml"),
+                format!("issue-prefix: \"{}\"\n", prefix),
+            )
+            .unwrap();
+        }
+
+        let mut config = AllBeadsConfig::new();
+        for name in ["a", "b", "c"] {
+            config.add_context(
+                BossContext::new(name, "git@github.com:a/r.git", AuthStrategy::SshAgent)
+                    .with_path(dir.path().join(name)),
+            );
+        }
+
+        let issues = check_config(&config);
+        assert_eq!(issues.len(), 2);
+        assert!(issues
+            .iter()
+            .any(|i| !i.is_error() && i.message.contains("also used by 'a'")));
+        assert!(issues
+            .iter()
+            .any(|i| i.is_error() && i.message.contains("Invalid issue prefix")));
+    }
+
     #[test]
     fn test_gh_enterprise_token_validation() {
         let mut config = AllBeadsConfig::new();
@@ -0,0 +1,496 @@
+//! Health checks for `ab doctor`
+//!
+//! Checks that `bd` is installed, the configuration is valid, each context
+//! points at a beads repository, no bead ID is shared between contexts and
+//! the cache is writable, and collects the results into a report that can be
+//! printed or serialized as JSON.
+//!
+//! Contexts whose path has moved or lost its `.beads/` directory are reported
+//! as orphans: they load no beads, so without this they go unnoticed.
+
+use crate::config::{validate_config, AllBeadsConfig, BossContext, IssueSeverity};
+use crate::Result;
+use beads::Beads;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// Outcome of a single check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+/// Result of one health check
+#[derive(Debug, Clone, Serialize)]
+pub struct Check {
+    /// What was checked (`bd`, `config`, `context`, `orphan`, `beads`, `collisions`, `cache`)
+    pub name: String,
+    /// Context the check applies to, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    pub status: CheckStatus,
+    pub message: String,
+    /// Command that would fix the problem, if there is an obvious one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+/// All check results from one `ab doctor` run
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DoctorReport {
+    pub checks: Vec<Check>,
+}
+
+impl DoctorReport {
+    fn push(
+        &mut self,
+        name: &str,
+        context: Option<&str>,
+        status: CheckStatus,
+        message: impl Into<String>,
+    ) {
+        self.checks.push(Check {
+            name: name.to_string(),
+            context: context.map(str::to_string),
+            status,
+            message: message.into(),
+            suggestion: None,
+        });
+    }
+
+    /// Attach a fix suggestion to the most recently pushed check
+    fn suggest(&mut self, suggestion: impl Into<String>) {
+        if let Some(check) = self.checks.last_mut() {
+            check.suggestion = Some(suggestion.into());
+        }
+    }
+
+    /// Number of checks with the given status
+    pub fn count(&self, status: CheckStatus) -> usize {
+        self.checks.iter().filter(|c| c.status == status).count()
+    }
+
+    /// Whether any check failed
+    pub fn has_failures(&self) -> bool {
+        self.count(CheckStatus::Fail) > 0
+    }
+}
+
+/// Run every health check
+///
+/// `config` is the result of loading the configuration, so a config that
+/// fails to load is reported as a failed check rather than aborting the run.
+/// Context checks are limited to `contexts` when it is non-empty, and
+/// `bd_flags` are passed through to `bd doctor`.
+pub fn run(
+    config: &Result<AllBeadsConfig>,
+    cache_path: &Path,
+    contexts: &[String],
+    bd_flags: &[String],
+) -> DoctorReport {
+    let mut report = DoctorReport::default();
+
+    let bd_installed = check_bd(&mut report);
+
+    match config {
+        Ok(config) => {
+            check_config(&mut report, config);
+            check_contexts(&mut report, config, contexts, bd_installed, bd_flags);
+            check_collisions(&mut report, config, contexts);
+        }
+        Err(e) => report.push("config", None, CheckStatus::Fail, e.to_string()),
+    }
+
+    check_cache(&mut report, cache_path);
+
+    report
+}
+
+/// Check that `bd` is on the PATH, returning whether it is
+fn check_bd(report: &mut DoctorReport) -> bool {
+    match Beads::default().version() {
+        Ok(version) if beads::is_supported_version(&version) => {
+            report.push("bd", None, CheckStatus::Pass, format!("bd {}", version));
+            true
+        }
+        Ok(version) => {
+            report.push(
+                "bd",
+                None,
+                CheckStatus::Warn,
+                format!(
+                    "bd {} is untested (supported: {} or newer {}.x)",
+                    version,
+                    beads::MIN_SUPPORTED_VERSION,
+                    beads::MIN_SUPPORTED_VERSION.major
+                ),
+            );
+            true
+        }
+        Err(beads::Error::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+            report.push(
+                "bd",
+                None,
+                CheckStatus::Fail,
+                "bd is not installed or not in PATH",
+            );
+            false
+        }
+        Err(e) => {
+            report.push("bd", None, CheckStatus::Fail, e.to_string());
+            false
+        }
+    }
+}
+
+/// Check the config file's own fields (URLs, auth, required env vars)
+fn check_config(report: &mut DoctorReport, config: &AllBeadsConfig) {
+    match validate_config(config) {
+        Ok(()) => report.push(
+            "config",
+            None,
+            CheckStatus::Pass,
+            format!("{} context(s) configured", config.contexts.len()),
+        ),
+        Err(errors) => {
+            for error in errors {
+                report.push(
+                    "config",
+                    error.context.as_deref(),
+                    CheckStatus::Fail,
+                    format!("{}: {}", error.field, error.message),
+                );
+            }
+        }
+    }
+}
+
+/// Check that each context points at a beads repository
+fn check_contexts(
+    report: &mut DoctorReport,
+    config: &AllBeadsConfig,
+    contexts: &[String],
+    bd_installed: bool,
+    bd_flags: &[String],
+) {
+    let issues = config.validate();
+
+    for ctx in &config.contexts {
+        if !contexts.is_empty() && !contexts.iter().any(|c| c.eq_ignore_ascii_case(&ctx.name)) {
+            continue;
+        }
+        let name = Some(ctx.name.as_str());
+
+        if check_orphan(report, ctx) {
+            continue;
+        }
+
+        let ctx_issues: Vec<_> = issues
+            .iter()
+            .filter(|i| i.context.as_deref() == name)
+            .collect();
+        if !ctx_issues.is_empty() {
+            for issue in ctx_issues {
+                let status = match issue.severity {
+                    IssueSeverity::Error => CheckStatus::Fail,
+                    IssueSeverity::Warning => CheckStatus::Warn,
+                };
+                report.push("context", name, status, issue.message.clone());
+            }
+            continue;
+        }
+
+        let Some(path) = ctx.local_path() else {
+            let message = if ctx.is_remote() {
+                format!("Not cloned yet (run 'ab sync {}')", ctx.name)
+            } else {
+                "No local path configured".to_string()
+            };
+            report.push("context", name, CheckStatus::Warn, message);
+            continue;
+        };
+
+        if !path.join(".beads").is_dir() {
+            report.push(
+                "context",
+                name,
+                CheckStatus::Warn,
+                format!("No .beads/ directory in {}", path.display()),
+            );
+            continue;
+        }
+
+        let message = match ctx.issue_prefix() {
+            Some(prefix) => format!("{} (prefix: {})", path.display(), prefix),
+            None => path.display().to_string(),
+        };
+        report.push("context", name, CheckStatus::Pass, message);
+
+        if bd_installed {
+            let bd = Beads::with_workdir_and_flags(&path, bd_flags.to_vec());
+            match bd.doctor() {
+                Ok(output) if output.success => {
+                    report.push("beads", name, CheckStatus::Pass, "bd doctor passed")
+                }
+                Ok(output) => report.push(
+                    "beads",
+                    name,
+                    CheckStatus::Warn,
+                    first_line(&output.combined()),
+                ),
+                Err(e) => report.push("beads", name, CheckStatus::Warn, first_line(&e.to_string())),
+            }
+        }
+    }
+}
+
+/// Report a context whose configured path is gone or has no `.beads/`
+///
+/// Returns whether the context is orphaned. Remote contexts without a local
+/// path are not orphans; they are cloned on the next sync.
+fn check_orphan(report: &mut DoctorReport, ctx: &BossContext) -> bool {
+    let Some(ref path) = ctx.path else {
+        return false;
+    };
+    let name = Some(ctx.name.as_str());
+    let fix = format!(
+        "'ab context move {0} <new-path>' or 'ab context remove {0}'",
+        ctx.name
+    );
+
+    if !path.is_dir() {
+        let problem = if path.exists() {
+            "Path is not a directory"
+        } else {
+            "Path no longer exists"
+        };
+        report.push(
+            "orphan",
+            name,
+            CheckStatus::Fail,
+            format!("{}: {}", problem, path.display()),
+        );
+        report.suggest(format!("Run {}", fix));
+        return true;
+    }
+
+    if !path.join(".beads").is_dir() {
+        report.push(
+            "orphan",
+            name,
+            CheckStatus::Warn,
+            format!("No .beads/ directory in {}", path.display()),
+        );
+        report.suggest(format!("Run 'bd init' there, or {}", fix));
+        return true;
+    }
+
+    false
+}
+
+/// Check that no bead ID appears in more than one context
+///
+/// Aggregation keys beads by ID, so a shared ID means one context's bead is
+/// silently missing from the federated graph.
+fn check_collisions(report: &mut DoctorReport, config: &AllBeadsConfig, contexts: &[String]) {
+    let mut owners: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+    let mut scanned = 0;
+    for ctx in &config.contexts {
+        if !contexts.is_empty() && !contexts.iter().any(|c| c.eq_ignore_ascii_case(&ctx.name)) {
+            continue;
+        }
+        let Some(file) = ctx
+            .local_path()
+            .and_then(|p| fs::File::open(p.join(".beads/issues.jsonl")).ok())
+        else {
+            continue;
+        };
+        scanned += 1;
+        for line in BufReader::new(file).lines().map_while(std::io::Result::ok) {
+            let Ok(issue) = serde_json::from_str::<serde_json::Value>(&line) else {
+                continue;
+            };
+            if let Some(id) = issue.get("id").and_then(|v| v.as_str()) {
+                owners.entry(id.to_string()).or_default().push(&ctx.name);
+            }
+        }
+    }
+    if scanned < 2 {
+        return;
+    }
+
+    let mut collisions = 0;
+    for (id, names) in &owners {
+        let mut names = names.clone();
+        names.dedup();
+        if names.len() > 1 {
+            collisions += 1;
+            report.push(
+                "collisions",
+                None,
+                CheckStatus::Warn,
+                format!("{} exists in contexts {}", id, names.join(", ")),
+            );
+        }
+    }
+    if collisions == 0 {
+        report.push(
+            "collisions",
+            None,
+            CheckStatus::Pass,
+            format!("No bead IDs shared between {} contexts", scanned),
+        );
+    }
+}
+
+/// Check that the cache database can be created and written
+fn check_cache(report: &mut DoctorReport, cache_path: &Path) {
+    let writable = cache_path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| {
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(cache_path)
+        });
+    match writable {
+        Ok(_) => report.push(
+            "cache",
+            None,
+            CheckStatus::Pass,
+            format!("{} is writable", cache_path.display()),
+        ),
+        Err(e) => report.push(
+            "cache",
+            None,
+            CheckStatus::Fail,
+            format!("Cannot write {}: {}", cache_path.display(), e),
+        ),
+    }
+}
+
+fn first_line(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty())
+        .unwrap_or("bd doctor reported problems")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AuthStrategy;
+
+    #[test]
+    fn test_context_checks() {
+        let dir = tempfile::tempdir().unwrap();
+        let good = dir.path().join("good");
+        std::fs::create_dir_all(good.join(".beads")).unwrap();
+        let bare = dir.path().join("bare");
+        std::fs::create_dir_all(&bare).unwrap();
+
+        let mut config = AllBeadsConfig::new();
+        for (name, path) in [
+            ("good", good),
+            ("bare", bare),
+            ("gone", dir.path().join("gone")),
+        ] {
+            config.add_context(
+                BossContext::new(name, "https://github.com/o/r.git", AuthStrategy::SshAgent)
+                    .with_path(path),
+            );
+        }
+
+        let mut report = DoctorReport::default();
+        check_contexts(&mut report, &config, &[], false, &[]);
+        let status = |ctx: &str| {
+            report
+                .checks
+                .iter()
+                .find(|c| c.context.as_deref() == Some(ctx))
+                .map(|c| c.status)
+        };
+        assert_eq!(status("good"), Some(CheckStatus::Pass));
+        assert_eq!(status("bare"), Some(CheckStatus::Warn));
+        assert_eq!(status("gone"), Some(CheckStatus::Fail));
+        assert!(report.has_failures());
+
+        let orphans: Vec<&Check> = report
+            .checks
+            .iter()
+            .filter(|c| c.name == "orphan")
+            .collect();
+        assert_eq!(orphans.len(), 2);
+        let gone = orphans
+            .iter()
+            .find(|c| c.context.as_deref() == Some("gone"))
+            .unwrap();
+        assert!(gone.message.starts_with("Path no longer exists"));
+        assert_eq!(
+            gone.suggestion.as_deref(),
+            Some("Run 'ab context move gone <new-path>' or 'ab context remove gone'")
+        );
+        let json = serde_json::to_value(&report).unwrap();
+        assert!(json["checks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|c| c["name"] == "orphan" && c["suggestion"].is_string()));
+
+        let mut report = DoctorReport::default();
+        check_contexts(&mut report, &config, &["GOOD".to_string()], false, &[]);
+        assert_eq!(report.checks.len(), 1);
+        assert!(!report.has_failures());
+    }
+
+    #[test]
+    fn test_collision_check() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = AllBeadsConfig::new();
+        for (name, ids) in [("work", ["ab-1", "ab-2"]), ("fork", ["ab-2", "ab-3"])] {
+            let path = dir.path().join(name);
+            std::fs::create_dir_all(path.join(".beads")).unwrap();
+            let lines: String = ids
+                .iter()
+                .map(|id| format!("{{\"id\":\"{}\",\"title\":\"x\"}}\n", id))
+                .collect();
+            std::fs::write(path.join(".beads/issues.jsonl"), lines).unwrap();
+            config.add_context(
+                BossContext::new(name, "https://github.com/o/r.git", AuthStrategy::SshAgent)
+                    .with_path(path),
+            );
+        }
+
+        let mut report = DoctorReport::default();
+        check_collisions(&mut report, &config, &[]);
+        assert_eq!(report.count(CheckStatus::Warn), 1);
+        assert_eq!(
+            report.checks[0].message,
+            "ab-2 exists in contexts work, fork"
+        );
+
+        let mut report = DoctorReport::default();
+        check_collisions(&mut report, &config, &["work".to_string()]);
+        assert!(report.checks.is_empty());
+    }
+
+    #[test]
+    fn test_cache_check() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut report = DoctorReport::default();
+        check_cache(&mut report, &dir.path().join("nested/cache.db"));
+        assert_eq!(report.count(CheckStatus::Pass), 1);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["checks"][0]["status"], "pass");
+        assert!(json["checks"][0].get("context").is_none());
+    }
+}
@@ -0,0 +1,120 @@
+//! Relative time parsing
+//!
+//! Parses short durations like `30s`, `24h`, `7d` or `2w` and `--since`
+//! values that may be either a duration or an absolute date.
+
+use crate::{AllBeadsError, Result};
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+
+/// Parse a short duration: a number followed by `s`, `m`, `h`, `d` or `w`
+pub fn parse_duration(s: &str) -> Result<Duration> {
+    let s = s.trim();
+    let invalid = || {
+        AllBeadsError::Parse(format!(
+            "Invalid duration '{}': expected a number followed by s, m, h, d or w (e.g. 7d)",
+            s
+        ))
+    };
+
+    let unit = s.chars().last().ok_or_else(invalid)?;
+    let amount: i64 = s[..s.len() - unit.len_utf8()]
+        .parse()
+        .map_err(|_| invalid())?;
+    if amount < 0 {
+        return Err(invalid());
+    }
+
+    let duration = match unit {
+        's' => Duration::try_seconds(amount),
+        'm' => Duration::try_minutes(amount),
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => return Err(invalid()),
+    };
+    duration.ok_or_else(|| AllBeadsError::Parse(format!("Duration '{}' is too large", s)))
+}
+
+/// Parse a `--since` value into a cutoff time
+///
+/// Accepts a duration relative to `now` (`24h`, `7d`, `2w`), a date
+/// (`2026-01-15`, midnight UTC) or an RFC3339 timestamp.
+pub fn parse_since(s: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let s = s.trim();
+
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(s) {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Ok(date.and_time(NaiveTime::MIN).and_utc());
+    }
+
+    let duration = parse_duration(s).map_err(|_| {
+        AllBeadsError::Parse(format!(
+            "Invalid --since value '{}': use a duration (24h, 7d, 2w) or a date (YYYY-MM-DD)",
+            s
+        ))
+    })?;
+    now.checked_sub_signed(duration)
+        .ok_or_else(|| AllBeadsError::Parse(format!("--since value '{}' is too far back", s)))
+}
+
+/// Check whether an `updated_at` timestamp is at or after `cutoff`
+///
+/// Missing or unparseable timestamps never match.
+pub fn updated_since(updated_at: &str, cutoff: DateTime<Utc>) -> bool {
+    crate::graph::parse_timestamp(updated_at).is_some_and(|t| t >= cutoff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("45s").unwrap(), Duration::seconds(45));
+        assert_eq!(parse_duration("30m").unwrap(), Duration::minutes(30));
+        assert_eq!(parse_duration("24h").unwrap(), Duration::hours(24));
+        assert_eq!(parse_duration("7d").unwrap(), Duration::days(7));
+        assert_eq!(parse_duration("2w").unwrap(), Duration::weeks(2));
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("d").is_err());
+        assert!(parse_duration("7y").is_err());
+        assert!(parse_duration("-1d").is_err());
+        assert!(parse_duration("1000000000000d").is_err());
+    }
+
+    #[test]
+    fn test_parse_since() {
+        let now = DateTime::parse_from_rfc3339("2026-03-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(
+            parse_since("7d", now).unwrap().to_rfc3339(),
+            "2026-03-03T12:00:00+00:00"
+        );
+        assert_eq!(
+            parse_since("2026-03-01", now).unwrap().to_rfc3339(),
+            "2026-03-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            parse_since("2026-03-01T08:00:00-02:00", now)
+                .unwrap()
+                .to_rfc3339(),
+            "2026-03-01T10:00:00+00:00"
+        );
+        assert!(parse_since("last week", now).is_err());
+        assert!(parse_since("1000000000d", now).is_err());
+    }
+
+    #[test]
+    fn test_updated_since() {
+        let cutoff = DateTime::parse_from_rfc3339("2026-03-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert!(updated_since("2026-03-02T00:00:00Z", cutoff));
+        assert!(!updated_since("2026-02-28T23:59:59Z", cutoff));
+        assert!(!updated_since("not a date", cutoff));
+    }
+}
@@ -5,4 +5,7 @@
 
 mod operations;
 
-pub use operations::{BossRepo, GitCredentials, RepoStatus};
+pub use operations::{
+    current_user, is_transient_git_error, run_git_with_retry, BossRepo, GitCredentials, GitRun,
+    RepoStatus,
+};
@@ -219,7 +219,7 @@ impl BossRepo {
 
         tracing::info!(
             context = %self.context.name,
-            url = %self.context.url,
+            url = %self.context.clone_url(),
             path = %self.path.display(),
             "Cloning Boss repository"
         );
@@ -237,7 +237,7 @@ impl BossRepo {
         let mut builder = git2::build::RepoBuilder::new();
         builder.fetch_options(fetch_options);
 
-        let repo = builder.clone(&self.context.url, &self.path)?;
+        let repo = builder.clone(self.context.clone_url(), &self.path)?;
 
         tracing::info!(path = %self.path.display(), "Repository cloned successfully");
 
@@ -545,6 +545,104 @@ impl BossRepo {
     }
 }
 
+/// The current user's git `user.email`
+///
+/// Reads the config of the repository containing the current directory (which
+/// layers in global and system config), or the global config outside a repo.
+pub fn current_user() -> Option<String> {
+    user_email_at(Path::new("."))
+}
+
+fn user_email_at(dir: &Path) -> Option<String> {
+    let config = Repository::discover(dir)
+        .and_then(|repo| repo.config())
+        .or_else(|_| git2::Config::open_default())
+        .ok()?;
+    config
+        .get_string("user.email")
+        .ok()
+        .filter(|email| !email.trim().is_empty())
+}
+
+/// How many times [`run_git_with_retry`] runs a command before giving up
+const GIT_ATTEMPTS: u32 = 3;
+
+/// Output of a `git` command run by [`run_git_with_retry`]
+#[derive(Debug)]
+pub struct GitRun {
+    pub output: std::process::Output,
+    /// Number of times the command was run
+    pub attempts: u32,
+}
+
+/// Whether git's stderr describes a network hiccup worth retrying
+///
+/// Authentication failures, rejected pushes and merge conflicts are never
+/// transient, even if the message also mentions the network.
+pub fn is_transient_git_error(stderr: &str) -> bool {
+    const PERMANENT: &[&str] = &[
+        "authentication failed",
+        "permission denied",
+        "could not read username",
+        "repository not found",
+        "403",
+        "rejected",
+        "conflict",
+        "not possible to fast-forward",
+        "would be overwritten",
+    ];
+    const TRANSIENT: &[&str] = &[
+        "could not resolve host",
+        "connection timed out",
+        "operation timed out",
+        "connection reset",
+        "connection refused",
+        "network is unreachable",
+        "temporary failure",
+        "remote end hung up unexpectedly",
+        "early eof",
+        "rpc failed",
+        "502",
+        "503",
+        "504",
+    ];
+
+    let stderr = stderr.to_lowercase();
+    !PERMANENT.iter().any(|p| stderr.contains(p)) && TRANSIENT.iter().any(|t| stderr.contains(t))
+}
+
+/// Run `git -C <dir> <args>`, retrying with backoff on transient failures
+///
+/// Non-transient failures are returned after the first attempt; callers
+/// inspect `output.status` as with a plain `Command::output`.
+pub fn run_git_with_retry(dir: &Path, args: &[&str]) -> Result<GitRun> {
+    let mut attempts = 1;
+    loop {
+        let output = std::process::Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .output()
+            .map_err(|e| AllBeadsError::Git(format!("Failed to run git {}: {}", args[0], e)))?;
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if output.status.success() || attempts >= GIT_ATTEMPTS || !is_transient_git_error(&stderr) {
+            return Ok(GitRun { output, attempts });
+        }
+
+        let backoff = std::time::Duration::from_secs(1 << (attempts - 1));
+        tracing::warn!(
+            command = args[0],
+            attempt = attempts,
+            "Transient git failure, retrying in {:?}: {}",
+            backoff,
+            stderr.trim()
+        );
+        std::thread::sleep(backoff);
+        attempts += 1;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -610,4 +708,38 @@ mod tests {
             PathBuf::from("/tmp/test/boss/.beads/issues.jsonl")
         );
     }
+
+    #[test]
+    fn test_user_email_from_repo_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        repo.config()
+            .unwrap()
+            .set_str("user.email", "dev@example.com")
+            .unwrap();
+
+        let nested = dir.path().join("src");
+        std::fs::create_dir(&nested).unwrap();
+        assert_eq!(user_email_at(&nested).as_deref(), Some("dev@example.com"));
+    }
+
+    #[test]
+    fn test_is_transient_git_error() {
+        assert!(is_transient_git_error(
+            "fatal: unable to access 'https://github.com/x/y/': Could not resolve host: github.com"
+        ));
+        assert!(is_transient_git_error(
+            "fatal: the remote end hung up unexpectedly"
+        ));
+        assert!(!is_transient_git_error(
+            "fatal: Authentication failed for 'https://github.com/x/y/'"
+        ));
+        assert!(!is_transient_git_error(
+            " ! [rejected]        main -> main (fetch first)"
+        ));
+        assert!(!is_transient_git_error(
+            "CONFLICT (content): Merge conflict in config.yaml"
+        ));
+        assert!(!is_transient_git_error("fatal: not a git repository"));
+    }
 }
@@ -1,11 +1,13 @@
 //! Policy checker - executes policy rules against beads
 
-use super::policy::{Policy, PolicyType};
+use super::policy::{Policy, PolicySeverity, PolicyType};
 use super::rules::{
-    CheckResult, CycleDetectionRule, MaxInProgressRule, PolicyRule, RequireAssigneeRule,
-    RequireDescriptionRule, RequireLabelsRule, RequirePriorityRule,
+    CheckResult, CycleDetectionRule, DeclarativeRule, MaxInProgressRule, PolicyRule,
+    RequireAssigneeRule, RequireDescriptionRule, RequireLabelsRule, RequirePriorityRule,
+    ValidDependenciesRule,
 };
 use crate::graph::FederatedGraph;
+use serde::Serialize;
 
 /// Policy checker that runs policies against the federated graph
 pub struct PolicyChecker {
@@ -69,6 +71,8 @@ impl PolicyChecker {
             PolicyType::DependencyCycleCheck => Box::new(CycleDetectionRule),
             PolicyType::RequirePriority => Box::new(RequirePriorityRule),
             PolicyType::RequireAssignee => Box::new(RequireAssigneeRule),
+            PolicyType::ValidDependencies => Box::new(ValidDependenciesRule),
+            PolicyType::Rule => Box::new(DeclarativeRule),
             PolicyType::Custom { .. } => {
                 // Custom rules would need a registry, for now return a no-op
                 Box::new(RequirePriorityRule) // Placeholder
@@ -93,6 +97,39 @@ impl PolicyChecker {
             total_affected_beads: total_affected,
         }
     }
+
+    /// Flatten failed results into one violation per affected bead
+    ///
+    /// Failures without affected beads yield a single violation with no bead
+    /// ID. Severity comes from the matching policy, defaulting to warning.
+    pub fn violations(&self, results: &[CheckResult]) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        for result in results.iter().filter(|r| !r.passed) {
+            let severity = self
+                .policies
+                .iter()
+                .find(|p| p.name == result.policy_name)
+                .map(|p| p.severity)
+                .unwrap_or_default();
+            let violation = |bead_id: Option<String>| Violation {
+                bead_id,
+                policy: result.policy_name.clone(),
+                message: result.message.clone(),
+                severity,
+            };
+            if result.affected_beads.is_empty() {
+                violations.push(violation(None));
+            } else {
+                violations.extend(
+                    result
+                        .affected_beads
+                        .iter()
+                        .map(|id| violation(Some(id.clone()))),
+                );
+            }
+        }
+        violations
+    }
 }
 
 impl Default for PolicyChecker {
@@ -101,8 +138,17 @@ impl Default for PolicyChecker {
     }
 }
 
+/// A single policy violation, for machine-readable output
+#[derive(Debug, Clone, Serialize)]
+pub struct Violation {
+    pub bead_id: Option<String>,
+    pub policy: String,
+    pub message: String,
+    pub severity: PolicySeverity,
+}
+
 /// Summary of check results
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CheckSummary {
     pub total_checks: usize,
     pub passed: usize,
@@ -141,6 +187,7 @@ mod tests {
             notes: None,
             aiki_tasks: Vec::new(),
             handoff: None,
+            parent: None,
         }
     }
 
@@ -205,4 +252,26 @@ mod tests {
         assert_eq!(summary.total_affected_beads, 3);
         assert!(!summary.all_passed());
     }
+
+    #[test]
+    fn test_violations() {
+        let mut checker = PolicyChecker::new();
+        let mut policy = Policy::new("require-description", PolicyType::RequireDescription);
+        policy.severity = PolicySeverity::Error;
+        checker.add_policy(policy);
+
+        let results = vec![
+            CheckResult::pass("other", "Passed"),
+            CheckResult::fail("require-description", "Missing")
+                .with_affected_beads(vec!["a".to_string(), "b".to_string()]),
+            CheckResult::fail("unknown", "Failed"),
+        ];
+
+        let violations = checker.violations(&results);
+        assert_eq!(violations.len(), 3);
+        assert_eq!(violations[0].bead_id.as_deref(), Some("a"));
+        assert_eq!(violations[0].severity, PolicySeverity::Error);
+        assert_eq!(violations[2].bead_id, None);
+        assert_eq!(violations[2].severity, PolicySeverity::Warning);
+    }
 }
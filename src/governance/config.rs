@@ -1,6 +1,7 @@
 //! Policy configuration loading from YAML files
 
 use super::policy::{Policy, PolicyConfig, PolicySeverity, PolicyType};
+use super::rules::DeclarativeRule;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
@@ -69,7 +70,19 @@ impl PoliciesConfig {
         let content = std::fs::read_to_string(path.as_ref())
             .map_err(|e| format!("Failed to read policies file: {}", e))?;
 
-        serde_yaml::from_str(&content).map_err(|e| format!("Failed to parse policies YAML: {}", e))
+        let config: Self = serde_yaml::from_str(&content)
+            .map_err(|e| format!("Failed to parse policies YAML: {}", e))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Check each policy's config, naming the first invalid policy
+    pub fn validate(&self) -> Result<(), String> {
+        for def in &self.policies {
+            def.validate()
+                .map_err(|e| format!("Policy '{}': {}", def.name, e))?;
+        }
+        Ok(())
     }
 
     /// Load policies from the default location (.beads/policies.yaml)
@@ -114,6 +127,26 @@ impl PolicyDef {
         })
     }
 
+    /// Reject config values that can't be used and, for `type: rule`,
+    /// options or values the rule doesn't understand
+    fn validate(&self) -> Result<(), String> {
+        let mut keys: Vec<&String> = self.config.keys().collect();
+        keys.sort();
+        for key in keys {
+            let value = &self.config[key];
+            if value.as_str().is_none() && value.as_u64().is_none() && value.as_bool().is_none() {
+                return Err(format!(
+                    "option '{}' must be a string, whole number or boolean",
+                    key
+                ));
+            }
+        }
+        if self.policy_type == "rule" {
+            DeclarativeRule::validate(&self.to_policy_config())?;
+        }
+        Ok(())
+    }
+
     fn parse_policy_type(&self) -> Option<PolicyType> {
         match self.policy_type.as_str() {
             "require_description" => Some(PolicyType::RequireDescription),
@@ -136,6 +169,8 @@ impl PolicyDef {
             "dependency_cycle_check" => Some(PolicyType::DependencyCycleCheck),
             "require_priority" => Some(PolicyType::RequirePriority),
             "require_assignee" => Some(PolicyType::RequireAssignee),
+            "valid_dependencies" => Some(PolicyType::ValidDependencies),
+            "rule" => Some(PolicyType::Rule),
             other => Some(PolicyType::Custom {
                 rule_name: other.to_string(),
             }),
@@ -176,12 +211,37 @@ impl Policy {
             }
             PolicyType::RequirePriority => "All beads must have a valid priority set".to_string(),
             PolicyType::RequireAssignee => "Open beads should have an assignee".to_string(),
+            PolicyType::ValidDependencies => {
+                "Dependencies must reference existing beads".to_string()
+            }
+            PolicyType::Rule => "Declarative rule".to_string(),
             PolicyType::Custom { rule_name } => format!("Custom rule: {}", rule_name),
         }
     }
 }
 
+/// Example declarative rules, in the same format as .beads/policies.yaml
+pub const EXAMPLE_POLICIES_YAML: &str = include_str!("example_policies.yaml");
+
+/// Parse [`EXAMPLE_POLICIES_YAML`] into policies
+pub fn example_policies() -> Vec<Policy> {
+    serde_yaml::from_str::<PoliciesConfig>(EXAMPLE_POLICIES_YAML)
+        .map(|config| config.to_policies())
+        .unwrap_or_default()
+}
+
+/// Load policies from a context's .beads directory, failing on an invalid file
+pub fn try_load_policies_for_context(
+    context_path: impl AsRef<Path>,
+) -> Result<Vec<Policy>, String> {
+    let beads_dir = context_path.as_ref().join(".beads");
+    PoliciesConfig::from_beads_dir(&beads_dir).map(|config| config.to_policies())
+}
+
 /// Load policies from a context's .beads directory
+///
+/// An invalid file is reported on stderr and yields no policies; use
+/// [`try_load_policies_for_context`] to treat it as an error.
 pub fn load_policies_for_context(context_path: impl AsRef<Path>) -> Vec<Policy> {
     let beads_dir = context_path.as_ref().join(".beads");
     match PoliciesConfig::from_beads_dir(&beads_dir) {
@@ -248,6 +308,16 @@ policies:
         assert!(config.policies.is_empty());
     }
 
+    #[test]
+    fn test_example_policies() {
+        let policies = example_policies();
+        assert_eq!(policies.len(), 3);
+        assert!(policies.iter().all(|p| p.policy_type == PolicyType::Rule));
+        assert_eq!(policies[0].severity, PolicySeverity::Error);
+        assert_eq!(policies[0].config.get("issue_type"), Some("bug"));
+        assert_eq!(policies[0].config.get("max_priority"), Some("2"));
+    }
+
     #[test]
     fn test_load_allbeads_policies() {
         // Test loading the actual AllBeads policies.yaml file
@@ -290,4 +360,32 @@ policies:
             PolicyType::Custom { rule_name } if rule_name == "my_custom_rule"
         ));
     }
+
+    #[test]
+    fn test_invalid_rule_config() {
+        let yaml = r#"
+policies:
+  - name: bugs-need-owner
+    type: rule
+    config:
+      issue_type: bug
+      require: assigne
+"#;
+        let config: PoliciesConfig = serde_yaml::from_str(yaml).unwrap();
+        let err = config.validate().unwrap_err();
+        assert!(err.starts_with("Policy 'bugs-need-owner'"), "{}", err);
+
+        let yaml = r#"
+policies:
+  - name: old-bugs
+    type: rule
+    config:
+      max_age_days: -1
+"#;
+        let config: PoliciesConfig = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.validate().is_err());
+
+        let examples: PoliciesConfig = serde_yaml::from_str(EXAMPLE_POLICIES_YAML).unwrap();
+        assert!(examples.validate().is_ok());
+    }
 }
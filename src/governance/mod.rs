@@ -38,8 +38,11 @@ pub use agents::{
     detect_agents, print_agent_scan, AgentDetection, AgentScanResult, AgentType,
     DetectionConfidence,
 };
-pub use checker::PolicyChecker;
-pub use config::{load_policies_for_context, PoliciesConfig};
+pub use checker::{CheckSummary, PolicyChecker, Violation};
+pub use config::{
+    example_policies, load_policies_for_context, try_load_policies_for_context, PoliciesConfig,
+    EXAMPLE_POLICIES_YAML,
+};
 pub use policy::{Enforcement, Policy, PolicyConfig, PolicySeverity, PolicyType};
 pub use repo_policy::{
     check_all_policies, check_policy, default_policies_path, PolicyCheckResult, PolicyExemption,
@@ -48,8 +51,9 @@ pub use repo_policy::{
 pub use rules::PolicyRule;
 pub use scanner::{
     format_scan_result_csv, format_scan_result_csv_with_fields, format_scan_result_junit,
-    print_scan_result, DetailedInfo, FieldSet, GitHubScanner, OnboardingPriority, ScanField,
-    ScanFilter, ScanOptions, ScanResult, ScanSource, ScanSummary, ScannedRepo,
+    print_scan_result, CancellationToken, DetailedInfo, FieldSet, GitHubScanner,
+    OnboardingPriority, ScanField, ScanFilter, ScanOptions, ScanResult, ScanSource, ScanSummary,
+    ScannedRepo,
 };
 pub use storage::PolicyStorage;
 pub use usage::{print_usage_stats, UsageRecord, UsageStats, UsageStorage, UsageTrend};
@@ -25,6 +25,10 @@ pub enum PolicyType {
     RequirePriority,
     /// Open beads should have an assignee
     RequireAssignee,
+    /// Dependencies must reference beads that exist
+    ValidDependencies,
+    /// Declarative rule defined by filters and requirements in the config
+    Rule,
     /// Custom rule with arbitrary configuration
     Custom { rule_name: String },
 }
@@ -204,6 +208,10 @@ impl Policy {
             }
             PolicyType::RequirePriority => "All beads must have a valid priority set".to_string(),
             PolicyType::RequireAssignee => "Open beads should have an assignee".to_string(),
+            PolicyType::ValidDependencies => {
+                "Dependencies must reference existing beads".to_string()
+            }
+            PolicyType::Rule => "Declarative rule".to_string(),
             PolicyType::Custom { rule_name } => {
                 format!("Custom rule: {}", rule_name)
             }
@@ -120,6 +120,7 @@ mod tests {
             notes: None,
             aiki_tasks: Vec::new(),
             handoff: None,
+            parent: None,
         }
     }
 
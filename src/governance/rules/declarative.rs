@@ -0,0 +1,397 @@
+//! Rule: Declarative rule from policies.yaml
+//!
+//! A `type: rule` policy selects beads with optional filters and then applies
+//! requirements, all read from the policy's `config` map:
+//!
+//! ```yaml
+//! - name: epics-have-children
+//!   type: rule
+//!   config:
+//!     issue_type: epic        # filter: bug, feature, task, epic, ...
+//!     status: open            # filter: comma-separated statuses
+//!     label: backend          # filter: bead must carry this label
+//!     min_children: 1         # require: beads whose parent is this bead
+//! ```
+//!
+//! Other requirements are `require` (comma-separated fields that must be set:
+//! description, assignee, labels, notes, parent), `max_priority` (0-4) and
+//! `max_age_days` (days since the bead was last updated).
+//!
+//! Policies are checked with [`DeclarativeRule::validate`] when they load, so
+//! a typo is reported instead of quietly disabling part of the rule.
+
+use super::{CheckResult, PolicyRule};
+use crate::governance::policy::{Policy, PolicyConfig};
+use crate::graph::{parse_timestamp, Bead, FederatedGraph, IssueType, Status};
+use std::collections::HashMap;
+
+/// Rule driven entirely by the policy's config options
+pub struct DeclarativeRule;
+
+/// Config options a rule understands
+const OPTIONS: [&str; 7] = [
+    "issue_type",
+    "status",
+    "label",
+    "require",
+    "max_priority",
+    "max_age_days",
+    "min_children",
+];
+
+/// Fields `require` can name
+const REQUIRE_FIELDS: [&str; 5] = ["description", "assignee", "labels", "notes", "parent"];
+
+const ISSUE_TYPES: [IssueType; 8] = [
+    IssueType::Bug,
+    IssueType::Feature,
+    IssueType::Task,
+    IssueType::Epic,
+    IssueType::Chore,
+    IssueType::MergeRequest,
+    IssueType::Molecule,
+    IssueType::Gate,
+];
+
+const STATUSES: [Status; 6] = [
+    Status::Open,
+    Status::InProgress,
+    Status::Blocked,
+    Status::Deferred,
+    Status::Closed,
+    Status::Tombstone,
+];
+
+impl DeclarativeRule {
+    /// Check a rule's config for unknown options and values that don't parse
+    pub fn validate(config: &PolicyConfig) -> Result<(), String> {
+        let mut keys: Vec<&str> = config.options.keys().map(String::as_str).collect();
+        keys.sort();
+        for key in keys {
+            if !OPTIONS.contains(&key) {
+                return Err(format!(
+                    "unknown option '{}' (expected one of: {})",
+                    key,
+                    OPTIONS.join(", ")
+                ));
+            }
+        }
+
+        let check_list = |key: &str, known: &[&str]| -> Result<(), String> {
+            for value in config.get(key).map(list).into_iter().flatten() {
+                if !known.contains(&value) {
+                    return Err(format!(
+                        "unknown {} '{}' (expected one of: {})",
+                        key,
+                        value,
+                        known.join(", ")
+                    ));
+                }
+            }
+            Ok(())
+        };
+        let issue_types: Vec<&str> = ISSUE_TYPES.iter().map(issue_type_name).collect();
+        let statuses: Vec<&str> = STATUSES.iter().map(status_name).collect();
+        check_list("issue_type", &issue_types)?;
+        check_list("status", &statuses)?;
+        check_list("require", &REQUIRE_FIELDS)?;
+
+        if let Some(value) = config.get("max_priority") {
+            if !value.parse::<u8>().is_ok_and(|p| p <= 4) {
+                return Err(format!("max_priority must be 0-4, got '{}'", value));
+            }
+        }
+        for key in ["max_age_days", "min_children"] {
+            if let Some(value) = config.get(key) {
+                if value.parse::<usize>().is_err() {
+                    return Err(format!("{} must be a whole number, got '{}'", key, value));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the bead passes the rule's filters
+    fn matches(bead: &Bead, config: &PolicyConfig) -> bool {
+        if let Some(issue_type) = config.get("issue_type") {
+            if !list(issue_type).any(|t| issue_type_name(&bead.issue_type) == t) {
+                return false;
+            }
+        }
+        if let Some(status) = config.get("status") {
+            if !list(status).any(|s| status_name(&bead.status) == s) {
+                return false;
+            }
+        }
+        if let Some(label) = config.get("label") {
+            if !bead.labels.contains(label) {
+                return false;
+            }
+        }
+        // Closed and deleted beads are only checked when asked for by status
+        config.get("status").is_some() || !matches!(bead.status, Status::Closed | Status::Tombstone)
+    }
+
+    /// Reasons the bead fails the rule's requirements
+    fn problems(bead: &Bead, config: &PolicyConfig, children: usize) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        for field in config.get("require").map(list).into_iter().flatten() {
+            let set = match field {
+                "description" => bead.description.as_deref().is_some_and(has_text),
+                "assignee" => bead.assignee.as_deref().is_some_and(has_text),
+                "notes" => bead.notes.as_deref().is_some_and(has_text),
+                "labels" => !bead.labels.is_empty(),
+                "parent" => bead.parent.is_some(),
+                _ => true,
+            };
+            if !set {
+                problems.push(format!("missing {}", field));
+            }
+        }
+
+        if let Some(max) = config
+            .get("max_priority")
+            .and_then(|v| v.parse::<u8>().ok())
+        {
+            if bead.priority as u8 > max {
+                problems.push(format!(
+                    "priority P{} is below P{}",
+                    bead.priority as u8, max
+                ));
+            }
+        }
+
+        if let Some(days) = config
+            .get("max_age_days")
+            .and_then(|v| v.parse::<i64>().ok())
+        {
+            if let Some(updated) = parse_timestamp(&bead.updated_at) {
+                let age = (chrono::Utc::now() - updated).num_days();
+                if age > days {
+                    problems.push(format!("no update in {} days", age));
+                }
+            }
+        }
+
+        if let Some(min) = config
+            .get("min_children")
+            .and_then(|v| v.parse::<usize>().ok())
+        {
+            if children < min {
+                problems.push(format!("{} child bead(s), needs {}", children, min));
+            }
+        }
+
+        problems
+    }
+}
+
+impl PolicyRule for DeclarativeRule {
+    fn check_bead(&self, bead: &Bead, config: &PolicyConfig) -> Option<CheckResult> {
+        if !Self::matches(bead, config) {
+            return None;
+        }
+        let problems = Self::problems(bead, config, 0);
+        if problems.is_empty() {
+            return None;
+        }
+        Some(
+            CheckResult::fail(
+                "rule",
+                format!("Bead {}: {}", bead.id.as_str(), problems.join(", ")),
+            )
+            .with_affected_beads(vec![bead.id.as_str().to_string()]),
+        )
+    }
+
+    fn check_graph(&self, graph: &FederatedGraph, policy: &Policy) -> CheckResult {
+        let mut children: HashMap<&str, usize> = HashMap::new();
+        for bead in graph.beads.values() {
+            if let Some(parent) = &bead.parent {
+                *children.entry(parent.as_str()).or_default() += 1;
+            }
+        }
+
+        let mut checked = 0;
+        let mut failures = Vec::new();
+        let mut first_problem = None;
+        for bead in graph.beads.values() {
+            if !Self::matches(bead, &policy.config) {
+                continue;
+            }
+            checked += 1;
+            let count = children.get(bead.id.as_str()).copied().unwrap_or(0);
+            let problems = Self::problems(bead, &policy.config, count);
+            if !problems.is_empty() {
+                first_problem.get_or_insert_with(|| problems.join(", "));
+                failures.push(bead.id.as_str().to_string());
+            }
+        }
+        failures.sort();
+
+        if failures.is_empty() {
+            CheckResult::pass(
+                &policy.name,
+                format!("All {} matching beads passed", checked),
+            )
+        } else {
+            CheckResult::fail(
+                &policy.name,
+                format!(
+                    "{} bead(s) violate '{}' (e.g. {})",
+                    failures.len(),
+                    policy.description,
+                    first_problem.unwrap_or_default()
+                ),
+            )
+            .with_affected_beads(failures)
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "rule"
+    }
+}
+
+fn list(value: &str) -> impl Iterator<Item = &str> {
+    value.split(',').map(str::trim).filter(|s| !s.is_empty())
+}
+
+fn has_text(s: &str) -> bool {
+    !s.trim().is_empty()
+}
+
+fn issue_type_name(issue_type: &IssueType) -> &'static str {
+    match issue_type {
+        IssueType::Bug => "bug",
+        IssueType::Feature => "feature",
+        IssueType::Task => "task",
+        IssueType::Epic => "epic",
+        IssueType::Chore => "chore",
+        IssueType::MergeRequest => "merge_request",
+        IssueType::Molecule => "molecule",
+        IssueType::Gate => "gate",
+    }
+}
+
+fn status_name(status: &Status) -> &'static str {
+    match status {
+        Status::Open => "open",
+        Status::InProgress => "in_progress",
+        Status::Blocked => "blocked",
+        Status::Deferred => "deferred",
+        Status::Closed => "closed",
+        Status::Tombstone => "tombstone",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::policy::PolicyType;
+    use crate::graph::{BeadId, Priority};
+    use std::collections::HashSet;
+
+    fn make_bead(id: &str, issue_type: IssueType, parent: Option<&str>) -> Bead {
+        Bead {
+            id: BeadId::new(id),
+            title: "Test".to_string(),
+            description: None,
+            status: Status::Open,
+            priority: Priority::P2,
+            labels: HashSet::new(),
+            dependencies: vec![],
+            blocks: vec![],
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: chrono::Utc::now().to_rfc3339(),
+            created_by: "test".to_string(),
+            assignee: None,
+            issue_type,
+            notes: None,
+            aiki_tasks: Vec::new(),
+            handoff: None,
+            parent: parent.map(BeadId::new),
+        }
+    }
+
+    fn graph(beads: Vec<Bead>) -> FederatedGraph {
+        let mut graph = FederatedGraph::new();
+        for bead in beads {
+            graph.beads.insert(bead.id.clone(), bead);
+        }
+        graph
+    }
+
+    fn policy(options: &[(&str, &str)]) -> Policy {
+        let config = options
+            .iter()
+            .fold(PolicyConfig::new(), |c, (k, v)| c.with_option(*k, *v));
+        Policy::new("test-rule", PolicyType::Rule).with_config(config)
+    }
+
+    #[test]
+    fn test_epics_need_children() {
+        let graph = graph(vec![
+            make_bead("ab-1", IssueType::Epic, None),
+            make_bead("ab-2", IssueType::Epic, None),
+            make_bead("ab-3", IssueType::Task, Some("ab-1")),
+        ]);
+        let result = DeclarativeRule.check_graph(
+            &graph,
+            &policy(&[("issue_type", "epic"), ("min_children", "1")]),
+        );
+        assert!(!result.passed);
+        assert_eq!(result.affected_beads, vec!["ab-2"]);
+    }
+
+    #[test]
+    fn test_filters_and_requirements() {
+        let mut bug = make_bead("ab-1", IssueType::Bug, None);
+        bug.priority = Priority::P3;
+        let mut stale = make_bead("ab-2", IssueType::Task, None);
+        stale.updated_at = "2020-01-01T00:00:00Z".to_string();
+        let graph = graph(vec![bug, stale]);
+
+        let bugs = DeclarativeRule.check_graph(
+            &graph,
+            &policy(&[
+                ("issue_type", "bug"),
+                ("require", "assignee"),
+                ("max_priority", "2"),
+            ]),
+        );
+        assert_eq!(bugs.affected_beads, vec!["ab-1"]);
+        assert!(bugs.message.contains("missing assignee"));
+
+        let stale = DeclarativeRule.check_graph(&graph, &policy(&[("max_age_days", "90")]));
+        assert_eq!(stale.affected_beads, vec!["ab-2"]);
+
+        let none = DeclarativeRule.check_graph(&graph, &policy(&[("label", "backend")]));
+        assert!(none.passed);
+    }
+
+    #[test]
+    fn test_validate() {
+        let validate =
+            |options: &[(&str, &str)]| DeclarativeRule::validate(&policy(options).config);
+        assert!(validate(&[
+            ("issue_type", "bug, epic"),
+            ("status", "open"),
+            ("require", "assignee,labels"),
+            ("max_priority", "2"),
+            ("max_age_days", "90"),
+            ("min_children", "1"),
+        ])
+        .is_ok());
+
+        let err = validate(&[("require", "assigne")]).unwrap_err();
+        assert!(err.contains("unknown require 'assigne'"), "{}", err);
+        let err = validate(&[("max_priorty", "2")]).unwrap_err();
+        assert!(err.contains("unknown option 'max_priorty'"), "{}", err);
+        assert!(validate(&[("max_priority", "P2")]).is_err());
+        assert!(validate(&[("max_priority", "7")]).is_err());
+        assert!(validate(&[("max_age_days", "ninety")]).is_err());
+        assert!(validate(&[("status", "done")]).is_err());
+    }
+}
@@ -1,18 +1,22 @@
 //! Policy rules implementations
 
 mod cycle_detection;
+mod declarative;
 mod max_in_progress;
 mod require_assignee;
 mod require_description;
 mod require_labels;
 mod require_priority;
+mod valid_dependencies;
 
 pub use cycle_detection::CycleDetectionRule;
+pub use declarative::DeclarativeRule;
 pub use max_in_progress::MaxInProgressRule;
 pub use require_assignee::RequireAssigneeRule;
 pub use require_description::RequireDescriptionRule;
 pub use require_labels::RequireLabelsRule;
 pub use require_priority::RequirePriorityRule;
+pub use valid_dependencies::ValidDependenciesRule;
 
 use crate::governance::policy::{Policy, PolicyConfig};
 use crate::graph::{Bead, FederatedGraph};
@@ -56,6 +56,7 @@ mod tests {
             notes: None,
             aiki_tasks: Vec::new(),
             handoff: None,
+            parent: None,
         }
     }
 
@@ -64,6 +64,7 @@ mod tests {
             notes: None,
             aiki_tasks: Vec::new(),
             handoff: None,
+            parent: None,
         }
     }
 
@@ -0,0 +1,94 @@
+//! Rule: Dependencies must reference existing beads
+
+use super::{CheckResult, PolicyRule};
+use crate::governance::policy::{Policy, PolicyConfig};
+use crate::graph::{Bead, FederatedGraph};
+use std::collections::HashSet;
+
+/// Rule that flags dependencies on bead IDs that don't exist
+///
+/// Only dependencies sharing a prefix with a bead in the graph are checked,
+/// so references into other contexts don't count as missing.
+pub struct ValidDependenciesRule;
+
+impl PolicyRule for ValidDependenciesRule {
+    fn check_bead(&self, _bead: &Bead, _config: &PolicyConfig) -> Option<CheckResult> {
+        // This rule needs the whole graph to know which IDs exist
+        None
+    }
+
+    fn check_graph(&self, graph: &FederatedGraph, policy: &Policy) -> CheckResult {
+        let prefixes: HashSet<&str> = graph
+            .beads
+            .keys()
+            .filter_map(|id| prefix(id.as_str()))
+            .collect();
+
+        let mut missing: Vec<String> = Vec::new();
+        for bead in graph.beads.values() {
+            for dep in &bead.dependencies {
+                let known_prefix = prefix(dep.as_str()).is_some_and(|p| prefixes.contains(p));
+                if known_prefix && !graph.beads.contains_key(dep) {
+                    missing.push(format!("{} -> {}", bead.id.as_str(), dep.as_str()));
+                }
+            }
+        }
+        missing.sort();
+
+        if missing.is_empty() {
+            CheckResult::pass(&policy.name, "All dependencies reference existing beads")
+        } else {
+            CheckResult::fail(
+                &policy.name,
+                format!(
+                    "Found {} dependency(ies) on missing beads: {}",
+                    missing.len(),
+                    missing.join(", ")
+                ),
+            )
+            .with_affected_beads(missing)
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "valid-dependencies"
+    }
+}
+
+fn prefix(id: &str) -> Option<&str> {
+    id.rsplit_once('-').map(|(prefix, _)| prefix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::policy::PolicyType;
+    use crate::graph::BeadId;
+
+    fn graph(beads: &[(&str, &[&str])]) -> FederatedGraph {
+        let mut graph = FederatedGraph::new();
+        for (id, deps) in beads {
+            let mut bead = Bead::new(*id, "Test", "test");
+            bead.dependencies = deps.iter().map(|d| BeadId::new(*d)).collect();
+            graph.beads.insert(bead.id.clone(), bead);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_missing_dependency_fails() {
+        let policy = Policy::new("valid-dependencies", PolicyType::ValidDependencies);
+        let graph = graph(&[("ab-1", &["ab-2", "ab-9", "other-1"]), ("ab-2", &[])]);
+
+        let result = ValidDependenciesRule.check_graph(&graph, &policy);
+        assert!(!result.passed);
+        assert_eq!(result.affected_beads, vec!["ab-1 -> ab-9"]);
+    }
+
+    #[test]
+    fn test_existing_dependencies_pass() {
+        let policy = Policy::new("valid-dependencies", PolicyType::ValidDependencies);
+        let graph = graph(&[("ab-1", &["ab-2"]), ("ab-2", &[])]);
+        assert!(ValidDependenciesRule.check_graph(&graph, &policy).passed);
+    }
+}
@@ -25,6 +25,7 @@ use reqwest::{header, Client};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -417,6 +418,9 @@ pub struct ScanResult {
     pub source: ScanSource,
     pub repositories: Vec<ScannedRepo>,
     pub summary: ScanSummary,
+    /// The scan was cancelled and `repositories` holds only what was found so far
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub cancelled: bool,
 }
 
 /// Scan filter options
@@ -442,6 +446,8 @@ pub struct ScanOptions {
     pub show_progress: bool,
     /// Fields to include in output (determines if detailed API calls are needed)
     pub fields: FieldSet,
+    /// Stops the scan early, keeping partial results
+    pub cancel: CancellationToken,
 }
 
 impl Default for ScanOptions {
@@ -451,10 +457,33 @@ impl Default for ScanOptions {
             use_search_api: true,
             show_progress: true,
             fields: FieldSet::basic(),
+            cancel: CancellationToken::new(),
         }
     }
 }
 
+/// Cooperative cancellation for a running scan
+///
+/// Clones share the same flag. Scans check it before each GitHub request, so
+/// requests already in flight finish and their results are kept.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ask the scan to stop
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
 /// GitHub code search result
 #[derive(Debug, Clone, Deserialize)]
 #[allow(dead_code)]
@@ -581,7 +610,7 @@ impl GitHubScanner {
             eprint!("Fetching repository list for {}...", username);
             io::stderr().flush().ok();
         }
-        let repos = self.list_user_repos(username).await?;
+        let repos = self.list_user_repos(username, &options.cancel).await?;
         if options.show_progress {
             eprintln!(" found {} repos", repos.len());
         }
@@ -611,7 +640,7 @@ impl GitHubScanner {
             eprint!("Fetching repository list for {}...", org);
             io::stderr().flush().ok();
         }
-        let repos = self.list_org_repos(org).await?;
+        let repos = self.list_org_repos(org, &options.cancel).await?;
         if options.show_progress {
             eprintln!(" found {} repos", repos.len());
         }
@@ -756,6 +785,7 @@ impl GitHubScanner {
                 unmanaged_repos: if managed { 0 } else { 1 },
                 ..Default::default()
             },
+            cancelled: false,
         })
     }
 
@@ -808,8 +838,12 @@ impl GitHubScanner {
         agents
     }
 
-    /// List all repos for a user
-    async fn list_user_repos(&self, username: &str) -> Result<Vec<GitHubRepo>> {
+    /// List all repos for a user, stopping at the current page if cancelled
+    async fn list_user_repos(
+        &self,
+        username: &str,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<GitHubRepo>> {
         let mut all_repos = Vec::new();
         let mut page = 1;
         let per_page = 100;
@@ -844,7 +878,7 @@ impl GitHubScanner {
 
             all_repos.extend(repos);
 
-            if all_repos.len() < per_page * page {
+            if all_repos.len() < per_page * page || cancel.is_cancelled() {
                 break; // Last page
             }
 
@@ -854,8 +888,12 @@ impl GitHubScanner {
         Ok(all_repos)
     }
 
-    /// List all repos for an organization
-    async fn list_org_repos(&self, org: &str) -> Result<Vec<GitHubRepo>> {
+    /// List all repos for an organization, stopping at the current page if cancelled
+    async fn list_org_repos(
+        &self,
+        org: &str,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<GitHubRepo>> {
         let mut all_repos = Vec::new();
         let mut page = 1;
         let per_page = 100;
@@ -890,7 +928,7 @@ impl GitHubScanner {
 
             all_repos.extend(repos);
 
-            if all_repos.len() < per_page * page {
+            if all_repos.len() < per_page * page || cancel.is_cancelled() {
                 break; // Last page
             }
 
@@ -989,29 +1027,31 @@ impl GitHubScanner {
 
         // Second pass: detect agents
         // Use GitHub Search API if enabled (much faster for many repos)
-        let agent_map: HashMap<String, Vec<AgentType>> =
-            if options.use_search_api && self.token.is_some() {
-                self.detect_agents_via_search(&source, options).await?
-            } else {
-                // Fallback: parallel per-repo checks
-                self.detect_agents_parallel(&filtered_repos, options)
-                    .await?
-            };
-
-        // Third pass: fetch detailed info if any detailed fields are requested (in parallel)
-        let detailed_map: HashMap<String, DetailedInfo> = if options.fields.requires_detailed() {
-            if options.show_progress {
-                eprintln!(
-                    "Fetching detailed info for {} repos via Git Trees API...",
-                    filtered_repos.len()
-                );
-            }
-            self.fetch_detailed_info_parallel(&filtered_repos, options)
-                .await?
-        } else {
+        let agent_map: HashMap<String, Vec<AgentType>> = if options.cancel.is_cancelled() {
             HashMap::new()
+        } else if options.use_search_api && self.token.is_some() {
+            self.detect_agents_via_search(&source, options).await?
+        } else {
+            // Fallback: parallel per-repo checks
+            self.detect_agents_parallel(&filtered_repos, options)
+                .await?
         };
 
+        // Third pass: fetch detailed info if any detailed fields are requested (in parallel)
+        let detailed_map: HashMap<String, DetailedInfo> =
+            if options.fields.requires_detailed() && !options.cancel.is_cancelled() {
+                if options.show_progress {
+                    eprintln!(
+                        "Fetching detailed info for {} repos via Git Trees API...",
+                        filtered_repos.len()
+                    );
+                }
+                self.fetch_detailed_info_parallel(&filtered_repos, options)
+                    .await?
+            } else {
+                HashMap::new()
+            };
+
         // Build final results
         let mut scanned_repos = Vec::new();
 
@@ -1069,9 +1109,14 @@ impl GitHubScanner {
         });
 
         let summary = self.calculate_summary(&scanned_repos);
+        let cancelled = options.cancel.is_cancelled();
 
         if options.show_progress {
-            eprintln!("Scan complete!");
+            if cancelled {
+                eprintln!("Scan cancelled, showing partial results");
+            } else {
+                eprintln!("Scan complete!");
+            }
         }
 
         Ok(ScanResult {
@@ -1079,6 +1124,7 @@ impl GitHubScanner {
             source,
             repositories: scanned_repos,
             summary,
+            cancelled,
         })
     }
 
@@ -1120,6 +1166,9 @@ impl GitHubScanner {
         }
 
         for (i, (query, agent_type)) in searches.iter().enumerate() {
+            if options.cancel.is_cancelled() {
+                break;
+            }
             if options.show_progress {
                 eprint!(
                     "  [{}/{}] Searching for {}...",
@@ -1160,7 +1209,11 @@ impl GitHubScanner {
                                 }
                             }
                             if options.show_progress {
-                                eprintln!(" {} repos", count);
+                                eprintln!(
+                                    " {} repos ({} with agents so far)",
+                                    count,
+                                    agent_map.len()
+                                );
                             }
                         } else if options.show_progress {
                             eprintln!(" (parse error)");
@@ -1199,7 +1252,8 @@ impl GitHubScanner {
             );
         }
 
-        let counter = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = Arc::new(AtomicUsize::new(0));
+        let found = Arc::new(AtomicUsize::new(0));
 
         // Process repos in parallel batches
         let _results: Vec<_> = stream::iter(repos.iter().enumerate())
@@ -1211,9 +1265,14 @@ impl GitHubScanner {
                 let default_branch = repo.default_branch.clone();
                 let agent_map = agent_map.clone();
                 let counter = counter.clone();
+                let found = found.clone();
+                let cancel = options.cancel.clone();
                 let show_progress = options.show_progress;
 
                 async move {
+                    if cancel.is_cancelled() {
+                        return;
+                    }
                     let agents = detect_agents_for_repo(
                         &client,
                         token.as_deref(),
@@ -1223,13 +1282,18 @@ impl GitHubScanner {
                     )
                     .await;
 
-                    let count = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+                    let count = counter.fetch_add(1, Ordering::SeqCst) + 1;
+                    let with_agents = if agents.is_empty() {
+                        found.load(Ordering::SeqCst)
+                    } else {
+                        found.fetch_add(1, Ordering::SeqCst) + 1
+                    };
 
                     if show_progress {
                         let pct = (count as f64 / total as f64) * 100.0;
                         eprint!(
-                            "\r  [{}/{}] {:.0}% - {}                    ",
-                            count, total, pct, full_name
+                            "\r  [{}/{}] {:.0}% - {} with agents - {}                    ",
+                            count, total, pct, with_agents, full_name
                         );
                         io::stderr().flush().ok();
                     }
@@ -1365,7 +1429,7 @@ impl GitHubScanner {
         let detailed_map: Arc<tokio::sync::Mutex<HashMap<String, DetailedInfo>>> =
             Arc::new(tokio::sync::Mutex::new(HashMap::new()));
 
-        let counter = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = Arc::new(AtomicUsize::new(0));
 
         let _results: Vec<_> = stream::iter(repos.iter())
             .map(|(repo, _, _, _, _)| {
@@ -1376,9 +1440,13 @@ impl GitHubScanner {
                 let default_branch = repo.default_branch.clone();
                 let detailed_map = detailed_map.clone();
                 let counter = counter.clone();
+                let cancel = options.cancel.clone();
                 let show_progress = options.show_progress;
 
                 async move {
+                    if cancel.is_cancelled() {
+                        return;
+                    }
                     let url = format!(
                         "{}/repos/{}/git/trees/{}?recursive=1",
                         base_url, full_name, default_branch
@@ -1389,7 +1457,7 @@ impl GitHubScanner {
                         request = request.bearer_auth(t);
                     }
 
-                    let count = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+                    let count = counter.fetch_add(1, Ordering::SeqCst) + 1;
 
                     if show_progress {
                         let pct = (count as f64 / total as f64) * 100.0;
@@ -1687,6 +1755,10 @@ pub fn print_scan_result(result: &ScanResult, show_all: bool) {
     );
     println!("═══════════════════════════════════════════════════════════════");
     println!();
+    if result.cancelled {
+        println!("Scan cancelled: results below are partial");
+        println!();
+    }
     println!(
         "Found {} repositories, {} already managed by AllBeads",
         result.summary.total_repos, result.summary.managed_repos
@@ -2222,6 +2294,7 @@ mod tests {
             source: ScanSource::Repository("user/repo".to_string()),
             repositories: vec![],
             summary: ScanSummary::default(),
+            cancelled: false,
         };
 
         assert!(matches!(result.source, ScanSource::Repository(_)));
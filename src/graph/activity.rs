@@ -0,0 +1,118 @@
+//! Federated activity log
+//!
+//! `bd activity` only covers one repository. This merges the logs of several
+//! contexts into one stream, newest first, so changes can be audited across
+//! the whole federation.
+
+use super::parse_timestamp;
+use beads::{Activity, Beads};
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// An activity entry tagged with the context it came from
+#[derive(Debug, Clone, Serialize)]
+pub struct ContextActivity {
+    pub context: String,
+    #[serde(flatten)]
+    pub activity: Activity,
+}
+
+/// Read `bd activity` for each `(context, path)` and merge the results
+///
+/// Contexts whose activity can't be read are logged and skipped. `limit`
+/// applies both per context and to the merged stream.
+pub fn federated_activity(
+    contexts: &[(String, PathBuf)],
+    bd_flags: &[String],
+    limit: Option<usize>,
+) -> Vec<ContextActivity> {
+    let mut logs = Vec::new();
+    for (name, path) in contexts {
+        let bd = Beads::with_workdir_and_flags(path, bd_flags.to_vec());
+        match bd.activity(limit) {
+            Ok(entries) => logs.push((name.clone(), entries)),
+            Err(e) => tracing::warn!(context = %name, error = %e, "Failed to read activity"),
+        }
+    }
+    merge_activity(logs, limit)
+}
+
+/// Interleave per-context activity logs by timestamp, newest first
+///
+/// Entries with an unparseable timestamp sort after everything else.
+pub fn merge_activity(
+    logs: Vec<(String, Vec<Activity>)>,
+    limit: Option<usize>,
+) -> Vec<ContextActivity> {
+    let mut merged: Vec<ContextActivity> = logs
+        .into_iter()
+        .flat_map(|(context, entries)| {
+            entries.into_iter().map(move |activity| ContextActivity {
+                context: context.clone(),
+                activity,
+            })
+        })
+        .collect();
+
+    // Stable sort keeps each context's own order for equal timestamps
+    merged.sort_by_key(|entry| std::cmp::Reverse(parse_timestamp(&entry.activity.timestamp)));
+    if let Some(limit) = limit {
+        merged.truncate(limit);
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn activity(timestamp: &str, action: &str) -> Activity {
+        Activity {
+            timestamp: timestamp.to_string(),
+            action: action.to_string(),
+            issue_id: None,
+            details: None,
+        }
+    }
+
+    #[test]
+    fn test_merge_activity() {
+        let logs = vec![
+            (
+                "work".to_string(),
+                vec![
+                    activity("2026-03-03T10:00:00Z", "close"),
+                    activity("2026-03-01T10:00:00Z", "create"),
+                ],
+            ),
+            (
+                "oss".to_string(),
+                vec![
+                    activity("2026-03-02T10:00:00Z", "update"),
+                    activity("garbage", "comment"),
+                ],
+            ),
+        ];
+
+        let merged = merge_activity(logs.clone(), None);
+        let order: Vec<(&str, &str)> = merged
+            .iter()
+            .map(|e| (e.context.as_str(), e.activity.action.as_str()))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                ("work", "close"),
+                ("oss", "update"),
+                ("work", "create"),
+                ("oss", "comment"),
+            ]
+        );
+
+        assert_eq!(merge_activity(logs, Some(2)).len(), 2);
+
+        let json = serde_json::to_value(&merged[0]).unwrap();
+        assert_eq!(json["context"], "work");
+        assert_eq!(json["action"], "close");
+    }
+}
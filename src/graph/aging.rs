@@ -0,0 +1,128 @@
+//! Bead age histogram
+//!
+//! Buckets not-closed beads by time since `created_at`, per status. Raw
+//! open/closed counts hide a backlog that is mostly months old; this doesn't.
+
+use super::{parse_timestamp, FederatedGraph, Status};
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+/// Bucket labels, youngest first
+pub const AGE_BUCKETS: [&str; 4] = ["<1d", "1-7d", "1-4w", ">1mo"];
+
+/// Bead counts per age bucket
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct AgeBuckets {
+    #[serde(rename = "<1d")]
+    pub day: usize,
+    #[serde(rename = "1-7d")]
+    pub week: usize,
+    #[serde(rename = "1-4w")]
+    pub month: usize,
+    #[serde(rename = ">1mo")]
+    pub older: usize,
+}
+
+impl AgeBuckets {
+    /// Count a bead of the given age
+    pub fn add(&mut self, age: Duration) {
+        if age < Duration::days(1) {
+            self.day += 1;
+        } else if age < Duration::days(7) {
+            self.week += 1;
+        } else if age < Duration::days(30) {
+            self.month += 1;
+        } else {
+            self.older += 1;
+        }
+    }
+
+    /// Counts in [`AGE_BUCKETS`] order
+    pub fn counts(&self) -> [usize; 4] {
+        [self.day, self.week, self.month, self.older]
+    }
+
+    pub fn total(&self) -> usize {
+        self.counts().iter().sum()
+    }
+}
+
+/// Age buckets for one status
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StatusAges {
+    pub status: Status,
+    #[serde(flatten)]
+    pub buckets: AgeBuckets,
+}
+
+/// Bucket open, in-progress, blocked and deferred beads by age
+pub fn age_histogram(graph: &FederatedGraph) -> Vec<StatusAges> {
+    age_histogram_as_of(graph, Utc::now())
+}
+
+/// Like [`age_histogram`], measuring ages relative to `now`
+///
+/// Statuses with no beads are omitted; beads with an unparseable
+/// `created_at` are skipped.
+pub fn age_histogram_as_of(graph: &FederatedGraph, now: DateTime<Utc>) -> Vec<StatusAges> {
+    let statuses = [
+        Status::Open,
+        Status::InProgress,
+        Status::Blocked,
+        Status::Deferred,
+    ];
+    let mut buckets = [AgeBuckets::default(); 4];
+    for bead in graph.beads.values() {
+        let Some(i) = statuses.iter().position(|s| *s == bead.status) else {
+            continue;
+        };
+        if let Some(created) = parse_timestamp(&bead.created_at) {
+            buckets[i].add(now - created);
+        }
+    }
+
+    statuses
+        .into_iter()
+        .zip(buckets)
+        .filter(|(_, b)| b.total() > 0)
+        .map(|(status, buckets)| StatusAges { status, buckets })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Bead;
+
+    fn bead(id: &str, status: Status, created_at: &str) -> Bead {
+        let mut bead = Bead::new(id, "Test", "user");
+        bead.status = status;
+        bead.created_at = created_at.to_string();
+        bead
+    }
+
+    #[test]
+    fn test_age_histogram() {
+        let now = DateTime::parse_from_rfc3339("2026-03-31T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut graph = FederatedGraph::new();
+        graph.add_bead(bead("ab-1", Status::Open, "2026-03-31T08:00:00Z"));
+        graph.add_bead(bead("ab-2", Status::Open, "2026-03-28T12:00:00Z"));
+        graph.add_bead(bead("ab-3", Status::Open, "2026-01-01T00:00:00Z"));
+        graph.add_bead(bead("ab-4", Status::InProgress, "2026-03-15T00:00:00Z"));
+        graph.add_bead(bead("ab-5", Status::Closed, "2026-01-01T00:00:00Z"));
+        graph.add_bead(bead("ab-6", Status::Open, "not a date"));
+
+        let histogram = age_histogram_as_of(&graph, now);
+        assert_eq!(histogram.len(), 2);
+        assert_eq!(histogram[0].status, Status::Open);
+        assert_eq!(histogram[0].buckets.counts(), [1, 1, 0, 1]);
+        assert_eq!(histogram[1].status, Status::InProgress);
+        assert_eq!(histogram[1].buckets.counts(), [0, 0, 1, 0]);
+
+        let json = serde_json::to_value(&histogram[1]).unwrap();
+        assert_eq!(json["status"], "in_progress");
+        assert_eq!(json["1-4w"], 1);
+    }
+}
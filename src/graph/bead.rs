@@ -5,12 +5,13 @@
 
 use super::BeadId;
 use crate::handoff::AgentHandoff;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use std::collections::HashSet;
 
 /// Issue status
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum Status {
     #[default]
@@ -54,8 +55,24 @@ impl From<Priority> for u8 {
     }
 }
 
+// Serialized as a bare number (serde_repr), which the derive can't express
+impl JsonSchema for Priority {
+    fn schema_name() -> String {
+        "Priority".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Integer.into()),
+            enum_values: Some((0..=4).map(serde_json::Value::from).collect()),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
 /// Issue type
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum IssueType {
     Bug,
@@ -72,7 +89,7 @@ pub enum IssueType {
 /// Core bead structure representing an issue/task/epic
 ///
 /// This matches the beads JSONL schema for compatibility with the `bd` CLI.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Bead {
     /// Unique identifier (e.g., "ab-ldr")
     pub id: BeadId,
@@ -116,6 +133,10 @@ pub struct Bead {
     )]
     pub dependencies: Vec<BeadId>,
 
+    /// Parent epic, if this bead is part of one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<BeadId>,
+
     /// Beads that depend on this one (blocked by this)
     #[serde(
         default,
@@ -161,6 +182,7 @@ impl Bead {
             created_by: created_by.into(),
             assignee: None,
             dependencies: Vec::new(),
+            parent: None,
             blocks: Vec::new(),
             labels: HashSet::new(),
             notes: None,
@@ -224,6 +246,31 @@ impl Bead {
     pub fn has_aiki_task(&self, task_id: &str) -> bool {
         self.aiki_tasks.iter().any(|id| id == task_id)
     }
+
+    /// First `file:line` reference in the description, then the notes
+    ///
+    /// Janitor findings record where they were found as `Found at src/x.rs:42`.
+    pub fn source_location(&self) -> Option<(String, u32)> {
+        [&self.description, &self.notes]
+            .into_iter()
+            .flatten()
+            .find_map(|text| parse_source_location(text))
+    }
+}
+
+/// Find a `path:line` (or `path:line:col`) token in free text
+fn parse_source_location(text: &str) -> Option<(String, u32)> {
+    text.split_whitespace().find_map(|token| {
+        let token = token
+            .trim_start_matches(|c: char| "`'\"([<".contains(c))
+            .trim_end_matches(|c: char| "`'\")]>,;.".contains(c));
+        let mut parts = token.split(':');
+        let path = parts.next()?;
+        let line: u32 = parts.next()?.parse().ok()?;
+        // Require something path-like so times and ports don't match
+        let looks_like_path = (path.contains('.') || path.contains('/')) && !path.contains("//");
+        (looks_like_path && line > 0).then(|| (path.to_string(), line))
+    })
 }
 
 /// Custom deserializer for dependency/block IDs
@@ -311,6 +358,37 @@ mod tests {
         assert!(Priority::P3 < Priority::P4);
     }
 
+    #[test]
+    fn test_source_location() {
+        let mut bead = Bead::new("ab-1", "TODO: fix", "janitor");
+        assert_eq!(bead.source_location(), None);
+
+        bead.description = Some("Found at src/main.rs:42\nTODO: fix this".to_string());
+        assert_eq!(
+            bead.source_location(),
+            Some(("src/main.rs".to_string(), 42))
+        );
+
+        bead.description = Some("Meeting at 10:30, see https://example.com:8080".to_string());
+        bead.notes = Some("Crash in (`lib/parse.py:7:3`).".to_string());
+        assert_eq!(
+            bead.source_location(),
+            Some(("lib/parse.py".to_string(), 7))
+        );
+    }
+
+    #[test]
+    fn test_parent_serialization() {
+        let mut bead = Bead::new("ab-123.1", "Child", "alice");
+        let json = serde_json::to_string(&bead).unwrap();
+        assert!(!json.contains("parent"));
+
+        bead.parent = Some(BeadId::new("ab-123"));
+        let json = serde_json::to_string(&bead).unwrap();
+        let back: Bead = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.parent, Some(BeadId::new("ab-123")));
+    }
+
     #[test]
     fn test_labels() {
         let mut bead = Bead::new("ab-123", "Test", "alice");
@@ -321,4 +399,17 @@ mod tests {
         assert!(bead.labels.contains("p1"));
         assert_eq!(bead.labels.len(), 2);
     }
+
+    #[test]
+    fn test_json_schema() {
+        let schema = serde_json::to_value(schemars::schema_for!(Bead)).unwrap();
+        let required = schema["required"].as_array().unwrap();
+        assert!(required.contains(&"id".into()));
+        assert!(!required.contains(&"labels".into()));
+        assert_eq!(schema["definitions"]["Priority"]["type"], "integer");
+        assert_eq!(
+            schema["definitions"]["Status"]["enum"][1],
+            serde_json::json!("in_progress")
+        );
+    }
 }
@@ -0,0 +1,185 @@
+//! Bead detail rendering for `ab show`
+//!
+//! [`BeadDetail`] collects the display fields shared by the text and
+//! Markdown renderers; [`render_bead_markdown`] produces the Markdown form.
+
+use super::{
+    absolute_timestamp, display_timestamp, Bead, BeadId, BeadLink, FederatedGraph, IssueType,
+    Status,
+};
+use chrono::Utc;
+
+/// Display fields of a bead, shared by the `ab show` text and Markdown renderers
+pub struct BeadDetail<'a> {
+    pub type_str: &'static str,
+    pub status_str: &'static str,
+    pub priority: u8,
+    /// Absolute and relative creation time
+    pub created: String,
+    /// Absolute and relative update time
+    pub updated: String,
+    /// Sorted, so output is stable across runs; link labels are left out
+    pub labels: Vec<&'a str>,
+    pub dependencies: Vec<&'a str>,
+    pub blocks: Vec<&'a str>,
+    /// Non-blocking links, shown apart from dependencies
+    pub links: Vec<BeadLink>,
+}
+
+impl<'a> BeadDetail<'a> {
+    pub fn new(bead: &'a Bead) -> Self {
+        let now = Utc::now();
+        let mut labels: Vec<&str> = bead
+            .labels
+            .iter()
+            .map(|s| s.as_str())
+            .filter(|l| BeadLink::from_label(l).is_none())
+            .collect();
+        labels.sort_unstable();
+        Self {
+            type_str: detail_type(bead.issue_type),
+            status_str: detail_status(bead.status),
+            priority: u8::from(bead.priority),
+            created: display_timestamp(&bead.created_at, now),
+            updated: display_timestamp(&bead.updated_at, now),
+            labels,
+            dependencies: bead.dependencies.iter().map(|id| id.as_str()).collect(),
+            blocks: bead.blocks.iter().map(|id| id.as_str()).collect(),
+            links: bead.links(),
+        }
+    }
+}
+
+/// Render a bead as a Markdown section for pasting into PRs
+///
+/// Each section starts with an `id` anchor, so dependency links resolve when
+/// several beads are pasted into the same document. `graph`, when available,
+/// supplies the titles of linked beads. Dates are absolute, since a pasted
+/// "3 days ago" goes stale.
+pub fn render_bead_markdown(bead: &Bead, graph: Option<&FederatedGraph>) -> String {
+    let detail = BeadDetail::new(bead);
+    let cell = |s: &str| s.replace('|', "\\|").replace('\n', " ");
+    let link = |id: &str| {
+        let title = graph
+            .and_then(|g| g.get_bead(&BeadId::new(id)))
+            .map(|b| format!(" {}", b.title))
+            .unwrap_or_default();
+        format!("- [{}](#{}){}\n", id, id, title)
+    };
+
+    let mut out = format!(
+        "<a id=\"{}\"></a>\n\n## {}: {}\n\n",
+        bead.id.as_str(),
+        bead.id.as_str(),
+        bead.title
+    );
+    out.push_str("| Field | Value |\n|-------|-------|\n");
+    let mut row = |field: &str, value: &str| {
+        out.push_str(&format!("| {} | {} |\n", field, cell(value)));
+    };
+    row("Type", detail.type_str);
+    row("Status", detail.status_str);
+    row("Priority", &format!("P{}", detail.priority));
+    if let Some(ref assignee) = bead.assignee {
+        row("Assignee", assignee);
+    }
+    if let Some(ref parent) = bead.parent {
+        row("Parent", parent.as_str());
+    }
+    if !detail.labels.is_empty() {
+        row("Labels", &detail.labels.join(", "));
+    }
+    row(
+        "Created",
+        &format!(
+            "{} by {}",
+            absolute_timestamp(&bead.created_at),
+            bead.created_by
+        ),
+    );
+    row("Updated", &absolute_timestamp(&bead.updated_at));
+
+    if let Some(ref description) = bead.description {
+        out.push_str(&format!("\n{}\n", description.trim_end()));
+    }
+    if !detail.dependencies.is_empty() {
+        out.push_str("\n### Depends on\n\n");
+        for id in &detail.dependencies {
+            out.push_str(&link(id));
+        }
+    }
+    if !detail.blocks.is_empty() {
+        out.push_str("\n### Blocks\n\n");
+        for id in &detail.blocks {
+            out.push_str(&link(id));
+        }
+    }
+    if !detail.links.is_empty() {
+        out.push_str("\n### Links\n\n");
+        for l in &detail.links {
+            out.push_str(&format!(
+                "- {} {}",
+                l.link_type.display_name(),
+                link(l.target.as_str()).trim_start_matches("- ")
+            ));
+        }
+    }
+    if let Some(ref notes) = bead.notes {
+        out.push_str(&format!("\n### Notes\n\n{}\n", notes.trim_end()));
+    }
+    out
+}
+
+fn detail_status(status: Status) -> &'static str {
+    match status {
+        Status::Open => "open",
+        Status::InProgress => "in_progress",
+        Status::Blocked => "blocked",
+        Status::Deferred => "deferred",
+        Status::Closed => "closed",
+        Status::Tombstone => "tombstone",
+    }
+}
+
+fn detail_type(issue_type: IssueType) -> &'static str {
+    match issue_type {
+        IssueType::Bug => "bug",
+        IssueType::Feature => "feature",
+        IssueType::Task => "task",
+        IssueType::Epic => "epic",
+        IssueType::Chore => "chore",
+        IssueType::MergeRequest => "merge-request",
+        IssueType::Molecule => "molecule",
+        IssueType::Gate => "gate",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Priority;
+
+    #[test]
+    fn test_render_bead_markdown() {
+        let mut bead = Bead::new("ab-1", "Fix login", "alice");
+        bead.created_at = "2026-03-01T12:30:00Z".to_string();
+        bead.updated_at = "2026-03-02T08:00:00Z".to_string();
+        bead.priority = Priority::P1;
+        bead.description = Some("Users | admins can't log in\n".to_string());
+        bead.dependencies.push(BeadId::new("ab-2"));
+        bead.labels.insert("auth".to_string());
+
+        let mut graph = FederatedGraph::new();
+        graph.add_bead(Bead::new("ab-2", "Rotate keys", "bob"));
+
+        let out = render_bead_markdown(&bead, Some(&graph));
+        assert!(out.starts_with("<a id=\"ab-1\"></a>\n\n## ab-1: Fix login\n\n"));
+        assert!(out.contains("| Priority | P1 |\n"));
+        assert!(out.contains("| Labels | auth |\n"));
+        assert!(out.contains("| Created | 2026-03-01 12:30 UTC by alice |\n"));
+        assert!(out.contains("| Updated | 2026-03-02 08:00 UTC |\n"));
+        assert!(!out.contains("ago"));
+        assert!(out.contains("\nUsers | admins can't log in\n"));
+        assert!(out.contains("### Depends on\n\n- [ab-2](#ab-2) Rotate keys\n"));
+    }
+}
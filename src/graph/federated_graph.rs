@@ -3,8 +3,15 @@
 //! Unified graph containing beads, shadow beads, and cross-repo dependencies.
 
 use super::{Bead, BeadId, Rig, RigId, ShadowBead, Status};
+use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 
+/// Most paths [`FederatedGraph::blocker_chain`] returns
+///
+/// Diamond-shaped dependencies multiply the number of paths at every level,
+/// so without a cap a few dozen beads could take exponential time.
+pub const MAX_BLOCKER_CHAINS: usize = 64;
+
 /// Federated graph aggregating beads across multiple contexts
 ///
 /// The FederatedGraph is the central data structure that unifies:
@@ -32,6 +39,29 @@ pub struct FederatedGraph {
 
     /// Index: Label -> Set of BeadIds with that label
     label_index: HashMap<String, HashSet<BeadId>>,
+
+    /// Beads dropped during aggregation because their ID was already taken
+    pub collisions: Vec<Collision>,
+
+    /// Hash of each context's issues.jsonl when it was loaded (context name -> hash)
+    pub content_hashes: HashMap<String, String>,
+
+    /// Statuses of beads dropped by [`Self::retain_beads`], so dependencies on
+    /// beads in filtered-out contexts still resolve
+    pruned_statuses: HashMap<BeadId, Status>,
+}
+
+/// A bead ID that was loaded from more than one context
+///
+/// Happens when two contexts share an issue prefix. The first bead loaded
+/// keeps the ID; the later one is dropped rather than overwriting it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collision {
+    pub id: BeadId,
+    /// Context whose bead kept the ID
+    pub kept: String,
+    /// Context whose bead was dropped
+    pub dropped: String,
 }
 
 impl FederatedGraph {
@@ -64,6 +94,33 @@ impl FederatedGraph {
         self.beads.insert(id, bead);
     }
 
+    /// Add a bead loaded from a context, tagging it with `@context`
+    ///
+    /// If a bead with the same ID was already loaded from a different
+    /// context, the new bead is dropped and a [`Collision`] is recorded.
+    /// Returns whether the bead was added.
+    pub fn add_context_bead(&mut self, mut bead: Bead, context: &str) -> bool {
+        if let Some(existing) = self.beads.get(&bead.id) {
+            let existing_context = existing
+                .labels
+                .iter()
+                .find_map(|l| l.strip_prefix('@'))
+                .unwrap_or_default();
+            if existing_context != context {
+                self.collisions.push(Collision {
+                    id: bead.id.clone(),
+                    kept: existing_context.to_string(),
+                    dropped: context.to_string(),
+                });
+                return false;
+            }
+        }
+
+        bead.add_label(format!("@{}", context));
+        self.add_bead(bead);
+        true
+    }
+
     /// Add a shadow bead to the graph
     pub fn add_shadow_bead(&mut self, shadow: ShadowBead) {
         let id = shadow.id.clone();
@@ -162,7 +219,7 @@ impl FederatedGraph {
     /// Check if a bead is ready to work (no blocking dependencies)
     pub fn is_bead_ready(&self, id: &BeadId) -> bool {
         if let Some(bead) = self.beads.get(id) {
-            bead.is_ready()
+            bead.status == Status::Open && !self.has_open_blockers(bead)
         } else if let Some(shadow) = self.shadow_beads.get(id) {
             // For shadow beads, check if all cross-repo dependencies are closed
             shadow.cross_repo_dependencies.iter().all(|uri| {
@@ -185,14 +242,192 @@ impl FederatedGraph {
         }
     }
 
-    /// Get all beads that are ready to work (no blocking dependencies)
+    /// Get all open beads with no open blockers anywhere in the federation
+    ///
+    /// Dependencies are looked up across every context, so a bead waiting on
+    /// an open bead in another repository is not ready. Closed blockers don't
+    /// count; see [`Self::is_open_blocker`] for blockers missing from the graph.
     pub fn ready_beads(&self) -> Vec<&Bead> {
         self.beads
             .values()
-            .filter(|b| b.is_ready() && b.status != Status::Closed)
+            .filter(|b| b.status == Status::Open && !self.has_open_blockers(b))
             .collect()
     }
 
+    /// Whether any of a bead's dependencies is still open, in any context
+    pub fn has_open_blockers(&self, bead: &Bead) -> bool {
+        bead.dependencies
+            .iter()
+            .any(|dep| self.is_open_blocker(dep))
+    }
+
+    /// Whether a bead is blocked: marked so, or unclosed with an open blocker
+    pub fn is_blocked(&self, bead: &Bead) -> bool {
+        bead.status == Status::Blocked
+            || (!matches!(bead.status, Status::Closed | Status::Tombstone)
+                && self.has_open_blockers(bead))
+    }
+
+    /// Keep only the beads matching `keep`, e.g. for a `--context` filter
+    ///
+    /// Dropped beads' statuses are remembered, so readiness of the remaining
+    /// beads still accounts for blockers in the dropped contexts.
+    pub fn retain_beads(&mut self, mut keep: impl FnMut(&Bead) -> bool) {
+        let pruned = &mut self.pruned_statuses;
+        self.beads.retain(|id, bead| {
+            let kept = keep(bead);
+            if !kept {
+                pruned.insert(id.clone(), bead.status);
+            }
+            kept
+        });
+    }
+
+    /// Get shadow beads whose target bead is not in any aggregated context
+    ///
+    /// Only `bead://` pointers are checked; shadows of external issues
+    /// (JIRA, GitHub) have no native bead to resolve. Sorted by shadow ID.
+    pub fn unresolved_shadows(&self) -> Vec<&ShadowBead> {
+        let mut shadows: Vec<&ShadowBead> = self
+            .shadow_beads
+            .values()
+            .filter(|shadow| {
+                shadow
+                    .pointer
+                    .bead_id()
+                    .is_some_and(|target| !self.beads.contains_key(&target))
+            })
+            .collect();
+        shadows.sort_by(|a, b| a.id.as_str().cmp(b.id.as_str()));
+        shadows
+    }
+
+    /// Get the paths of open blockers from a bead down to its root blockers
+    ///
+    /// Each path starts at a direct blocker of `id` and follows open
+    /// dependencies until it reaches a blocker that is not itself blocked.
+    /// Closed blockers are ignored; blockers missing from the graph are
+    /// assumed open. A dependency cycle ends the path at the repeated bead.
+    /// Paths are found depth first and stop after [`MAX_BLOCKER_CHAINS`].
+    pub fn blocker_chain(&self, id: &BeadId) -> Vec<Vec<BeadId>> {
+        let mut chains = Vec::new();
+        let mut path = vec![id.clone()];
+        self.collect_blocker_chains(&mut path, &mut chains);
+        chains
+    }
+
+    fn collect_blocker_chains(&self, path: &mut Vec<BeadId>, chains: &mut Vec<Vec<BeadId>>) {
+        if chains.len() >= MAX_BLOCKER_CHAINS {
+            return;
+        }
+        let Some(current) = path.last() else {
+            return;
+        };
+        let blockers: Vec<BeadId> = self
+            .beads
+            .get(current)
+            .map(|b| {
+                b.dependencies
+                    .iter()
+                    .filter(|dep| self.is_open_blocker(dep) && !path.contains(dep))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if blockers.is_empty() {
+            if path.len() > 1 {
+                chains.push(path[1..].to_vec());
+            }
+            return;
+        }
+
+        for blocker in blockers {
+            path.push(blocker);
+            self.collect_blocker_chains(path, chains);
+            path.pop();
+        }
+    }
+
+    /// Whether a dependency still blocks its dependents
+    ///
+    /// Unknown IDs are assumed open, since the blocker may live in a context
+    /// that isn't configured.
+    pub fn is_open_blocker(&self, id: &BeadId) -> bool {
+        let status = self
+            .beads
+            .get(id)
+            .map(|b| b.status)
+            .or_else(|| self.shadow_beads.get(id).map(|s| s.status))
+            .or_else(|| self.pruned_statuses.get(id).copied());
+        !matches!(status, Some(Status::Closed | Status::Tombstone))
+    }
+
+    /// Get the chain of parent epics above a bead, nearest first
+    ///
+    /// Stops at the first parent missing from the graph or at a repeated ID,
+    /// so malformed parent cycles cannot loop forever.
+    pub fn ancestors(&self, id: &BeadId) -> Vec<&BeadId> {
+        let mut chain = Vec::new();
+        let mut seen = HashSet::new();
+        let mut current = self.beads.get(id).and_then(|b| b.parent.as_ref());
+
+        while let Some(parent) = current {
+            if !seen.insert(parent) {
+                break;
+            }
+            chain.push(parent);
+            current = self.beads.get(parent).and_then(|b| b.parent.as_ref());
+        }
+
+        chain
+    }
+
+    /// Check whether making `parent` the parent of `id` would create a cycle
+    pub fn would_create_parent_cycle(&self, id: &BeadId, parent: &BeadId) -> bool {
+        id == parent || self.ancestors(parent).contains(&id)
+    }
+
+    /// Roll up the status of every bead under an epic, recursing into sub-epics
+    ///
+    /// Sub-epics are counted along with their own children. Deferred beads
+    /// count as open; tombstoned ones are skipped. An unknown ID or an epic
+    /// without children yields an empty rollup at 0%.
+    pub fn epic_rollup(&self, id: &BeadId) -> EpicRollup {
+        let mut children: HashMap<&BeadId, Vec<&Bead>> = HashMap::new();
+        for bead in self.beads.values() {
+            if let Some(parent) = &bead.parent {
+                children.entry(parent).or_default().push(bead);
+            }
+        }
+
+        let mut rollup = EpicRollup::default();
+        let mut seen: HashSet<&BeadId> = HashSet::from([id]);
+        let mut stack = vec![id];
+        while let Some(parent) = stack.pop() {
+            for child in children.get(parent).into_iter().flatten() {
+                // Guard against malformed parent cycles
+                if !seen.insert(&child.id) {
+                    continue;
+                }
+                stack.push(&child.id);
+                match child.status {
+                    Status::Open | Status::Deferred => rollup.open += 1,
+                    Status::InProgress => rollup.in_progress += 1,
+                    Status::Blocked => rollup.blocked += 1,
+                    Status::Closed => rollup.closed += 1,
+                    Status::Tombstone => continue,
+                }
+                rollup.total += 1;
+            }
+        }
+
+        if rollup.total > 0 {
+            rollup.percent = rollup.closed as f64 * 100.0 / rollup.total as f64;
+        }
+        rollup
+    }
+
     /// Get statistics about the graph
     pub fn stats(&self) -> GraphStats {
         let total_beads = self.beads.len();
@@ -266,6 +501,18 @@ impl FederatedGraph {
     }
 }
 
+/// Status counts for all beads under an epic
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct EpicRollup {
+    pub total: usize,
+    pub open: usize,
+    pub in_progress: usize,
+    pub blocked: usize,
+    pub closed: usize,
+    /// Share of beads closed, 0-100
+    pub percent: f64,
+}
+
 /// Statistics about the federated graph
 #[derive(Debug, Clone, Default)]
 pub struct GraphStats {
@@ -302,6 +549,29 @@ mod tests {
         assert!(graph.get_bead(&BeadId::new("ab-test")).is_some());
     }
 
+    #[test]
+    fn test_add_context_bead_collision() {
+        let mut graph = FederatedGraph::new();
+
+        let mut work = Bead::new("ab-1", "Work bead", "user");
+        work.status = Status::InProgress;
+        assert!(graph.add_context_bead(work, "work"));
+        assert!(graph.add_context_bead(Bead::new("ab-1", "Work bead", "user"), "work"));
+        assert!(!graph.add_context_bead(Bead::new("ab-1", "Fork bead", "user"), "fork"));
+
+        let bead = graph.get_bead(&BeadId::new("ab-1")).unwrap();
+        assert_eq!(bead.title, "Work bead");
+        assert!(bead.labels.contains("@work"));
+        assert_eq!(
+            graph.collisions,
+            vec![Collision {
+                id: BeadId::new("ab-1"),
+                kept: "work".to_string(),
+                dropped: "fork".to_string(),
+            }]
+        );
+    }
+
     #[test]
     fn test_query_by_status() {
         let mut graph = FederatedGraph::new();
@@ -361,6 +631,38 @@ mod tests {
         assert_eq!(personal_beads.len(), 0);
     }
 
+    #[test]
+    fn test_unresolved_shadows() {
+        let mut graph = FederatedGraph::new();
+        graph.add_bead(Bead::new("auth-1", "Present", "user"));
+
+        let rig_id = RigId::new("auth-service");
+        graph.add_shadow_bead(ShadowBead::new(
+            "ab-s1",
+            &rig_id,
+            &BeadId::new("auth-1"),
+            "Resolved",
+            "work",
+        ));
+        graph.add_shadow_bead(ShadowBead::new(
+            "ab-s2",
+            &rig_id,
+            &BeadId::new("auth-404"),
+            "Dangling",
+            "work",
+        ));
+        graph.add_shadow_bead(
+            ShadowBead::from_external("ab-s3", "JIRA issue", "jira://PROJ-1").build(),
+        );
+
+        let unresolved: Vec<&str> = graph
+            .unresolved_shadows()
+            .iter()
+            .map(|s| s.id.as_str())
+            .collect();
+        assert_eq!(unresolved, vec!["ab-s2"]);
+    }
+
     #[test]
     fn test_dependents_tracking() {
         let mut graph = FederatedGraph::new();
@@ -378,6 +680,173 @@ mod tests {
         assert_eq!(dependents[0].id.as_str(), "ab-2");
     }
 
+    #[test]
+    fn test_blocker_chain() {
+        let mut graph = FederatedGraph::new();
+
+        // ab-1 <- ab-2 <- ab-4, ab-3 <- ab-4; ab-5 is closed
+        let root = Bead::new("ab-1", "Root", "user");
+        let mut mid = Bead::new("ab-2", "Mid", "user");
+        mid.add_dependency(BeadId::new("ab-1"));
+        let other_root = Bead::new("ab-3", "Other root", "user");
+        let mut closed = Bead::new("ab-5", "Done", "user");
+        closed.status = Status::Closed;
+        let mut leaf = Bead::new("ab-4", "Leaf", "user");
+        leaf.add_dependency(BeadId::new("ab-2"));
+        leaf.add_dependency(BeadId::new("ab-3"));
+        leaf.add_dependency(BeadId::new("ab-5"));
+
+        for bead in [root, mid, other_root, closed, leaf] {
+            graph.add_bead(bead);
+        }
+
+        let chains = graph.blocker_chain(&BeadId::new("ab-4"));
+        let chains: Vec<Vec<&str>> = chains
+            .iter()
+            .map(|chain| chain.iter().map(|id| id.as_str()).collect())
+            .collect();
+        assert_eq!(chains, vec![vec!["ab-2", "ab-1"], vec!["ab-3"]]);
+        assert!(graph.blocker_chain(&BeadId::new("ab-1")).is_empty());
+
+        // A cycle ends the path instead of looping
+        let mut cyclic = graph.beads.get(&BeadId::new("ab-1")).unwrap().clone();
+        cyclic.add_dependency(BeadId::new("ab-2"));
+        graph.add_bead(cyclic);
+        let chains = graph.blocker_chain(&BeadId::new("ab-2"));
+        assert_eq!(chains, vec![vec![BeadId::new("ab-1")]]);
+    }
+
+    #[test]
+    fn test_blocker_chain_caps_diamond_paths() {
+        // 40 stacked diamonds: 2^40 paths from the top to the bottom
+        let mut graph = FederatedGraph::new();
+        graph.add_bead(Bead::new("d-0", "Bottom", "user"));
+        for level in 1..=40 {
+            for side in ["a", "b"] {
+                let mut bead = Bead::new(format!("d-{}{}", level, side), "Side", "user");
+                bead.add_dependency(BeadId::new(format!("d-{}", level - 1)));
+                graph.add_bead(bead);
+            }
+            let mut join = Bead::new(format!("d-{}", level), "Join", "user");
+            join.add_dependency(BeadId::new(format!("d-{}a", level)));
+            join.add_dependency(BeadId::new(format!("d-{}b", level)));
+            graph.add_bead(join);
+        }
+
+        let chains = graph.blocker_chain(&BeadId::new("d-40"));
+        assert_eq!(chains.len(), MAX_BLOCKER_CHAINS);
+        assert!(chains
+            .iter()
+            .all(|chain| chain.last() == Some(&BeadId::new("d-0"))));
+    }
+
+    #[test]
+    fn test_ready_across_contexts() {
+        let mut graph = FederatedGraph::new();
+
+        // frontend's ab-2 waits on backend's be-1; ab-3 waits on a closed bead
+        let blocker = Bead::new("be-1", "API", "user");
+        let mut waiting = Bead::new("ab-2", "UI", "user");
+        waiting.add_dependency(BeadId::new("be-1"));
+        let mut done = Bead::new("be-4", "Schema", "user");
+        done.status = Status::Closed;
+        let mut unblocked = Bead::new("ab-3", "Form", "user");
+        unblocked.add_dependency(BeadId::new("be-4"));
+        graph.add_context_bead(blocker, "backend");
+        graph.add_context_bead(done, "backend");
+        graph.add_context_bead(waiting, "frontend");
+        graph.add_context_bead(unblocked, "frontend");
+
+        let ready_ids = |graph: &FederatedGraph| {
+            let mut ids: Vec<String> = graph
+                .ready_beads()
+                .iter()
+                .map(|b| b.id.as_str().to_string())
+                .collect();
+            ids.sort();
+            ids
+        };
+        assert_eq!(ready_ids(&graph), vec!["ab-3", "be-1"]);
+        assert!(graph.is_blocked(graph.get_bead(&BeadId::new("ab-2")).unwrap()));
+        assert!(!graph.is_blocked(graph.get_bead(&BeadId::new("ab-3")).unwrap()));
+
+        // Filtering to one context keeps the other context's blockers in view
+        graph.retain_beads(|b| b.labels.contains("@frontend"));
+        assert_eq!(graph.beads.len(), 2);
+        assert_eq!(ready_ids(&graph), vec!["ab-3"]);
+
+        // Once the blocker closes, the bead becomes ready
+        let mut closed = Bead::new("be-1", "API", "user");
+        closed.status = Status::Closed;
+        graph.add_context_bead(closed, "backend");
+        assert_eq!(ready_ids(&graph), vec!["ab-2", "ab-3"]);
+    }
+
+    #[test]
+    fn test_ancestors_and_parent_cycles() {
+        let mut graph = FederatedGraph::new();
+
+        let root = Bead::new("ab-1", "Root epic", "user");
+        let mut mid = Bead::new("ab-2", "Sub epic", "user");
+        mid.parent = Some(BeadId::new("ab-1"));
+        let mut leaf = Bead::new("ab-3", "Task", "user");
+        leaf.parent = Some(BeadId::new("ab-2"));
+
+        graph.add_bead(root);
+        graph.add_bead(mid);
+        graph.add_bead(leaf);
+
+        let ancestors: Vec<&str> = graph
+            .ancestors(&BeadId::new("ab-3"))
+            .iter()
+            .map(|id| id.as_str())
+            .collect();
+        assert_eq!(ancestors, vec!["ab-2", "ab-1"]);
+
+        assert!(graph.would_create_parent_cycle(&BeadId::new("ab-1"), &BeadId::new("ab-3")));
+        assert!(graph.would_create_parent_cycle(&BeadId::new("ab-2"), &BeadId::new("ab-2")));
+        assert!(!graph.would_create_parent_cycle(&BeadId::new("ab-3"), &BeadId::new("ab-1")));
+    }
+
+    #[test]
+    fn test_epic_rollup() {
+        let mut graph = FederatedGraph::new();
+        let mut add = |id: &str, parent: Option<&str>, status: Status| {
+            let mut bead = Bead::new(id, id, "user");
+            bead.parent = parent.map(BeadId::new);
+            bead.status = status;
+            graph.add_bead(bead);
+        };
+        add("ab-1", None, Status::Open);
+        add("ab-2", Some("ab-1"), Status::Closed);
+        add("ab-3", Some("ab-1"), Status::InProgress);
+        add("ab-4", Some("ab-1"), Status::Open);
+        add("ab-5", Some("ab-4"), Status::Closed);
+        add("ab-6", Some("ab-4"), Status::Blocked);
+        add("ab-7", Some("ab-4"), Status::Tombstone);
+        add("ab-8", None, Status::Open);
+        // Cycle back to the root must not loop or count the root
+        add("ab-9", Some("ab-5"), Status::Closed);
+        graph.beads.get_mut(&BeadId::new("ab-1")).unwrap().parent = Some(BeadId::new("ab-9"));
+
+        let rollup = graph.epic_rollup(&BeadId::new("ab-1"));
+        assert_eq!(
+            rollup,
+            EpicRollup {
+                total: 6,
+                open: 1,
+                in_progress: 1,
+                blocked: 1,
+                closed: 3,
+                percent: 50.0,
+            }
+        );
+
+        let empty = graph.epic_rollup(&BeadId::new("ab-8"));
+        assert_eq!(empty.total, 0);
+        assert_eq!(empty.percent, 0.0);
+    }
+
     #[test]
     fn test_graph_stats() {
         let mut graph = FederatedGraph::new();
@@ -0,0 +1,397 @@
+//! Bead history over time
+//!
+//! Reconstructs open/closed counts per day, and the field changes of a single
+//! bead, by walking the git history of a context's `.beads/issues.jsonl` and
+//! replaying each committed snapshot.
+
+use crate::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Path of the beads JSONL file within a repository
+const ISSUES_JSONL: &str = ".beads/issues.jsonl";
+
+/// Open/closed counts from a single issues.jsonl snapshot
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct StatusCounts {
+    /// Beads that are not closed (open, in progress, blocked, deferred)
+    pub open: usize,
+    /// Closed beads
+    pub closed: usize,
+}
+
+/// Counts for one day, summed across contexts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DailyCounts {
+    pub date: NaiveDate,
+    pub open: usize,
+    pub closed: usize,
+}
+
+/// Snapshots of a single context keyed by day (last commit of the day wins)
+pub type ContextHistory = BTreeMap<NaiveDate, StatusCounts>;
+
+/// Fields compared by [`bead_history`], in display order
+pub const HISTORY_FIELDS: [&str; 3] = ["status", "priority", "assignee"];
+
+/// One field that changed in a commit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    /// Value before the commit, `None` if unset
+    pub from: Option<String>,
+    /// Value after the commit, `None` if unset
+    pub to: Option<String>,
+}
+
+/// A commit that created a bead or changed one of its [`HISTORY_FIELDS`]
+#[derive(Debug, Clone, Serialize)]
+pub struct BeadHistoryEntry {
+    /// Abbreviated commit ID
+    pub commit: String,
+    pub author: String,
+    pub date: DateTime<Utc>,
+    /// First line of the commit message
+    pub summary: String,
+    /// Whether this commit first added the bead
+    pub created: bool,
+    /// Changed fields; for the creating commit, every field that is set
+    pub changes: Vec<FieldChange>,
+}
+
+/// Minimal view of a JSONL line; only the status matters for counting
+#[derive(Deserialize)]
+struct StatusOnly {
+    #[serde(default)]
+    status: String,
+}
+
+/// Walk the git history of a repository's issues.jsonl
+///
+/// Returns one snapshot per day on which issues.jsonl changed. Repositories
+/// without commits or without the file yield an empty history.
+pub fn context_history(repo_path: &Path) -> Result<ContextHistory> {
+    let repo = git2::Repository::open(repo_path)?;
+    let mut history = ContextHistory::new();
+
+    let mut revwalk = repo.revwalk()?;
+    if revwalk.push_head().is_err() {
+        // Unborn HEAD (no commits yet)
+        return Ok(history);
+    }
+    revwalk.set_sorting(git2::Sort::TIME | git2::Sort::REVERSE)?;
+
+    let mut last_blob = None;
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        let entry = match commit.tree()?.get_path(Path::new(ISSUES_JSONL)) {
+            Ok(entry) => entry,
+            Err(_) => continue,
+        };
+
+        // Unchanged file: counts carry forward on their own
+        if last_blob == Some(entry.id()) {
+            continue;
+        }
+        last_blob = Some(entry.id());
+
+        let Some(date) = DateTime::from_timestamp(commit.time().seconds(), 0) else {
+            continue;
+        };
+        let blob = repo.find_blob(entry.id())?;
+        history.insert(date.date_naive(), count_statuses(blob.content()));
+    }
+
+    Ok(history)
+}
+
+/// Timeline of a bead's field changes from the git history of issues.jsonl
+///
+/// Commits are returned oldest first. Only commits that change the bead's
+/// line in a way that touches [`HISTORY_FIELDS`] are included, so edits to
+/// other beads or other fields are skipped. Unlike the bd activity log this
+/// works for any history that was committed, whatever wrote it.
+pub fn bead_history(context_path: &Path, bead_id: &str) -> Result<Vec<BeadHistoryEntry>> {
+    let repo = git2::Repository::open(context_path)?;
+    let mut entries = Vec::new();
+
+    let mut revwalk = repo.revwalk()?;
+    if revwalk.push_head().is_err() {
+        // Unborn HEAD (no commits yet)
+        return Ok(entries);
+    }
+    revwalk.set_sorting(git2::Sort::TIME | git2::Sort::REVERSE)?;
+
+    let mut last_blob = None;
+    let mut last_fields: Option<Vec<Option<String>>> = None;
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        let entry = match commit.tree()?.get_path(Path::new(ISSUES_JSONL)) {
+            Ok(entry) => entry,
+            Err(_) => continue,
+        };
+        if last_blob == Some(entry.id()) {
+            continue;
+        }
+        last_blob = Some(entry.id());
+
+        let blob = repo.find_blob(entry.id())?;
+        let Some(fields) = bead_fields(blob.content(), bead_id) else {
+            // Not created yet, or removed in this snapshot
+            continue;
+        };
+
+        let changes: Vec<FieldChange> = HISTORY_FIELDS
+            .iter()
+            .enumerate()
+            .filter_map(|(i, field)| {
+                let from = last_fields.as_ref().and_then(|prev| prev[i].clone());
+                let to = fields[i].clone();
+                (from != to).then(|| FieldChange {
+                    field: field.to_string(),
+                    from,
+                    to,
+                })
+            })
+            .collect();
+        let created = last_fields.is_none();
+        last_fields = Some(fields);
+        if changes.is_empty() && !created {
+            continue;
+        }
+
+        let author = commit.author();
+        entries.push(BeadHistoryEntry {
+            commit: commit
+                .as_object()
+                .short_id()?
+                .as_str()
+                .unwrap_or("")
+                .to_string(),
+            author: author.name().unwrap_or("unknown").to_string(),
+            date: DateTime::from_timestamp(commit.time().seconds(), 0).unwrap_or_default(),
+            summary: commit.summary().unwrap_or("").to_string(),
+            created,
+            changes,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Values of [`HISTORY_FIELDS`] for one bead in an issues.jsonl snapshot
+fn bead_fields(content: &[u8], bead_id: &str) -> Option<Vec<Option<String>>> {
+    String::from_utf8_lossy(content)
+        .lines()
+        .filter(|line| line.contains(bead_id))
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .find(|value| value["id"] == bead_id)
+        .map(|value| {
+            HISTORY_FIELDS
+                .iter()
+                .map(|field| match &value[*field] {
+                    serde_json::Value::Null => None,
+                    serde_json::Value::String(s) if s.is_empty() => None,
+                    serde_json::Value::String(s) => Some(s.clone()),
+                    other => Some(other.to_string()),
+                })
+                .collect()
+        })
+}
+
+/// Count open and closed beads in an issues.jsonl snapshot
+///
+/// Lines that fail to parse and tombstoned (deleted) beads are ignored.
+pub fn count_statuses(content: &[u8]) -> StatusCounts {
+    let mut counts = StatusCounts::default();
+    for line in String::from_utf8_lossy(content).lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let Ok(entry) = serde_json::from_str::<StatusOnly>(line) else {
+            continue;
+        };
+        match entry.status.as_str() {
+            "closed" => counts.closed += 1,
+            "tombstone" => {}
+            _ => counts.open += 1,
+        }
+    }
+    counts
+}
+
+/// Combine per-context histories into dense daily totals from `start` to `end`
+///
+/// Each context contributes its most recent snapshot on or before each day,
+/// so days without commits carry the previous counts forward.
+pub fn daily_totals(
+    histories: &[ContextHistory],
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Vec<DailyCounts> {
+    start
+        .iter_days()
+        .take_while(|date| *date <= end)
+        .map(|date| {
+            let mut day = DailyCounts {
+                date,
+                open: 0,
+                closed: 0,
+            };
+            for history in histories {
+                if let Some((_, counts)) = history.range(..=date).next_back() {
+                    day.open += counts.open;
+                    day.closed += counts.closed;
+                }
+            }
+            day
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn commit_at(repo: &git2::Repository, content: &str, secs: i64) {
+        let path = repo.workdir().unwrap().join(ISSUES_JSONL);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, content).unwrap();
+
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new(ISSUES_JSONL)).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig =
+            git2::Signature::new("test", "test@example.com", &git2::Time::new(secs, 0)).unwrap();
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(Some("HEAD"), &sig, &sig, "update", &tree, &parents)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_count_statuses() {
+        let content = br#"{"id":"ab-1","status":"open"}
+{"id":"ab-2","status":"closed"}
+{"id":"ab-3","status":"in_progress"}
+{"id":"ab-4","status":"tombstone"}
+not json
+"#;
+        let counts = count_statuses(content);
+        assert_eq!(counts.open, 2);
+        assert_eq!(counts.closed, 1);
+    }
+
+    #[test]
+    fn test_context_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = git2::Repository::init(dir.path()).unwrap();
+
+        // 2026-01-01 and 2026-01-03 (UTC)
+        commit_at(
+            &repo,
+            "{\"id\":\"ab-1\",\"status\":\"open\"}\n",
+            1_767_225_600,
+        );
+        commit_at(
+            &repo,
+            "{\"id\":\"ab-1\",\"status\":\"closed\"}\n{\"id\":\"ab-2\",\"status\":\"open\"}\n",
+            1_767_398_400,
+        );
+
+        let history = context_history(dir.path()).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(
+            history[&date("2026-01-01")],
+            StatusCounts { open: 1, closed: 0 }
+        );
+        assert_eq!(
+            history[&date("2026-01-03")],
+            StatusCounts { open: 1, closed: 1 }
+        );
+    }
+
+    #[test]
+    fn test_bead_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = git2::Repository::init(dir.path()).unwrap();
+
+        let snapshots = [
+            r#"{"id":"ab-2","status":"open","priority":2}"#,
+            concat!(
+                r#"{"id":"ab-2","status":"open","priority":2}"#,
+                "\n",
+                r#"{"id":"ab-1","status":"open","priority":2}"#
+            ),
+            // Only ab-2 changes
+            concat!(
+                r#"{"id":"ab-2","status":"closed","priority":2}"#,
+                "\n",
+                r#"{"id":"ab-1","status":"open","priority":2}"#
+            ),
+            concat!(
+                r#"{"id":"ab-2","status":"closed","priority":2}"#,
+                "\n",
+                r#"{"id":"ab-1","status":"in_progress","priority":0,"assignee":"alice"}"#
+            ),
+            // Title-only edit is not a tracked change
+            concat!(
+                r#"{"id":"ab-2","status":"closed","priority":2}"#,
+                "\n",
+                r#"{"id":"ab-1","title":"x","status":"in_progress","priority":0,"assignee":"alice"}"#
+            ),
+        ];
+        for (i, snapshot) in snapshots.iter().enumerate() {
+            commit_at(&repo, snapshot, 1_767_225_600 + i as i64 * 3600);
+        }
+
+        let history = bead_history(dir.path(), "ab-1").unwrap();
+        assert_eq!(history.len(), 2);
+        assert!(history[0].created);
+        assert_eq!(history[0].author, "test");
+        assert_eq!(history[0].changes.len(), 2);
+        assert!(!history[1].created);
+        let changed: Vec<(&str, Option<&str>, Option<&str>)> = history[1]
+            .changes
+            .iter()
+            .map(|c| (c.field.as_str(), c.from.as_deref(), c.to.as_deref()))
+            .collect();
+        assert_eq!(
+            changed,
+            vec![
+                ("status", Some("open"), Some("in_progress")),
+                ("priority", Some("2"), Some("0")),
+                ("assignee", None, Some("alice")),
+            ]
+        );
+
+        assert!(bead_history(dir.path(), "ab-9").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_context_history_empty_repo() {
+        let dir = tempfile::tempdir().unwrap();
+        git2::Repository::init(dir.path()).unwrap();
+        assert!(context_history(dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_daily_totals_carry_forward() {
+        let mut a = ContextHistory::new();
+        a.insert(date("2026-01-01"), StatusCounts { open: 3, closed: 0 });
+        a.insert(date("2026-01-03"), StatusCounts { open: 1, closed: 2 });
+        let mut b = ContextHistory::new();
+        b.insert(date("2026-01-02"), StatusCounts { open: 5, closed: 1 });
+
+        let days = daily_totals(&[a, b], date("2026-01-01"), date("2026-01-04"));
+        let totals: Vec<(usize, usize)> = days.iter().map(|d| (d.open, d.closed)).collect();
+        assert_eq!(totals, vec![(3, 0), (8, 1), (6, 3), (6, 3)]);
+    }
+}
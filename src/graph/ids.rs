@@ -1,5 +1,6 @@
 //! Type-safe ID wrappers for beads and rigs
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -7,7 +8,7 @@ use std::fmt;
 ///
 /// Prevents mixing up bead IDs with rig IDs at compile time.
 /// Format: prefix-hash (e.g., "ab-ldr", "work-5fm")
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct BeadId(String);
 
 impl BeadId {
@@ -0,0 +1,169 @@
+//! Ready work ranked by impact
+//!
+//! A bead's impact is the number of open beads that transitively depend on
+//! it, i.e. how much work closing it would eventually unblock. `ab next`
+//! picks the first bead of [`ready_by_impact`]; `ab ready --leaves` and
+//! `--unblockers` split the ready queue into quick wins and leverage.
+
+use super::{Bead, BeadId, FederatedGraph, Status};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// A ready bead with its impact
+#[derive(Debug, Clone, Serialize)]
+pub struct RankedBead<'a> {
+    pub bead: &'a Bead,
+    /// Open beads that transitively depend on this one
+    pub impact: usize,
+}
+
+/// Ready beads ordered by priority, then impact (highest first), then age (oldest first)
+pub fn ready_by_impact(graph: &FederatedGraph) -> Vec<RankedBead<'_>> {
+    let dependents = dependents_index(graph);
+
+    let mut ranked: Vec<RankedBead> = graph
+        .ready_beads()
+        .into_iter()
+        .map(|bead| RankedBead {
+            bead,
+            impact: impact_with(&dependents, &bead.id),
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| {
+        a.bead
+            .priority
+            .cmp(&b.bead.priority)
+            .then_with(|| b.impact.cmp(&a.impact))
+            .then_with(|| a.bead.created_at.cmp(&b.bead.created_at))
+            .then_with(|| a.bead.id.as_str().cmp(b.bead.id.as_str()))
+    });
+    ranked
+}
+
+/// Ready beads no open bead depends on, by priority then age (oldest first)
+pub fn ready_leaves(graph: &FederatedGraph) -> Vec<RankedBead<'_>> {
+    let mut leaves: Vec<RankedBead> = ready_by_impact(graph)
+        .into_iter()
+        .filter(|r| r.impact == 0)
+        .collect();
+    leaves.sort_by(|a, b| {
+        a.bead
+            .priority
+            .cmp(&b.bead.priority)
+            .then_with(|| a.bead.created_at.cmp(&b.bead.created_at))
+            .then_with(|| a.bead.id.as_str().cmp(b.bead.id.as_str()))
+    });
+    leaves
+}
+
+/// Ready beads that unblock other work, most impact first, then by priority
+pub fn ready_unblockers(graph: &FederatedGraph) -> Vec<RankedBead<'_>> {
+    let mut unblockers: Vec<RankedBead> = ready_by_impact(graph)
+        .into_iter()
+        .filter(|r| r.impact > 0)
+        .collect();
+    // Stable sort keeps ready_by_impact's priority/age order within a tie
+    unblockers.sort_by_key(|r| std::cmp::Reverse(r.impact));
+    unblockers
+}
+
+/// Number of open beads that transitively depend on `id`
+pub fn impact(graph: &FederatedGraph, id: &BeadId) -> usize {
+    impact_with(&dependents_index(graph), id)
+}
+
+/// Map each bead to the open beads that list it as a dependency
+fn dependents_index(graph: &FederatedGraph) -> HashMap<&BeadId, Vec<&BeadId>> {
+    let mut dependents: HashMap<&BeadId, Vec<&BeadId>> = HashMap::new();
+    for bead in graph.beads.values() {
+        if matches!(bead.status, Status::Closed | Status::Tombstone) {
+            continue;
+        }
+        for dep in &bead.dependencies {
+            dependents.entry(dep).or_default().push(&bead.id);
+        }
+    }
+    dependents
+}
+
+fn impact_with(dependents: &HashMap<&BeadId, Vec<&BeadId>>, id: &BeadId) -> usize {
+    let mut seen: HashSet<&BeadId> = HashSet::new();
+    let mut stack: Vec<&BeadId> = dependents.get(id).cloned().unwrap_or_default();
+    while let Some(next) = stack.pop() {
+        if next != id && seen.insert(next) {
+            stack.extend(dependents.get(next).into_iter().flatten());
+        }
+    }
+    seen.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Priority;
+
+    #[test]
+    fn test_ready_by_impact() {
+        let mut graph = FederatedGraph::new();
+        let mut add = |id: &str, priority: Priority, created: &str, deps: &[&str]| {
+            let mut bead = Bead::new(id, id, "user");
+            bead.priority = priority;
+            bead.created_at = created.to_string();
+            bead.dependencies = deps.iter().map(|d| BeadId::new(*d)).collect();
+            graph.add_bead(bead);
+        };
+        // ab-1 unblocks ab-3 and, through it, ab-4
+        add("ab-1", Priority::P1, "2026-02-01T00:00:00Z", &[]);
+        add("ab-2", Priority::P1, "2026-01-01T00:00:00Z", &[]);
+        add("ab-3", Priority::P2, "2026-01-01T00:00:00Z", &["ab-1"]);
+        add("ab-4", Priority::P2, "2026-01-01T00:00:00Z", &["ab-3"]);
+        add("ab-5", Priority::P0, "2026-03-01T00:00:00Z", &[]);
+        add("ab-6", Priority::P1, "2025-01-01T00:00:00Z", &[]);
+
+        assert_eq!(impact(&graph, &BeadId::new("ab-1")), 2);
+        assert_eq!(impact(&graph, &BeadId::new("ab-4")), 0);
+
+        let order: Vec<(&str, usize)> = ready_by_impact(&graph)
+            .iter()
+            .map(|r| (r.bead.id.as_str(), r.impact))
+            .collect();
+        assert_eq!(
+            order,
+            vec![("ab-5", 0), ("ab-1", 2), ("ab-6", 0), ("ab-2", 0)]
+        );
+
+        let ids = |ranked: Vec<RankedBead>| -> Vec<String> {
+            ranked
+                .iter()
+                .map(|r| r.bead.id.as_str().to_string())
+                .collect()
+        };
+        assert_eq!(ids(ready_leaves(&graph)), vec!["ab-5", "ab-6", "ab-2"]);
+        assert_eq!(ids(ready_unblockers(&graph)), vec!["ab-1"]);
+    }
+
+    #[test]
+    fn test_ready_unblockers_order() {
+        let mut graph = FederatedGraph::new();
+        let mut add = |id: &str, priority: Priority, deps: &[&str]| {
+            let mut bead = Bead::new(id, id, "user");
+            bead.priority = priority;
+            bead.dependencies = deps.iter().map(|d| BeadId::new(*d)).collect();
+            graph.add_bead(bead);
+        };
+        // ab-1 (P0) unblocks one bead, ab-2 (P3) unblocks two
+        add("ab-1", Priority::P0, &[]);
+        add("ab-2", Priority::P3, &[]);
+        add("ab-3", Priority::P2, &["ab-1"]);
+        add("ab-4", Priority::P2, &["ab-2"]);
+        add("ab-5", Priority::P2, &["ab-2"]);
+
+        let order: Vec<(&str, usize)> = ready_unblockers(&graph)
+            .iter()
+            .map(|r| (r.bead.id.as_str(), r.impact))
+            .collect();
+        assert_eq!(order, vec![("ab-2", 2), ("ab-1", 1)]);
+        assert!(ready_leaves(&graph).is_empty());
+    }
+}
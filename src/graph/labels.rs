@@ -0,0 +1,122 @@
+//! Near-duplicate label detection and label renames
+//!
+//! Catches typos like `back-end` for an existing `backend` before they
+//! split the label namespace, for `ab label add`.
+
+/// Largest edit distance at which two labels count as near-duplicates
+pub const SIMILAR_LABEL_DISTANCE: usize = 2;
+
+/// Levenshtein edit distance between two strings, by character
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { prev } else { prev + 1 };
+            prev = row[j + 1];
+            row[j + 1] = cost.min(row[j] + 1).min(prev + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Existing labels that look like a typo of `label`, closest first
+///
+/// Comparison ignores case. Returns nothing if `label` already exists, since
+/// reusing a label is never a typo. Labels under 5 characters only match at
+/// distance 1, otherwise every short label would look alike.
+pub fn similar_labels<'a>(
+    label: &str,
+    existing: impl IntoIterator<Item = &'a str>,
+) -> Vec<&'a str> {
+    let wanted = label.to_lowercase();
+    let max_distance = if wanted.chars().count() < 5 {
+        1
+    } else {
+        SIMILAR_LABEL_DISTANCE
+    };
+
+    let mut matches = Vec::new();
+    for candidate in existing {
+        let distance = levenshtein(&wanted, &candidate.to_lowercase());
+        if distance == 0 {
+            return Vec::new();
+        }
+        if distance <= max_distance {
+            matches.push((distance, candidate));
+        }
+    }
+    matches.sort();
+    matches.dedup();
+    matches.into_iter().map(|(_, label)| label).collect()
+}
+
+/// Validate the labels for `ab label rename`, returning them trimmed
+///
+/// Context labels (`@name`) belong to AllBeads, and renaming a label to
+/// itself would only remove it from every bead.
+pub fn check_label_rename<'a>(old: &'a str, new: &'a str) -> crate::Result<(&'a str, &'a str)> {
+    let (old, new) = (old.trim(), new.trim());
+    if old.starts_with('@') || new.starts_with('@') {
+        return Err(crate::AllBeadsError::Other(
+            "Context labels (@name) are managed by AllBeads and can't be renamed".to_string(),
+        ));
+    }
+    if old.is_empty() || new.is_empty() {
+        return Err(crate::AllBeadsError::Other(
+            "Label names can't be empty".to_string(),
+        ));
+    }
+    if old == new {
+        return Err(crate::AllBeadsError::Other(format!(
+            "'{}' is already the label's name",
+            old
+        )));
+    }
+    Ok((old, new))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("", ""), 0);
+        assert_eq!(levenshtein("abc", ""), 3);
+        assert_eq!(levenshtein("backend", "back-end"), 1);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("frontend", "fronted"), 1);
+    }
+
+    #[test]
+    fn test_similar_labels() {
+        let existing = ["backend", "frontend", "bug", "ui", "backends"];
+        assert_eq!(
+            similar_labels("back-end", existing),
+            vec!["backend", "backends"]
+        );
+        assert_eq!(similar_labels("Frontnd", existing), vec!["frontend"]);
+        // Exact (case-insensitive) reuse is fine
+        assert!(similar_labels("Backend", existing).is_empty());
+        // Short labels need to be closer
+        assert_eq!(similar_labels("bgu", existing), Vec::<&str>::new());
+        assert_eq!(similar_labels("bugs", existing), vec!["bug"]);
+        assert!(similar_labels("database", existing).is_empty());
+    }
+
+    #[test]
+    fn test_check_label_rename() {
+        assert_eq!(
+            check_label_rename(" bug ", "defect").unwrap(),
+            ("bug", "defect")
+        );
+        assert!(check_label_rename("bug", "bug").is_err());
+        assert!(check_label_rename("bug", " bug ").is_err());
+        assert!(check_label_rename("bug", "").is_err());
+        assert!(check_label_rename("@work", "job").is_err());
+        assert!(check_label_rename("bug", "@job").is_err());
+    }
+}
@@ -0,0 +1,125 @@
+//! Non-blocking links between beads
+//!
+//! `bd` dependencies always block, so softer relationships are stored as
+//! structured labels of the form `<type>:<bead-id>` (e.g. `relates-to:ab-12`)
+//! on the source bead. They never affect ready/blocked status.
+
+use super::{Bead, BeadId};
+
+/// Kind of non-blocking relationship
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+pub enum LinkType {
+    /// Related work, worth reading together
+    RelatesTo,
+    /// Same issue reported twice
+    DuplicateOf,
+    /// Piece of a larger bead that isn't its parent epic
+    PartOf,
+}
+
+impl LinkType {
+    pub const ALL: [LinkType; 3] = [LinkType::RelatesTo, LinkType::DuplicateOf, LinkType::PartOf];
+
+    /// Label prefix, also the `--type` value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LinkType::RelatesTo => "relates-to",
+            LinkType::DuplicateOf => "duplicate-of",
+            LinkType::PartOf => "part-of",
+        }
+    }
+
+    /// Heading used when rendering links
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            LinkType::RelatesTo => "Relates to",
+            LinkType::DuplicateOf => "Duplicate of",
+            LinkType::PartOf => "Part of",
+        }
+    }
+
+    /// Label recording a link of this type to `target`
+    pub fn label(&self, target: &str) -> String {
+        format!("{}:{}", self.as_str(), target)
+    }
+}
+
+/// A link from one bead to another
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BeadLink {
+    pub link_type: LinkType,
+    pub target: BeadId,
+}
+
+impl BeadLink {
+    /// Parse a link label such as `relates-to:ab-12`
+    pub fn from_label(label: &str) -> Option<Self> {
+        let (prefix, target) = label.split_once(':')?;
+        let link_type = LinkType::ALL.into_iter().find(|t| t.as_str() == prefix)?;
+        (!target.is_empty()).then(|| BeadLink {
+            link_type,
+            target: BeadId::new(target),
+        })
+    }
+}
+
+impl Bead {
+    /// Outgoing non-blocking links, sorted by type then target
+    pub fn links(&self) -> Vec<BeadLink> {
+        let mut links: Vec<BeadLink> = self
+            .labels
+            .iter()
+            .filter_map(|l| BeadLink::from_label(l))
+            .collect();
+        links.sort_by(|a, b| {
+            (a.link_type, a.target.as_str()).cmp(&(b.link_type, b.target.as_str()))
+        });
+        links
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_label() {
+        let link = BeadLink::from_label("duplicate-of:ab-12").unwrap();
+        assert_eq!(link.link_type, LinkType::DuplicateOf);
+        assert_eq!(link.target.as_str(), "ab-12");
+        assert_eq!(
+            BeadLink::from_label(&LinkType::PartOf.label("ab-3")),
+            Some(BeadLink {
+                link_type: LinkType::PartOf,
+                target: BeadId::new("ab-3"),
+            })
+        );
+        assert!(BeadLink::from_label("relates-to:").is_none());
+        assert!(BeadLink::from_label("milestone:v1").is_none());
+        assert!(BeadLink::from_label("backend").is_none());
+    }
+
+    #[test]
+    fn test_bead_links() {
+        let mut bead = Bead::new("ab-1", "Test", "alice");
+        bead.add_label("@work");
+        bead.add_label("relates-to:ab-9");
+        bead.add_label("duplicate-of:ab-4");
+        bead.add_label("relates-to:ab-2");
+
+        let links: Vec<(LinkType, String)> = bead
+            .links()
+            .into_iter()
+            .map(|l| (l.link_type, l.target.as_str().to_string()))
+            .collect();
+        assert_eq!(
+            links,
+            vec![
+                (LinkType::RelatesTo, "ab-2".to_string()),
+                (LinkType::RelatesTo, "ab-9".to_string()),
+                (LinkType::DuplicateOf, "ab-4".to_string()),
+            ]
+        );
+        assert!(!bead.is_blocked());
+    }
+}
@@ -2,14 +2,43 @@
 //!
 //! Defines Bead, ShadowBead, Rig, and FederatedGraph types.
 
+mod activity;
+mod aging;
 mod bead;
+mod detail;
 mod federated_graph;
+mod history;
 mod ids;
+mod impact;
+mod labels;
+mod links;
+mod porcelain;
 mod rig;
 mod shadow_bead;
+mod sort;
+mod stale;
+mod summary;
+mod timestamp;
+mod workload;
 
+pub use activity::{federated_activity, merge_activity, ContextActivity};
+pub use aging::{age_histogram, age_histogram_as_of, AgeBuckets, StatusAges, AGE_BUCKETS};
 pub use bead::{Bead, IssueType, Priority, Status};
-pub use federated_graph::{FederatedGraph, GraphStats};
+pub use detail::{render_bead_markdown, BeadDetail};
+pub use federated_graph::{Collision, EpicRollup, FederatedGraph, GraphStats, MAX_BLOCKER_CHAINS};
+pub use history::{
+    bead_history, context_history, count_statuses, daily_totals, BeadHistoryEntry, ContextHistory,
+    DailyCounts, FieldChange, StatusCounts, HISTORY_FIELDS,
+};
 pub use ids::{BeadId, RigId};
+pub use impact::{impact, ready_by_impact, ready_leaves, ready_unblockers, RankedBead};
+pub use labels::{check_label_rename, levenshtein, similar_labels, SIMILAR_LABEL_DISTANCE};
+pub use links::{BeadLink, LinkType};
+pub use porcelain::{porcelain_line, PORCELAIN_FIELDS};
 pub use rig::{AuthStrategy as RigAuthStrategy, Rig};
 pub use shadow_bead::{BeadUri, ShadowBead, ShadowBeadBuilder};
+pub use sort::{sort_beads, status_rank, SortKey};
+pub use stale::{stale, stale_as_of, StaleBead};
+pub use summary::{context_summary, format_oneline, ContextSummary};
+pub use timestamp::{absolute_timestamp, display_timestamp, format_relative, parse_timestamp};
+pub use workload::{priority_weight, workload, WorkloadStats, UNASSIGNED};
@@ -0,0 +1,81 @@
+//! `--porcelain` output for scripts
+//!
+//! One bead per line as `id<TAB>status<TAB>priority<TAB>type<TAB>title`, with
+//! no colors or headers. This format is a stability guarantee: fields are
+//! only ever appended, never reordered or reformatted. The spellings below are
+//! written out rather than derived from serde or `Display` so that renaming a
+//! variant can't silently change them.
+
+use super::{Bead, IssueType, Status};
+
+/// Field names, in output order
+pub const PORCELAIN_FIELDS: [&str; 5] = ["id", "status", "priority", "type", "title"];
+
+/// Render a bead as one porcelain line, without the trailing newline
+///
+/// Tabs and line breaks in the title become spaces so each bead stays on one
+/// line with exactly five fields.
+pub fn porcelain_line(bead: &Bead) -> String {
+    let title: String = bead
+        .title
+        .chars()
+        .map(|c| {
+            if matches!(c, '\t' | '\n' | '\r') {
+                ' '
+            } else {
+                c
+            }
+        })
+        .collect();
+    format!(
+        "{}\t{}\t{}\t{}\t{}",
+        bead.id.as_str(),
+        porcelain_status(bead.status),
+        u8::from(bead.priority),
+        porcelain_type(bead.issue_type),
+        title
+    )
+}
+
+fn porcelain_status(status: Status) -> &'static str {
+    match status {
+        Status::Open => "open",
+        Status::InProgress => "in_progress",
+        Status::Blocked => "blocked",
+        Status::Deferred => "deferred",
+        Status::Closed => "closed",
+        Status::Tombstone => "tombstone",
+    }
+}
+
+fn porcelain_type(issue_type: IssueType) -> &'static str {
+    match issue_type {
+        IssueType::Bug => "bug",
+        IssueType::Feature => "feature",
+        IssueType::Task => "task",
+        IssueType::Epic => "epic",
+        IssueType::Chore => "chore",
+        IssueType::MergeRequest => "merge_request",
+        IssueType::Molecule => "molecule",
+        IssueType::Gate => "gate",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Priority;
+
+    #[test]
+    fn test_porcelain_line() {
+        let mut bead = Bead::new("ab-12", "Fix\tlogin\nflow", "alice");
+        bead.status = Status::InProgress;
+        bead.priority = Priority::P1;
+        bead.issue_type = IssueType::MergeRequest;
+        bead.add_label("@work");
+
+        let line = porcelain_line(&bead);
+        assert_eq!(line, "ab-12\tin_progress\t1\tmerge_request\tFix login flow");
+        assert_eq!(line.split('\t').count(), PORCELAIN_FIELDS.len());
+    }
+}
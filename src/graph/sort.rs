@@ -0,0 +1,221 @@
+//! Bead ordering for `--sort`
+//!
+//! Shared by `ab list`, `ab search`, `ab ready` and `ab blocked` so the same
+//! key always produces the same order. Ties fall back to priority, status and
+//! finally ID, which keeps output stable across runs.
+
+use super::{Bead, Status};
+use crate::{AllBeadsError, Result};
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+/// Field to sort beads by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortKey {
+    /// Priority, then status
+    #[default]
+    Priority,
+    Created,
+    Updated,
+    /// Open, in progress, blocked, deferred, closed
+    Status,
+    Id,
+    /// Case-insensitive title
+    Title,
+    Type,
+}
+
+impl SortKey {
+    /// Every key, in the order shown in help and error messages
+    pub const ALL: [SortKey; 7] = [
+        SortKey::Priority,
+        SortKey::Created,
+        SortKey::Updated,
+        SortKey::Status,
+        SortKey::Id,
+        SortKey::Title,
+        SortKey::Type,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortKey::Priority => "priority",
+            SortKey::Created => "created",
+            SortKey::Updated => "updated",
+            SortKey::Status => "status",
+            SortKey::Id => "id",
+            SortKey::Title => "title",
+            SortKey::Type => "type",
+        }
+    }
+
+    /// Name of the same key for `bd list --sort`, used by `ab --here list`
+    ///
+    /// Written out rather than reusing [`Self::as_str`] so renaming a key
+    /// here can't silently change what is passed to bd.
+    pub fn bd_key(&self) -> &'static str {
+        match self {
+            SortKey::Priority => "priority",
+            SortKey::Created => "created",
+            SortKey::Updated => "updated",
+            SortKey::Status => "status",
+            SortKey::Id => "id",
+            SortKey::Title => "title",
+            SortKey::Type => "type",
+        }
+    }
+}
+
+impl FromStr for SortKey {
+    type Err = AllBeadsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        SortKey::ALL
+            .into_iter()
+            .find(|key| key.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let keys: Vec<&str> = SortKey::ALL.iter().map(SortKey::as_str).collect();
+                AllBeadsError::Parse(format!(
+                    "Unknown sort key '{}'. Expected one of: {}",
+                    s,
+                    keys.join(", ")
+                ))
+            })
+    }
+}
+
+/// Rank of a status in [`SortKey::Status`] order
+pub fn status_rank(status: Status) -> u8 {
+    match status {
+        Status::Open => 0,
+        Status::InProgress => 1,
+        Status::Blocked => 2,
+        Status::Deferred => 3,
+        Status::Closed => 4,
+        Status::Tombstone => 5,
+    }
+}
+
+/// Sort beads by `key`, then reverse the whole order if asked
+pub fn sort_beads(beads: &mut [&Bead], key: SortKey, reverse: bool) {
+    beads.sort_by(|a, b| compare_key(a, b, key).then_with(|| tiebreak(a, b)));
+    if reverse {
+        beads.reverse();
+    }
+}
+
+fn compare_key(a: &Bead, b: &Bead, key: SortKey) -> Ordering {
+    match key {
+        SortKey::Priority => Ordering::Equal,
+        SortKey::Created => a.created_at.cmp(&b.created_at),
+        SortKey::Updated => a.updated_at.cmp(&b.updated_at),
+        SortKey::Status => status_rank(a.status).cmp(&status_rank(b.status)),
+        SortKey::Id => a.id.as_str().cmp(b.id.as_str()),
+        SortKey::Title => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+        SortKey::Type => format!("{:?}", a.issue_type).cmp(&format!("{:?}", b.issue_type)),
+    }
+}
+
+fn tiebreak(a: &Bead, b: &Bead) -> Ordering {
+    (a.priority, status_rank(a.status), a.id.as_str()).cmp(&(
+        b.priority,
+        status_rank(b.status),
+        b.id.as_str(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{IssueType, Priority};
+
+    fn beads() -> Vec<Bead> {
+        let make = |id: &str,
+                    title: &str,
+                    priority: Priority,
+                    status: Status,
+                    issue_type: IssueType,
+                    created: &str,
+                    updated: &str| {
+            let mut bead = Bead::new(id, title, "user");
+            bead.priority = priority;
+            bead.status = status;
+            bead.issue_type = issue_type;
+            bead.created_at = created.to_string();
+            bead.updated_at = updated.to_string();
+            bead
+        };
+        vec![
+            make(
+                "ab-3",
+                "beta",
+                Priority::P1,
+                Status::InProgress,
+                IssueType::Task,
+                "2026-01-02T00:00:00Z",
+                "2026-01-05T00:00:00Z",
+            ),
+            make(
+                "ab-1",
+                "Gamma",
+                Priority::P2,
+                Status::Open,
+                IssueType::Bug,
+                "2026-01-03T00:00:00Z",
+                "2026-01-04T00:00:00Z",
+            ),
+            make(
+                "ab-2",
+                "alpha",
+                Priority::P1,
+                Status::Open,
+                IssueType::Feature,
+                "2026-01-01T00:00:00Z",
+                "2026-01-06T00:00:00Z",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_sort_beads_matrix() {
+        let beads = beads();
+        let cases = [
+            (SortKey::Priority, ["ab-2", "ab-3", "ab-1"]),
+            (SortKey::Created, ["ab-2", "ab-3", "ab-1"]),
+            (SortKey::Updated, ["ab-1", "ab-3", "ab-2"]),
+            (SortKey::Status, ["ab-2", "ab-1", "ab-3"]),
+            (SortKey::Id, ["ab-1", "ab-2", "ab-3"]),
+            (SortKey::Title, ["ab-2", "ab-3", "ab-1"]),
+            (SortKey::Type, ["ab-1", "ab-2", "ab-3"]),
+        ];
+
+        for (key, expected) in cases {
+            for reverse in [false, true] {
+                // Input order must not matter
+                for rotation in 0..beads.len() {
+                    let mut refs: Vec<&Bead> = beads.iter().collect();
+                    refs.rotate_left(rotation);
+                    sort_beads(&mut refs, key, reverse);
+
+                    let mut expected = expected.to_vec();
+                    if reverse {
+                        expected.reverse();
+                    }
+                    let ids: Vec<&str> = refs.iter().map(|b| b.id.as_str()).collect();
+                    assert_eq!(ids, expected, "key {:?}, reverse {}", key, reverse);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_key_from_str() {
+        for key in SortKey::ALL {
+            assert_eq!(key.as_str().parse::<SortKey>().unwrap(), key);
+        }
+        assert_eq!("Title".parse::<SortKey>().unwrap(), SortKey::Title);
+        let err = "size".parse::<SortKey>().unwrap_err().to_string();
+        assert!(err.contains("priority, created"), "{}", err);
+        assert_eq!("Created".parse::<SortKey>().unwrap().bd_key(), "created");
+    }
+}
@@ -0,0 +1,82 @@
+//! Stale bead detection
+//!
+//! Finds open and in-progress beads whose `updated_at` is older than a
+//! threshold. In-progress beads that have not moved in weeks are usually
+//! abandoned work.
+
+use super::{parse_timestamp, Bead, FederatedGraph, Status};
+use chrono::{DateTime, Duration, Utc};
+
+/// A bead that has not been updated within the threshold
+#[derive(Debug, Clone)]
+pub struct StaleBead<'a> {
+    pub bead: &'a Bead,
+    /// Time since the bead was last updated
+    pub age: Duration,
+}
+
+/// Find open and in-progress beads not updated within `max_age`, oldest first
+pub fn stale(graph: &FederatedGraph, max_age: Duration) -> Vec<StaleBead<'_>> {
+    stale_as_of(graph, max_age, Utc::now())
+}
+
+/// Like [`stale`], measuring ages relative to `now`
+///
+/// Beads with an unparseable `updated_at` are skipped.
+pub fn stale_as_of(
+    graph: &FederatedGraph,
+    max_age: Duration,
+    now: DateTime<Utc>,
+) -> Vec<StaleBead<'_>> {
+    let mut found: Vec<StaleBead> = graph
+        .beads
+        .values()
+        .filter(|b| matches!(b.status, Status::Open | Status::InProgress))
+        .filter_map(|bead| {
+            let updated = parse_timestamp(&bead.updated_at)?;
+            let age = now - updated;
+            (age > max_age).then_some(StaleBead { bead, age })
+        })
+        .collect();
+
+    found.sort_by(|a, b| {
+        b.age
+            .cmp(&a.age)
+            .then_with(|| a.bead.id.as_str().cmp(b.bead.id.as_str()))
+    });
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bead(id: &str, status: Status, updated_at: &str) -> Bead {
+        let mut bead = Bead::new(id, id, "user");
+        bead.status = status;
+        bead.updated_at = updated_at.to_string();
+        bead
+    }
+
+    #[test]
+    fn test_stale() {
+        let mut graph = FederatedGraph::new();
+        graph.add_bead(bead("ab-1", Status::Open, "2026-01-01T00:00:00Z"));
+        graph.add_bead(bead("ab-2", Status::InProgress, "2025-12-01T00:00:00Z"));
+        graph.add_bead(bead("ab-3", Status::Closed, "2025-01-01T00:00:00Z"));
+        graph.add_bead(bead("ab-4", Status::Open, "2026-02-25T00:00:00Z"));
+        graph.add_bead(bead("ab-5", Status::Blocked, "2025-01-01T00:00:00Z"));
+        graph.add_bead(bead("ab-6", Status::Open, "not a date"));
+
+        let now = DateTime::parse_from_rfc3339("2026-03-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let stale = stale_as_of(&graph, Duration::days(30), now);
+
+        let found: Vec<(&str, i64)> = stale
+            .iter()
+            .map(|s| (s.bead.id.as_str(), s.age.num_days()))
+            .collect();
+        assert_eq!(found, vec![("ab-2", 90), ("ab-1", 59)]);
+    }
+}
@@ -0,0 +1,115 @@
+//! Per-context ready/blocked counts
+//!
+//! Backs `ab summary`, which is meant to run on every shell prompt render,
+//! so everything here works on an already-loaded graph.
+
+use super::{FederatedGraph, Status};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Bead counts for one context
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ContextSummary {
+    pub context: String,
+    /// Open beads with no open blockers in any context
+    pub ready: usize,
+    pub in_progress: usize,
+    /// Blocked beads, plus unclosed beads waiting on open blockers (as in `ab blocked`)
+    pub blocked: usize,
+}
+
+/// Count ready, in-progress and blocked beads per context, sorted by context
+///
+/// Beads without an `@context` label are not counted.
+pub fn context_summary(graph: &FederatedGraph) -> Vec<ContextSummary> {
+    let mut by_context: BTreeMap<&str, ContextSummary> = BTreeMap::new();
+    for bead in graph.beads.values() {
+        let Some(context) = bead.labels.iter().find_map(|l| l.strip_prefix('@')) else {
+            continue;
+        };
+        let summary = by_context.entry(context).or_insert_with(|| ContextSummary {
+            context: context.to_string(),
+            ..Default::default()
+        });
+
+        if bead.status == Status::Open && !graph.has_open_blockers(bead) {
+            summary.ready += 1;
+        } else if graph.is_blocked(bead) {
+            summary.blocked += 1;
+        } else if bead.status == Status::InProgress {
+            summary.in_progress += 1;
+        }
+    }
+    by_context.into_values().collect()
+}
+
+/// Render summaries on one line, e.g. `@work: 3 ready, 2 blocked | @personal: 1 ready`
+///
+/// Zero counts and contexts with nothing ready or blocked are left out, so
+/// the line is empty when there is nothing to act on.
+pub fn format_oneline(summaries: &[ContextSummary]) -> String {
+    summaries
+        .iter()
+        .filter_map(|s| {
+            let mut parts = Vec::new();
+            if s.ready > 0 {
+                parts.push(format!("{} ready", s.ready));
+            }
+            if s.blocked > 0 {
+                parts.push(format!("{} blocked", s.blocked));
+            }
+            (!parts.is_empty()).then(|| format!("@{}: {}", s.context, parts.join(", ")))
+        })
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Bead;
+
+    #[test]
+    fn test_context_summary_oneline() {
+        let mut graph = FederatedGraph::new();
+        let mut add = |id: &str, context: Option<&str>, status: Status, deps: &[&str]| {
+            let mut bead = Bead::new(id, id, "user");
+            bead.status = status;
+            if let Some(context) = context {
+                bead.add_label(format!("@{}", context));
+            }
+            for dep in deps {
+                bead.add_dependency(*dep);
+            }
+            graph.add_bead(bead);
+        };
+        add("w-1", Some("work"), Status::Open, &[]);
+        add("w-2", Some("work"), Status::Open, &[]);
+        add("w-3", Some("work"), Status::Open, &["w-1"]);
+        add("w-4", Some("work"), Status::Blocked, &[]);
+        add("w-5", Some("work"), Status::InProgress, &[]);
+        add("w-6", Some("work"), Status::Closed, &["w-1"]);
+        add("p-1", Some("personal"), Status::Open, &[]);
+        add("o-1", Some("oss"), Status::Closed, &[]);
+        add("x-1", None, Status::Open, &[]);
+
+        let summaries = context_summary(&graph);
+        let contexts: Vec<&str> = summaries.iter().map(|s| s.context.as_str()).collect();
+        assert_eq!(contexts, vec!["oss", "personal", "work"]);
+        assert_eq!(
+            summaries[2],
+            ContextSummary {
+                context: "work".to_string(),
+                ready: 2,
+                in_progress: 1,
+                blocked: 2,
+            }
+        );
+
+        assert_eq!(
+            format_oneline(&summaries),
+            "@personal: 1 ready | @work: 2 ready, 2 blocked"
+        );
+        assert_eq!(format_oneline(&[]), "");
+    }
+}
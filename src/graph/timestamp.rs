@@ -0,0 +1,133 @@
+//! Bead timestamp parsing and display
+//!
+//! Beads store `created_at`/`updated_at` as strings. bd writes RFC3339, but
+//! imported or hand-edited data may drop the offset or the time entirely.
+//! [`parse_timestamp`] accepts those forms and never panics on short input.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+/// Parse a bead timestamp into UTC
+///
+/// Accepts RFC3339, `YYYY-MM-DDTHH:MM:SS` or `YYYY-MM-DD HH:MM:SS` without an
+/// offset (taken as UTC), and plain dates (midnight UTC).
+pub fn parse_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    let s = s.trim();
+    if let Ok(t) = DateTime::parse_from_rfc3339(s) {
+        return Some(t.with_timezone(&Utc));
+    }
+    for format in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"] {
+        if let Ok(t) = NaiveDateTime::parse_from_str(s, format) {
+            return Some(t.and_utc());
+        }
+    }
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|t| t.and_utc())
+}
+
+/// Describe `t` relative to `now`, e.g. "3 days ago" or "in 2 hours"
+pub fn format_relative(t: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let delta = now - t;
+    let seconds = delta.num_seconds().abs();
+    if seconds < 60 {
+        return "just now".to_string();
+    }
+
+    let (amount, unit) = match seconds {
+        s if s < 3600 => (s / 60, "minute"),
+        s if s < 86_400 => (s / 3600, "hour"),
+        s if s < 30 * 86_400 => (s / 86_400, "day"),
+        s if s < 365 * 86_400 => (s / (30 * 86_400), "month"),
+        s => (s / (365 * 86_400), "year"),
+    };
+    let plural = if amount == 1 { "" } else { "s" };
+    if delta.num_seconds() >= 0 {
+        format!("{} {}{} ago", amount, unit, plural)
+    } else {
+        format!("in {} {}{}", amount, unit, plural)
+    }
+}
+
+/// Format of the absolute part of a displayed timestamp
+const ABSOLUTE_FORMAT: &str = "%Y-%m-%d %H:%M UTC";
+
+/// Absolute and relative form of a bead timestamp, relative to `now`
+///
+/// Unparseable input is returned unchanged so nothing is hidden.
+pub fn display_timestamp(s: &str, now: DateTime<Utc>) -> String {
+    match parse_timestamp(s) {
+        Some(t) => format!(
+            "{} ({})",
+            t.format(ABSOLUTE_FORMAT),
+            format_relative(t, now)
+        ),
+        None => s.to_string(),
+    }
+}
+
+/// Absolute form of a bead timestamp, for output that outlives the moment
+/// it was rendered
+///
+/// Unparseable input is returned unchanged so nothing is hidden.
+pub fn absolute_timestamp(s: &str) -> String {
+    match parse_timestamp(s) {
+        Some(t) => t.format(ABSOLUTE_FORMAT).to_string(),
+        None => s.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        parse_timestamp(s).unwrap()
+    }
+
+    #[test]
+    fn test_parse_timestamp_formats() {
+        let expected = at("2026-03-01T12:30:00Z");
+        assert_eq!(parse_timestamp("2026-03-01T13:30:00+01:00"), Some(expected));
+        assert_eq!(parse_timestamp("2026-03-01T12:30:00"), Some(expected));
+        assert_eq!(parse_timestamp("2026-03-01 12:30:00.000"), Some(expected));
+        assert_eq!(
+            parse_timestamp("2026-03-01"),
+            Some(at("2026-03-01T00:00:00Z"))
+        );
+        assert_eq!(parse_timestamp("2026-03"), None);
+        assert_eq!(parse_timestamp(""), None);
+    }
+
+    #[test]
+    fn test_format_relative() {
+        let now = at("2026-03-10T12:00:00Z");
+        assert_eq!(format_relative(at("2026-03-10T11:59:30Z"), now), "just now");
+        assert_eq!(
+            format_relative(at("2026-03-10T11:00:00Z"), now),
+            "1 hour ago"
+        );
+        assert_eq!(
+            format_relative(at("2026-03-07T12:00:00Z"), now),
+            "3 days ago"
+        );
+        assert_eq!(
+            format_relative(at("2025-01-01T00:00:00Z"), now),
+            "1 year ago"
+        );
+        assert_eq!(
+            format_relative(at("2026-03-10T14:00:00Z"), now),
+            "in 2 hours"
+        );
+    }
+
+    #[test]
+    fn test_display_timestamp() {
+        let now = at("2026-03-10T12:00:00Z");
+        assert_eq!(
+            display_timestamp("2026-03-07T12:00:00Z", now),
+            "2026-03-07 12:00 UTC (3 days ago)"
+        );
+        assert_eq!(display_timestamp("garbage", now), "garbage");
+    }
+}
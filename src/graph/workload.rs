@@ -0,0 +1,96 @@
+//! Assignee workload
+//!
+//! Summarizes open and in-progress beads per assignee across every context,
+//! for sprint and capacity planning.
+
+use super::{FederatedGraph, Priority, Status};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Key used for beads without an assignee
+pub const UNASSIGNED: &str = "(unassigned)";
+
+/// Active beads held by one assignee
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct WorkloadStats {
+    pub open: usize,
+    pub in_progress: usize,
+    /// Sum of priority weights (P0 = 5 down to P4 = 1)
+    pub priority_weight: u32,
+}
+
+impl WorkloadStats {
+    /// Total active beads
+    pub fn total(&self) -> usize {
+        self.open + self.in_progress
+    }
+}
+
+/// Weight of a priority in the workload sum; more urgent work weighs more
+pub fn priority_weight(priority: Priority) -> u32 {
+    5 - priority as u32
+}
+
+/// Count open and in-progress beads per assignee
+///
+/// Beads without an assignee are grouped under [`UNASSIGNED`].
+pub fn workload(graph: &FederatedGraph) -> BTreeMap<String, WorkloadStats> {
+    let mut workload: BTreeMap<String, WorkloadStats> = BTreeMap::new();
+
+    for bead in graph.beads.values() {
+        if !matches!(bead.status, Status::Open | Status::InProgress) {
+            continue;
+        }
+        let assignee = bead
+            .assignee
+            .as_deref()
+            .filter(|a| !a.is_empty())
+            .unwrap_or(UNASSIGNED);
+
+        let stats = workload.entry(assignee.to_string()).or_default();
+        if bead.status == Status::InProgress {
+            stats.in_progress += 1;
+        } else {
+            stats.open += 1;
+        }
+        stats.priority_weight += priority_weight(bead.priority);
+    }
+
+    workload
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Bead;
+
+    #[test]
+    fn test_workload() {
+        let mut graph = FederatedGraph::new();
+        let mut add = |id: &str, assignee: Option<&str>, status: Status, priority: Priority| {
+            let mut bead = Bead::new(id, id, "user");
+            bead.assignee = assignee.map(str::to_string);
+            bead.status = status;
+            bead.priority = priority;
+            graph.add_bead(bead);
+        };
+        add("ab-1", Some("alice"), Status::Open, Priority::P0);
+        add("ab-2", Some("alice"), Status::InProgress, Priority::P2);
+        add("ab-3", Some("alice"), Status::Closed, Priority::P0);
+        add("ab-4", None, Status::Open, Priority::P4);
+        add("ab-5", Some("bob"), Status::Blocked, Priority::P1);
+
+        let workload = workload(&graph);
+        assert_eq!(workload.len(), 2);
+        assert_eq!(
+            workload["alice"],
+            WorkloadStats {
+                open: 1,
+                in_progress: 1,
+                priority_weight: 8,
+            }
+        );
+        assert_eq!(workload[UNASSIGNED].total(), 1);
+        assert_eq!(workload[UNASSIGNED].priority_weight, 1);
+    }
+}
@@ -7,4 +7,6 @@ mod config;
 mod types;
 
 pub use config::{get_preferred_agent, is_worktree_enabled, save_preferred_agent};
-pub use types::{detect_installed_agents, get_installed_agents, AgentHandoff, AgentType};
+pub use types::{
+    detect_installed_agents, get_installed_agents, parse_handoff_comment, AgentHandoff, AgentType,
+};
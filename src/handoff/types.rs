@@ -3,12 +3,13 @@
 //! Defines the agent types and handoff metadata stored in beads.
 
 use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::path::PathBuf;
 
 /// Supported agent types for handoff
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum AgentType {
     // Terminal-native agents
@@ -268,7 +269,7 @@ impl std::str::FromStr for AgentType {
 ///
 /// Stored in the bead to track which agent is working on it
 /// and provide a link back to the agent's task/session.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AgentHandoff {
     /// Type of agent handling this bead
     pub agent_type: AgentType,
@@ -313,6 +314,13 @@ impl AgentHandoff {
     }
 }
 
+/// Agent name and time from a `[HANDOFF] Agent: <name>, Time: <rfc3339>` comment
+pub fn parse_handoff_comment(content: &str) -> Option<(&str, &str)> {
+    let rest = content.strip_prefix("[HANDOFF] Agent: ")?;
+    let (agent, time) = rest.split_once(", Time:")?;
+    Some((agent, time.trim()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -357,4 +365,17 @@ mod tests {
         assert!(json.contains("claude"));
         assert!(json.contains("/tmp/test"));
     }
+
+    #[test]
+    fn test_parse_handoff_comment() {
+        assert_eq!(
+            parse_handoff_comment("[HANDOFF] Agent: Claude Code, Time: 2026-01-15T10:30:00Z"),
+            Some(("Claude Code", "2026-01-15T10:30:00Z"))
+        );
+        assert_eq!(
+            parse_handoff_comment("[QUEUED] Agent: Codex, Time: now"),
+            None
+        );
+        assert_eq!(parse_handoff_comment("[HANDOFF] Agent: Codex"), None);
+    }
 }
@@ -24,6 +24,8 @@ pub mod coding_agent;
 pub mod config;
 pub mod context;
 pub mod context_new;
+pub mod doctor;
+pub mod duration;
 pub mod error;
 pub mod git;
 pub mod graph;
@@ -31,12 +33,14 @@ pub mod logging;
 pub mod storage;
 pub mod style;
 pub mod tui;
+pub mod undo;
 
 // Components (will be implemented in phases)
 pub mod boss_board;
 pub mod governance;
 pub mod handoff;
 pub mod integrations;
+pub mod janitor;
 pub mod mail;
 pub mod manifest;
 pub mod onboarding;
@@ -1,9 +1,81 @@
 //! Logging configuration using tracing
 //!
 //! Provides structured logging to stderr and file with support for RUST_LOG environment variable.
+//!
+//! Also holds the CLI verbosity (`-q`, `-v`, `-vv`), which sets the default
+//! tracing level and gates informational status lines printed via [`status!`].
 
+use std::sync::atomic::{AtomicU8, Ordering};
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
+/// How much the CLI prints besides command results and errors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Verbosity {
+    /// Command results and errors only (`-q`)
+    Quiet,
+    /// Status lines and warnings (default)
+    #[default]
+    Normal,
+    /// Info-level tracing (`-v`)
+    Verbose,
+    /// Debug-level tracing (`-vv` and up)
+    Debug,
+}
+
+impl Verbosity {
+    /// Verbosity from the `--quiet` flag and the number of `-v` flags
+    pub fn from_flags(quiet: bool, verbose: u8) -> Self {
+        match (quiet, verbose) {
+            (true, _) => Self::Quiet,
+            (false, 0) => Self::Normal,
+            (false, 1) => Self::Verbose,
+            (false, _) => Self::Debug,
+        }
+    }
+
+    /// Default tracing filter when RUST_LOG is not set
+    pub fn filter(self) -> &'static str {
+        match self {
+            Self::Quiet => "error",
+            Self::Normal => "warn",
+            Self::Verbose => "info",
+            Self::Debug => "debug",
+        }
+    }
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+
+/// Set the process-wide verbosity
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+}
+
+/// Current process-wide verbosity
+pub fn verbosity() -> Verbosity {
+    match VERBOSITY.load(Ordering::Relaxed) {
+        0 => Verbosity::Quiet,
+        1 => Verbosity::Normal,
+        2 => Verbosity::Verbose,
+        _ => Verbosity::Debug,
+    }
+}
+
+/// Whether informational status lines should be printed
+pub fn show_status() -> bool {
+    verbosity() > Verbosity::Quiet
+}
+
+/// Print an informational status line to stderr unless running with `--quiet`
+#[macro_export]
+macro_rules! status {
+    ($($arg:tt)*) => {
+        if $crate::logging::show_status() {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
 /// Initialize the tracing subscriber
 ///
 /// Sets up structured logging with:
@@ -20,8 +92,21 @@ use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, Env
 /// # Errors
 /// Returns an error if the subscriber has already been initialized
 pub fn init() -> crate::Result<()> {
-    // Create an EnvFilter that respects RUST_LOG, defaulting to "warn" for quiet CLI output
-    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn"));
+    init_with_verbosity(Verbosity::Normal)
+}
+
+/// Initialize the tracing subscriber and record the CLI verbosity
+///
+/// RUST_LOG still takes precedence over the level implied by `verbosity`.
+///
+/// # Errors
+/// Returns an error if the subscriber has already been initialized
+pub fn init_with_verbosity(verbosity: Verbosity) -> crate::Result<()> {
+    set_verbosity(verbosity);
+
+    // Create an EnvFilter that respects RUST_LOG, defaulting to the verbosity's level
+    let env_filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(verbosity.filter()));
 
     // Configure the tracing subscriber with:
     // - Environment-based filtering
@@ -67,6 +152,16 @@ mod tests {
         init_test(); // Can be called multiple times
     }
 
+    #[test]
+    fn test_verbosity_from_flags() {
+        assert_eq!(Verbosity::from_flags(true, 2), Verbosity::Quiet);
+        assert_eq!(Verbosity::from_flags(false, 0), Verbosity::Normal);
+        assert_eq!(Verbosity::from_flags(false, 1), Verbosity::Verbose);
+        assert_eq!(Verbosity::from_flags(false, 3), Verbosity::Debug);
+        assert_eq!(Verbosity::Quiet.filter(), "error");
+        assert_eq!(Verbosity::Debug.filter(), "debug");
+    }
+
     #[test]
     fn test_logging_macros() {
         init_test();
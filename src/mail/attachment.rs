@@ -0,0 +1,199 @@
+//! Mail attachments
+//!
+//! Messages carry references to files, not their contents. Senders write (or
+//! import) a file into the managed `mail-attachments/` directory next to
+//! `mail.db`, and the Postmaster only stores the name and path. Paths outside
+//! that directory are rejected so a message can't point a recipient at
+//! arbitrary files on disk.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Name of the managed attachments directory, created next to `mail.db`
+pub const ATTACHMENTS_DIR: &str = "mail-attachments";
+
+/// Maximum size of a single attachment (10 MiB)
+pub const MAX_ATTACHMENT_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Maximum number of attachments on one message
+pub const MAX_ATTACHMENTS: usize = 10;
+
+/// Attachment errors
+#[derive(Debug, Error)]
+pub enum AttachmentError {
+    #[error("attachment not found: {0}")]
+    NotFound(PathBuf),
+
+    #[error("attachment {0} is outside the managed attachments directory")]
+    OutsideDir(PathBuf),
+
+    #[error("attachment {path} is {size} bytes (limit is {MAX_ATTACHMENT_SIZE})")]
+    TooLarge { path: PathBuf, size: u64 },
+
+    #[error("too many attachments: {0} (limit is {MAX_ATTACHMENTS})")]
+    TooMany(usize),
+
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Reference to a file in the managed attachments directory
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attachment {
+    /// Display name (usually the original file name)
+    pub name: String,
+
+    /// Path of the file inside the attachments directory
+    pub path: PathBuf,
+}
+
+impl Attachment {
+    /// Create an attachment reference
+    pub fn new(name: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        Self {
+            name: name.into(),
+            path: path.into(),
+        }
+    }
+
+    /// Current size of the referenced file, if it still exists
+    pub fn size(&self) -> Option<u64> {
+        fs::metadata(&self.path).ok().map(|m| m.len())
+    }
+
+    /// Check that the file exists inside `dir` and is within the size limit
+    ///
+    /// Returns the file size.
+    pub fn validate(&self, dir: &Path) -> Result<u64, AttachmentError> {
+        let path = self
+            .path
+            .canonicalize()
+            .map_err(|_| AttachmentError::NotFound(self.path.clone()))?;
+        let dir = dir
+            .canonicalize()
+            .map_err(|_| AttachmentError::OutsideDir(self.path.clone()))?;
+        if !path.starts_with(&dir) {
+            return Err(AttachmentError::OutsideDir(self.path.clone()));
+        }
+
+        let metadata = fs::metadata(&path)?;
+        if !metadata.is_file() {
+            return Err(AttachmentError::NotFound(self.path.clone()));
+        }
+        if metadata.len() > MAX_ATTACHMENT_SIZE {
+            return Err(AttachmentError::TooLarge {
+                path: self.path.clone(),
+                size: metadata.len(),
+            });
+        }
+        Ok(metadata.len())
+    }
+}
+
+/// Validate every attachment on a message against the managed directory
+pub fn validate_attachments(attachments: &[Attachment], dir: &Path) -> Result<(), AttachmentError> {
+    if attachments.len() > MAX_ATTACHMENTS {
+        return Err(AttachmentError::TooMany(attachments.len()));
+    }
+    for attachment in attachments {
+        attachment.validate(dir)?;
+    }
+    Ok(())
+}
+
+/// Copy a file into the managed attachments directory
+///
+/// The copy is stored under a unique prefix so attachments with the same
+/// name don't overwrite each other.
+pub fn import_attachment(source: &Path, dir: &Path) -> Result<Attachment, AttachmentError> {
+    let metadata = fs::metadata(source).map_err(|_| AttachmentError::NotFound(source.into()))?;
+    if !metadata.is_file() {
+        return Err(AttachmentError::NotFound(source.into()));
+    }
+    if metadata.len() > MAX_ATTACHMENT_SIZE {
+        return Err(AttachmentError::TooLarge {
+            path: source.into(),
+            size: metadata.len(),
+        });
+    }
+
+    let name = source
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "attachment".to_string());
+
+    fs::create_dir_all(dir)?;
+    let stored = dir.join(format!("{}-{}", super::MessageId::new().as_str(), name));
+    fs::copy(source, &stored)?;
+
+    Ok(Attachment::new(name, stored))
+}
+
+/// Format a byte count for display (e.g. `512 B`, `4.2 KB`, `1.5 MB`)
+pub fn format_size(bytes: u64) -> String {
+    const KB: f64 = 1024.0;
+    const MB: f64 = KB * 1024.0;
+    let b = bytes as f64;
+    if b >= MB {
+        format!("{:.1} MB", b / MB)
+    } else if b >= KB {
+        format!("{:.1} KB", b / KB)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_import_and_validate() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join(ATTACHMENTS_DIR);
+        let source = temp_dir.path().join("fix.diff");
+        fs::write(&source, "--- a\n+++ b\n").unwrap();
+
+        let attachment = import_attachment(&source, &dir).unwrap();
+        assert_eq!(attachment.name, "fix.diff");
+        assert!(attachment.path.starts_with(&dir));
+        assert_eq!(attachment.validate(&dir).unwrap(), 12);
+        assert_eq!(attachment.size(), Some(12));
+
+        // The original file lives outside the managed directory
+        let outside = Attachment::new("fix.diff", &source);
+        assert!(matches!(
+            outside.validate(&dir),
+            Err(AttachmentError::OutsideDir(_))
+        ));
+
+        // So does anything reached through `..`
+        let escaped = Attachment::new("fix.diff", dir.join("..").join("fix.diff"));
+        assert!(matches!(
+            escaped.validate(&dir),
+            Err(AttachmentError::OutsideDir(_))
+        ));
+
+        let missing = Attachment::new("gone.log", dir.join("gone.log"));
+        assert!(matches!(
+            missing.validate(&dir),
+            Err(AttachmentError::NotFound(_))
+        ));
+
+        let many = vec![attachment; MAX_ATTACHMENTS + 1];
+        assert!(matches!(
+            validate_attachments(&many, &dir),
+            Err(AttachmentError::TooMany(_))
+        ));
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(2048), "2.0 KB");
+        assert_eq!(format_size(3 * 1024 * 1024 / 2), "1.5 MB");
+    }
+}
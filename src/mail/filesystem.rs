@@ -58,6 +58,12 @@ struct MessageJson {
     payload: serde_json::Value,
     timestamp: String,
     correlation_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    in_reply_to: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    thread_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attachments: Vec<super::Attachment>,
 }
 
 impl FilesystemTransport {
@@ -311,6 +317,12 @@ fn message_to_json(message: &Message) -> MessageJson {
             .correlation_id
             .as_ref()
             .map(|id| id.as_str().to_string()),
+        in_reply_to: message
+            .in_reply_to
+            .as_ref()
+            .map(|id| id.as_str().to_string()),
+        thread_id: message.thread_id.as_ref().map(|id| id.as_str().to_string()),
+        attachments: message.attachments.clone(),
     }
 }
 
@@ -375,6 +387,9 @@ fn record_to_stored_message(record: MessageRecord) -> Result<StoredMessage> {
         message_type,
         timestamp,
         correlation_id: record.message.correlation_id.map(MessageId::from_string),
+        in_reply_to: record.message.in_reply_to.map(MessageId::from_string),
+        thread_id: record.message.thread_id.map(MessageId::from_string),
+        attachments: record.message.attachments,
     };
 
     Ok(StoredMessage {
@@ -528,6 +528,18 @@ pub struct Message {
     /// Optional correlation ID for request/response matching
     #[serde(skip_serializing_if = "Option::is_none")]
     pub correlation_id: Option<MessageId>,
+
+    /// Message this one replies to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_reply_to: Option<MessageId>,
+
+    /// ID of the first message in the conversation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thread_id: Option<MessageId>,
+
+    /// Files in the managed attachments directory
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<super::Attachment>,
 }
 
 impl Message {
@@ -540,6 +552,9 @@ impl Message {
             message_type,
             timestamp: Utc::now(),
             correlation_id: None,
+            in_reply_to: None,
+            thread_id: None,
+            attachments: Vec::new(),
         }
     }
 
@@ -578,6 +593,27 @@ impl Message {
         self
     }
 
+    /// Mark this message as a reply to `parent`
+    ///
+    /// The thread is inherited from the parent, or started at the parent if
+    /// it was not itself part of a thread.
+    pub fn reply_to(mut self, parent: &Message) -> Self {
+        self.in_reply_to = Some(parent.id.clone());
+        self.thread_id = Some(
+            parent
+                .thread_id
+                .clone()
+                .unwrap_or_else(|| parent.id.clone()),
+        );
+        self
+    }
+
+    /// Attach a file from the managed attachments directory
+    pub fn with_attachment(mut self, attachment: super::Attachment) -> Self {
+        self.attachments.push(attachment);
+        self
+    }
+
     /// Check if this is a lock request
     pub fn is_lock(&self) -> bool {
         matches!(self.message_type, MessageType::Lock(_))
@@ -736,4 +772,25 @@ mod tests {
         assert_eq!(response.status, ResponseStatus::Success);
         assert_eq!(response.in_reply_to, original_id);
     }
+
+    #[test]
+    fn test_reply_threading() {
+        let notify = |text: &str| MessageType::Notify(NotifyPayload::new(text));
+        let root = Message::from_strings("worker@project", "human@localhost", notify("Done"));
+        let reply = Message::from_strings("human@localhost", "worker@project", notify("Thanks"))
+            .reply_to(&root);
+        assert_eq!(reply.in_reply_to.as_ref(), Some(&root.id));
+        assert_eq!(reply.thread_id.as_ref(), Some(&root.id));
+
+        let second = Message::from_strings("worker@project", "human@localhost", notify("np"))
+            .reply_to(&reply);
+        assert_eq!(second.in_reply_to.as_ref(), Some(&reply.id));
+        assert_eq!(second.thread_id.as_ref(), Some(&root.id));
+
+        let json = serde_json::to_string(&root).unwrap();
+        assert!(!json.contains("thread_id"));
+        let parsed: Message =
+            serde_json::from_str(&serde_json::to_string(&second).unwrap()).unwrap();
+        assert_eq!(parsed.thread_id, second.thread_id);
+    }
 }
@@ -50,6 +50,7 @@
 //! ```
 
 mod address;
+mod attachment;
 mod filesystem;
 mod locks;
 mod message;
@@ -59,6 +60,10 @@ mod server;
 mod transport;
 
 pub use address::{Address, AddressError, RoutingTarget};
+pub use attachment::{
+    format_size, import_attachment, validate_attachments, Attachment, AttachmentError,
+    ATTACHMENTS_DIR, MAX_ATTACHMENTS, MAX_ATTACHMENT_SIZE,
+};
 pub use filesystem::FilesystemTransport;
 pub use locks::{ConflictStrategy, LockInfo, LockManager, LockResult};
 pub use message::{
@@ -15,7 +15,7 @@
 //!
 //! ```no_run
 //! use allbeads::mail::{Postmaster, Message, MessageType, NotifyPayload, Address};
-//! use std::path::PathBuf;
+//! use std::path::{Path, PathBuf};
 //!
 //! let mut postmaster = Postmaster::new(PathBuf::from("mail.db")).unwrap();
 //!
@@ -33,12 +33,13 @@
 //! ```
 
 use super::{
-    Address, ConflictStrategy, LockManager, LockRequest, LockResult, Message, MessageId,
-    MessageType, ResponsePayload, ResponseStatus, RoutingTarget, UnlockRequest,
+    validate_attachments, Address, AttachmentError, ConflictStrategy, LockManager, LockRequest,
+    LockResult, Message, MessageId, MessageType, ResponsePayload, ResponseStatus, RoutingTarget,
+    UnlockRequest,
 };
 use chrono::{DateTime, Utc};
 use rusqlite::{params, Connection, Result as SqliteResult};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 /// Postmaster errors
@@ -55,6 +56,9 @@ pub enum PostmasterError {
 
     #[error("message not found: {0}")]
     MessageNotFound(String),
+
+    #[error("invalid attachment: {0}")]
+    InvalidAttachment(#[from] AttachmentError),
 }
 
 /// Result type for Postmaster operations
@@ -117,6 +121,8 @@ pub struct Postmaster {
     lock_manager: LockManager,
     /// Project ID for this postmaster instance
     project_id: String,
+    /// Managed directory that message attachments must live in
+    attachments_dir: PathBuf,
 }
 
 impl Postmaster {
@@ -128,11 +134,16 @@ impl Postmaster {
     /// Create a new Postmaster with a specific project ID
     pub fn with_project_id(db_path: PathBuf, project_id: impl Into<String>) -> Result<Self> {
         let conn = Connection::open(&db_path)?;
+        let attachments_dir = db_path
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join(super::ATTACHMENTS_DIR);
 
         let postmaster = Self {
             conn,
             lock_manager: LockManager::new(),
             project_id: project_id.into(),
+            attachments_dir,
         };
 
         postmaster.init_schema()?;
@@ -154,7 +165,10 @@ impl Postmaster {
                 status TEXT NOT NULL DEFAULT 'pending',
                 stored_at TEXT NOT NULL,
                 delivered_at TEXT,
-                read_at TEXT
+                read_at TEXT,
+                attachments TEXT,
+                in_reply_to TEXT,
+                thread_id TEXT
             );
 
             CREATE INDEX IF NOT EXISTS idx_messages_to ON messages(to_addr);
@@ -169,13 +183,73 @@ impl Postmaster {
             );
             "#,
         )?;
+
+        // Databases created before attachments and threading existed lack the columns
+        for column in ["attachments", "in_reply_to", "thread_id"] {
+            let has_column: bool = self.conn.query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info('messages') WHERE name = ?1",
+                [column],
+                |row| row.get(0),
+            )?;
+            if !has_column {
+                self.conn.execute(
+                    &format!("ALTER TABLE messages ADD COLUMN {} TEXT", column),
+                    [],
+                )?;
+            }
+        }
+        self.conn.execute_batch(
+            "CREATE INDEX IF NOT EXISTS idx_messages_thread ON messages(thread_id);",
+        )?;
         Ok(())
     }
 
+    /// Directory that message attachments must be written to
+    pub fn attachments_dir(&self) -> &Path {
+        &self.attachments_dir
+    }
+
+    /// Project ID this postmaster routes for
+    pub fn project_id(&self) -> &str {
+        &self.project_id
+    }
+
+    /// Agents in this project that have registered or exchanged mail
+    ///
+    /// Broadcast and postmaster addresses are left out. Sorted by address.
+    pub fn known_agents(&self) -> Result<Vec<Address>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT from_addr FROM messages
+            UNION SELECT to_addr FROM messages
+            UNION SELECT address FROM agents
+            "#,
+        )?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+
+        let mut agents = Vec::new();
+        for row in rows {
+            let Ok(address) = row?.parse::<Address>() else {
+                continue;
+            };
+            if address.is_in_project(&self.project_id)
+                && !address.is_broadcast()
+                && !address.is_postmaster()
+            {
+                agents.push(address);
+            }
+        }
+        agents.sort_by_key(|a| a.to_string());
+        Ok(agents)
+    }
+
     /// Send a message
     ///
     /// Routes the message to the appropriate recipient(s) and stores it.
     pub fn send(&mut self, message: Message) -> Result<SendResult> {
+        // Only references are stored, so check them before accepting the message
+        validate_attachments(&message.attachments, &self.attachments_dir)?;
+
         // Handle special message types
         match &message.message_type {
             MessageType::Lock(lock_req) => {
@@ -202,6 +276,23 @@ impl Postmaster {
         }
     }
 
+    /// Reply to a stored message
+    ///
+    /// The reply goes back to the parent's sender and joins the parent's thread.
+    pub fn reply(
+        &mut self,
+        parent_id: &MessageId,
+        from: Address,
+        message_type: MessageType,
+    ) -> Result<SendResult> {
+        let parent = self
+            .get_message(parent_id)?
+            .ok_or_else(|| PostmasterError::MessageNotFound(parent_id.as_str().to_string()))?;
+        let message =
+            Message::new(from, parent.message.from.clone(), message_type).reply_to(&parent.message);
+        self.send(message)
+    }
+
     /// Handle a LOCK request
     fn handle_lock_request(
         &mut self,
@@ -371,12 +462,17 @@ impl Postmaster {
         } else {
             None
         };
+        let attachments = if message.attachments.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(&message.attachments)?)
+        };
 
         self.conn.execute(
             r#"
             INSERT OR REPLACE INTO messages
-            (id, from_addr, to_addr, message_type, payload, timestamp, correlation_id, status, stored_at, delivered_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+            (id, from_addr, to_addr, message_type, payload, timestamp, correlation_id, status, stored_at, delivered_at, attachments, in_reply_to, thread_id)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
             "#,
             params![
                 message.id.as_str(),
@@ -389,6 +485,9 @@ impl Postmaster {
                 status.as_str(),
                 now,
                 delivered_at,
+                attachments,
+                message.in_reply_to.as_ref().map(|id| id.as_str()),
+                message.thread_id.as_ref().map(|id| id.as_str()),
             ],
         )?;
 
@@ -405,6 +504,35 @@ impl Postmaster {
         self.inbox_with_filter(address, Some(DeliveryStatus::Delivered))
     }
 
+    /// Get unread messages sent to or from any agent in a project
+    ///
+    /// Covers both agent inboxes (`worker@project`) and notifications from the
+    /// project's agents to the human.
+    pub fn unread_in_project(&self, project_id: &str) -> Result<Vec<StoredMessage>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT id, from_addr, to_addr, message_type, payload, timestamp,
+                   correlation_id, status, stored_at, delivered_at, read_at, attachments,
+                   in_reply_to, thread_id
+            FROM messages
+            WHERE status = ?1
+              AND (substr(to_addr, -length(?2) - 1) = '@' || ?2
+                   OR substr(from_addr, -length(?2) - 1) = '@' || ?2)
+            ORDER BY timestamp DESC
+            "#,
+        )?;
+        let rows = stmt.query_map(
+            params![DeliveryStatus::Delivered.as_str(), project_id],
+            |row| self.row_to_stored_message(row),
+        )?;
+
+        let mut messages = Vec::new();
+        for row in rows {
+            messages.push(row?);
+        }
+        Ok(messages)
+    }
+
     /// Get messages with optional status filter
     fn inbox_with_filter(
         &self,
@@ -418,7 +546,8 @@ impl Postmaster {
             let mut stmt = self.conn.prepare(
                 r#"
                 SELECT id, from_addr, to_addr, message_type, payload, timestamp,
-                       correlation_id, status, stored_at, delivered_at, read_at
+                       correlation_id, status, stored_at, delivered_at, read_at, attachments,
+                   in_reply_to, thread_id
                 FROM messages
                 WHERE to_addr = ?1 AND status = ?2
                 ORDER BY timestamp DESC
@@ -434,7 +563,8 @@ impl Postmaster {
             let mut stmt = self.conn.prepare(
                 r#"
                 SELECT id, from_addr, to_addr, message_type, payload, timestamp,
-                       correlation_id, status, stored_at, delivered_at, read_at
+                       correlation_id, status, stored_at, delivered_at, read_at, attachments,
+                   in_reply_to, thread_id
                 FROM messages
                 WHERE to_addr = ?1
                 ORDER BY timestamp DESC
@@ -462,6 +592,9 @@ impl Postmaster {
         let stored_at_str: String = row.get(8)?;
         let delivered_at_str: Option<String> = row.get(9)?;
         let read_at_str: Option<String> = row.get(10)?;
+        let attachments_json: Option<String> = row.get(11)?;
+        let in_reply_to: Option<String> = row.get(12)?;
+        let thread_id: Option<String> = row.get(13)?;
 
         // Parse addresses
         let from: Address = from_str.parse().unwrap_or_else(|_| Address::human());
@@ -497,6 +630,11 @@ impl Postmaster {
             message_type,
             timestamp,
             correlation_id: correlation_id.map(MessageId::from_string),
+            in_reply_to: in_reply_to.map(MessageId::from_string),
+            thread_id: thread_id.map(MessageId::from_string),
+            attachments: attachments_json
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default(),
         };
 
         Ok(StoredMessage {
@@ -529,21 +667,39 @@ impl Postmaster {
     }
 
     /// Mark a message as read
+    ///
+    /// Messages that are already read keep their original `read_at`.
     pub fn mark_read(&self, message_id: &MessageId) -> Result<()> {
         let now = Utc::now().to_rfc3339();
-        self.conn.execute(
-            "UPDATE messages SET status = 'read', read_at = ?1 WHERE id = ?2",
+        let updated = self.conn.execute(
+            "UPDATE messages SET status = 'read', read_at = COALESCE(read_at, ?1) WHERE id = ?2",
             params![now, message_id.as_str()],
         )?;
+        if updated == 0 {
+            return Err(PostmasterError::MessageNotFound(
+                message_id.as_str().to_string(),
+            ));
+        }
         Ok(())
     }
 
+    /// Mark every unread message in an inbox as read, returning how many changed
+    pub fn mark_all_read(&self, address: &Address) -> Result<usize> {
+        let now = Utc::now().to_rfc3339();
+        let updated = self.conn.execute(
+            "UPDATE messages SET status = 'read', read_at = ?1 WHERE to_addr = ?2 AND status = 'delivered'",
+            params![now, address.to_string()],
+        )?;
+        Ok(updated)
+    }
+
     /// Get a specific message by ID
     pub fn get_message(&self, message_id: &MessageId) -> Result<Option<StoredMessage>> {
         let mut stmt = self.conn.prepare(
             r#"
             SELECT id, from_addr, to_addr, message_type, payload, timestamp,
-                   correlation_id, status, stored_at, delivered_at, read_at
+                   correlation_id, status, stored_at, delivered_at, read_at, attachments,
+                   in_reply_to, thread_id
             FROM messages
             WHERE id = ?1
             "#,
@@ -567,7 +723,8 @@ impl Postmaster {
         let mut stmt = self.conn.prepare(
             r#"
             SELECT id, from_addr, to_addr, message_type, payload, timestamp,
-                   correlation_id, status, stored_at, delivered_at, read_at
+                   correlation_id, status, stored_at, delivered_at, read_at, attachments,
+                   in_reply_to, thread_id
             FROM messages
             WHERE from_addr = ?1
             ORDER BY timestamp DESC
@@ -769,5 +926,139 @@ mod tests {
         postmaster.mark_read(message_id).unwrap();
 
         assert_eq!(postmaster.unread_count(&human).unwrap(), 0);
+        assert!(matches!(
+            postmaster.mark_read(&MessageId::from_string("msg-missing")),
+            Err(PostmasterError::MessageNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_attachments() {
+        let (mut postmaster, dir) = create_test_postmaster();
+
+        let source = dir.path().join("build.log");
+        std::fs::write(&source, "error: oops").unwrap();
+        let attachment =
+            super::super::import_attachment(&source, postmaster.attachments_dir()).unwrap();
+
+        let msg = Message::from_strings(
+            "worker@test-project",
+            "human@localhost",
+            MessageType::Notify(super::super::NotifyPayload::new("Build failed")),
+        )
+        .with_attachment(attachment.clone());
+        postmaster.send(msg).unwrap();
+
+        let inbox = postmaster.inbox(&Address::human()).unwrap();
+        assert_eq!(inbox[0].message.attachments, vec![attachment]);
+
+        // Files outside the managed directory are rejected
+        let msg = Message::from_strings(
+            "worker@test-project",
+            "human@localhost",
+            MessageType::Notify(super::super::NotifyPayload::new("Sneaky")),
+        )
+        .with_attachment(super::super::Attachment::new("build.log", &source));
+        assert!(matches!(
+            postmaster.send(msg),
+            Err(PostmasterError::InvalidAttachment(_))
+        ));
+        assert_eq!(postmaster.inbox_count(&Address::human()).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_unread_in_project() {
+        let (mut postmaster, dir) = create_test_postmaster();
+
+        for (from, to) in [
+            ("worker@test-project", "human@localhost"),
+            ("human@localhost", "reviewer@test-project"),
+            ("worker@other-project", "human@localhost"),
+            ("worker@test-project-2", "human@localhost"),
+        ] {
+            let msg = Message::from_strings(
+                from,
+                to,
+                MessageType::Notify(super::super::NotifyPayload::new("Hello!")),
+            );
+            postmaster.send(msg).unwrap();
+        }
+
+        assert_eq!(
+            postmaster.unread_in_project("test-project").unwrap().len(),
+            2
+        );
+
+        let human = Address::human();
+        assert_eq!(postmaster.mark_all_read(&human).unwrap(), 3);
+        let unread = postmaster.unread_in_project("test-project").unwrap();
+        assert_eq!(unread.len(), 1);
+        assert_eq!(unread[0].message.to.to_string(), "reviewer@test-project");
+
+        // Read state survives reopening the database
+        let db_path = dir.path().join("mail.db");
+        drop(postmaster);
+        let reopened = Postmaster::with_project_id(db_path, "test-project").unwrap();
+        assert_eq!(reopened.unread_count(&human).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_known_agents() {
+        let (mut postmaster, _dir) = create_test_postmaster();
+
+        for (from, to) in [
+            ("worker@test-project", "human@localhost"),
+            ("human@localhost", "reviewer@test-project"),
+            ("worker@other-project", "human@localhost"),
+            ("worker@test-project", "all@test-project"),
+        ] {
+            let msg = Message::from_strings(
+                from,
+                to,
+                MessageType::Notify(super::super::NotifyPayload::new("Hello!")),
+            );
+            postmaster.send(msg).unwrap();
+        }
+
+        let agents: Vec<String> = postmaster
+            .known_agents()
+            .unwrap()
+            .iter()
+            .map(|a| a.to_string())
+            .collect();
+        assert_eq!(agents, vec!["reviewer@test-project", "worker@test-project"]);
+    }
+
+    #[test]
+    fn test_reply() {
+        let (mut postmaster, _dir) = create_test_postmaster();
+        let notify = |text: &str| MessageType::Notify(super::super::NotifyPayload::new(text));
+
+        let msg = Message::from_strings("worker@test-project", "human@localhost", notify("Done"));
+        let parent_id = msg.id.clone();
+        postmaster.send(msg).unwrap();
+
+        let result = postmaster
+            .reply(&parent_id, Address::human(), notify("Thanks"))
+            .unwrap();
+        let SendResult::Delivered { message_id } = result else {
+            panic!("expected delivery");
+        };
+
+        let worker: Address = "worker@test-project".parse().unwrap();
+        let inbox = postmaster.inbox(&worker).unwrap();
+        assert_eq!(inbox.len(), 1);
+        assert_eq!(inbox[0].message.id, message_id);
+        assert_eq!(inbox[0].message.in_reply_to.as_ref(), Some(&parent_id));
+        assert_eq!(inbox[0].message.thread_id.as_ref(), Some(&parent_id));
+
+        assert!(matches!(
+            postmaster.reply(
+                &MessageId::from_string("msg-missing"),
+                Address::human(),
+                notify("?")
+            ),
+            Err(PostmasterError::MessageNotFound(_))
+        ));
     }
 }
@@ -170,7 +170,10 @@ impl RemoteMailClient {
                 .correlation_id
                 .as_ref()
                 .map(|id| id.as_str().to_string()),
-            parent_id: None,
+            parent_id: message
+                .in_reply_to
+                .as_ref()
+                .map(|id| id.as_str().to_string()),
             metadata: serde_json::json!({}),
         };
 
@@ -35,8 +35,8 @@
 //! ```
 
 use super::{
-    Address, LockInfo, LockResult, Message, MessageId, MessageType, Postmaster, PostmasterError,
-    SendResult, StoredMessage,
+    Address, Attachment, LockInfo, LockResult, Message, MessageId, MessageType, Postmaster,
+    PostmasterError, SendResult, StoredMessage,
 };
 use axum::{
     body::Body,
@@ -300,6 +300,8 @@ pub struct SendMessageRequest {
     pub to: String,
     pub message_type: MessageType,
     pub correlation_id: Option<String>,
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
 }
 
 /// Response from sending a message
@@ -405,8 +407,14 @@ pub struct StoredMessageDto {
     pub message_type: MessageType,
     pub timestamp: String,
     pub correlation_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_reply_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_id: Option<String>,
     pub status: String,
     pub read_at: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
 }
 
 impl From<StoredMessage> for StoredMessageDto {
@@ -418,8 +426,11 @@ impl From<StoredMessage> for StoredMessageDto {
             message_type: msg.message.message_type,
             timestamp: msg.message.timestamp.to_rfc3339(),
             correlation_id: msg.message.correlation_id.map(|id| id.as_str().to_string()),
+            in_reply_to: msg.message.in_reply_to.map(|id| id.as_str().to_string()),
+            thread_id: msg.message.thread_id.map(|id| id.as_str().to_string()),
             status: format!("{:?}", msg.status),
             read_at: msg.read_at.map(|t| t.to_rfc3339()),
+            attachments: msg.message.attachments,
         }
     }
 }
@@ -522,12 +533,17 @@ async fn send_message(
     if let Some(corr_id) = req.correlation_id {
         message.correlation_id = Some(MessageId::from_string(corr_id));
     }
+    message.attachments = req.attachments;
 
     // Send via postmaster
     let mut postmaster = state.postmaster.lock().await;
     let result = postmaster.send(message).map_err(|e| {
+        let status = match e {
+            PostmasterError::InvalidAttachment(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
         (
-            StatusCode::INTERNAL_SERVER_ERROR,
+            status,
             Json(ErrorResponse {
                 error: e.to_string(),
             }),
@@ -609,8 +625,12 @@ async fn mark_read(
 
     let postmaster = state.postmaster.lock().await;
     postmaster.mark_read(&id).map_err(|e| {
+        let status = match e {
+            PostmasterError::MessageNotFound(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
         (
-            StatusCode::INTERNAL_SERVER_ERROR,
+            status,
             Json(ErrorResponse {
                 error: e.to_string(),
             }),
@@ -7,13 +7,15 @@ mod commands;
 use allbeads::aggregator::{Aggregator, AggregatorConfig, RefreshProgress, SyncMode};
 use allbeads::cache::{Cache, CacheConfig};
 use allbeads::config::{AllBeadsConfig, AuthStrategy, BossContext};
-use allbeads::graph::{BeadId, FederatedGraph, IssueType, Priority, Status};
+use allbeads::graph::{sort_beads, BeadId, FederatedGraph, IssueType, Priority, SortKey, Status};
 use allbeads::style;
 use beads::Beads;
 use clap::Parser;
 use commands::*;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
 use serde::{Deserialize, Serialize};
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Read, Write};
 use std::path::{Path, PathBuf};
 use std::process;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -21,15 +23,19 @@ use std::sync::Arc;
 
 /// Load graph using parallel refresh with progress indicator
 ///
-/// Shows real-time progress as repos are fetched in parallel.
+/// Shows real-time progress as repos are fetched in parallel and as each
+/// context's beads are loaded. Progress lines are only drawn when stderr is a
+/// terminal; errors are always reported.
 fn load_graph_parallel(
     config: AllBeadsConfig,
     agg_config: AggregatorConfig,
+    previous: Option<FederatedGraph>,
     message: &str,
 ) -> allbeads::Result<FederatedGraph> {
-    eprintln!("⏳ {}...", message);
+    allbeads::status!("⏳ {}...", message);
 
     let total_repos = config.contexts.len();
+    let interactive = io::stderr().is_terminal() && allbeads::logging::show_status();
     let completed = Arc::new(AtomicUsize::new(0));
     let errors = Arc::new(std::sync::Mutex::new(Vec::<(String, String)>::new()));
 
@@ -39,7 +45,7 @@ fn load_graph_parallel(
 
     let progress_callback = move |event: RefreshProgress| {
         match event {
-            RefreshProgress::FetchingRepo { name, .. } => {
+            RefreshProgress::FetchingRepo { name, .. } if interactive => {
                 let done = completed_clone.load(Ordering::SeqCst);
                 // Use carriage return + clear to end of line to update in place
                 eprint!(
@@ -54,7 +60,18 @@ fn load_graph_parallel(
                 completed_clone.fetch_add(1, Ordering::SeqCst);
             }
             RefreshProgress::CloningRepo { name, .. } => {
-                eprintln!("\r  📦 Cloning {}...", name);
+                allbeads::status!("\r  📦 Cloning {}...", name);
+            }
+            RefreshProgress::LoadedContext {
+                name,
+                beads,
+                index,
+                total,
+            } if interactive => {
+                eprintln!(
+                    "\r\x1b[K  Loaded context @{} ({} beads) [{}/{}]",
+                    name, beads, index, total
+                );
             }
             RefreshProgress::RepoError { name, error } => {
                 // Clear line and show error in a clean format
@@ -69,9 +86,11 @@ fn load_graph_parallel(
                 succeeded, failed, ..
             } => {
                 // Clear the progress line
-                eprint!("\r\x1b[K");
+                if interactive {
+                    eprint!("\r\x1b[K");
+                }
                 if failed > 0 {
-                    eprintln!(
+                    allbeads::status!(
                         "  {} {} repos synced, {} skipped",
                         style::dim("ℹ"),
                         succeeded,
@@ -90,7 +109,9 @@ fn load_graph_parallel(
 
     let graph = runtime.block_on(async {
         let mut aggregator = Aggregator::new(config, agg_config)?;
-        aggregator.aggregate_parallel(Some(progress_callback)).await
+        aggregator
+            .aggregate_parallel(Some(progress_callback), previous.as_ref())
+            .await
     })?;
 
     Ok(graph)
@@ -121,11 +142,6 @@ fn truncate_error(error: &str) -> String {
 }
 
 fn main() {
-    // Initialize logging
-    if let Err(e) = allbeads::logging::init() {
-        eprintln!("Failed to initialize logging: {}", e);
-    }
-
     // Check for help BEFORE clap parsing for main command only
     let args: Vec<String> = std::env::args().collect();
     if args.len() == 1
@@ -136,9 +152,24 @@ fn main() {
     }
 
     let cli = Cli::parse();
+    allbeads::style::init_color(cli.color);
+
+    // Initialize logging at the level chosen by -q/-v
+    let verbosity = allbeads::logging::Verbosity::from_flags(cli.quiet, cli.verbose);
+    if let Err(e) = allbeads::logging::init_with_verbosity(verbosity) {
+        eprintln!("Failed to initialize logging: {}", e);
+    }
 
-    if let Err(e) = run(cli) {
+    let json = cli.json;
+    let result = run(cli);
+    if let Err(ref e) = result {
         eprintln!("Error: {}", e);
+    }
+    // Explains parse errors after a bd upgrade; only known if bd was checked
+    if let Some(warning) = beads::version_warning().filter(|_| !json) {
+        allbeads::status!("warning: {}", warning);
+    }
+    if result.is_err() {
         process::exit(1);
     }
 }
@@ -315,12 +346,216 @@ fn parse_and_display_aiki_tasks(xml: &str, linked_tasks: &[String]) {
     }
 }
 
+/// Run a bead command against the current directory's bd only (`ab --here ...`)
+///
+/// Changes made this way go straight to bd and are not recorded for `ab undo`.
+fn handle_here_command(mut command: Commands, bd_flags: Vec<String>) -> allbeads::Result<()> {
+    resolve_assignee_me(&mut command, None)?;
+
+    if let Commands::List {
+        porcelain: true, ..
+    }
+    | Commands::Ready {
+        porcelain: true, ..
+    }
+    | Commands::Blocked { porcelain: true } = command
+    {
+        return Err(allbeads::AllBeadsError::Config(
+            "--porcelain is not supported with --here (bd's output is passed through)".to_string(),
+        ));
+    }
+    if let Commands::Ready {
+        leaves, unblockers, ..
+    } = command
+    {
+        if leaves || unblockers {
+            return Err(allbeads::AllBeadsError::Config(
+                "--leaves and --unblockers need the federated graph; drop --here".to_string(),
+            ));
+        }
+    }
+    if let Commands::List {
+        ref since,
+        ref context,
+        ref group,
+        local,
+        ..
+    } = command
+    {
+        let unsupported: Vec<&str> = [
+            ("--since", since.is_some()),
+            ("--context", context.is_some()),
+            ("--group", group.is_some()),
+            ("--local", local),
+        ]
+        .into_iter()
+        .filter_map(|(flag, set)| set.then_some(flag))
+        .collect();
+        if !unsupported.is_empty() {
+            return Err(allbeads::AllBeadsError::Config(format!(
+                "{} not supported with --here (bd lists the current repo only)",
+                unsupported.join(", ")
+            )));
+        }
+    }
+
+    let cwd = std::env::current_dir()?;
+    let bd = Beads::with_workdir_and_flags(&cwd, bd_flags);
+
+    fn push(args: &mut Vec<String>, flag: &str, value: Option<String>) {
+        if let Some(v) = value {
+            args.push(format!("--{}={}", flag, v));
+        }
+    }
+
+    let dry_run = matches!(
+        command,
+        Commands::Close { dry_run: true, .. } | Commands::Reopen { dry_run: true, .. }
+    );
+
+    let mut args: Vec<String> = Vec::new();
+    let subcommand = match command {
+        Commands::List {
+            status,
+            priority,
+            label,
+            issue_type,
+            assignee,
+            parent,
+            ready,
+            all,
+            sort,
+            reverse,
+            limit,
+            ..
+        } if !ready => {
+            push(&mut args, "status", status);
+            push(&mut args, "priority", priority);
+            push(&mut args, "label", label);
+            push(&mut args, "type", issue_type);
+            push(&mut args, "assignee", assignee);
+            push(&mut args, "parent", parent);
+            if let Some(key) = sort.as_deref().map(str::parse::<SortKey>).transpose()? {
+                if !bd.supports_list_flag("sort") {
+                    return Err(allbeads::AllBeadsError::Config(
+                        "The installed bd has no list --sort; drop --here to sort in ab"
+                            .to_string(),
+                    ));
+                }
+                push(&mut args, "sort", Some(key.bd_key().to_string()));
+            }
+            if reverse {
+                args.push("--reverse".to_string());
+            }
+            if all {
+                args.push("--all".to_string());
+            }
+            if limit > 0 {
+                args.push(format!("--limit={}", limit));
+            }
+            "list"
+        }
+        Commands::List { .. } | Commands::Ready { .. } => "ready",
+        Commands::Blocked { .. } => "blocked",
+        Commands::Show { id, .. } => {
+            args.push(id);
+            "show"
+        }
+        Commands::Search {
+            query,
+            status,
+            issue_type,
+            assignee,
+            ..
+        } => {
+            args.extend(query);
+            push(&mut args, "status", status);
+            push(&mut args, "type", issue_type);
+            push(&mut args, "assignee", assignee);
+            "search"
+        }
+        Commands::Create {
+            template: Some(_), ..
+        } => {
+            return Err(allbeads::AllBeadsError::Config(
+                "--template reads bead_templates from the config, which --here skips".to_string(),
+            ))
+        }
+        Commands::Create {
+            title,
+            issue_type,
+            priority,
+            assignee,
+            description,
+            ..
+        } => {
+            push(&mut args, "title", Some(title));
+            push(&mut args, "type", issue_type);
+            push(&mut args, "priority", priority);
+            push(&mut args, "assignee", assignee);
+            push(&mut args, "description", description);
+            "create"
+        }
+        Commands::Update {
+            ids,
+            status,
+            priority,
+            assignee,
+            description,
+            notes,
+            ..
+        } => {
+            args.extend(ids);
+            push(&mut args, "status", status);
+            push(&mut args, "priority", priority);
+            push(&mut args, "assignee", assignee);
+            push(&mut args, "description", description);
+            push(&mut args, "notes", notes);
+            "update"
+        }
+        Commands::Close { ids, reason, .. } => {
+            args.extend(ids);
+            push(&mut args, "reason", reason);
+            "close"
+        }
+        Commands::Reopen { ids, .. } => {
+            args.extend(ids);
+            "reopen"
+        }
+        _ => return Err(allbeads::AllBeadsError::Config(
+            "--here supports list, ready, blocked, show, search, create, update, close and reopen"
+                .to_string(),
+        )),
+    };
+
+    let mut bd_args = vec![subcommand];
+    bd_args.extend(args.iter().map(String::as_str));
+    if dry_run {
+        println!(
+            "  {} Dry run - would run: bd {}",
+            style::dim("→"),
+            bd_args.join(" ")
+        );
+        return Ok(());
+    }
+    let output = bd
+        .run(&bd_args)
+        .map_err(|e| allbeads::AllBeadsError::Other(format!("bd {} failed: {}", subcommand, e)))?;
+
+    print!("{}", output.stdout);
+    if !output.success {
+        eprint!("{}", output.stderr);
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
 fn run(mut cli: Cli) -> allbeads::Result<()> {
     // Get bd-compatible global flags to pass through to wrapper commands
     let bd_flags = cli.bd_global_flags();
 
     // Take command - if None, show help
-    let command = match cli.command.take() {
+    let mut command = match cli.command.take() {
         Some(cmd) => cmd,
         None => {
             println!("{}", custom_help());
@@ -328,6 +563,13 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
         }
     };
 
+    read_stdin_body(&mut command)?;
+
+    // --here acts like plain bd in the current repo: no config, no graph
+    if cli.here {
+        return handle_here_command(command, bd_flags);
+    }
+
     // Handle init command first (creates config)
     if let Commands::Init {
         remote,
@@ -428,17 +670,17 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
 
     // Handle swarm commands (don't need graph)
     if let Commands::Swarm(ref swarm_cmd) = command {
-        return handle_swarm_command(swarm_cmd);
+        return handle_swarm_command(swarm_cmd, &cli.config, cli.json);
     }
 
     // Handle config sync commands (don't need graph)
     if let Commands::Config(ref config_cmd) = command {
-        return handle_config_command(config_cmd);
+        return handle_config_command(config_cmd, &cli.config);
     }
 
     // Handle plugin commands (don't need graph)
     if let Commands::Plugin(ref plugin_cmd) = command {
-        return handle_plugin_command(plugin_cmd);
+        return handle_plugin_command(plugin_cmd, &cli.config);
     }
 
     // Handle coding agent commands (don't need graph)
@@ -461,8 +703,23 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
         dry_run,
         worktree,
         queue,
+        print_prompt,
+        assign,
+        no_assign,
+        ref action,
     } = command
     {
+        if let Some(HandoffCommands::Prune {
+            merged,
+            yes,
+            dry_run,
+        }) = *action
+        {
+            return handle_handoff_prune(merged, yes, dry_run);
+        }
+        if print_prompt {
+            return handle_handoff_print_prompt(id.as_deref(), agent.as_deref(), cli.json);
+        }
         return handle_handoff_command(
             id.as_deref(),
             agent.as_deref(),
@@ -472,6 +729,7 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             dry_run,
             worktree,
             queue,
+            (assign || no_assign).then_some(assign),
         );
     }
 
@@ -483,6 +741,7 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
         pre_commit,
         ref bead,
         ref format,
+        cycles,
     } = command
     {
         return handle_check_command(
@@ -492,6 +751,7 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             pre_commit,
             bead.as_deref(),
             format,
+            cycles,
         );
     }
 
@@ -527,6 +787,7 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
         ref message,
         status,
         web,
+        resolve,
     } = command
     {
         return handle_sync_command(
@@ -535,10 +796,45 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             message.as_deref(),
             status,
             web,
+            resolve,
             &cli.config,
+            cli.json,
         );
     }
 
+    // Handle shell completion (doesn't need config or graph)
+    if let Commands::Completion { shell } = command {
+        return handle_completion_command(shell);
+    }
+
+    if let Commands::Schema { schema_type } = command {
+        return handle_schema_command(schema_type);
+    }
+
+    if let Commands::CompleteContexts = command {
+        return handle_complete_contexts_command(&cli.config);
+    }
+
+    if let Commands::CompleteLabels = command {
+        return handle_complete_labels_command();
+    }
+
+    // Prompt-friendly: any cached graph will do, expired or not
+    if let Commands::Summary { oneline } = command {
+        if let Some(graph) = Cache::new(CacheConfig::default())?.load_previous()? {
+            let context_filter: Vec<String> = cli
+                .contexts
+                .as_deref()
+                .map(|c| {
+                    c.split(',')
+                        .map(|s| s.trim().trim_start_matches('@').to_string())
+                        .collect()
+                })
+                .unwrap_or_default();
+            return handle_summary_command(&graph, &context_filter, oneline, cli.json);
+        }
+    }
+
     // Handle agent commands that don't need graph
     if let Commands::Quickstart = command {
         return handle_quickstart_command();
@@ -552,14 +848,36 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
         return handle_human_command(message);
     }
 
+    // Health checks run before config load so a broken config is reported
+    if let Commands::Doctor { shadows: false } = command {
+        return handle_doctor_command(&cli.config, cli.contexts.as_deref(), &bd_flags, cli.json);
+    }
+
+    // Handle undo (replays the undo log, doesn't need graph)
+    if let Commands::Undo { list, skip } = command {
+        return handle_undo_command(&cli.config, list, skip, &bd_flags);
+    }
+
     // Handle rename-prefix (doesn't need graph)
     if let Commands::RenamePrefix {
         ref new_prefix,
         ref from,
         ref path,
+        force,
+    } = command
+    {
+        return handle_rename_prefix_command(new_prefix, from.as_deref(), path, force, &cli.config);
+    }
+
+    // Handle import (writes straight into one context, doesn't need graph)
+    if let Commands::Import {
+        ref file,
+        ref context,
+        format,
+        dry_run,
     } = command
     {
-        return handle_rename_prefix_command(new_prefix, from.as_deref(), path, &cli.config);
+        return handle_import_command(file, context, format, dry_run, &cli.config, &bd_flags);
     }
 
     // Handle web app authentication commands (don't need graph)
@@ -586,8 +904,10 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
         ref id,
         provenance,
         tasks,
+        format,
     } = command
     {
+        let format = if cli.json { ShowFormat::Json } else { format };
         // Check if we're in a directory with beads
         let beads_path = std::path::Path::new(".beads");
         if beads_path.exists() {
@@ -599,6 +919,9 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                     Ok(issue) => {
                         match issue_to_bead(issue) {
                             Ok(bead) => {
+                                if format != ShowFormat::Text {
+                                    return print_bead_show(&bead, format, None);
+                                }
                                 print_bead_detailed(&bead);
 
                                 // Show handoff info if bead has been handed off
@@ -696,8 +1019,44 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
 
     tracing::info!(contexts = config.contexts.len(), "Configuration loaded");
 
+    // Saved searches: --save and --list-saved only touch config, --load fills in the filters
+    if handle_saved_search(&mut command, &config, &cli.config)? {
+        return Ok(());
+    }
+    resolve_assignee_me(&mut command, config.default_assignee.as_deref())?;
+
+    // Watch and History run without the graph, so bare numbers only resolve
+    // inside a context or when a single prefix is configured
+    if matches!(command, Commands::Watch { .. } | Commands::History { .. }) {
+        resolve_bead_ids(&mut command, &config, |_| true)?;
+    }
+
+    // Watch polls a single context directly, no graph needed
+    if let Commands::Watch {
+        ref id,
+        ref until,
+        ref timeout,
+        ref interval,
+    } = command
+    {
+        return handle_watch_command(
+            &config,
+            id,
+            until,
+            timeout.as_deref(),
+            interval,
+            &bd_flags,
+            cli.json,
+        );
+    }
+
+    // History reads one context's git history directly, no graph needed
+    if let Commands::History { ref id } = command {
+        return handle_history_command(&config, id, cli.json);
+    }
+
     // Parse context filter (strip @ prefix if present, normalize to lowercase for comparison)
-    let context_filter: Vec<String> = if let Some(ref contexts) = cli.contexts {
+    let mut context_filter: Vec<String> = if let Some(ref contexts) = cli.contexts {
         contexts
             .split(',')
             .map(|s| s.trim().trim_start_matches('@').to_string())
@@ -706,6 +1065,45 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
         Vec::new()
     };
 
+    // Resolve --group into its member context names
+    if let Commands::List {
+        group: Some(ref group),
+        ..
+    }
+    | Commands::Export {
+        group: Some(ref group),
+        ..
+    } = command
+    {
+        let members = config.group_members(group);
+        if members.is_empty() {
+            let groups: Vec<&str> = config.groups().into_keys().collect();
+            return Err(allbeads::AllBeadsError::Config(format!(
+                "Group '{}' not found. Available groups: {}",
+                group,
+                if groups.is_empty() {
+                    "(none)".to_string()
+                } else {
+                    groups.join(", ")
+                }
+            )));
+        }
+        if context_filter.is_empty() {
+            context_filter = members.iter().map(|m| m.to_string()).collect();
+        } else {
+            // Both -C and --group: keep only contexts in both
+            context_filter.retain(|ctx| members.iter().any(|m| m.eq_ignore_ascii_case(ctx)));
+            // An empty filter means "every context", so an empty intersection is an error
+            if context_filter.is_empty() {
+                return Err(allbeads::AllBeadsError::Config(format!(
+                    "None of the contexts given with -C are in group '{}' ({})",
+                    group,
+                    members.join(", ")
+                )));
+            }
+        }
+    }
+
     // Validate context filter - ensure all specified contexts exist
     if !context_filter.is_empty() {
         let valid_context_names: Vec<&str> =
@@ -732,9 +1130,12 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
         SyncMode::Fetch
     };
 
+    // Always aggregate every context: the cache must hold the full graph, and
+    // blockers in filtered-out contexts still decide readiness. The context
+    // filter is applied to the loaded graph below.
     let agg_config = AggregatorConfig {
         sync_mode,
-        context_filter: context_filter.clone(),
+        context_filter: Vec::new(),
         skip_errors: true,
     };
 
@@ -748,9 +1149,31 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
     // Clone config for use in CRUD wrapper commands
     let config_for_commands = config.clone();
 
+    // Handle manifest commands (don't need graph, just config)
+    if let Commands::Manifest(ref manifest_cmd) = command {
+        return handle_manifest_command(manifest_cmd, &config);
+    }
+
+    if let Commands::Activity { all, limit } = command {
+        return handle_activity_command(&config, &context_filter, all, limit, cli.json, &bd_flags);
+    }
+
     // Try to load from cache first
-    // Handle Open command (doesn't need graph, just config)
-    if let Commands::Open { ref id } = command {
+    // Handle Open for external issues (doesn't need graph, just config).
+    // Bead IDs fall through and open their source location once the graph is loaded;
+    // an uppercase KEY-123 is only taken as JIRA when no context uses that prefix.
+    let external_open_id = match command {
+        Commands::Open { ref id }
+            if (id.contains('/') && id.contains('#'))
+                || (id.contains('-')
+                    && id.chars().next().map(|c| c.is_uppercase()).unwrap_or(false)
+                    && config.context_for_bead_id(id).is_none()) =>
+        {
+            Some(id.clone())
+        }
+        _ => None,
+    };
+    if let Some(id) = external_open_id {
         // Try to determine what kind of ID this is and open in browser
         let url = if id.contains('/') && id.contains('#') {
             // GitHub issue: owner/repo#123
@@ -764,7 +1187,7 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                     "Invalid GitHub issue format. Use owner/repo#123".to_string(),
                 ));
             }
-        } else if id.contains('-') && id.chars().next().map(|c| c.is_uppercase()).unwrap_or(false) {
+        } else {
             // Looks like JIRA issue: PROJ-123
             // Check if we have a JIRA URL in config
             let jira_url = config
@@ -779,11 +1202,6 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                     "No JIRA URL configured. Add JIRA integration to your context.".to_string(),
                 ));
             }
-        } else {
-            return Err(allbeads::AllBeadsError::Other(format!(
-                "Unknown ID format '{}'. Use:\n  - GitHub: owner/repo#123\n  - JIRA: PROJ-123",
-                id
-            )));
         };
 
         println!("Opening: {}", style::dim(&url));
@@ -829,30 +1247,36 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             cached_graph
         } else {
             tracing::info!("Cache miss, aggregating from Boss repositories");
-            let graph = load_graph_parallel(config, agg_config, "Loading beads from repositories")?;
+            let graph =
+                load_graph_parallel(config, agg_config, None, "Loading beads from repositories")?;
             cache.store_graph(&graph)?;
-            eprintln!(
-                "✓ Loaded {} beads from {} contexts\n",
-                graph.beads.len(),
-                graph.rigs.len()
-            );
+            print_load_summary(&graph);
             graph
         }
     } else {
         tracing::info!("Cache expired, aggregating from Boss repositories");
-        let graph = load_graph_parallel(config, agg_config, "Refreshing beads from repositories")?;
+        // Contexts whose issues.jsonl is unchanged reuse the expired cache's beads
+        let previous = cache.load_previous()?;
+        let graph = load_graph_parallel(
+            config,
+            agg_config,
+            previous,
+            "Refreshing beads from repositories",
+        )?;
         cache.store_graph(&graph)?;
-        eprintln!(
-            "✓ Loaded {} beads from {} contexts\n",
-            graph.beads.len(),
-            graph.rigs.len()
-        );
+        print_load_summary(&graph);
         graph
     };
 
-    // Apply context filter to loaded graph (needed when loading from cache)
+    // Expand bare bead numbers against every context, before any filtering
+    resolve_bead_ids(&mut command, &config_for_commands, |id| {
+        graph.beads.contains_key(&BeadId::new(id))
+    })?;
+
+    // Apply context filter to the loaded graph
     if !context_filter.is_empty() {
-        graph.beads.retain(|_, bead| {
+        // Dropped beads still count as blockers for the ones kept
+        graph.retain_beads(|bead| {
             bead.labels.iter().any(|label| {
                 if let Some(ctx_name) = label.strip_prefix('@') {
                     context_filter
@@ -871,78 +1295,141 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             status,
             priority,
             context,
+            group: _,
             label,
             issue_type,
             assignee,
+            parent,
             ready,
             all,
+            since,
+            sort,
+            reverse,
             limit,
             local,
+            porcelain,
         } => {
+            let since = since
+                .map(|s| allbeads::duration::parse_since(&s, chrono::Utc::now()))
+                .transpose()?;
+
             // Fast path: use local bd list directly (skip aggregation)
             if local {
                 let bd = Beads::new().map_err(|e| {
                     allbeads::AllBeadsError::Config(format!("Not in a beads repository: {}", e))
                 })?;
 
+                // Push filters to bd list when the installed bd supports them
+                let pushed = |value: &Option<String>, flag: &str| {
+                    !ready && value.is_some() && bd.supports_list_flag(flag)
+                };
+                let push_type = pushed(&issue_type, "type");
+                let push_assignee = pushed(&assignee, "assignee");
+                let push_label = pushed(&label, "label");
+
+                // Apply filters that weren't passed to bd
+                let priority_filter = priority
+                    .as_deref()
+                    .map(str::parse::<beads::Priority>)
+                    .transpose()
+                    .map_err(|e| allbeads::AllBeadsError::Parse(e.to_string()))?;
+                let type_lower = issue_type
+                    .as_ref()
+                    .filter(|_| !push_type)
+                    .map(|t| t.to_lowercase());
+                let matches = |i: &beads::Issue| {
+                    if priority_filter.is_some_and(|p| i.priority_typed() != Some(p)) {
+                        return false;
+                    }
+                    if let Some(label_str) = label.as_ref().filter(|_| !push_label) {
+                        if !i.labels.contains(label_str) {
+                            return false;
+                        }
+                    }
+                    if let Some(type_str) = &type_lower {
+                        if i.issue_type.to_lowercase() != *type_str {
+                            return false;
+                        }
+                    }
+                    // Exact, like bd's own --assignee, so results don't depend
+                    // on whether the installed bd supports the flag
+                    if let Some(assignee_str) = assignee.as_ref().filter(|_| !push_assignee) {
+                        if i.assignee.as_ref() != Some(assignee_str) {
+                            return false;
+                        }
+                    }
+                    if parent
+                        .as_ref()
+                        .is_some_and(|p| i.parent.as_ref() != Some(p))
+                    {
+                        return false;
+                    }
+                    if let Some(cutoff) = since {
+                        if !i
+                            .updated_at
+                            .as_deref()
+                            .is_some_and(|t| allbeads::duration::updated_since(t, cutoff))
+                        {
+                            return false;
+                        }
+                    }
+                    // Filter closed unless --all
+                    all || status.is_some() || ready || i.status != "closed"
+                };
+
+                // Keep only the top `limit` by priority while counting every
+                // match, so large repos never hold the full list in memory.
+                // Stable sorts keep bd's order among equal priorities.
+                let keep = if limit == 0 { usize::MAX } else { limit };
+                let mut total = 0;
+                let mut filtered: Vec<beads::Issue> = Vec::new();
+                let mut consider = |issue: beads::Issue| {
+                    if !matches(&issue) {
+                        return;
+                    }
+                    total += 1;
+                    filtered.push(issue);
+                    if filtered.len() >= keep.saturating_mul(2).max(256) {
+                        filtered.sort_by_key(|i| i.priority_typed().unwrap_or_default());
+                        filtered.truncate(keep);
+                    }
+                };
+
                 // Use bd ready if --ready flag is set
-                let issues = if ready {
-                    bd.ready().map_err(|e| {
+                if ready {
+                    let issues = bd.ready().map_err(|e| {
                         allbeads::AllBeadsError::Config(format!("Failed to get ready beads: {}", e))
-                    })?
+                    })?;
+                    issues.into_iter().for_each(&mut consider);
                 } else {
-                    // Build bd list arguments
-                    let status_arg = status.as_deref();
-                    bd.list(status_arg, None).map_err(|e| {
+                    let filter = beads::ListFilter {
+                        status: status.as_deref(),
+                        issue_type: issue_type.as_deref().filter(|_| push_type),
+                        assignee: assignee.as_deref().filter(|_| push_assignee),
+                        label: label.as_deref().filter(|_| push_label),
+                    };
+                    let list_err = |e: beads::Error| {
                         allbeads::AllBeadsError::Config(format!("Failed to list beads: {}", e))
-                    })?
-                };
-
-                // Apply additional filters that bd list doesn't support
-                let mut filtered: Vec<_> = issues.iter().collect();
-
-                if let Some(priority_str) = &priority {
-                    let p = priority_str.trim_start_matches('P').parse::<u8>().ok();
-                    filtered.retain(|i| i.priority == p);
-                }
-
-                if let Some(label_str) = &label {
-                    filtered.retain(|i| i.labels.contains(label_str));
-                }
-
-                if let Some(type_str) = &issue_type {
-                    let type_lower = type_str.to_lowercase();
-                    filtered.retain(|i| i.issue_type.to_lowercase() == type_lower);
-                }
-
-                if let Some(assignee_str) = &assignee {
-                    filtered.retain(|i| {
-                        i.assignee
-                            .as_ref()
-                            .is_some_and(|a| a.contains(assignee_str))
-                    });
-                }
-
-                // Filter closed unless --all
-                if !all && status.is_none() && !ready {
-                    filtered.retain(|i| i.status != "closed");
+                    };
+                    for issue in bd.list_stream(&filter).map_err(list_err)? {
+                        consider(issue.map_err(list_err)?);
+                    }
                 }
 
                 // Sort by priority
-                filtered.sort_by_key(|i| i.priority.unwrap_or(2));
+                filtered.sort_by_key(|i| i.priority_typed().unwrap_or_default());
+                filtered.truncate(keep);
 
                 // Apply limit
-                let display_count = filtered.len().min(limit);
-                let total = filtered.len();
+                let display_count = filtered.len();
 
                 println!("Found {} beads (local):", total);
                 println!();
-                for issue in filtered.into_iter().take(limit) {
-                    let p = issue.priority.unwrap_or(2);
+                for issue in filtered {
                     println!(
-                        "{} [P{}] [{}] {} - {}",
+                        "{} [{}] [{}] {} - {}",
                         style::status_indicator(&issue.status),
-                        p,
+                        issue.priority_typed().unwrap_or_default(),
                         issue.issue_type,
                         style::issue_id(&issue.id),
                         issue.title
@@ -960,60 +1447,41 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                 return Ok(());
             }
 
-            let mut beads: Vec<_> = graph.beads.values().collect();
-
-            // Apply ready filter (open, no blockers)
-            if ready {
-                beads.retain(|b| b.status == Status::Open && b.dependencies.is_empty());
-            }
-
-            // Apply filters
-            if let Some(status_str) = status {
-                let status_filter = parse_status(&status_str)?;
-                beads.retain(|b| b.status == status_filter);
-            } else if !all && !ready {
-                // Default: exclude closed unless --all or --ready
-                beads.retain(|b| b.status != Status::Closed);
-            }
-
-            if let Some(priority_str) = priority {
-                let priority_filter = parse_priority(&priority_str)?;
-                beads.retain(|b| b.priority == priority_filter);
-            }
-
-            if let Some(context_str) = context {
-                let context_tag = if context_str.starts_with('@') {
-                    context_str
-                } else {
-                    format!("@{}", context_str)
-                };
-                beads.retain(|b| b.labels.contains(&context_tag));
-            }
-
-            if let Some(label_str) = label {
-                beads.retain(|b| b.labels.contains(&label_str));
-            }
-
-            if let Some(type_str) = issue_type {
-                let type_filter = parse_issue_type(&type_str)?;
-                beads.retain(|b| b.issue_type == type_filter);
-            }
-
-            if let Some(assignee_str) = assignee {
-                beads.retain(|b| {
-                    b.assignee
-                        .as_ref()
-                        .is_some_and(|a| a.contains(&assignee_str))
-                });
+            if let Some(parent) = &parent {
+                if graph.get_bead(&BeadId::from(parent.as_str())).is_none() {
+                    return Err(allbeads::AllBeadsError::IssueNotFound(parent.clone()));
+                }
             }
 
-            // Sort by priority then status
-            beads.sort_by_key(|b| (b.priority, status_to_sort_key(b.status)));
+            let mut beads = filter_beads(
+                &graph,
+                &BeadFilter {
+                    status,
+                    priority,
+                    context,
+                    label,
+                    issue_type,
+                    assignee,
+                    parent,
+                    ready,
+                    all,
+                    since,
+                },
+            )?;
+            let sort = sort.as_deref().map(str::parse::<SortKey>).transpose()?;
+            sort_beads(&mut beads, sort.unwrap_or_default(), reverse);
 
             // Apply limit
             let total = beads.len();
             let display_count = if limit == 0 { total } else { total.min(limit) };
 
+            if porcelain {
+                for bead in beads.into_iter().take(display_count) {
+                    println!("{}", allbeads::graph::porcelain_line(bead));
+                }
+                return Ok(());
+            }
+
             // Display results
             println!("Found {} beads:", total);
             println!();
@@ -1034,13 +1502,82 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             }
         }
 
-        Commands::Show {
+        Commands::Tree { id, up } => {
+            let bead_id = BeadId::from(id.as_str());
+            let bead = graph
+                .get_bead(&bead_id)
+                .ok_or_else(|| allbeads::AllBeadsError::IssueNotFound(id.clone()))?;
+
+            println!("{}", format_tree_node(bead));
+            let mut path = vec![bead_id.clone()];
+            let mut expanded = std::collections::HashSet::new();
+            expanded.insert(bead_id);
+            print_dep_tree(&graph, bead, up, "", &mut path, &mut expanded);
+        }
+
+        Commands::Doctor { .. } => {
+            // Health checks are handled before the graph is loaded
+            print_unresolved_shadows(&graph);
+        }
+
+        Commands::Export {
+            format,
+            output,
+            status,
+            priority,
+            context,
+            group: _,
+            label,
+            issue_type,
+            assignee,
+            ready,
+            all,
+            since,
+        } => {
+            let since = since
+                .map(|s| allbeads::duration::parse_since(&s, chrono::Utc::now()))
+                .transpose()?;
+            let beads = filter_beads(
+                &graph,
+                &BeadFilter {
+                    status,
+                    priority,
+                    context,
+                    label,
+                    issue_type,
+                    assignee,
+                    parent: None,
+                    ready,
+                    all,
+                    since,
+                },
+            )?;
+
+            let rendered = match format {
+                ExportFormat::Csv => export_csv(&beads),
+                ExportFormat::Markdown => export_markdown(&beads),
+            };
+
+            if let Some(path) = output {
+                std::fs::write(&path, rendered)?;
+                allbeads::status!("✓ Exported {} beads to {}", beads.len(), path);
+            } else {
+                print!("{}", rendered);
+            }
+        }
+
+        Commands::Show {
             id,
             provenance,
             tasks,
+            format,
         } => {
+            let format = if cli.json { ShowFormat::Json } else { format };
             let bead_id = BeadId::new(&id);
             if let Some(bead) = graph.get_bead(&bead_id) {
+                if format != ShowFormat::Text {
+                    return print_bead_show(bead, format, Some(&graph));
+                }
                 print_bead_detailed(bead);
 
                 // Show handoff info if bead has been handed off
@@ -1092,10 +1629,55 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             }
         }
 
-        Commands::Ready => {
+        Commands::Ready {
+            porcelain,
+            leaves,
+            unblockers,
+        } if leaves || unblockers => {
+            let ranked = if leaves {
+                allbeads::graph::ready_leaves(&graph)
+            } else {
+                allbeads::graph::ready_unblockers(&graph)
+            };
+            if porcelain {
+                for r in ranked {
+                    println!("{}", allbeads::graph::porcelain_line(r.bead));
+                }
+                return Ok(());
+            }
+            println!();
+            if leaves {
+                println!(
+                    "{} Ready leaves ({} beads nothing else waits on):",
+                    style::header("○"),
+                    style::count_ready(ranked.len())
+                );
+            } else {
+                println!(
+                    "{} Ready unblockers ({} beads, most unblocking first):",
+                    style::header("○"),
+                    style::count_ready(ranked.len())
+                );
+            }
+            println!();
+            for r in ranked {
+                print_bead_summary(r.bead);
+                if r.impact > 0 {
+                    println!("  {} Unblocks {} bead(s)", style::dim("→"), r.impact);
+                }
+            }
+        }
+
+        Commands::Ready { porcelain, .. } => {
             let mut ready = graph.ready_beads();
             // Sort by priority (lower number = higher priority, like bd)
-            ready.sort_by_key(|b| b.priority);
+            sort_beads(&mut ready, SortKey::Priority, false);
+            if porcelain {
+                for bead in ready {
+                    println!("{}", allbeads::graph::porcelain_line(bead));
+                }
+                return Ok(());
+            }
             println!();
             println!(
                 "{} Ready work ({} beads with no blockers):",
@@ -1108,17 +1690,102 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             }
         }
 
-        Commands::Blocked => {
+        Commands::Triage { context, all } => {
+            handle_triage_command(
+                &graph,
+                &config_for_commands,
+                &bd_flags,
+                context.as_deref(),
+                all,
+            )?;
+        }
+
+        Commands::Next {
+            context,
+            detail,
+            prompt,
+        } => {
+            let context = context.as_deref().map(|c| c.trim_start_matches('@'));
+            let next = allbeads::graph::ready_by_impact(&graph)
+                .into_iter()
+                .find(|r| context.is_none_or(|c| bead_context(r.bead).eq_ignore_ascii_case(c)));
+
+            let Some(next) = next else {
+                if cli.json {
+                    println!("null");
+                } else {
+                    println!(
+                        "No ready beads{}",
+                        context.map(|c| format!(" in @{}", c)).unwrap_or_default()
+                    );
+                }
+                return Ok(());
+            };
+
+            // The prompt is built from bd's view of the bead, like `ab handoff`
+            let handoff_prompt = if prompt {
+                let id = next.bead.id.as_str();
+                let bd = match config_for_commands
+                    .context_for_bead_id(id)
+                    .and_then(|ctx| ctx.local_path())
+                {
+                    Some(path) => Beads::with_workdir_and_flags(&path, bd_flags.clone()),
+                    None => {
+                        let mut bd = Beads::new().map_err(|e| {
+                            allbeads::AllBeadsError::Config(format!(
+                                "Failed to initialize beads: {}",
+                                e
+                            ))
+                        })?;
+                        bd.set_global_flags(bd_flags.clone());
+                        bd
+                    }
+                };
+                let issue = bd.show(id).map_err(|e| {
+                    allbeads::AllBeadsError::Other(format!("Failed to load bead '{}': {}", id, e))
+                })?;
+                Some(build_handoff_prompt(&issue, false))
+            } else {
+                None
+            };
+
+            if cli.json {
+                let mut value = serde_json::to_value(&next)?;
+                if let Some(ref p) = handoff_prompt {
+                    value["prompt"] = serde_json::Value::String(p.clone());
+                }
+                println!("{}", serde_json::to_string_pretty(&value)?);
+                return Ok(());
+            }
+
+            if detail {
+                print_bead_detailed(next.bead);
+            } else {
+                print_bead_summary(next.bead);
+            }
+            if next.impact > 0 {
+                println!("  {} Unblocks {} bead(s)", style::dim("→"), next.impact);
+            }
+            if let Some(p) = handoff_prompt {
+                println!();
+                println!("{}", p);
+            }
+        }
+
+        Commands::Blocked { porcelain } => {
             let mut blocked: Vec<_> = graph
                 .beads
                 .values()
-                .filter(|b| {
-                    b.status == Status::Blocked
-                        || (!b.dependencies.is_empty() && b.status != Status::Closed)
-                })
+                .filter(|b| graph.is_blocked(b))
                 .collect();
 
-            blocked.sort_by_key(|b| (b.priority, status_to_sort_key(b.status)));
+            sort_beads(&mut blocked, SortKey::Priority, false);
+            if porcelain {
+                for bead in blocked {
+                    println!("{}", allbeads::graph::porcelain_line(bead));
+                }
+                return Ok(());
+            }
 
             println!();
             println!(
@@ -1129,11 +1796,34 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             println!();
             for bead in blocked {
                 print_bead_summary(bead);
-                if !bead.dependencies.is_empty() {
+                let open_blockers: Vec<String> = bead
+                    .dependencies
+                    .iter()
+                    .filter(|id| graph.is_open_blocker(id))
+                    .map(|id| style::issue_id(id.as_str()).to_string())
+                    .collect();
+                if !open_blockers.is_empty() {
                     println!(
                         "  {} Blocked by: {}",
                         style::dim("→"),
-                        bead.dependencies
+                        open_blockers.join(", ")
+                    );
+                }
+
+                // Root blockers, when they differ from the direct blockers
+                let mut roots: Vec<BeadId> = Vec::new();
+                for chain in graph.blocker_chain(&bead.id) {
+                    if let Some(root) = chain.last() {
+                        if !roots.contains(root) {
+                            roots.push(root.clone());
+                        }
+                    }
+                }
+                if roots.iter().any(|r| !bead.dependencies.contains(r)) {
+                    println!(
+                        "  {} Ultimately waiting on: {}",
+                        style::dim("⇢"),
+                        roots
                             .iter()
                             .map(|id| style::issue_id(id.as_str()).to_string())
                             .collect::<Vec<_>>()
@@ -1143,9 +1833,43 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             }
         }
 
-        Commands::Open { .. } => {
-            // Handled early (before graph loading) - should not reach here
-            unreachable!("Open command should be handled early");
+        Commands::Open { id } => {
+            // Bead IDs only; external issue references were opened before graph loading
+            let bead_id = allbeads::graph::BeadId::from(id.as_str());
+            let Some(bead) = graph.beads.get(&bead_id) else {
+                eprintln!("Bead {} not found", id);
+                return Ok(());
+            };
+            let ctx_path = bead
+                .labels
+                .iter()
+                .find(|l| l.starts_with('@'))
+                .map(|l| l.trim_start_matches('@'))
+                .and_then(|name| config_for_commands.contexts.iter().find(|c| c.name == name))
+                .and_then(|ctx| ctx.local_path());
+
+            match (bead.source_location(), &ctx_path) {
+                (Some((file, line)), Some(ctx_path)) if ctx_path.join(&file).exists() => {
+                    open_in_editor(&ctx_path.join(&file), line)?;
+                }
+                (location, Some(ctx_path)) => {
+                    if let Some((file, line)) = location {
+                        eprintln!(
+                            "{} {}:{} not found in @{}; editing the bead instead",
+                            style::warning("!"),
+                            file,
+                            line,
+                            bead_context(bead)
+                        );
+                    }
+                    let bd = Beads::with_workdir_and_flags(ctx_path, bd_flags.to_vec());
+                    match bd.edit(&id, None) {
+                        Ok(output) => println!("{}", output.stdout),
+                        Err(e) => eprintln!("Error: {}", e),
+                    }
+                }
+                (_, None) => eprintln!("No local context found for bead {}", id),
+            }
         }
 
         Commands::Search {
@@ -1160,7 +1884,15 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             sort,
             reverse,
             limit,
+            fuzzy,
+            ..
         } => {
+            let sort = sort
+                .as_deref()
+                .map(str::parse::<SortKey>)
+                .transpose()?
+                .unwrap_or_default();
+            let limit = limit.unwrap_or(50);
             let query_lower = query.as_ref().map(|q| q.to_lowercase());
 
             // Parse priority bounds
@@ -1217,8 +1949,10 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                 .beads
                 .values()
                 .filter(|b| {
-                    // Text search (if query provided)
-                    let matches_text = if let Some(ref q) = query_lower {
+                    // Text search (if query provided); fuzzy matching is scored below
+                    let matches_text = if fuzzy {
+                        true
+                    } else if let Some(ref q) = query_lower {
                         b.title.to_lowercase().contains(q)
                             || b.id.as_str().to_lowercase().contains(q)
                             || b.description
@@ -1311,23 +2045,22 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                 })
                 .collect();
 
-            // Sort results
-            match sort.to_lowercase().as_str() {
-                "priority" => results.sort_by_key(|b| b.priority),
-                "created" => results.sort_by(|a, b| a.created_at.cmp(&b.created_at)),
-                "updated" => results.sort_by(|a, b| a.updated_at.cmp(&b.updated_at)),
-                "status" => results.sort_by_key(|b| status_to_sort_key(b.status)),
-                "id" => results.sort_by(|a, b| a.id.as_str().cmp(b.id.as_str())),
-                "title" => {
-                    results.sort_by(|a, b| a.title.to_lowercase().cmp(&b.title.to_lowercase()))
-                }
-                "type" => results.sort_by_key(|b| format!("{:?}", b.issue_type)),
-                _ => results.sort_by_key(|b| (b.priority, status_to_sort_key(b.status))),
-            }
+            sort_beads(&mut results, sort, reverse);
 
-            if reverse {
-                results.reverse();
-            }
+            // Fuzzy mode ranks by score, keeping the --sort order for ties
+            let mut results: Vec<(&allbeads::graph::Bead, Option<i64>)> =
+                match query.as_deref().filter(|_| fuzzy) {
+                    Some(q) => {
+                        let matcher = SkimMatcherV2::default();
+                        let mut scored: Vec<_> = results
+                            .into_iter()
+                            .filter_map(|b| fuzzy_score(&matcher, b, q).map(|s| (b, Some(s))))
+                            .collect();
+                        scored.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+                        scored
+                    }
+                    None => results.into_iter().map(|b| (b, None)).collect(),
+                };
 
             // Apply limit
             let total = results.len();
@@ -1344,20 +2077,69 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                 println!("Search results for '{}': {} beads", query_display, total);
             }
             println!();
-            for bead in results {
+            for (bead, score) in results {
+                if let Some(score) = score {
+                    print!("{} ", style::dim(&format!("[{:>3}]", score)));
+                }
                 print_bead_summary(bead);
             }
         }
 
         Commands::Duplicates {
             threshold,
+            min_title_len,
             include_closed,
+            save_default,
+            action,
         } => {
+            if let Some(DuplicatesCommands::Merge { keep, dup }) = action {
+                return handle_duplicates_merge(
+                    &graph,
+                    &config_for_commands,
+                    &keep,
+                    &dup,
+                    &bd_flags,
+                    cli.json,
+                );
+            }
+
+            let defaults = config_for_commands.duplicates;
+            let threshold = threshold.unwrap_or(defaults.threshold);
+            let min_title_len = min_title_len.unwrap_or(defaults.min_title_len);
+            if !(0.0..=1.0).contains(&threshold) {
+                return Err(allbeads::AllBeadsError::Config(format!(
+                    "Threshold must be between 0.0 and 1.0, got {}",
+                    threshold
+                )));
+            }
+
+            if save_default {
+                let path = cli
+                    .config
+                    .as_ref()
+                    .map(PathBuf::from)
+                    .unwrap_or_else(AllBeadsConfig::default_path);
+                let mut config = config_for_commands.clone();
+                config.duplicates = allbeads::config::DuplicatesConfig {
+                    threshold,
+                    min_title_len,
+                };
+                config.save(&path)?;
+                println!(
+                    "{} Saved duplicates defaults: threshold {}, min title length {}",
+                    style::success("✓"),
+                    threshold,
+                    min_title_len
+                );
+                return Ok(());
+            }
+
             // Group beads by similarity (filter to open by default)
             let beads: Vec<_> = graph
                 .beads
                 .values()
                 .filter(|b| include_closed || b.status != allbeads::graph::Status::Closed)
+                .filter(|b| b.title.trim().chars().count() >= min_title_len)
                 .collect();
             let mut duplicates: Vec<(f64, &allbeads::graph::Bead, &allbeads::graph::Bead)> =
                 Vec::new();
@@ -1400,7 +2182,158 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             }
         }
 
-        Commands::Stats { remote } => {
+        Commands::Stale { days, add_label } => {
+            let stale = allbeads::graph::stale(&graph, chrono::Duration::days(i64::from(days)));
+
+            // Group by context; each group stays oldest first
+            let mut by_context: std::collections::BTreeMap<&str, Vec<_>> =
+                std::collections::BTreeMap::new();
+            for entry in &stale {
+                by_context
+                    .entry(bead_context(entry.bead))
+                    .or_default()
+                    .push(entry);
+            }
+
+            if cli.json {
+                let output: Vec<_> = stale
+                    .iter()
+                    .map(|s| {
+                        serde_json::json!({
+                            "id": s.bead.id.as_str(),
+                            "title": s.bead.title,
+                            "status": format_status(s.bead.status),
+                            "priority": priority_to_num(s.bead.priority),
+                            "context": bead_context(s.bead),
+                            "updated_at": s.bead.updated_at,
+                            "age_days": s.age.num_days(),
+                        })
+                    })
+                    .collect();
+                println!("{}", serde_json::to_string_pretty(&output)?);
+            } else if stale.is_empty() {
+                println!(
+                    "{} No open beads older than {} days",
+                    style::success("✓"),
+                    days
+                );
+            } else {
+                println!();
+                println!(
+                    "{} Stale beads ({}) not updated in {}+ days:",
+                    style::warning("⚠"),
+                    stale.len(),
+                    days
+                );
+                for (ctx_name, entries) in &by_context {
+                    println!();
+                    println!("{}", style::subheader(&format!("@{}", ctx_name)));
+                    for entry in entries {
+                        print!(
+                            "  {} ",
+                            style::dim(&format!("{:>4}d", entry.age.num_days()))
+                        );
+                        print_bead_summary(entry.bead);
+                    }
+                }
+            }
+
+            if add_label {
+                let mut labeled = 0;
+                for (ctx_name, entries) in &by_context {
+                    let Some(ctx_path) = config_for_commands
+                        .get_context(ctx_name)
+                        .and_then(|ctx| ctx.local_path())
+                    else {
+                        eprintln!("Warning: No local path for @{}, not labeling", ctx_name);
+                        continue;
+                    };
+                    let bd = Beads::with_workdir_and_flags(&ctx_path, bd_flags.to_vec());
+                    for entry in entries.iter().filter(|e| !e.bead.labels.contains("stale")) {
+                        match bd.label_add(entry.bead.id.as_str(), "stale") {
+                            Ok(_) => labeled += 1,
+                            Err(e) => eprintln!(
+                                "Warning: Failed to label {}: {}",
+                                entry.bead.id.as_str(),
+                                e
+                            ),
+                        }
+                    }
+                }
+                if !cli.json {
+                    println!();
+                    println!(
+                        "{} Added 'stale' label to {} bead(s)",
+                        style::success("✓"),
+                        labeled
+                    );
+                }
+            }
+        }
+
+        Commands::Workload => {
+            let workload = allbeads::graph::workload(&graph);
+
+            if cli.json {
+                println!("{}", serde_json::to_string_pretty(&workload)?);
+                return Ok(());
+            }
+
+            if workload.is_empty() {
+                println!("No open or in-progress beads");
+                return Ok(());
+            }
+
+            // Busiest assignees first
+            let mut rows: Vec<_> = workload.iter().collect();
+            rows.sort_by(|a, b| {
+                b.1.priority_weight
+                    .cmp(&a.1.priority_weight)
+                    .then_with(|| a.0.cmp(b.0))
+            });
+
+            println!();
+            println!("{}", style::header("Workload by Assignee"));
+            println!();
+            let header = format!(
+                "  {:<24} {:>6} {:>12} {:>6} {:>8}",
+                "Assignee", "Open", "In Progress", "Total", "Weight"
+            );
+            println!("{}", style::dim(&header));
+            for (assignee, stats) in rows {
+                println!(
+                    "  {:<24} {:>6} {:>12} {:>6} {:>8}",
+                    assignee,
+                    stats.open,
+                    stats.in_progress,
+                    stats.total(),
+                    stats.priority_weight
+                );
+            }
+            println!();
+            println!(
+                "  {} Weight sums priorities (P0 = 5 ... P4 = 1)",
+                style::dim("→")
+            );
+        }
+
+        Commands::Summary { oneline } => {
+            handle_summary_command(&graph, &context_filter, oneline, cli.json)?;
+        }
+
+        Commands::Stats {
+            remote,
+            history,
+            days,
+            age,
+        } => {
+            if history {
+                return handle_stats_history(&config_for_commands, &context_filter, days, cli.json);
+            }
+            if age {
+                return handle_stats_age(&graph, cli.json);
+            }
+
             if remote {
                 // Fetch from web API
                 let ab_config = AllBeadsConfig::load_default()
@@ -1739,13 +2672,15 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             );
         }
 
-        Commands::Tui => {
+        Commands::Tui { mouse } => {
             // Determine mail database path (in config directory)
             let mail_db_path = AllBeadsConfig::default_path()
                 .parent()
                 .map(|p| p.join("mail.db"));
 
-            let tui_result = allbeads::tui::run_with_mail(graph, mail_db_path, &tui_project_id)?;
+            let mouse = mouse || config_for_commands.visualization.mouse;
+            let tui_result =
+                allbeads::tui::run_with_mail(graph, mail_db_path, &tui_project_id, mouse)?;
 
             // Handle onboarding request from GitHub picker
             if !tui_result.repos_to_onboard.is_empty() {
@@ -1786,7 +2721,17 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             path,
             verbose,
             dry_run,
+            template,
+            rules,
+            min_priority,
+            include,
+            exclude,
         } => {
+            let min_priority = min_priority
+                .as_deref()
+                .map(parse_priority)
+                .transpose()?
+                .map(priority_to_num);
             let repo_path = PathBuf::from(&path);
             if !repo_path.exists() {
                 return Err(allbeads::AllBeadsError::Config(format!(
@@ -1803,7 +2748,41 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                 println!();
             }
 
-            run_full_janitor_analysis(&repo_path, verbose, dry_run)?;
+            let mut templates = config_for_commands.janitor_templates.clone();
+            for spec in &template {
+                let (category, value) = spec.split_once('=').ok_or_else(|| {
+                    allbeads::AllBeadsError::Config(format!(
+                        "Invalid --template '{}': expected CATEGORY=TEMPLATE",
+                        spec
+                    ))
+                })?;
+                let value = match value.strip_prefix('@') {
+                    Some(file) => std::fs::read_to_string(file).map_err(|e| {
+                        allbeads::AllBeadsError::Config(format!(
+                            "Failed to read template {}: {}",
+                            file, e
+                        ))
+                    })?,
+                    None => value.to_string(),
+                };
+                templates.insert(category.to_string(), value);
+            }
+
+            let mut scanner = allbeads::janitor::SecurityScanner::new();
+            if let Some(rules) = &rules {
+                scanner.add_rules_file(Path::new(rules))?;
+            }
+            let filter = allbeads::janitor::PathFilter::new(&include, &exclude)?;
+
+            run_full_janitor_analysis(
+                &repo_path,
+                verbose > 0,
+                dry_run,
+                &templates,
+                &scanner,
+                &filter,
+                min_priority,
+            )?;
         }
 
         Commands::Sheriff {
@@ -1812,6 +2791,7 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             foreground,
             mail_poll,
             mail_interval,
+            max_concurrent,
         } => {
             use allbeads::sheriff::{Sheriff, SheriffConfig};
             use std::time::Duration;
@@ -1822,7 +2802,8 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                 .with_verbose(foreground)
                 .with_project_id(&tui_project_id)
                 .with_mail_poll(mail_poll)
-                .with_mail_poll_interval(Duration::from_secs(mail_interval));
+                .with_mail_poll_interval(Duration::from_secs(mail_interval))
+                .with_max_concurrent_polls(max_concurrent);
 
             if let Some(manifest_path) = manifest {
                 sheriff_config = sheriff_config.with_manifest(manifest_path);
@@ -1855,12 +2836,13 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                                     println!("[Sheriff] Poll cycle started");
                                 }
                                 allbeads::sheriff::SheriffEvent::PollCompleted {
-                                    rigs_polled,
+                                    rig_id,
                                     changes,
                                 } => {
                                     println!(
-                                        "[Sheriff] Poll completed: {} rigs, {} changes",
-                                        rigs_polled, changes
+                                        "[Sheriff] Rig {} polled: {} changes",
+                                        rig_id.as_str(),
+                                        changes
                                     );
                                 }
                                 allbeads::sheriff::SheriffEvent::RigSynced { rig_id, result } => {
@@ -1913,144 +2895,149 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
         }
 
         Commands::Update {
-            id,
+            ids,
             status,
             priority,
             assignee,
+            description,
+            notes,
+            ..
         } => {
-            // Find which context this bead belongs to
-            let bead_id = allbeads::graph::BeadId::from(id.as_str());
-            if let Some(bead) = graph.beads.get(&bead_id) {
-                // Get the context path from the bead's labels
-                let context_label = bead
-                    .labels
+            let by_context = group_ids_by_context(&ids, &graph, &config_for_commands);
+            if by_context.is_empty() {
+                eprintln!("No beads to update");
+                return Ok(());
+            }
+
+            // Parse priority string to u8 if provided
+            let priority_u8 = priority
+                .as_deref()
+                .map(str::parse::<beads::Priority>)
+                .transpose()
+                .map_err(|e| allbeads::AllBeadsError::Parse(e.to_string()))?
+                .map(u8::from);
+
+            // A single bead keeps bd's own output; several get a line each
+            let single = ids.len() == 1;
+            let mut updated = 0;
+            for (ctx_name, bead_ids) in by_context {
+                let Some(ctx) = config_for_commands
+                    .contexts
                     .iter()
-                    .find(|l| l.starts_with('@'))
-                    .map(|l| l.trim_start_matches('@'));
+                    .find(|c| c.name == ctx_name)
+                else {
+                    eprintln!("Context '{}' not found in config", ctx_name);
+                    continue;
+                };
+                let Some(ctx_path) = ctx.local_path() else {
+                    eprintln!("Context '{}' has no local path configured", ctx_name);
+                    continue;
+                };
 
-                if let Some(ctx_name) = context_label {
-                    // Find the context path
-                    if let Some(ctx) = config_for_commands
-                        .contexts
-                        .iter()
-                        .find(|c| c.name == ctx_name)
-                    {
-                        if let Some(ctx_path) = &ctx.path {
-                            println!(
-                                "Updating {} in context @{}...",
-                                style::issue_id(&id),
-                                ctx_name
-                            );
+                if single {
+                    println!(
+                        "Updating {} in context @{}...",
+                        style::issue_id(&bead_ids[0]),
+                        ctx_name
+                    );
+                } else {
+                    println!(
+                        "Updating {} bead(s) in context @{}...",
+                        bead_ids.len(),
+                        ctx_name
+                    );
+                }
 
-                            // Parse priority string to u8 if provided
-                            let priority_u8 = priority
+                let bd = Beads::with_workdir_and_flags(&ctx_path, bd_flags.to_vec());
+                for id in &bead_ids {
+                    // Snapshot the fields being changed so `ab undo` can restore them
+                    let prior = graph.beads.get(&BeadId::from(id.as_str())).map(|b| {
+                        allbeads::undo::PriorFields {
+                            status: status.as_ref().map(|_| format_status(b.status).to_string()),
+                            priority: priority_u8.map(|_| u8::from(b.priority)),
+                            assignee: assignee
                                 .as_ref()
-                                .and_then(|p| p.trim_start_matches('P').parse::<u8>().ok());
-
-                            let bd = Beads::with_workdir_and_flags(ctx_path, bd_flags.to_vec());
-                            match bd.update(
-                                &id,
-                                status.as_deref(),
-                                priority_u8,
-                                assignee.as_deref(),
-                                None, // title
-                            ) {
-                                Ok(output) => {
-                                    if output.success {
-                                        println!("{}", output.stdout);
-                                    } else {
-                                        eprintln!("{}", output.stderr);
-                                    }
-                                }
-                                Err(e) => eprintln!("Error: {}", e),
+                                .map(|_| b.assignee.clone().unwrap_or_default()),
+                            description: description
+                                .as_ref()
+                                .map(|_| b.description.clone().unwrap_or_default()),
+                            notes: notes.as_ref().map(|_| b.notes.clone().unwrap_or_default()),
+                        }
+                    });
+                    match bd.update(
+                        id,
+                        status.as_deref(),
+                        priority_u8,
+                        assignee.as_deref(),
+                        None, // title
+                        description.as_deref(),
+                        notes.as_deref(),
+                    ) {
+                        Ok(output) if output.success => {
+                            updated += 1;
+                            if let Some(before) = prior {
+                                record_undo(
+                                    &config_for_commands,
+                                    Some(&ctx_name),
+                                    &ctx_path,
+                                    allbeads::undo::OpKind::Update {
+                                        id: id.clone(),
+                                        before,
+                                    },
+                                );
+                            }
+                            if single {
+                                println!("{}", output.stdout);
+                            } else {
+                                println!("  {} {}", style::success("✓"), style::issue_id(id));
                             }
-                        } else {
-                            eprintln!("Context '{}' has no local path configured", ctx_name);
                         }
-                    } else {
-                        eprintln!("Context '{}' not found in config", ctx_name);
+                        Ok(output) if single => eprintln!("{}", output.stderr),
+                        Ok(output) => eprintln!(
+                            "  {} {}: {}",
+                            style::error("✗"),
+                            style::issue_id(id),
+                            output.stderr.trim()
+                        ),
+                        Err(e) if single => eprintln!("Error: {}", e),
+                        Err(e) => {
+                            eprintln!("  {} {}: {}", style::error("✗"), style::issue_id(id), e)
+                        }
                     }
-                } else {
-                    eprintln!("Could not determine context for bead {}", id);
                 }
-            } else {
-                eprintln!("Bead {} not found", id);
             }
-        }
 
-        Commands::Close { ids, reason } => {
-            // Helper to find context by reading .beads/config.yaml prefix
-            fn find_context_by_prefix<'a>(
-                prefix: &str,
-                contexts: &'a [allbeads::config::BossContext],
-            ) -> Option<&'a allbeads::config::BossContext> {
-                for ctx in contexts {
-                    if let Some(path) = &ctx.path {
-                        let config_path = std::path::Path::new(path).join(".beads/config.yaml");
-                        if let Ok(content) = std::fs::read_to_string(&config_path) {
-                            // Parse issue-prefix from YAML
-                            for line in content.lines() {
-                                if let Some(value) = line.strip_prefix("issue-prefix:") {
-                                    let ctx_prefix =
-                                        value.trim().trim_matches('"').trim_matches('\'');
-                                    if ctx_prefix.eq_ignore_ascii_case(prefix) {
-                                        return Some(ctx);
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-                None
+            if ids.len() > 1 {
+                println!();
+                println!("Updated {} of {} bead(s)", updated, ids.len());
             }
+        }
 
+        Commands::Close {
+            ids,
+            reason,
+            dry_run,
+        } => {
             // Group beads by context
-            let mut by_context: std::collections::HashMap<String, Vec<String>> =
-                std::collections::HashMap::new();
-
-            for id in &ids {
-                let bead_id = allbeads::graph::BeadId::from(id.as_str());
-
-                // First try to find in graph
-                if let Some(bead) = graph.beads.get(&bead_id) {
-                    if let Some(ctx_name) = bead
-                        .labels
-                        .iter()
-                        .find(|l| l.starts_with('@'))
-                        .map(|l| l.trim_start_matches('@').to_string())
-                    {
-                        by_context.entry(ctx_name).or_default().push(id.clone());
-                        continue;
-                    }
-                }
-
-                // Fallback: extract prefix from ID and find matching context
-                if let Some(prefix) = id.split('-').next() {
-                    if let Some(ctx) = find_context_by_prefix(prefix, &config_for_commands.contexts)
-                    {
-                        by_context
-                            .entry(ctx.name.clone())
-                            .or_default()
-                            .push(id.clone());
-                        continue;
-                    }
-                }
-
-                eprintln!("Warning: Could not determine context for bead {}", id);
-            }
+            let by_context = group_ids_by_context(&ids, &graph, &config_for_commands);
 
             if by_context.is_empty() {
                 eprintln!("No beads to close");
                 return Ok(());
             }
 
+            if dry_run {
+                print_dry_run("close", &by_context, &graph);
+                return Ok(());
+            }
+
             for (ctx_name, bead_ids) in by_context {
                 if let Some(ctx) = config_for_commands
                     .contexts
                     .iter()
                     .find(|c| c.name == ctx_name)
                 {
-                    if let Some(ctx_path) = &ctx.path {
+                    if let Some(ctx_path) = &ctx.local_path() {
                         println!(
                             "Closing {} bead(s) in context @{}...",
                             bead_ids.len(),
@@ -2075,6 +3062,12 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                             Ok(output) => {
                                 if output.success {
                                     println!("{}", output.stdout);
+                                    record_undo(
+                                        &config_for_commands,
+                                        Some(&ctx_name),
+                                        ctx_path,
+                                        allbeads::undo::OpKind::Close { ids: bead_ids },
+                                    );
                                 } else {
                                     eprintln!("{}", output.stderr);
                                 }
@@ -2086,80 +3079,320 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             }
         }
 
-        Commands::Create {
-            title,
-            issue_type,
-            priority,
-            context,
-        } => {
-            // Find the target context
-            let ctx_name = context.unwrap_or_else(|| {
-                // Try to determine from current directory
-                let cwd = std::env::current_dir().unwrap_or_default();
-                config_for_commands
-                    .contexts
-                    .iter()
-                    .find(|c| c.path.as_ref().is_some_and(|p| cwd.starts_with(p)))
-                    .map(|c| c.name.clone())
-                    .unwrap_or_else(|| "default".to_string())
-            });
+        Commands::Move { id, parent } => {
+            let bead_id = BeadId::from(id.as_str());
+            let parent_id = BeadId::from(parent.as_str());
+
+            let bead = graph
+                .get_bead(&bead_id)
+                .ok_or_else(|| allbeads::AllBeadsError::IssueNotFound(id.clone()))?;
+            let epic = graph
+                .get_bead(&parent_id)
+                .ok_or_else(|| allbeads::AllBeadsError::IssueNotFound(parent.clone()))?;
+
+            if epic.issue_type != IssueType::Epic {
+                return Err(allbeads::AllBeadsError::Other(format!(
+                    "{} is a {}, not an epic",
+                    parent,
+                    format_issue_type(epic.issue_type)
+                )));
+            }
 
-            if let Some(ctx) = config_for_commands
+            let ctx_name = bead_context(bead);
+            if ctx_name != bead_context(epic) {
+                return Err(allbeads::AllBeadsError::Other(format!(
+                    "{} and {} are in different contexts; parents must be in the same context",
+                    id, parent
+                )));
+            }
+
+            if graph.would_create_parent_cycle(&bead_id, &parent_id) {
+                return Err(allbeads::AllBeadsError::Other(format!(
+                    "Moving {} under {} would create a parent cycle",
+                    id, parent
+                )));
+            }
+
+            if bead.parent.as_ref() == Some(&parent_id) {
+                println!("{} is already under {}", style::issue_id(&id), parent);
+                return Ok(());
+            }
+
+            let ctx_path = config_for_commands
                 .contexts
                 .iter()
                 .find(|c| c.name == ctx_name)
-            {
-                if let Some(ctx_path) = &ctx.path {
-                    println!("Creating bead in context @{}...", ctx_name);
+                .and_then(|c| c.local_path())
+                .ok_or_else(|| {
+                    allbeads::AllBeadsError::Config(format!(
+                        "Context '{}' has no local path configured",
+                        ctx_name
+                    ))
+                })?;
+
+            let bd = Beads::with_workdir_and_flags(&ctx_path, bd_flags.to_vec());
+            let output = bd
+                .set_parent(&id, &parent)
+                .map_err(|e| allbeads::AllBeadsError::Other(e.to_string()))?;
+            if !output.success {
+                return Err(allbeads::AllBeadsError::Other(format!(
+                    "Failed to move {}: {}",
+                    id,
+                    output.stderr.trim()
+                )));
+            }
 
-                    // Parse priority string to u8
-                    let priority_u8 = priority.trim_start_matches('P').parse::<u8>().ok();
+            match &bead.parent {
+                Some(old) => println!(
+                    "{} Moved {} from {} to {}",
+                    style::success("✓"),
+                    style::issue_id(&id),
+                    old,
+                    parent
+                ),
+                None => println!(
+                    "{} Moved {} under {}",
+                    style::success("✓"),
+                    style::issue_id(&id),
+                    parent
+                ),
+            }
+        }
 
-                    let bd = Beads::with_workdir_and_flags(ctx_path, bd_flags.to_vec());
-                    match bd.create(&title, &issue_type, priority_u8, None) {
-                        Ok(output) => {
-                            if output.success {
-                                println!("{}", output.stdout);
+        Commands::Create {
+            title,
+            issue_type,
+            priority,
+            assignee,
+            context,
+            template,
+            description,
+            ..
+        } => {
+            // Flags win over the template, then the context's defaults, then bd's
+            let template = match template {
+                Some(name) => config_for_commands
+                    .bead_templates
+                    .get(&name)
+                    .cloned()
+                    .ok_or_else(|| {
+                        let names: Vec<&str> = config_for_commands
+                            .bead_templates
+                            .keys()
+                            .map(String::as_str)
+                            .collect();
+                        allbeads::AllBeadsError::Config(format!(
+                            "Bead template '{}' not found. Templates: {}",
+                            name,
+                            if names.is_empty() {
+                                "(none)".to_string()
                             } else {
-                                eprintln!("{}", output.stderr);
+                                names.join(", ")
                             }
+                        ))
+                    })?,
+                None => allbeads::config::BeadTemplate::default(),
+            };
+            let issue_type = issue_type.or(template.issue_type);
+            let priority = priority.or(template.priority);
+            let description = description.or(template.description);
+
+            // Find the target context: --context, then cwd, then default_context
+            let cwd = std::env::current_dir().unwrap_or_default();
+            let ctx = config_for_commands.resolve_context(context.as_deref(), &cwd)?;
+            let ctx_name = &ctx.name;
+            if let Some(ctx_path) = &ctx.local_path() {
+                println!("Creating bead in context @{}...", ctx_name);
+
+                let issue_type = issue_type
+                    .or_else(|| ctx.default_type.clone())
+                    .unwrap_or_else(|| "task".to_string());
+                let priority = priority
+                    .or_else(|| ctx.default_priority.clone())
+                    .unwrap_or_else(|| "2".to_string())
+                    .parse::<beads::Priority>()
+                    .map_err(|e| allbeads::AllBeadsError::Parse(e.to_string()))?;
+                let priority_u8 = Some(u8::from(priority));
+
+                let labels: Vec<&str> = template.labels.iter().map(String::as_str).collect();
+                let bd = Beads::with_workdir_and_flags(ctx_path, bd_flags.to_vec());
+                match bd.create_full(
+                    &title,
+                    &issue_type,
+                    priority_u8,
+                    description.as_deref(),
+                    assignee.as_deref(),
+                    None,
+                    Some(labels.as_slice()),
+                ) {
+                    Ok(output) => {
+                        if output.success {
+                            println!("{}", output.stdout);
+                        } else {
+                            eprintln!("{}", output.stderr);
                         }
-                        Err(e) => eprintln!("Error: {}", e),
                     }
-                } else {
-                    eprintln!("Context '{}' has no local path configured", ctx_name);
+                    Err(e) => eprintln!("Error: {}", e),
                 }
             } else {
-                eprintln!("Context '{}' not found", ctx_name);
+                eprintln!("Context '{}' has no local path configured", ctx_name);
             }
         }
 
-        Commands::Reopen { ids } => {
-            // Group beads by context
-            let mut by_context: std::collections::HashMap<String, Vec<String>> =
-                std::collections::HashMap::new();
+        Commands::Clone { id, title } => {
+            let ctx = config_for_commands
+                .context_for_bead_id(&id)
+                .ok_or_else(|| {
+                    allbeads::AllBeadsError::Config(format!("No context found for bead '{}'", id))
+                })?;
+            let ctx_path = ctx.local_path().ok_or_else(|| {
+                allbeads::AllBeadsError::Config(format!(
+                    "Context '{}' has no local path configured",
+                    ctx.name
+                ))
+            })?;
 
-            for id in &ids {
-                let bead_id = allbeads::graph::BeadId::from(id.as_str());
-                if let Some(bead) = graph.beads.get(&bead_id) {
-                    if let Some(ctx_name) = bead
-                        .labels
-                        .iter()
-                        .find(|l| l.starts_with('@'))
-                        .map(|l| l.trim_start_matches('@').to_string())
-                    {
-                        by_context.entry(ctx_name).or_default().push(id.clone());
+            let bd = Beads::with_workdir_and_flags(&ctx_path, bd_flags.to_vec());
+            let source = bd.show(&id).map_err(|e| {
+                allbeads::AllBeadsError::Other(format!("Failed to load bead '{}': {}", id, e))
+            })?;
+
+            println!("Cloning {} in context @{}...", id, ctx.name);
+            let labels: Vec<&str> = source.labels.iter().map(String::as_str).collect();
+            match bd.create_full(
+                &title,
+                &source.issue_type,
+                source.priority,
+                source.description.as_deref(),
+                None,
+                None,
+                Some(labels.as_slice()),
+            ) {
+                Ok(output) => {
+                    if output.success {
+                        println!("{}", output.stdout);
+                    } else {
+                        eprintln!("{}", output.stderr);
+                    }
+                }
+                Err(e) => eprintln!("Error: {}", e),
+            }
+        }
+
+        Commands::Reassign {
+            from,
+            to,
+            context,
+            dry_run,
+        } => {
+            let context = context.as_deref().map(|c| c.trim_start_matches('@'));
+
+            let mut ids: Vec<String> = graph
+                .beads
+                .values()
+                .filter(|b| !matches!(b.status, Status::Closed | Status::Tombstone))
+                .filter(|b| b.assignee.as_deref() == Some(from.as_str()))
+                .filter(|b| context.is_none_or(|c| bead_context(b).eq_ignore_ascii_case(c)))
+                .map(|b| b.id.as_str().to_string())
+                .collect();
+            ids.sort();
+            if ids.is_empty() {
+                println!("No open beads are assigned to '{}'", from);
+                return Ok(());
+            }
+
+            let by_context = group_ids_by_context(&ids, &graph, &config_for_commands);
+            if dry_run {
+                print_dry_run("reassign", &by_context, &graph);
+                return Ok(());
+            }
+
+            let mut reassigned = 0;
+            let mut failed = 0;
+            for (ctx_name, bead_ids) in by_context {
+                let Some(ctx_path) = config_for_commands
+                    .get_context(&ctx_name)
+                    .and_then(|c| c.local_path())
+                else {
+                    eprintln!("Context '{}' has no local path configured", ctx_name);
+                    failed += bead_ids.len();
+                    continue;
+                };
+
+                println!(
+                    "Reassigning {} bead(s) in @{} from {} to {}...",
+                    bead_ids.len(),
+                    ctx_name,
+                    from,
+                    to
+                );
+                let bd = Beads::with_workdir_and_flags(&ctx_path, bd_flags.to_vec());
+                for id in &bead_ids {
+                    match bd.update(id, None, None, Some(&to), None, None, None) {
+                        Ok(output) if output.success => {
+                            reassigned += 1;
+                            println!("  {} {}", style::success("✓"), style::issue_id(id));
+                            record_undo(
+                                &config_for_commands,
+                                Some(&ctx_name),
+                                &ctx_path,
+                                allbeads::undo::OpKind::Update {
+                                    id: id.clone(),
+                                    before: allbeads::undo::PriorFields {
+                                        assignee: Some(from.clone()),
+                                        ..Default::default()
+                                    },
+                                },
+                            );
+                        }
+                        Ok(output) => {
+                            failed += 1;
+                            eprintln!(
+                                "  {} {}: {}",
+                                style::error("✗"),
+                                style::issue_id(id),
+                                output.stderr.trim()
+                            );
+                        }
+                        Err(e) => {
+                            failed += 1;
+                            eprintln!("  {} {}: {}", style::error("✗"), style::issue_id(id), e);
+                        }
                     }
                 }
             }
 
+            println!();
+            println!(
+                "Reassigned {} of {} bead(s) to {}",
+                reassigned,
+                ids.len(),
+                to
+            );
+            if failed > 0 {
+                return Err(allbeads::AllBeadsError::Other(format!(
+                    "{} bead(s) could not be updated",
+                    failed
+                )));
+            }
+        }
+
+        Commands::Reopen { ids, dry_run } => {
+            // Group beads by context
+            let by_context = group_ids_by_context(&ids, &graph, &config_for_commands);
+
+            if dry_run {
+                print_dry_run("reopen", &by_context, &graph);
+                return Ok(());
+            }
+
             for (ctx_name, bead_ids) in by_context {
                 if let Some(ctx) = config_for_commands
                     .contexts
                     .iter()
                     .find(|c| c.name == ctx_name)
                 {
-                    if let Some(ctx_path) = &ctx.path {
+                    if let Some(ctx_path) = &ctx.local_path() {
                         println!(
                             "Reopening {} bead(s) in context @{}...",
                             bead_ids.len(),
@@ -2172,6 +3405,14 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                             Ok(output) => {
                                 if output.success {
                                     println!("{}", output.stdout);
+                                    record_undo(
+                                        &config_for_commands,
+                                        Some(&ctx_name),
+                                        ctx_path,
+                                        allbeads::undo::OpKind::Reopen {
+                                            ids: bead_ids.clone(),
+                                        },
+                                    );
                                 } else {
                                     eprintln!("{}", output.stderr);
                                 }
@@ -2200,7 +3441,7 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                                 .iter()
                                 .find(|c| c.name == ctx_name)
                             {
-                                if let Some(ctx_path) = &ctx.path {
+                                if let Some(ctx_path) = &ctx.local_path() {
                                     let bd =
                                         Beads::with_workdir_and_flags(ctx_path, bd_flags.clone());
                                     match bd.dep_add(&issue, &depends_on) {
@@ -2228,7 +3469,7 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                                 .iter()
                                 .find(|c| c.name == ctx_name)
                             {
-                                if let Some(ctx_path) = &ctx.path {
+                                if let Some(ctx_path) = &ctx.local_path() {
                                     let bd =
                                         Beads::with_workdir_and_flags(ctx_path, bd_flags.clone());
                                     match bd.dep_remove(&issue, &depends_on) {
@@ -2245,9 +3486,30 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             }
         }
 
+        Commands::Link {
+            from,
+            to,
+            link_type,
+            remove,
+        } => {
+            handle_link_command(
+                &graph,
+                &config_for_commands,
+                &from,
+                &to,
+                link_type,
+                remove,
+                &bd_flags,
+            )?;
+        }
+
         Commands::Label(label_cmd) => {
             match label_cmd {
-                LabelCommands::Add { issue, label } => {
+                LabelCommands::Add {
+                    issue,
+                    label,
+                    force,
+                } => {
                     let bead_id = allbeads::graph::BeadId::from(issue.as_str());
                     if let Some(bead) = graph.beads.get(&bead_id) {
                         if let Some(ctx_name) = bead
@@ -2256,16 +3518,34 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                             .find(|l| l.starts_with('@'))
                             .map(|l| l.trim_start_matches('@'))
                         {
+                            let label = if force || !io::stdin().is_terminal() {
+                                label
+                            } else {
+                                confirm_similar_label(&graph, ctx_name, label)?
+                            };
                             if let Some(ctx) = config_for_commands
                                 .contexts
                                 .iter()
                                 .find(|c| c.name == ctx_name)
                             {
-                                if let Some(ctx_path) = &ctx.path {
+                                if let Some(ctx_path) = &ctx.local_path() {
                                     let bd =
                                         Beads::with_workdir_and_flags(ctx_path, bd_flags.clone());
                                     match bd.label_add(&issue, &label) {
-                                        Ok(output) => println!("{}", output.stdout),
+                                        Ok(output) => {
+                                            println!("{}", output.stdout);
+                                            if output.success {
+                                                record_undo(
+                                                    &config_for_commands,
+                                                    Some(ctx_name),
+                                                    ctx_path,
+                                                    allbeads::undo::OpKind::LabelAdd {
+                                                        id: issue.clone(),
+                                                        label: label.clone(),
+                                                    },
+                                                );
+                                            }
+                                        }
                                         Err(e) => eprintln!("Error: {}", e),
                                     }
                                 }
@@ -2289,11 +3569,24 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                                 .iter()
                                 .find(|c| c.name == ctx_name)
                             {
-                                if let Some(ctx_path) = &ctx.path {
+                                if let Some(ctx_path) = &ctx.local_path() {
                                     let bd =
                                         Beads::with_workdir_and_flags(ctx_path, bd_flags.clone());
                                     match bd.label_remove(&issue, &label) {
-                                        Ok(output) => println!("{}", output.stdout),
+                                        Ok(output) => {
+                                            println!("{}", output.stdout);
+                                            if output.success {
+                                                record_undo(
+                                                    &config_for_commands,
+                                                    Some(ctx_name),
+                                                    ctx_path,
+                                                    allbeads::undo::OpKind::LabelRemove {
+                                                        id: issue.clone(),
+                                                        label: label.clone(),
+                                                    },
+                                                );
+                                            }
+                                        }
                                         Err(e) => eprintln!("Error: {}", e),
                                     }
                                 }
@@ -2306,7 +3599,7 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                 LabelCommands::List => {
                     // List labels from all contexts
                     for ctx in &config_for_commands.contexts {
-                        if let Some(ctx_path) = &ctx.path {
+                        if let Some(ctx_path) = &ctx.local_path() {
                             let bd = Beads::with_workdir_and_flags(ctx_path, bd_flags.to_vec());
                             println!("Labels in @{}:", ctx.name);
                             match bd.label_list() {
@@ -2316,6 +3609,91 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                         }
                     }
                 }
+                LabelCommands::Rename { old, new, context } => {
+                    let (old, new) = allbeads::graph::check_label_rename(&old, &new)?;
+                    let (old, new) = (old.to_string(), new.to_string());
+                    let context = context.as_deref().map(|c| c.trim_start_matches('@'));
+
+                    let mut ids: Vec<String> = graph
+                        .beads
+                        .values()
+                        .filter(|b| b.labels.contains(&old))
+                        .filter(|b| context.is_none_or(|c| bead_context(b).eq_ignore_ascii_case(c)))
+                        .map(|b| b.id.as_str().to_string())
+                        .collect();
+                    ids.sort();
+                    if ids.is_empty() {
+                        println!("No beads have label '{}'", old);
+                        return Ok(());
+                    }
+
+                    let by_context = group_ids_by_context(&ids, &graph, &config_for_commands);
+                    let mut renamed = 0;
+                    let mut failed = 0;
+                    for (ctx_name, bead_ids) in by_context {
+                        let Some(ctx_path) = config_for_commands
+                            .get_context(&ctx_name)
+                            .and_then(|c| c.local_path())
+                        else {
+                            eprintln!("Context '{}' has no local path configured", ctx_name);
+                            failed += bead_ids.len();
+                            continue;
+                        };
+
+                        println!(
+                            "Renaming '{}' to '{}' on {} bead(s) in @{}...",
+                            old,
+                            new,
+                            bead_ids.len(),
+                            ctx_name
+                        );
+                        let bd = Beads::with_workdir_and_flags(&ctx_path, bd_flags.to_vec());
+                        let mut ctx_renamed = Vec::new();
+                        for id in &bead_ids {
+                            let has_new = graph
+                                .beads
+                                .get(&BeadId::from(id.as_str()))
+                                .is_some_and(|b| b.labels.contains(&new));
+                            match rename_bead_label(&bd, id, &old, &new, has_new) {
+                                Ok(()) => {
+                                    renamed += 1;
+                                    ctx_renamed.push(id.clone());
+                                    println!("  {} {}", style::success("✓"), style::issue_id(id));
+                                }
+                                Err(e) => {
+                                    failed += 1;
+                                    eprintln!(
+                                        "  {} {}: {}",
+                                        style::error("✗"),
+                                        style::issue_id(id),
+                                        e
+                                    );
+                                }
+                            }
+                        }
+                        if !ctx_renamed.is_empty() {
+                            record_undo(
+                                &config_for_commands,
+                                Some(&ctx_name),
+                                &ctx_path,
+                                allbeads::undo::OpKind::LabelRename {
+                                    ids: ctx_renamed,
+                                    old: old.clone(),
+                                    new: new.clone(),
+                                },
+                            );
+                        }
+                    }
+
+                    println!();
+                    println!("Renamed label on {} of {} bead(s)", renamed, ids.len());
+                    if failed > 0 {
+                        return Err(allbeads::AllBeadsError::Other(format!(
+                            "{} bead(s) could not be updated",
+                            failed
+                        )));
+                    }
+                }
             }
         }
 
@@ -2361,7 +3739,7 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                                 .iter()
                                 .find(|c| c.name == ctx_name)
                             {
-                                if let Some(ctx_path) = &ctx.path {
+                                if let Some(ctx_path) = &ctx.local_path() {
                                     let bd =
                                         Beads::with_workdir_and_flags(ctx_path, bd_flags.clone());
                                     match bd.comments(&issue) {
@@ -2393,7 +3771,10 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                 issue,
                 content,
                 remote,
+                ..
             } => {
+                // clap requires either content or --stdin, which fills it in
+                let content = content.unwrap_or_default();
                 if remote {
                     // Post to web API
                     let ab_config = AllBeadsConfig::load_default()
@@ -2422,7 +3803,7 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                                 .iter()
                                 .find(|c| c.name == ctx_name)
                             {
-                                if let Some(ctx_path) = &ctx.path {
+                                if let Some(ctx_path) = &ctx.local_path() {
                                     let bd =
                                         Beads::with_workdir_and_flags(ctx_path, bd_flags.clone());
                                     match bd.comment_add(&issue, &content) {
@@ -2445,37 +3826,28 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             priority,
             context,
         } => {
-            // Find the target context
-            let ctx_name = context.unwrap_or_else(|| {
-                let cwd = std::env::current_dir().unwrap_or_default();
-                config_for_commands
-                    .contexts
-                    .iter()
-                    .find(|c| c.path.as_ref().is_some_and(|p| cwd.starts_with(p)))
-                    .map(|c| c.name.clone())
-                    .unwrap_or_else(|| "default".to_string())
-            });
-
-            if let Some(ctx) = config_for_commands
-                .contexts
-                .iter()
-                .find(|c| c.name == ctx_name)
-            {
-                if let Some(ctx_path) = &ctx.path {
-                    let priority_u8 = priority
-                        .as_ref()
-                        .and_then(|p| p.trim_start_matches('P').parse::<u8>().ok());
+            // Find the target context: --context, then cwd, then default_context
+            let cwd = std::env::current_dir().unwrap_or_default();
+            let ctx = config_for_commands.resolve_context(context.as_deref(), &cwd)?;
+            let ctx_name = &ctx.name;
+            if let Some(ctx_path) = &ctx.local_path() {
+                // Omitted flags fall back to the context's defaults, then bd's
+                let issue_type = issue_type.or_else(|| ctx.default_type.clone());
+                let priority_u8 = priority
+                    .or_else(|| ctx.default_priority.clone())
+                    .as_deref()
+                    .map(str::parse::<beads::Priority>)
+                    .transpose()
+                    .map_err(|e| allbeads::AllBeadsError::Parse(e.to_string()))?
+                    .map(u8::from);
 
-                    let bd = Beads::with_workdir_and_flags(ctx_path, bd_flags.to_vec());
-                    match bd.quick_create_full(&title, issue_type.as_deref(), priority_u8) {
-                        Ok(id) => println!("{}", id),
-                        Err(e) => eprintln!("Error: {}", e),
-                    }
-                } else {
-                    eprintln!("Context '{}' has no local path configured", ctx_name);
+                let bd = Beads::with_workdir_and_flags(ctx_path, bd_flags.to_vec());
+                match bd.quick_create_full(&title, issue_type.as_deref(), priority_u8) {
+                    Ok(id) => println!("{}", id),
+                    Err(e) => eprintln!("Error: {}", e),
                 }
             } else {
-                eprintln!("Context '{}' not found", ctx_name);
+                eprintln!("Context '{}' has no local path configured", ctx_name);
             }
         }
 
@@ -2484,7 +3856,7 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                 EpicCommands::List { open } => {
                     // List epics from all contexts
                     for ctx in &config_for_commands.contexts {
-                        if let Some(ctx_path) = &ctx.path {
+                        if let Some(ctx_path) = &ctx.local_path() {
                             let bd = Beads::with_workdir_and_flags(ctx_path, bd_flags.to_vec());
                             let result: beads::Result<Vec<beads::Issue>> = if open {
                                 bd.epic_list_open()
@@ -2497,9 +3869,9 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                                         println!("Epics in @{}:", ctx.name);
                                         for epic in epics {
                                             println!(
-                                                "  {} [P{}] - {}",
+                                                "  {} [{}] - {}",
                                                 epic.id,
-                                                epic.priority.unwrap_or(2),
+                                                epic.priority_typed().unwrap_or_default(),
                                                 epic.title
                                             );
                                         }
@@ -2515,28 +3887,16 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                     priority,
                     context,
                 } => {
-                    let ctx_name = context.clone().unwrap_or_else(|| {
-                        let cwd = std::env::current_dir().unwrap_or_default();
-                        config_for_commands
-                            .contexts
-                            .iter()
-                            .find(|c| c.path.as_ref().is_some_and(|p| cwd.starts_with(p)))
-                            .map(|c| c.name.clone())
-                            .unwrap_or_else(|| "default".to_string())
-                    });
-
-                    if let Some(ctx) = config_for_commands
-                        .contexts
-                        .iter()
-                        .find(|c| c.name == ctx_name)
-                    {
-                        if let Some(ctx_path) = &ctx.path {
-                            let priority_u8 = priority.trim_start_matches('P').parse::<u8>().ok();
-                            let bd = Beads::with_workdir_and_flags(ctx_path, bd_flags.to_vec());
-                            match bd.create_epic(&title, priority_u8) {
-                                Ok(output) => println!("{}", output.stdout),
-                                Err(e) => eprintln!("Error: {}", e),
-                            }
+                    let cwd = std::env::current_dir().unwrap_or_default();
+                    let ctx = config_for_commands.resolve_context(context.as_deref(), &cwd)?;
+                    if let Some(ctx_path) = &ctx.local_path() {
+                        let priority = priority
+                            .parse::<beads::Priority>()
+                            .map_err(|e| allbeads::AllBeadsError::Parse(e.to_string()))?;
+                        let bd = Beads::with_workdir_and_flags(ctx_path, bd_flags.to_vec());
+                        match bd.create_epic(&title, Some(u8::from(priority))) {
+                            Ok(output) => println!("{}", output.stdout),
+                            Err(e) => eprintln!("Error: {}", e),
                         }
                     }
                 }
@@ -2554,7 +3914,7 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                                 .iter()
                                 .find(|c| c.name == ctx_name)
                             {
-                                if let Some(ctx_path) = &ctx.path {
+                                if let Some(ctx_path) = &ctx.local_path() {
                                     let bd =
                                         Beads::with_workdir_and_flags(ctx_path, bd_flags.clone());
                                     match bd.epic_show(&id) {
@@ -2574,14 +3934,43 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                         eprintln!("Epic {} not found", id);
                     }
                 }
-            }
-        }
+                EpicCommands::Stats { id } => {
+                    let bead_id = allbeads::graph::BeadId::from(id.as_str());
+                    let epic = graph
+                        .get_bead(&bead_id)
+                        .ok_or_else(|| allbeads::AllBeadsError::IssueNotFound(id.clone()))?;
+                    let rollup = graph.epic_rollup(&bead_id);
 
-        Commands::Milestones(milestone_cmd) => {
-            handle_milestones_command(&milestone_cmd, &graph, &config_for_commands, &bd_flags)?;
-        }
+                    if cli.json {
+                        println!("{}", serde_json::to_string_pretty(&rollup)?);
+                        return Ok(());
+                    }
 
-        Commands::Edit { id, field } => {
+                    println!("{} {}", style::issue_id(&id), epic.title);
+                    if rollup.total == 0 {
+                        println!("  0% complete {}", style::dim("(no child beads)"));
+                        return Ok(());
+                    }
+                    println!(
+                        "  {:.0}% complete ({} of {} closed)",
+                        rollup.percent, rollup.closed, rollup.total
+                    );
+                    println!(
+                        "  Open: {}  In progress: {}  Blocked: {}  Closed: {}",
+                        style::count_ready(rollup.open),
+                        style::count_in_progress(rollup.in_progress),
+                        style::count_blocked(rollup.blocked),
+                        style::count_normal(rollup.closed)
+                    );
+                }
+            }
+        }
+
+        Commands::Milestones(milestone_cmd) => {
+            handle_milestones_command(&milestone_cmd, &graph, &config_for_commands, &bd_flags)?;
+        }
+
+        Commands::Edit { id, field } => {
             let bead_id = allbeads::graph::BeadId::from(id.as_str());
             if let Some(bead) = graph.beads.get(&bead_id) {
                 if let Some(ctx_name) = bead
@@ -2595,7 +3984,7 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                         .iter()
                         .find(|c| c.name == ctx_name)
                     {
-                        if let Some(ctx_path) = &ctx.path {
+                        if let Some(ctx_path) = &ctx.local_path() {
                             let bd = Beads::with_workdir_and_flags(ctx_path, bd_flags.to_vec());
                             match bd.edit(&id, field.as_deref()) {
                                 Ok(output) => println!("{}", output.stdout),
@@ -2609,23 +3998,17 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
             }
         }
 
-        Commands::Delete { ids, yes: _ } => {
+        Commands::Delete {
+            ids,
+            yes: _,
+            dry_run,
+        } => {
             // Group beads by context
-            let mut by_context: std::collections::HashMap<String, Vec<String>> =
-                std::collections::HashMap::new();
+            let by_context = group_ids_by_context(&ids, &graph, &config_for_commands);
 
-            for id in &ids {
-                let bead_id = allbeads::graph::BeadId::from(id.as_str());
-                if let Some(bead) = graph.beads.get(&bead_id) {
-                    if let Some(ctx_name) = bead
-                        .labels
-                        .iter()
-                        .find(|l| l.starts_with('@'))
-                        .map(|l| l.trim_start_matches('@').to_string())
-                    {
-                        by_context.entry(ctx_name).or_default().push(id.clone());
-                    }
-                }
+            if dry_run {
+                print_dry_run("delete (bd delete --force)", &by_context, &graph);
+                return Ok(());
             }
 
             for (ctx_name, bead_ids) in by_context {
@@ -2634,7 +4017,7 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                     .iter()
                     .find(|c| c.name == ctx_name)
                 {
-                    if let Some(ctx_path) = &ctx.path {
+                    if let Some(ctx_path) = &ctx.local_path() {
                         println!(
                             "Deleting {} bead(s) in context @{}...",
                             bead_ids.len(),
@@ -2647,6 +4030,14 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                             Ok(output) => {
                                 if output.success {
                                     println!("{}", output.stdout);
+                                    record_undo(
+                                        &config_for_commands,
+                                        Some(&ctx_name),
+                                        ctx_path,
+                                        allbeads::undo::OpKind::Delete {
+                                            ids: bead_ids.clone(),
+                                        },
+                                    );
                                 } else {
                                     eprintln!("{}", output.stderr);
                                 }
@@ -2672,7 +4063,7 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
                         .iter()
                         .find(|c| c.name == ctx_name)
                     {
-                        if let Some(ctx_path) = &ctx.path {
+                        if let Some(ctx_path) = &ctx.local_path() {
                             let bd = Beads::with_workdir_and_flags(ctx_path, bd_flags.to_vec());
                             match bd.duplicate(&id, &of) {
                                 Ok(output) => println!("{}", output.stdout),
@@ -2687,6 +4078,15 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
         }
 
         Commands::RenamePrefix { .. }
+        | Commands::Undo { .. }
+        | Commands::Completion { .. }
+        | Commands::Schema { .. }
+        | Commands::Activity { .. }
+        | Commands::CompleteContexts
+        | Commands::CompleteLabels
+        | Commands::Watch { .. }
+        | Commands::History { .. }
+        | Commands::Import { .. }
         | Commands::Context(_)
         | Commands::Init { .. }
         | Commands::OnboardRepo { .. }
@@ -2697,6 +4097,7 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
         | Commands::GitHub(_)
         | Commands::Swarm(_)
         | Commands::Config(_)
+        | Commands::Manifest(_)
         | Commands::Quickstart
         | Commands::Setup
         | Commands::Human { .. }
@@ -2722,10 +4123,48 @@ fn run(mut cli: Cli) -> allbeads::Result<()> {
     Ok(())
 }
 
+fn handle_manifest_command(
+    cmd: &ManifestCommands,
+    config: &AllBeadsConfig,
+) -> allbeads::Result<()> {
+    use allbeads::manifest::Manifest;
+
+    match cmd {
+        ManifestCommands::Generate { output } => {
+            let xml = Manifest::from_config(config).to_xml()?;
+            if let Some(path) = output {
+                std::fs::write(path, &xml)?;
+                eprintln!(
+                    "✓ Wrote manifest with {} project(s) to {}",
+                    config.contexts.len(),
+                    path
+                );
+            } else {
+                print!("{}", xml);
+            }
+        }
+        ManifestCommands::Validate { path } => {
+            if let Err(errors) = allbeads::manifest::validate(Path::new(path)) {
+                for error in &errors {
+                    eprintln!("{} {}:{}", style::error("error:"), path, error);
+                }
+                return Err(allbeads::AllBeadsError::Parse(format!(
+                    "Manifest has {} problem(s)",
+                    errors.len()
+                )));
+            }
+            println!("{} {} is valid", style::success("✓"), path);
+        }
+    }
+
+    Ok(())
+}
+
 fn handle_rename_prefix_command(
     new_prefix: &str,
     from: Option<&str>,
     path: &str,
+    force: bool,
     config_path: &Option<String>,
 ) -> allbeads::Result<()> {
     use beads::Beads;
@@ -2739,63 +4178,9 @@ fn handle_rename_prefix_command(
 
     // Determine target path: either from --from prefix search or --path
     let target_path = if let Some(old_prefix) = from {
-        // Search all contexts for one with matching prefix
-        let mut found_path: Option<std::path::PathBuf> = None;
-
-        for ctx in &config.contexts {
-            if let Some(ref ctx_path) = ctx.path {
-                // First, check config.yaml for issue-prefix
-                let config_file = ctx_path.join(".beads/config.yaml");
-                if config_file.exists() {
-                    if let Ok(content) = std::fs::read_to_string(&config_file) {
-                        for line in content.lines() {
-                            let trimmed = line.trim();
-                            if trimmed.starts_with('#') {
-                                continue;
-                            }
-                            if trimmed.starts_with("issue-prefix:") {
-                                let prefix = trimmed
-                                    .trim_start_matches("issue-prefix:")
-                                    .trim()
-                                    .trim_matches('"')
-                                    .trim_matches('\'');
-                                if prefix == old_prefix {
-                                    found_path = Some(ctx_path.clone());
-                                    break;
-                                }
-                            }
-                        }
-                    }
-                }
-
-                // If not found in config, check issues.jsonl for issue IDs with this prefix
-                if found_path.is_none() {
-                    let jsonl_file = ctx_path.join(".beads/issues.jsonl");
-                    if jsonl_file.exists() {
-                        if let Ok(content) = std::fs::read_to_string(&jsonl_file) {
-                            if let Some(first_line) = content.lines().next() {
-                                if let Ok(issue) =
-                                    serde_json::from_str::<serde_json::Value>(first_line)
-                                {
-                                    if let Some(id) = issue.get("id").and_then(|v| v.as_str()) {
-                                        if let Some(dash_pos) = id.rfind('-') {
-                                            let prefix = &id[..dash_pos];
-                                            if prefix == old_prefix {
-                                                found_path = Some(ctx_path.clone());
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-
-                if found_path.is_some() {
-                    break;
-                }
-            }
-        }
+        let found_path = config
+            .context_for_prefix(old_prefix)
+            .and_then(|ctx| ctx.local_path());
 
         match found_path {
             Some(p) => {
@@ -2810,7 +4195,7 @@ fn handle_rename_prefix_command(
                 eprintln!("Error: No context found with prefix '{}'", old_prefix);
                 eprintln!("Available contexts with local paths:");
                 for ctx in &config.contexts {
-                    if let Some(ref ctx_path) = ctx.path {
+                    if let Some(ctx_path) = ctx.local_path() {
                         eprintln!("  - {} ({})", ctx.name, ctx_path.display());
                     }
                 }
@@ -2823,6 +4208,45 @@ fn handle_rename_prefix_command(
         })?
     };
 
+    // Two contexts sharing a prefix make bead IDs ambiguous in the federated graph
+    let canonical = |p: &Path| std::fs::canonicalize(p).unwrap_or_else(|_| p.to_path_buf());
+    let target_canonical = canonical(&target_path);
+    let conflicts: Vec<&BossContext> = config
+        .contexts_using_prefix(new_prefix)
+        .into_iter()
+        .filter(|ctx| {
+            ctx.local_path()
+                .is_none_or(|p| canonical(&p) != target_canonical)
+        })
+        .collect();
+    if !conflicts.is_empty() {
+        let names: Vec<String> = conflicts
+            .iter()
+            .map(|ctx| match ctx.local_path() {
+                Some(p) => format!("@{} ({})", ctx.name, p.display()),
+                None => format!("@{}", ctx.name),
+            })
+            .collect();
+        if !force {
+            return Err(allbeads::AllBeadsError::Config(format!(
+                "Prefix '{}' is already used by {}. Bead IDs would collide during aggregation; \
+                 pass --force to rename anyway.",
+                new_prefix,
+                names.join(", ")
+            )));
+        }
+        eprintln!(
+            "  {} Prefix '{}' is already used by {}",
+            style::warning("⚠"),
+            new_prefix,
+            names.join(", ")
+        );
+    }
+
+    let old_prefix = from
+        .map(str::to_string)
+        .or_else(|| allbeads::config::read_issue_prefix(&target_path));
+
     let bd = Beads::with_workdir(&target_path);
     match bd.rename_prefix(new_prefix) {
         Ok(output) => {
@@ -2830,6 +4254,17 @@ fn handle_rename_prefix_command(
             if !output.stderr.is_empty() {
                 eprintln!("{}", output.stderr);
             }
+            if let (true, Some(old)) = (output.success, old_prefix) {
+                record_undo(
+                    &config,
+                    None,
+                    &target_path,
+                    allbeads::undo::OpKind::RenamePrefix {
+                        old,
+                        new: new_prefix.to_string(),
+                    },
+                );
+            }
         }
         Err(e) => eprintln!("Error: {}", e),
     }
@@ -2837,733 +4272,2679 @@ fn handle_rename_prefix_command(
     Ok(())
 }
 
-fn handle_init_command(
-    config_path: &Option<String>,
-    remote: Option<&str>,
-    target: Option<&str>,
-    janitor: bool,
-) -> allbeads::Result<()> {
-    // Handle remote repository initialization
-    if let Some(remote_url) = remote {
-        return handle_remote_init(remote_url, target, janitor);
-    }
-
-    // Standard local config initialization
-    let config_file = if let Some(path) = config_path {
-        PathBuf::from(path)
-    } else {
-        AllBeadsConfig::default_path()
+/// Dynamic completion for bash: context and label values come from `ab` itself
+const BASH_DYNAMIC_COMPLETION: &str = r#"
+_ab_dynamic() {
+    local cur="${COMP_WORDS[COMP_CWORD]}"
+    local prev="${COMP_WORDS[COMP_CWORD-1]}"
+    case "$prev" in
+        --context|--contexts|-C)
+            COMPREPLY=($(compgen -W "$("${COMP_WORDS[0]}" __complete_contexts 2>/dev/null)" -- "$cur"))
+            return 0
+            ;;
+        --label|-l)
+            COMPREPLY=($(compgen -W "$("${COMP_WORDS[0]}" __complete_labels 2>/dev/null)" -- "$cur"))
+            return 0
+            ;;
+    esac
+    _ab "$@"
+}
+complete -F _ab_dynamic -o bashdefault -o default ab allbeads
+"#;
+
+/// Dynamic completion for zsh
+const ZSH_DYNAMIC_COMPLETION: &str = r#"
+_ab_dynamic() {
+    case "${words[CURRENT-1]}" in
+        --context|--contexts|-C)
+            compadd -- ${(f)"$(${words[1]} __complete_contexts 2>/dev/null)"}
+            ;;
+        --label|-l)
+            compadd -- ${(f)"$(${words[1]} __complete_labels 2>/dev/null)"}
+            ;;
+        *)
+            _ab "$@"
+            ;;
+    esac
+}
+compdef _ab_dynamic ab allbeads
+"#;
+
+/// Dynamic completion for fish
+const FISH_DYNAMIC_COMPLETION: &str = r#"
+complete -c ab -l context -f -a '((commandline -opc)[1] __complete_contexts 2>/dev/null)'
+complete -c ab -s C -l contexts -f -a '((commandline -opc)[1] __complete_contexts 2>/dev/null)'
+complete -c ab -s l -l label -f -a '((commandline -opc)[1] __complete_labels 2>/dev/null)'
+complete -c allbeads -w ab
+"#;
+
+/// Handle `ab schema`
+fn handle_schema_command(schema_type: SchemaType) -> allbeads::Result<()> {
+    let schema = match schema_type {
+        SchemaType::Bead => schemars::schema_for!(allbeads::graph::Bead),
+        SchemaType::Issue => schemars::schema_for!(beads::Issue),
     };
+    println!("{}", serde_json::to_string_pretty(&schema)?);
+    Ok(())
+}
 
-    // Check if already initialized
-    if config_file.exists() {
-        println!("Configuration already exists at {}", config_file.display());
-        println!();
-        println!("To add contexts, run:");
-        println!("  ab context add <name> <repo-path>");
-        return Ok(());
-    }
-
-    // Create parent directory if needed
-    if let Some(parent) = config_file.parent() {
-        std::fs::create_dir_all(parent).map_err(|e| {
-            allbeads::AllBeadsError::Config(format!(
-                "Failed to create config directory {}: {}",
-                parent.display(),
-                e
-            ))
-        })?;
-    }
+fn handle_completion_command(shell: clap_complete::Shell) -> allbeads::Result<()> {
+    use clap::CommandFactory;
+    use clap_complete::Shell;
 
-    // Create empty config
-    let config = AllBeadsConfig::new();
-    config.save(&config_file)?;
+    let mut stdout = io::stdout();
+    clap_complete::generate(shell, &mut Cli::command(), "ab", &mut stdout);
 
-    println!("✓ Created configuration at {}", config_file.display());
-    println!();
-    println!("Next steps:");
-    println!("  1. Add a context (from within a git repo with beads):");
-    println!("     cd /path/to/repo && ab context add");
-    println!();
-    println!("  2. View aggregated beads:");
-    println!("     ab stats");
-    println!("     ab list");
-    println!("     ab tui");
+    // Context names and labels aren't known statically; the generated
+    // completions are wrapped so those values are fetched at completion time
+    let dynamic = match shell {
+        Shell::Bash => BASH_DYNAMIC_COMPLETION,
+        Shell::Zsh => ZSH_DYNAMIC_COMPLETION,
+        Shell::Fish => FISH_DYNAMIC_COMPLETION,
+        _ => "",
+    };
+    stdout.write_all(dynamic.as_bytes())?;
+    Ok(())
+}
 
+/// Print configured context names for shell completion
+///
+/// Errors are swallowed: completion should never print noise.
+fn handle_complete_contexts_command(config_path: &Option<String>) -> allbeads::Result<()> {
+    let config = match config_path {
+        Some(path) => AllBeadsConfig::load(path),
+        None => AllBeadsConfig::load_default(),
+    };
+    if let Ok(config) = config {
+        for ctx in &config.contexts {
+            println!("{}", ctx.name);
+        }
+    }
     Ok(())
 }
 
-/// Initialize a remote repository with beads
-fn handle_remote_init(
-    remote_url: &str,
-    target: Option<&str>,
-    janitor: bool,
+/// Print per-context ready/blocked counts for `ab summary`
+fn handle_summary_command(
+    graph: &FederatedGraph,
+    context_filter: &[String],
+    oneline: bool,
+    json: bool,
 ) -> allbeads::Result<()> {
-    use allbeads::git::BossRepo;
-    use allbeads::storage::BeadsRepo;
+    let mut summaries = allbeads::graph::context_summary(graph);
+    if !context_filter.is_empty() {
+        summaries.retain(|s| {
+            context_filter
+                .iter()
+                .any(|f| f.eq_ignore_ascii_case(&s.context))
+        });
+    }
 
-    // Derive target directory from URL if not specified
-    let target_dir = if let Some(t) = target {
-        PathBuf::from(t)
-    } else {
-        // Extract repo name from URL
-        let repo_name = remote_url
-            .trim_end_matches('/')
-            .trim_end_matches(".git")
-            .rsplit('/')
-            .next()
-            .unwrap_or("repo");
-        PathBuf::from(repo_name)
-    };
+    if json {
+        println!("{}", serde_json::to_string_pretty(&summaries)?);
+        return Ok(());
+    }
+    if oneline {
+        let line = allbeads::graph::format_oneline(&summaries);
+        if !line.is_empty() {
+            println!("{}", line);
+        }
+        return Ok(());
+    }
 
-    // Check if target already exists
-    if target_dir.exists() {
-        return Err(allbeads::AllBeadsError::Config(format!(
-            "Target directory already exists: {}",
-            target_dir.display()
-        )));
+    if summaries.is_empty() {
+        println!("No beads in the cache. Run 'ab list' to load them.");
+        return Ok(());
+    }
+    let width = summaries
+        .iter()
+        .map(|s| s.context.len() + 1)
+        .max()
+        .unwrap_or(0);
+    for s in &summaries {
+        println!(
+            "{:width$}  {} ready  {} in progress  {} blocked",
+            format!("@{}", s.context),
+            style::count_ready(s.ready),
+            style::count_in_progress(s.in_progress),
+            style::count_blocked(s.blocked),
+            width = width
+        );
     }
+    Ok(())
+}
 
-    println!("Cloning {} to {}...", remote_url, target_dir.display());
+/// `bd` for the context a bead lives in
+///
+/// Uses the bead's `@context` label, falling back to its ID prefix.
+fn bd_for_bead(
+    bead: &allbeads::graph::Bead,
+    config: &AllBeadsConfig,
+    bd_flags: &[String],
+) -> allbeads::Result<Beads> {
+    let id = bead.id.as_str();
+    let ctx = match bead.labels.iter().find_map(|l| l.strip_prefix('@')) {
+        Some(name) => config.contexts.iter().find(|c| c.name == name),
+        None => config.context_for_bead_id(id),
+    }
+    .ok_or_else(|| {
+        allbeads::AllBeadsError::Config(format!("Could not determine context for bead {}", id))
+    })?;
+    let path = ctx.local_path().ok_or_else(|| {
+        allbeads::AllBeadsError::Config(format!(
+            "Context '{}' has no local path configured",
+            ctx.name
+        ))
+    })?;
+    Ok(Beads::with_workdir_and_flags(path, bd_flags.to_vec()))
+}
 
-    // Clone the repository
-    let _repo = git2::Repository::clone(remote_url, &target_dir)
-        .map_err(|e| allbeads::AllBeadsError::Git(format!("Failed to clone repository: {}", e)))?;
+/// Resolve a duplicate pair for `ab duplicates merge`
+///
+/// Marks `dup` as a duplicate of `keep`, points beads that depended on `dup`
+/// at `keep` instead, and copies over labels `keep` lacks. Each bead is
+/// updated through its own context, so the pair may span contexts. Exits
+/// non-zero if any dependent or label could not be updated.
+fn handle_duplicates_merge(
+    graph: &FederatedGraph,
+    config: &AllBeadsConfig,
+    keep: &str,
+    dup: &str,
+    bd_flags: &[String],
+    json: bool,
+) -> allbeads::Result<()> {
+    if keep == dup {
+        return Err(allbeads::AllBeadsError::Other(
+            "Cannot merge a bead into itself".to_string(),
+        ));
+    }
+    let find = |id: &str| {
+        graph
+            .beads
+            .get(&BeadId::from(id))
+            .ok_or_else(|| allbeads::AllBeadsError::IssueNotFound(id.to_string()))
+    };
+    let keep_bead = find(keep)?;
+    let dup_bead = find(dup)?;
+    let keep_bd = bd_for_bead(keep_bead, config, bd_flags)?;
+    let dup_bd = bd_for_bead(dup_bead, config, bd_flags)?;
 
-    println!("✓ Repository cloned");
+    dup_bd.duplicate(dup, keep).map_err(|e| {
+        allbeads::AllBeadsError::Other(format!("Failed to mark {} as duplicate: {}", dup, e))
+    })?;
 
-    // Check if .beads/ already exists
-    let beads_dir = target_dir.join(".beads");
-    let already_has_beads = beads_dir.exists();
+    let mut repointed = Vec::new();
+    let mut failed = Vec::new();
+    for dependent in graph.get_dependents(&dup_bead.id) {
+        let id = dependent.id.as_str();
+        let result = bd_for_bead(dependent, config, bd_flags).and_then(|bd| {
+            let bd_err = |e: beads::Error| allbeads::AllBeadsError::Other(e.to_string());
+            // Add the new edge first, so a failure (e.g. keep lives in another
+            // context) leaves the dependent still blocked by dup.
+            // keep can't depend on itself, and an existing edge needs no copy
+            if id != keep && !dependent.dependencies.iter().any(|d| d.as_str() == keep) {
+                bd.dep_add(id, keep).map_err(bd_err)?;
+            }
+            bd.dep_remove(id, dup).map_err(bd_err)?;
+            Ok(())
+        });
+        match result {
+            Ok(()) => repointed.push(id),
+            Err(e) => failed.push(format!("{}: {}", id, e)),
+        }
+    }
 
-    if !already_has_beads {
-        // Initialize beads using BeadsRepo
-        let beads_repo = BeadsRepo::with_workdir(&target_dir);
-        beads_repo.init()?;
-        println!("✓ Initialized .beads/ directory");
+    let mut labels = Vec::new();
+    for label in bead_plain_labels(dup_bead) {
+        if keep_bead.labels.iter().any(|l| l == label) {
+            continue;
+        }
+        match keep_bd.label_add(keep, label) {
+            Ok(_) => labels.push(label),
+            Err(e) => failed.push(format!("label {}: {}", label, e)),
+        }
+    }
 
-        // Create an initial Analysis bead using the create API
-        beads_repo.create("Initial codebase analysis", "task", Some(1))?;
-        println!("✓ Created initial Analysis bead");
+    if json {
+        let output = serde_json::json!({
+            "keep": keep,
+            "duplicate": dup,
+            "repointed": repointed,
+            "labels": labels,
+            "errors": failed,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        if !failed.is_empty() {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
 
-        // Commit the .beads/ directory using BossRepo
-        let boss_repo = BossRepo::from_local(&target_dir)?;
-        boss_repo.add_beads()?;
-        boss_repo.commit(
-            "Initialize beads tracking\n\nAdded .beads/ directory with initial Analysis bead",
-            "AllBeads",
-            "noreply@allbeads.dev",
-        )?;
-        println!("✓ Committed .beads/ directory");
+    println!(
+        "{} Marked {} as a duplicate of {}",
+        style::success("✓"),
+        style::issue_id(dup),
+        style::issue_id(keep)
+    );
+    if repointed.is_empty() {
+        println!("  No dependents to re-point");
     } else {
-        println!("✓ Repository already has .beads/ directory");
+        println!("  Re-pointed {} dependent(s) to {}:", repointed.len(), keep);
+        for id in &repointed {
+            println!("    {}", style::issue_id(id));
+        }
     }
-
-    // Run janitor if requested
-    if janitor {
-        println!();
-        println!("Running janitor analysis...");
-        run_janitor_analysis(&target_dir)?;
+    if !labels.is_empty() {
+        println!("  Transferred labels: {}", labels.join(", "));
+    }
+    for error in &failed {
+        eprintln!("  {} {}", style::warning("⚠"), error);
+    }
+    if !failed.is_empty() {
+        std::process::exit(1);
     }
-
-    println!();
-    println!("Repository initialized successfully!");
-    println!();
-    println!("Next steps:");
-    println!("  cd {} && ab context add", target_dir.display());
-    println!("  ab list");
-
     Ok(())
 }
 
-/// Run janitor analysis to scan codebase and create issues
-fn run_janitor_analysis(repo_path: &Path) -> allbeads::Result<()> {
-    use allbeads::git::BossRepo;
-    use allbeads::storage::BeadsRepo;
+/// Poll a bead until it reaches the `until` status, for `ab watch`
+///
+/// Status changes are printed as they are seen. Returns an error (and so a
+/// non-zero exit) if `timeout` passes first.
+fn handle_watch_command(
+    config: &AllBeadsConfig,
+    id: &str,
+    until: &str,
+    timeout: Option<&str>,
+    interval: &str,
+    bd_flags: &[String],
+    json: bool,
+) -> allbeads::Result<()> {
+    let target = parse_status(until)?;
+    let timeout = timeout
+        .map(allbeads::duration::parse_duration)
+        .transpose()?
+        .map(|d| d.to_std().unwrap_or_default());
+    let interval = allbeads::duration::parse_duration(interval)?
+        .to_std()
+        .unwrap_or_default();
+    if interval.is_zero() {
+        return Err(allbeads::AllBeadsError::Config(
+            "--interval must be greater than zero".to_string(),
+        ));
+    }
 
-    let beads_repo = BeadsRepo::with_workdir(repo_path);
-    let mut created_count = 0;
+    let ctx = config.context_for_bead_id(id).ok_or_else(|| {
+        allbeads::AllBeadsError::Config(format!("No context matches the prefix of bead {}", id))
+    })?;
+    let path = ctx.local_path().ok_or_else(|| {
+        allbeads::AllBeadsError::Config(format!(
+            "Context '{}' has no local path configured",
+            ctx.name
+        ))
+    })?;
+    let bd = Beads::with_workdir_and_flags(path, bd_flags.to_vec());
 
-    // Check for missing README
-    if !repo_path.join("README.md").exists() && !repo_path.join("README").exists() {
-        beads_repo.create("Add README documentation", "chore", Some(2))?;
-        println!("  Created: Add README documentation");
-        created_count += 1;
-    }
+    let start = std::time::Instant::now();
+    let mut last_status = None;
+    loop {
+        let issue = bd.show(id).map_err(|e| match e {
+            beads::Error::IssueNotFound(_) => {
+                allbeads::AllBeadsError::IssueNotFound(id.to_string())
+            }
+            e => allbeads::AllBeadsError::Other(format!("bd show {} failed: {}", id, e)),
+        })?;
+        let status = parse_status(&issue.status)?;
 
-    // Check for missing license
-    let license_files = ["LICENSE", "LICENSE.md", "LICENSE.txt", "COPYING"];
-    let has_license = license_files.iter().any(|f| repo_path.join(f).exists());
-    if !has_license {
-        beads_repo.create("Add LICENSE file", "chore", Some(3))?;
-        println!("  Created: Add LICENSE file");
-        created_count += 1;
-    }
+        if last_status != Some(status) && !json {
+            println!(
+                "{} {} is {}",
+                style::dim(&chrono::Local::now().format("%H:%M:%S").to_string()),
+                style::issue_id(id),
+                format_status(status)
+            );
+        }
+        last_status = Some(status);
 
-    // Check for common config files
-    let has_gitignore = repo_path.join(".gitignore").exists();
-    if !has_gitignore {
-        beads_repo.create("Add .gitignore file", "chore", Some(3))?;
-        println!("  Created: Add .gitignore file");
-        created_count += 1;
-    }
+        if status == target {
+            if json {
+                let output = serde_json::json!({
+                    "id": id,
+                    "status": format_status(status),
+                    "elapsed_secs": start.elapsed().as_secs(),
+                });
+                println!("{}", serde_json::to_string_pretty(&output)?);
+            }
+            return Ok(());
+        }
 
-    // Look for TODO/FIXME comments in source files
-    let todo_patterns = scan_for_todos(repo_path)?;
-    for (_file, _line, text) in todo_patterns.iter().take(10) {
-        let title = if text.len() > 60 {
-            format!("TODO: {}...", &text[..57])
-        } else {
-            format!("TODO: {}", text)
+        let wait = match timeout {
+            Some(timeout) => {
+                let Some(remaining) = timeout.checked_sub(start.elapsed()) else {
+                    return Err(allbeads::AllBeadsError::Other(format!(
+                        "Timed out after {}s waiting for {} to be {} (still {})",
+                        timeout.as_secs(),
+                        id,
+                        format_status(target),
+                        format_status(status)
+                    )));
+                };
+                interval.min(remaining)
+            }
+            None => interval,
         };
-        beads_repo.create(&title, "task", Some(3))?;
-        println!("  Created: {}", title);
-        created_count += 1;
-    }
-
-    if todo_patterns.len() > 10 {
-        println!(
-            "  ... and {} more TODOs found (limited to 10)",
-            todo_patterns.len() - 10
-        );
+        std::thread::sleep(wait);
     }
+}
 
-    // Commit janitor findings if we created any
-    if created_count > 0 {
-        let boss_repo = BossRepo::from_local(repo_path)?;
-        boss_repo.add_beads()?;
-        boss_repo.commit(
-            &format!(
-                "Janitor: Created {} issues from codebase analysis",
-                created_count
-            ),
-            "AllBeads Janitor",
-            "janitor@allbeads.dev",
-        )?;
-        println!();
-        println!("✓ Created {} issues from janitor analysis", created_count);
-    } else {
-        println!("✓ No issues found - codebase looks clean!");
+/// Print labels from the cached graph for shell completion
+///
+/// Only the cache is read so completion stays fast; nothing is printed when
+/// no graph has been cached yet.
+fn handle_complete_labels_command() -> allbeads::Result<()> {
+    let Ok(Some(graph)) = Cache::new(CacheConfig::default()).and_then(|c| c.load_graph()) else {
+        return Ok(());
+    };
+    let labels: std::collections::BTreeSet<&str> = graph
+        .beads
+        .values()
+        .flat_map(|b| b.labels.iter())
+        .map(String::as_str)
+        .filter(|l| !l.starts_with('@'))
+        .collect();
+    for label in labels {
+        println!("{}", label);
     }
-
     Ok(())
 }
 
-/// Scan repository for TODO/FIXME comments
-fn scan_for_todos(repo_path: &std::path::Path) -> allbeads::Result<Vec<(String, usize, String)>> {
-    let mut results = Vec::new();
-
-    // Walk directory looking for source files
-    fn walk_dir(
-        dir: &std::path::Path,
-        base: &std::path::Path,
-        results: &mut Vec<(String, usize, String)>,
-    ) -> std::io::Result<()> {
-        if dir.is_dir() {
-            // Skip common ignored directories
-            let dir_name = dir.file_name().and_then(|n| n.to_str()).unwrap_or("");
-            if dir_name.starts_with('.')
-                || dir_name == "node_modules"
-                || dir_name == "target"
-                || dir_name == "vendor"
-                || dir_name == "dist"
-                || dir_name == "build"
-            {
-                return Ok(());
-            }
+fn handle_import_command(
+    file: &str,
+    context: &str,
+    format: ImportFormat,
+    dry_run: bool,
+    config_path: &Option<String>,
+    bd_flags: &[String],
+) -> allbeads::Result<()> {
+    let config = if let Some(ref cp) = config_path {
+        AllBeadsConfig::load(cp)?
+    } else {
+        AllBeadsConfig::load_default()?
+    };
+    let context = context.trim_start_matches('@');
+    let ctx = config.get_context(context).ok_or_else(|| {
+        allbeads::AllBeadsError::Config(format!("Context '{}' not found", context))
+    })?;
+    let ctx_path = ctx.local_path().ok_or_else(|| {
+        allbeads::AllBeadsError::Config(format!(
+            "Context '{}' has no local path configured",
+            context
+        ))
+    })?;
 
-            for entry in std::fs::read_dir(dir)? {
-                let entry = entry?;
-                let path = entry.path();
-                if path.is_dir() {
-                    walk_dir(&path, base, results)?;
-                } else if is_source_file(&path) {
-                    scan_file_for_todos(&path, base, results)?;
+    let content = std::fs::read_to_string(file)?;
+    let mut issues = Vec::new();
+    let mut skipped = 0;
+    match format {
+        ImportFormat::Jsonl => {
+            for (n, line) in content.lines().enumerate() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match serde_json::from_str::<beads::Issue>(line) {
+                    Ok(issue) => issues.push(issue),
+                    Err(e) => {
+                        skipped += 1;
+                        eprintln!("  {} line {}: {}", style::warning("⚠"), n + 1, e);
+                    }
                 }
             }
         }
-        Ok(())
     }
 
-    fn is_source_file(path: &std::path::Path) -> bool {
-        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-        matches!(
-            ext,
-            "rs" | "py"
-                | "js"
-                | "ts"
-                | "tsx"
-                | "jsx"
-                | "go"
-                | "java"
-                | "c"
-                | "cpp"
-                | "h"
-                | "hpp"
-                | "rb"
-                | "php"
-                | "swift"
-                | "kt"
-                | "scala"
-        )
+    // Keep the original ID only when it already belongs to this context
+    let keeps_id = |id: &str| {
+        ctx.issue_prefix().is_some_and(|prefix| {
+            id.strip_prefix(prefix)
+                .is_some_and(|rest| rest.starts_with('-'))
+        })
+    };
+
+    if dry_run {
+        println!(
+            "  {} Dry run - would import {} bead(s) into @{}:",
+            style::dim("→"),
+            issues.len(),
+            context
+        );
+        for issue in &issues {
+            let id = if keeps_id(&issue.id) {
+                style::issue_id(&issue.id).to_string()
+            } else {
+                format!("{} (new ID)", style::dim(&issue.id))
+            };
+            println!("    {} {}", id, issue.title);
+        }
+        if skipped > 0 {
+            println!();
+            println!("Skipped {} line(s) that could not be parsed", skipped);
+        }
+        return Ok(());
     }
 
-    fn scan_file_for_todos(
-        path: &std::path::Path,
-        base: &std::path::Path,
-        results: &mut Vec<(String, usize, String)>,
-    ) -> std::io::Result<()> {
-        let content = std::fs::read_to_string(path)?;
-        let relative_path = path
-            .strip_prefix(base)
-            .unwrap_or(path)
-            .to_string_lossy()
-            .to_string();
+    println!("Importing {} bead(s) into @{}...", issues.len(), context);
+    let bd = Beads::with_workdir_and_flags(&ctx_path, bd_flags.to_vec());
+    let mut created = 0;
+    let mut failed = 0;
+    for issue in &issues {
+        let labels: Vec<&str> = issue
+            .labels
+            .iter()
+            .map(String::as_str)
+            .filter(|l| !l.starts_with('@'))
+            .collect();
+        let result = if keeps_id(&issue.id) {
+            // create_full has no --id, so build the command directly
+            let priority = issue.priority.map(|p| p.to_string());
+            let mut args = vec![
+                "create",
+                "--id",
+                issue.id.as_str(),
+                "--title",
+                issue.title.as_str(),
+                "--type",
+                issue.issue_type.as_str(),
+            ];
+            if let Some(p) = &priority {
+                args.extend(["--priority", p.as_str()]);
+            }
+            if let Some(desc) = &issue.description {
+                args.extend(["--description", desc.as_str()]);
+            }
+            if let Some(user) = &issue.assignee {
+                args.extend(["--assignee", user.as_str()]);
+            }
+            for &label in &labels {
+                args.extend(["--label", label]);
+            }
+            bd.run(&args)
+        } else {
+            bd.create_full(
+                &issue.title,
+                &issue.issue_type,
+                issue.priority,
+                issue.description.as_deref(),
+                issue.assignee.as_deref(),
+                None, // parent IDs from another tool won't resolve here
+                Some(labels.as_slice()),
+            )
+        };
 
-        for (line_num, line) in content.lines().enumerate() {
-            let line_upper = line.to_uppercase();
-            if line_upper.contains("TODO")
-                || line_upper.contains("FIXME")
-                || line_upper.contains("HACK")
-            {
-                // Extract the comment text
-                let text = line.trim().to_string();
-                if !text.is_empty() && results.len() < 100 {
-                    results.push((relative_path.clone(), line_num + 1, text));
+        match result {
+            Ok(output) if output.success => {
+                created += 1;
+                println!(
+                    "  {} {} {}",
+                    style::success("✓"),
+                    style::issue_id(&issue.id),
+                    issue.title
+                );
+                // bd always creates beads open, so carry other statuses over
+                if issue.status != "open" {
+                    let new_id = if keeps_id(&issue.id) {
+                        Some(issue.id.as_str())
+                    } else {
+                        output
+                            .stdout
+                            .lines()
+                            .find(|l| l.contains("Created issue:"))
+                            .and_then(|l| l.split(':').nth(1))
+                            .map(str::trim)
+                    };
+                    if let Some(new_id) = new_id {
+                        let problem = match bd.update_status(new_id, &issue.status) {
+                            Ok(output) if output.success => None,
+                            Ok(output) => Some(output.stderr.trim().to_string()),
+                            Err(e) => Some(e.to_string()),
+                        };
+                        if let Some(problem) = problem {
+                            eprintln!(
+                                "    {} could not set status '{}': {}",
+                                style::warning("⚠"),
+                                issue.status,
+                                problem
+                            );
+                        }
+                    }
                 }
             }
+            Ok(output) => {
+                failed += 1;
+                eprintln!(
+                    "  {} {}: {}",
+                    style::error("✗"),
+                    style::issue_id(&issue.id),
+                    output.stderr.trim()
+                );
+            }
+            Err(e) => {
+                failed += 1;
+                eprintln!(
+                    "  {} {}: {}",
+                    style::error("✗"),
+                    style::issue_id(&issue.id),
+                    e
+                );
+            }
         }
-        Ok(())
     }
 
-    walk_dir(repo_path, repo_path, &mut results).map_err(allbeads::AllBeadsError::Io)?;
-
-    Ok(results)
+    println!();
+    println!(
+        "Created {}, skipped {} (parse errors), failed {}",
+        created, skipped, failed
+    );
+    if failed > 0 {
+        return Err(allbeads::AllBeadsError::Other(format!(
+            "{} bead(s) could not be created",
+            failed
+        )));
+    }
+    Ok(())
 }
 
-/// Run comprehensive janitor analysis on a repository
-fn run_full_janitor_analysis(
-    repo_path: &Path,
-    verbose: bool,
-    dry_run: bool,
+fn handle_undo_command(
+    config_path: &Option<String>,
+    list: bool,
+    skip: bool,
+    bd_flags: &[String],
 ) -> allbeads::Result<()> {
-    use allbeads::git::BossRepo;
-    use allbeads::storage::BeadsRepo;
+    use allbeads::undo::{OpKind, UndoLog};
 
-    let mut findings: Vec<JanitorFinding> = Vec::new();
+    // The log lives next to the config file the recording commands loaded
+    let config = if let Some(ref cp) = config_path {
+        AllBeadsConfig::load(cp)?
+    } else {
+        AllBeadsConfig::load_default()?
+    };
+    let log = UndoLog::for_config(&config);
 
-    // Check for missing documentation
-    println!("Checking documentation...");
+    if list {
+        let entries = log.entries()?;
+        if entries.is_empty() {
+            println!("Undo log is empty");
+            return Ok(());
+        }
+        for op in entries.iter().rev() {
+            let marker = if op.kind.is_reversible() {
+                style::dim("·")
+            } else {
+                style::warning("✗")
+            };
+            let context = op
+                .context
+                .as_deref()
+                .map(|c| format!("@{} ", c))
+                .unwrap_or_default();
+            println!(
+                "  {} {} {}{}",
+                marker,
+                style::dim(&op.timestamp.format("%Y-%m-%d %H:%M").to_string()),
+                context,
+                op.kind.describe()
+            );
+        }
+        return Ok(());
+    }
+
+    if skip {
+        match log.discard_last()? {
+            Some(op) => println!(
+                "{} Dropped '{}' from the undo log",
+                style::success("✓"),
+                op.kind.describe()
+            ),
+            None => println!("Undo log is empty"),
+        }
+        return Ok(());
+    }
+
+    let op = match log.pop() {
+        Ok(Some(op)) => op,
+        Ok(None) => {
+            println!("Nothing to undo");
+            return Ok(());
+        }
+        Err(e) => {
+            return Err(allbeads::AllBeadsError::Other(format!(
+                "{}. Run 'ab undo --skip' to drop it from the log.",
+                e
+            )))
+        }
+    };
+
+    println!("Undoing: {}", op.kind.describe());
+
+    let bd = Beads::with_workdir_and_flags(&op.path, bd_flags.to_vec());
+    let check = |result: beads::Result<beads::CommandOutput>| match result {
+        Ok(output) if output.success => Ok(()),
+        Ok(output) => Err(output.stderr.trim().to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+    let result = match &op.kind {
+        OpKind::Close { ids } => {
+            let id_refs: Vec<&str> = ids.iter().map(String::as_str).collect();
+            check(bd.reopen_multiple(&id_refs))
+        }
+        OpKind::Reopen { ids } => {
+            let id_refs: Vec<&str> = ids.iter().map(String::as_str).collect();
+            check(bd.close_multiple(&id_refs))
+        }
+        OpKind::LabelAdd { id, label } => check(bd.label_remove(id, label)),
+        OpKind::LabelRemove { id, label } => check(bd.label_add(id, label)),
+        OpKind::LabelRename { ids, old, new } => ids
+            .iter()
+            .try_for_each(|id| rename_bead_label(&bd, id, new, old, false)),
+        OpKind::Update { id, before } => check(bd.update(
+            id,
+            before.status.as_deref(),
+            before.priority,
+            before.assignee.as_deref(),
+            None,
+            before.description.as_deref(),
+            before.notes.as_deref(),
+        )),
+        OpKind::RenamePrefix { old, .. } => check(bd.rename_prefix(old)),
+        OpKind::Delete { .. } => unreachable!("pop() refuses irreversible operations"),
+    };
+
+    match result {
+        Ok(()) => {
+            println!("{} Undone", style::success("✓"));
+            Ok(())
+        }
+        Err(e) => {
+            // Put the entry back so the undo can be retried
+            log.record(&op)?;
+            Err(allbeads::AllBeadsError::Other(format!(
+                "Failed to undo '{}': {}",
+                op.kind.describe(),
+                e
+            )))
+        }
+    }
+}
+
+fn handle_init_command(
+    config_path: &Option<String>,
+    remote: Option<&str>,
+    target: Option<&str>,
+    janitor: bool,
+) -> allbeads::Result<()> {
+    // Handle remote repository initialization
+    if let Some(remote_url) = remote {
+        return handle_remote_init(remote_url, target, janitor);
+    }
+
+    // Standard local config initialization
+    let config_file = if let Some(path) = config_path {
+        PathBuf::from(path)
+    } else {
+        AllBeadsConfig::default_path()
+    };
+
+    // Check if already initialized
+    if config_file.exists() {
+        println!("Configuration already exists at {}", config_file.display());
+        println!();
+        println!("To add contexts, run:");
+        println!("  ab context add <name> <repo-path>");
+
+        // Courtesy check of the existing config
+        if let Ok(config) = AllBeadsConfig::load(&config_file) {
+            let issues = config.validate();
+            if !issues.is_empty() {
+                println!();
+                println!("Found {} config issue(s):", issues.len());
+                print_config_issues(&issues);
+                println!();
+                println!("Run 'ab config check' for details.");
+            }
+        }
+        return Ok(());
+    }
+
+    // Create parent directory if needed
+    if let Some(parent) = config_file.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| {
+            allbeads::AllBeadsError::Config(format!(
+                "Failed to create config directory {}: {}",
+                parent.display(),
+                e
+            ))
+        })?;
+    }
+
+    // Create empty config
+    let config = AllBeadsConfig::new();
+    config.save(&config_file)?;
+
+    println!("✓ Created configuration at {}", config_file.display());
+    println!();
+    println!("Next steps:");
+    println!("  1. Add a context (from within a git repo with beads):");
+    println!("     cd /path/to/repo && ab context add");
+    println!();
+    println!("  2. View aggregated beads:");
+    println!("     ab stats");
+    println!("     ab list");
+    println!("     ab tui");
+
+    Ok(())
+}
+
+/// Initialize a remote repository with beads
+fn handle_remote_init(
+    remote_url: &str,
+    target: Option<&str>,
+    janitor: bool,
+) -> allbeads::Result<()> {
+    use allbeads::git::BossRepo;
+    use allbeads::storage::BeadsRepo;
+
+    // Derive target directory from URL if not specified
+    let target_dir = if let Some(t) = target {
+        PathBuf::from(t)
+    } else {
+        // Extract repo name from URL
+        let repo_name = remote_url
+            .trim_end_matches('/')
+            .trim_end_matches(".git")
+            .rsplit('/')
+            .next()
+            .unwrap_or("repo");
+        PathBuf::from(repo_name)
+    };
+
+    // Check if target already exists
+    if target_dir.exists() {
+        return Err(allbeads::AllBeadsError::Config(format!(
+            "Target directory already exists: {}",
+            target_dir.display()
+        )));
+    }
+
+    println!("Cloning {} to {}...", remote_url, target_dir.display());
+
+    // Clone the repository
+    let _repo = git2::Repository::clone(remote_url, &target_dir)
+        .map_err(|e| allbeads::AllBeadsError::Git(format!("Failed to clone repository: {}", e)))?;
+
+    println!("✓ Repository cloned");
+
+    // Check if .beads/ already exists
+    let beads_dir = target_dir.join(".beads");
+    let already_has_beads = beads_dir.exists();
+
+    if !already_has_beads {
+        // Initialize beads using BeadsRepo
+        let beads_repo = BeadsRepo::with_workdir(&target_dir);
+        beads_repo.init()?;
+        println!("✓ Initialized .beads/ directory");
+
+        // Create an initial Analysis bead using the create API
+        beads_repo.create("Initial codebase analysis", "task", Some(1))?;
+        println!("✓ Created initial Analysis bead");
+
+        // Commit the .beads/ directory using BossRepo
+        let boss_repo = BossRepo::from_local(&target_dir)?;
+        boss_repo.add_beads()?;
+        boss_repo.commit(
+            "Initialize beads tracking\n\nAdded .beads/ directory with initial Analysis bead",
+            "AllBeads",
+            "noreply@allbeads.dev",
+        )?;
+        println!("✓ Committed .beads/ directory");
+    } else {
+        println!("✓ Repository already has .beads/ directory");
+    }
+
+    // Run janitor if requested
+    if janitor {
+        println!();
+        println!("Running janitor analysis...");
+        run_janitor_analysis(&target_dir)?;
+    }
+
+    println!();
+    println!("Repository initialized successfully!");
+    println!();
+    println!("Next steps:");
+    println!("  cd {} && ab context add", target_dir.display());
+    println!("  ab list");
+
+    Ok(())
+}
+
+/// Run janitor analysis to scan codebase and create issues
+fn run_janitor_analysis(repo_path: &Path) -> allbeads::Result<()> {
+    use allbeads::git::BossRepo;
+    use allbeads::storage::BeadsRepo;
+
+    let beads_repo = BeadsRepo::with_workdir(repo_path);
+    let mut created_count = 0;
+
+    // Check for missing README
     if !repo_path.join("README.md").exists() && !repo_path.join("README").exists() {
-        findings.push(JanitorFinding {
-            category: "Documentation",
-            title: "Add README documentation".to_string(),
-            description: "Repository is missing a README file.".to_string(),
-            issue_type: "chore",
-            priority: 2,
-        });
+        beads_repo.create("Add README documentation", "chore", Some(2))?;
+        println!("  Created: Add README documentation");
+        created_count += 1;
     }
 
+    // Check for missing license
     let license_files = ["LICENSE", "LICENSE.md", "LICENSE.txt", "COPYING"];
-    if !license_files.iter().any(|f| repo_path.join(f).exists()) {
-        findings.push(JanitorFinding {
-            category: "Documentation",
-            title: "Add LICENSE file".to_string(),
-            description: "Repository is missing a LICENSE file.".to_string(),
-            issue_type: "chore",
-            priority: 3,
-        });
+    let has_license = license_files.iter().any(|f| repo_path.join(f).exists());
+    if !has_license {
+        beads_repo.create("Add LICENSE file", "chore", Some(3))?;
+        println!("  Created: Add LICENSE file");
+        created_count += 1;
+    }
+
+    // Check for common config files
+    let has_gitignore = repo_path.join(".gitignore").exists();
+    if !has_gitignore {
+        beads_repo.create("Add .gitignore file", "chore", Some(3))?;
+        println!("  Created: Add .gitignore file");
+        created_count += 1;
+    }
+
+    // Look for TODO/FIXME comments in source files
+    let todo_patterns = scan_for_todos(repo_path, &allbeads::janitor::PathFilter::default())?;
+    for (_file, _line, text) in todo_patterns.iter().take(10) {
+        let title = if text.len() > 60 {
+            format!("TODO: {}...", &text[..57])
+        } else {
+            format!("TODO: {}", text)
+        };
+        beads_repo.create(&title, "task", Some(3))?;
+        println!("  Created: {}", title);
+        created_count += 1;
+    }
+
+    if todo_patterns.len() > 10 {
+        println!(
+            "  ... and {} more TODOs found (limited to 10)",
+            todo_patterns.len() - 10
+        );
     }
 
-    if !repo_path.join("CONTRIBUTING.md").exists() {
-        findings.push(JanitorFinding {
-            category: "Documentation",
-            title: "Add CONTRIBUTING guidelines".to_string(),
-            description: "Repository is missing contributing guidelines.".to_string(),
-            issue_type: "chore",
-            priority: 4,
-        });
+    // Commit janitor findings if we created any
+    if created_count > 0 {
+        let boss_repo = BossRepo::from_local(repo_path)?;
+        boss_repo.add_beads()?;
+        boss_repo.commit(
+            &format!(
+                "Janitor: Created {} issues from codebase analysis",
+                created_count
+            ),
+            "AllBeads Janitor",
+            "janitor@allbeads.dev",
+        )?;
+        println!();
+        println!("✓ Created {} issues from janitor analysis", created_count);
+    } else {
+        println!("✓ No issues found - codebase looks clean!");
+    }
+
+    Ok(())
+}
+
+/// Scan repository for TODO/FIXME comments in files that pass `filter`
+fn scan_for_todos(
+    repo_path: &std::path::Path,
+    filter: &allbeads::janitor::PathFilter,
+) -> allbeads::Result<Vec<(String, usize, String)>> {
+    let mut results = Vec::new();
+
+    // Walk directory looking for source files
+    fn walk_dir(
+        dir: &std::path::Path,
+        base: &std::path::Path,
+        filter: &allbeads::janitor::PathFilter,
+        results: &mut Vec<(String, usize, String)>,
+    ) -> std::io::Result<()> {
+        if dir.is_dir() {
+            // Skip common ignored directories
+            let dir_name = dir.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            if dir_name.starts_with('.')
+                || dir_name == "node_modules"
+                || dir_name == "target"
+                || dir_name == "vendor"
+                || dir_name == "dist"
+                || dir_name == "build"
+                || (dir != base && !filter.allows_dir(dir.strip_prefix(base).unwrap_or(dir)))
+            {
+                return Ok(());
+            }
+
+            for entry in std::fs::read_dir(dir)? {
+                let entry = entry?;
+                let path = entry.path();
+                if path.is_dir() {
+                    walk_dir(&path, base, filter, results)?;
+                } else if is_source_file(&path)
+                    && filter.allows_file(path.strip_prefix(base).unwrap_or(&path))
+                {
+                    scan_file_for_todos(&path, base, results)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn is_source_file(path: &std::path::Path) -> bool {
+        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        matches!(
+            ext,
+            "rs" | "py"
+                | "js"
+                | "ts"
+                | "tsx"
+                | "jsx"
+                | "go"
+                | "java"
+                | "c"
+                | "cpp"
+                | "h"
+                | "hpp"
+                | "rb"
+                | "php"
+                | "swift"
+                | "kt"
+                | "scala"
+        )
+    }
+
+    fn scan_file_for_todos(
+        path: &std::path::Path,
+        base: &std::path::Path,
+        results: &mut Vec<(String, usize, String)>,
+    ) -> std::io::Result<()> {
+        let content = std::fs::read_to_string(path)?;
+        let relative_path = path
+            .strip_prefix(base)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string();
+
+        for (line_num, line) in content.lines().enumerate() {
+            let line_upper = line.to_uppercase();
+            if line_upper.contains("TODO")
+                || line_upper.contains("FIXME")
+                || line_upper.contains("HACK")
+            {
+                // Extract the comment text
+                let text = line.trim().to_string();
+                if !text.is_empty() && results.len() < 100 {
+                    results.push((relative_path.clone(), line_num + 1, text));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    walk_dir(repo_path, repo_path, filter, &mut results).map_err(allbeads::AllBeadsError::Io)?;
+
+    Ok(results)
+}
+
+/// Run comprehensive janitor analysis on a repository
+///
+/// `templates` maps finding categories to description templates; categories
+/// without one keep the default description. Only findings at `min_priority`
+/// or more urgent become beads. Code scans only look at files `filter` allows.
+fn run_full_janitor_analysis(
+    repo_path: &Path,
+    verbose: bool,
+    dry_run: bool,
+    templates: &std::collections::BTreeMap<String, String>,
+    scanner: &allbeads::janitor::SecurityScanner,
+    filter: &allbeads::janitor::PathFilter,
+    min_priority: Option<u8>,
+) -> allbeads::Result<()> {
+    use allbeads::git::BossRepo;
+    use allbeads::storage::BeadsRepo;
+
+    // Render the category's template, or keep the default description
+    let describe = |category: &str,
+                    title: &str,
+                    default: String,
+                    location: Option<(&str, usize, &str)>|
+     -> String {
+        let Some(template) = allbeads::config::janitor_template(templates, category) else {
+            return default;
+        };
+        let (file, line, text) = match location {
+            Some((file, line, text)) => (file, line.to_string(), text),
+            None => ("", String::new(), default.as_str()),
+        };
+        allbeads::config::render_janitor_template(
+            template,
+            &[
+                ("category", category),
+                ("title", title),
+                ("file", file),
+                ("line", &line),
+                ("text", text),
+                ("description", &default),
+            ],
+        )
+    };
+
+    let mut findings: Vec<JanitorFinding> = Vec::new();
+
+    // Check for missing documentation
+    println!("Checking documentation...");
+    if !repo_path.join("README.md").exists() && !repo_path.join("README").exists() {
+        findings.push(JanitorFinding {
+            category: "Documentation",
+            title: "Add README documentation".to_string(),
+            description: describe(
+                "Documentation",
+                "Add README documentation",
+                "Repository is missing a README file.".to_string(),
+                None,
+            ),
+            issue_type: "chore",
+            priority: 2,
+        });
+    }
+
+    let license_files = ["LICENSE", "LICENSE.md", "LICENSE.txt", "COPYING"];
+    if !license_files.iter().any(|f| repo_path.join(f).exists()) {
+        findings.push(JanitorFinding {
+            category: "Documentation",
+            title: "Add LICENSE file".to_string(),
+            description: describe(
+                "Documentation",
+                "Add LICENSE file",
+                "Repository is missing a LICENSE file.".to_string(),
+                None,
+            ),
+            issue_type: "chore",
+            priority: 3,
+        });
+    }
+
+    if !repo_path.join("CONTRIBUTING.md").exists() {
+        findings.push(JanitorFinding {
+            category: "Documentation",
+            title: "Add CONTRIBUTING guidelines".to_string(),
+            description: describe(
+                "Documentation",
+                "Add CONTRIBUTING guidelines",
+                "Repository is missing contributing guidelines.".to_string(),
+                None,
+            ),
+            issue_type: "chore",
+            priority: 4,
+        });
+    }
+
+    // Check for configuration files
+    println!("Checking configuration...");
+    if !repo_path.join(".gitignore").exists() {
+        findings.push(JanitorFinding {
+            category: "Configuration",
+            title: "Add .gitignore file".to_string(),
+            description: describe(
+                "Configuration",
+                "Add .gitignore file",
+                "Repository is missing a .gitignore file.".to_string(),
+                None,
+            ),
+            issue_type: "chore",
+            priority: 3,
+        });
+    }
+
+    // Check for security files
+    println!("Checking security...");
+    if !repo_path.join("SECURITY.md").exists() {
+        findings.push(JanitorFinding {
+            category: "Security",
+            title: "Add SECURITY.md policy".to_string(),
+            description: describe(
+                "Security",
+                "Add SECURITY.md policy",
+                "Repository is missing a security vulnerability reporting policy.".to_string(),
+                None,
+            ),
+            issue_type: "chore",
+            priority: 3,
+        });
+    }
+
+    // Detect language and check for test directories
+    println!("Checking test coverage...");
+    let detected_langs = detect_project_languages(repo_path);
+
+    for lang in &detected_langs {
+        if verbose {
+            println!("  Detected language: {}", lang);
+        }
+        let test_dirs = get_test_directories(lang);
+        let has_tests = test_dirs.iter().any(|d| repo_path.join(d).exists());
+
+        if !has_tests {
+            findings.push(JanitorFinding {
+                category: "Testing",
+                title: format!("Add {} tests", lang),
+                description: describe(
+                    "Testing",
+                    &format!("Add {} tests", lang),
+                    format!("No test directory found for {} code.", lang),
+                    None,
+                ),
+                issue_type: "task",
+                priority: 2,
+            });
+        }
+    }
+
+    // Scan for TODO/FIXME comments
+    println!("Scanning for code comments...");
+    let todos = scan_for_todos(repo_path, filter)?;
+
+    for (file, line, text) in todos.iter().take(20) {
+        let title = if text.len() > 50 {
+            format!("{}...", &text[..50])
+        } else {
+            text.clone()
+        };
+
+        let is_fixme = text.to_uppercase().contains("FIXME");
+        let is_hack = text.to_uppercase().contains("HACK");
+
+        let category = if is_fixme {
+            "Bug"
+        } else if is_hack {
+            "Tech Debt"
+        } else {
+            "Task"
+        };
+        findings.push(JanitorFinding {
+            category,
+            description: describe(
+                category,
+                &title,
+                format!("Found at {}:{}\n{}", file, line, text),
+                Some((file.as_str(), *line, text.as_str())),
+            ),
+            title,
+            issue_type: if is_fixme { "bug" } else { "task" },
+            priority: if is_fixme { 2 } else { 3 },
+        });
+    }
+
+    if todos.len() > 20 {
+        println!(
+            "  Found {} more code comments (showing first 20)",
+            todos.len() - 20
+        );
+    }
+
+    // Check for potential security issues
+    println!("Scanning for potential issues...");
+    let security_issues = scanner.scan_dir(repo_path, 10, filter)?;
+    for issue in &security_issues {
+        let title = format!("Review potential {}", issue.rule);
+        findings.push(JanitorFinding {
+            category: "Security",
+            description: describe(
+                "Security",
+                &title,
+                format!("Found at {}:{}\n{}", issue.file, issue.line, issue.text),
+                Some((issue.file.as_str(), issue.line, issue.text.as_str())),
+            ),
+            title,
+            issue_type: "bug",
+            priority: 1,
+        });
+    }
+
+    // Most severe first; the sort is stable so ties keep scan order
+    findings.sort_by_key(JanitorFinding::severity);
+
+    // Print summary
+    println!();
+    println!("=== Janitor Analysis Summary ===");
+    println!();
+
+    if !findings.is_empty() {
+        println!("Top {} most important:", findings.len().min(5));
+        for item in findings.iter().take(5) {
+            println!("  [P{}] {}: {}", item.priority, item.category, item.title);
+        }
+        println!();
+    }
+
+    // Categories in order of their most severe finding
+    let mut by_category: Vec<(&str, Vec<&JanitorFinding>)> = Vec::new();
+    for finding in &findings {
+        match by_category.iter_mut().find(|(c, _)| *c == finding.category) {
+            Some((_, items)) => items.push(finding),
+            None => by_category.push((finding.category, vec![finding])),
+        }
+    }
+
+    for (category, items) in &by_category {
+        println!("{} ({} items):", category, items.len());
+        for item in items.iter().take(5) {
+            println!("  [P{}] {}", item.priority, item.title);
+            if verbose {
+                for line in item.description.lines().take(2) {
+                    println!("       {}", line);
+                }
+            }
+        }
+        if items.len() > 5 {
+            println!("  ... and {} more", items.len() - 5);
+        }
+        println!();
+    }
+
+    println!("Total findings: {}", findings.len());
+
+    let to_create: Vec<&JanitorFinding> = findings
+        .iter()
+        .filter(|f| min_priority.is_none_or(|p| f.priority <= p))
+        .collect();
+    if let Some(p) = min_priority {
+        let skipped = findings.len() - to_create.len();
+        if skipped > 0 {
+            println!("Skipping {} finding(s) less urgent than P{}", skipped, p);
+        }
+    }
+
+    // Create beads if not dry run
+    if !dry_run && !to_create.is_empty() {
+        println!();
+        println!("Creating beads...");
+
+        let beads_repo = BeadsRepo::with_workdir(repo_path);
+
+        // Check if beads is initialized
+        if !repo_path.join(".beads").exists() {
+            beads_repo.init()?;
+            println!("  Initialized .beads/ directory");
+        }
+
+        let mut created = 0;
+        for finding in to_create {
+            beads_repo.create_with_description(
+                &finding.title,
+                finding.issue_type,
+                Some(finding.priority),
+                &finding.description,
+            )?;
+            created += 1;
+        }
+
+        // Commit findings
+        if created > 0 {
+            let boss_repo = BossRepo::from_local(repo_path)?;
+            boss_repo.add_beads()?;
+            boss_repo.commit(
+                &format!("Janitor: Created {} issues from codebase analysis", created),
+                "AllBeads Janitor",
+                "janitor@allbeads.dev",
+            )?;
+            println!("✓ Created {} beads", created);
+        }
+    }
+
+    Ok(())
+}
+
+/// A finding from janitor analysis
+struct JanitorFinding {
+    category: &'static str,
+    title: String,
+    description: String,
+    issue_type: &'static str,
+    priority: u8,
+}
+
+impl JanitorFinding {
+    /// Sort key: priority first, then security ahead of other categories
+    fn severity(&self) -> (u8, bool) {
+        (self.priority, self.category != "Security")
+    }
+}
+
+/// Detect programming languages used in the project
+fn detect_project_languages(repo_path: &std::path::Path) -> Vec<&'static str> {
+    let mut langs = Vec::new();
+
+    // Rust
+    if repo_path.join("Cargo.toml").exists() {
+        langs.push("Rust");
+    }
+
+    // Python
+    if repo_path.join("pyproject.toml").exists()
+        || repo_path.join("setup.py").exists()
+        || repo_path.join("requirements.txt").exists()
+    {
+        langs.push("Python");
+    }
+
+    // JavaScript/TypeScript
+    if repo_path.join("package.json").exists() {
+        if repo_path.join("tsconfig.json").exists() {
+            langs.push("TypeScript");
+        } else {
+            langs.push("JavaScript");
+        }
+    }
+
+    // Go
+    if repo_path.join("go.mod").exists() {
+        langs.push("Go");
+    }
+
+    // Java
+    if repo_path.join("pom.xml").exists() || repo_path.join("build.gradle").exists() {
+        langs.push("Java");
+    }
+
+    // Ruby
+    if repo_path.join("Gemfile").exists() {
+        langs.push("Ruby");
+    }
+
+    langs
+}
+
+/// Get expected test directories for a language
+fn get_test_directories(lang: &str) -> Vec<&'static str> {
+    match lang {
+        "Rust" => vec!["tests", "src"], // Rust uses tests/ or inline tests
+        "Python" => vec!["tests", "test"],
+        "JavaScript" | "TypeScript" => vec!["tests", "test", "__tests__", "spec"],
+        "Go" => vec!["."], // Go tests are alongside code
+        "Java" => vec!["src/test"],
+        "Ruby" => vec!["test", "spec"],
+        _ => vec!["tests", "test"],
+    }
+}
+
+/// Show a bead's field changes from the git history of its context's issues.jsonl
+fn handle_history_command(config: &AllBeadsConfig, id: &str, json: bool) -> allbeads::Result<()> {
+    let ctx = config.context_for_bead_id(id).ok_or_else(|| {
+        allbeads::AllBeadsError::Config(format!("No context matches the prefix of bead {}", id))
+    })?;
+    let path = ctx.local_path().ok_or_else(|| {
+        allbeads::AllBeadsError::Config(format!(
+            "Context '{}' has no local path configured",
+            ctx.name
+        ))
+    })?;
+    let history = allbeads::graph::bead_history(&path, id)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&history)?);
+        return Ok(());
+    }
+
+    if history.is_empty() {
+        println!(
+            "No committed history for {} in @{} (is .beads/issues.jsonl committed?)",
+            id, ctx.name
+        );
+        return Ok(());
+    }
+
+    // Priorities are stored as numbers; show them the way the rest of the CLI does
+    let value = |field: &str, value: &Option<String>| match value {
+        None => "(none)".to_string(),
+        Some(v) if field == "priority" && v.parse::<u8>().is_ok() => format!("P{}", v),
+        Some(v) => v.clone(),
+    };
+
+    println!();
+    println!(
+        "{}",
+        style::header(&format!("History of {} (@{})", id, ctx.name))
+    );
+    println!();
+    for entry in &history {
+        let changes: Vec<String> = entry
+            .changes
+            .iter()
+            .map(|c| {
+                if entry.created {
+                    format!("{} {}", c.field, value(&c.field, &c.to))
+                } else {
+                    format!(
+                        "{} {} → {}",
+                        c.field,
+                        value(&c.field, &c.from),
+                        value(&c.field, &c.to)
+                    )
+                }
+            })
+            .collect();
+        let what = if entry.created {
+            format!("created: {}", changes.join(", "))
+        } else {
+            changes.join(", ")
+        };
+        println!(
+            "  {}  {}  {:<16} {}",
+            style::dim(
+                &entry
+                    .date
+                    .with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            ),
+            style::dim(&entry.commit),
+            entry.author,
+            what
+        );
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Handle `ab activity`: one repo's activity log, or every context's with `--all`
+fn handle_activity_command(
+    config: &AllBeadsConfig,
+    context_filter: &[String],
+    all: bool,
+    limit: Option<usize>,
+    json: bool,
+    bd_flags: &[String],
+) -> allbeads::Result<()> {
+    use allbeads::graph::{federated_activity, parse_timestamp, ContextActivity};
+
+    let entries: Vec<ContextActivity> = if all {
+        let contexts: Vec<(String, PathBuf)> = config
+            .contexts
+            .iter()
+            .filter(|ctx| {
+                context_filter.is_empty()
+                    || context_filter
+                        .iter()
+                        .any(|f| f.eq_ignore_ascii_case(&ctx.name))
+            })
+            .filter_map(|ctx| Some((ctx.name.clone(), ctx.local_path()?)))
+            .collect();
+        federated_activity(&contexts, bd_flags, limit)
+    } else {
+        let cwd = std::env::current_dir()?;
+        let context = config
+            .contexts
+            .iter()
+            .find(|ctx| ctx.local_path().is_some_and(|p| cwd.starts_with(p)))
+            .map(|ctx| ctx.name.clone())
+            .unwrap_or_default();
+        let log = Beads::with_workdir_and_flags(&cwd, bd_flags.to_vec())
+            .activity(limit)
+            .map_err(|e| allbeads::AllBeadsError::Other(format!("bd activity failed: {}", e)))?;
+        allbeads::graph::merge_activity(vec![(context, log)], limit)
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        return Ok(());
+    }
+
+    if entries.is_empty() {
+        println!("No activity");
+        return Ok(());
+    }
+
+    println!();
+    println!("{}", style::header("Activity"));
+    println!();
+    for entry in &entries {
+        let when = parse_timestamp(&entry.activity.timestamp)
+            .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|| entry.activity.timestamp.clone());
+        let mut line = format!("  {}  ", style::dim(&when));
+        if !entry.context.is_empty() {
+            line.push_str(&format!("{:<16} ", format!("@{}", entry.context)));
+        }
+        line.push_str(&format!("{:<10}", entry.activity.action));
+        if let Some(ref id) = entry.activity.issue_id {
+            line.push_str(&format!(" {}", style::issue_id(id)));
+        }
+        if let Some(ref details) = entry.activity.details {
+            line.push_str(&format!(" {}", details));
+        }
+        println!("{}", line);
+    }
+    println!();
+    Ok(())
+}
+
+/// Handle `ab stats --history`: burndown of open/closed beads over time
+fn handle_stats_history(
+    config: &AllBeadsConfig,
+    context_filter: &[String],
+    days: u32,
+    json: bool,
+) -> allbeads::Result<()> {
+    use allbeads::graph::{context_history, daily_totals};
+
+    let mut histories = Vec::new();
+    for ctx in &config.contexts {
+        if !context_filter.is_empty()
+            && !context_filter
+                .iter()
+                .any(|f| f.eq_ignore_ascii_case(&ctx.name))
+        {
+            continue;
+        }
+        let Some(path) = ctx.local_path() else {
+            tracing::debug!(context = %ctx.name, "No local clone, skipping history");
+            continue;
+        };
+        match context_history(&path) {
+            Ok(history) => histories.push(history),
+            Err(e) => tracing::warn!(context = %ctx.name, error = %e, "Failed to read history"),
+        }
+    }
+
+    let end = chrono::Utc::now().date_naive();
+    let start = end - chrono::Duration::days(i64::from(days.max(1)) - 1);
+    let totals = daily_totals(&histories, start, end);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&totals)?);
+        return Ok(());
+    }
+
+    println!();
+    println!(
+        "{}",
+        style::header(&format!("Beads History (last {} days)", totals.len()))
+    );
+    println!();
+
+    if histories.is_empty() {
+        println!("  No issues.jsonl history found in any context.");
+        println!();
+        return Ok(());
+    }
+
+    const BAR_WIDTH: usize = 40;
+    let max_open = totals.iter().map(|d| d.open).max().unwrap_or(0).max(1);
+    println!(
+        "  {}",
+        style::dim(&format!("{:<12} {:>6} {:>7}", "Date", "Open", "Closed"))
+    );
+    for day in &totals {
+        let bar = "█".repeat(day.open * BAR_WIDTH / max_open);
+        println!(
+            "  {:<12} {:>6} {:>7}  {}",
+            day.date.format("%Y-%m-%d"),
+            day.open,
+            day.closed,
+            bar
+        );
+    }
+
+    if let (Some(first), Some(last)) = (totals.first(), totals.last()) {
+        let closed_in_period = last.closed.saturating_sub(first.closed);
+        println!();
+        println!(
+            "  Open:     {} → {} ({:+})",
+            first.open,
+            last.open,
+            last.open as i64 - first.open as i64
+        );
+        println!(
+            "  Velocity: {} closed ({:.1}/day)",
+            closed_in_period,
+            closed_in_period as f64 / totals.len() as f64
+        );
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Handle `ab stats --age`
+fn handle_stats_age(graph: &FederatedGraph, json: bool) -> allbeads::Result<()> {
+    use allbeads::graph::{age_histogram, AGE_BUCKETS};
+
+    let histogram = age_histogram(graph);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&histogram)?);
+        return Ok(());
+    }
+
+    println!();
+    println!("{}", style::header("Open Beads by Age"));
+    println!();
+
+    if histogram.is_empty() {
+        println!("  No open beads.");
+        println!();
+        return Ok(());
+    }
+
+    const BAR_WIDTH: usize = 30;
+    let max = histogram
+        .iter()
+        .flat_map(|s| s.buckets.counts())
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    for status_ages in &histogram {
+        println!(
+            "{}",
+            style::subheader(&format!(
+                "{} ({})",
+                format_status(status_ages.status),
+                status_ages.buckets.total()
+            ))
+        );
+        for (label, count) in AGE_BUCKETS.iter().zip(status_ages.buckets.counts()) {
+            let bar = "█".repeat((count * BAR_WIDTH).div_ceil(max));
+            println!("  {:<6} {:>5}  {}", label, count, bar);
+        }
+        println!();
+    }
+
+    Ok(())
+}
+
+fn parse_status(s: &str) -> allbeads::Result<Status> {
+    match s.to_lowercase().as_str() {
+        "open" => Ok(Status::Open),
+        "in_progress" | "in-progress" => Ok(Status::InProgress),
+        "blocked" => Ok(Status::Blocked),
+        "deferred" => Ok(Status::Deferred),
+        "closed" => Ok(Status::Closed),
+        "tombstone" => Ok(Status::Tombstone),
+        _ => Err(allbeads::AllBeadsError::Parse(format!(
+            "Invalid status: {}. Must be one of: open, in_progress, blocked, deferred, closed, tombstone",
+            s
+        ))),
+    }
+}
+
+fn parse_priority(s: &str) -> allbeads::Result<Priority> {
+    match s.to_uppercase().as_str() {
+        "P0" | "0" => Ok(Priority::P0),
+        "P1" | "1" => Ok(Priority::P1),
+        "P2" | "2" => Ok(Priority::P2),
+        "P3" | "3" => Ok(Priority::P3),
+        "P4" | "4" => Ok(Priority::P4),
+        _ => Err(allbeads::AllBeadsError::Parse(format!(
+            "Invalid priority: {}. Must be one of: P0-P4 or 0-4",
+            s
+        ))),
+    }
+}
+
+/// Fill in a description or comment body from standard input for `--stdin`
+///
+/// Trailing newlines are dropped. Refuses to wait on an interactive terminal,
+/// since the flag is meant for pipes and redirects.
+fn read_stdin_body(command: &mut Commands) -> allbeads::Result<()> {
+    let body = match command {
+        Commands::Create {
+            stdin: true,
+            description,
+            ..
+        }
+        | Commands::Update {
+            stdin: true,
+            description,
+            ..
+        } => description,
+        Commands::Comments(CommentCommands::Add {
+            stdin: true,
+            content,
+            ..
+        }) => content,
+        _ => return Ok(()),
+    };
+
+    if io::stdin().is_terminal() {
+        return Err(allbeads::AllBeadsError::Config(
+            "--stdin expects piped input, e.g. cat notes.md | ab ... --stdin".to_string(),
+        ));
+    }
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+    let input = input.trim_end_matches(['\n', '\r']);
+    if input.trim().is_empty() {
+        return Err(allbeads::AllBeadsError::Config(
+            "--stdin read nothing from standard input".to_string(),
+        ));
+    }
+    *body = Some(input.to_string());
+    Ok(())
+}
+
+/// Replace `--assignee me` with the current user
+///
+/// Uses git's `user.email`, then `fallback` (the config's `default_assignee`).
+fn resolve_assignee_me(command: &mut Commands, fallback: Option<&str>) -> allbeads::Result<()> {
+    let (Commands::Create { assignee, .. }
+    | Commands::Update { assignee, .. }
+    | Commands::Search { assignee, .. }) = command
+    else {
+        return Ok(());
+    };
+    if assignee.as_deref() == Some("me") {
+        let user = allbeads::git::current_user()
+            .or_else(|| fallback.map(str::to_string))
+            .ok_or_else(|| {
+                allbeads::AllBeadsError::Config(
+                    "Can't resolve --assignee me: set git user.email or default_assignee in the config"
+                        .to_string(),
+                )
+            })?;
+        *assignee = Some(user);
+    }
+    Ok(())
+}
+
+/// Bead ID arguments of a command, for bare-number expansion
+fn bead_id_args(command: &mut Commands) -> Vec<&mut String> {
+    match command {
+        Commands::Show { id, .. }
+        | Commands::Tree { id, .. }
+        | Commands::Open { id }
+        | Commands::Watch { id, .. }
+        | Commands::History { id }
+        | Commands::Clone { id, .. }
+        | Commands::Edit { id, .. } => vec![id],
+        Commands::Update { ids, .. }
+        | Commands::Close { ids, .. }
+        | Commands::Reopen { ids, .. }
+        | Commands::Delete { ids, .. } => ids.iter_mut().collect(),
+        Commands::Move { id, parent } => vec![id, parent],
+        Commands::Duplicate { id, of } => vec![id, of],
+        Commands::Link { from, to, .. } => vec![from, to],
+        Commands::Dep(
+            DepCommands::Add { issue, depends_on } | DepCommands::Remove { issue, depends_on },
+        ) => vec![issue, depends_on],
+        Commands::Label(LabelCommands::Add { issue, .. } | LabelCommands::Remove { issue, .. })
+        | Commands::Comments(
+            CommentCommands::List { issue, .. } | CommentCommands::Add { issue, .. },
+        ) => vec![issue],
+        _ => Vec::new(),
+    }
+}
+
+/// Expand bare bead numbers (`1234`) in a command's ID arguments
+///
+/// See [`AllBeadsConfig::resolve_bead_id`]; `exists` reports whether a full ID
+/// is a known bead.
+fn resolve_bead_ids(
+    command: &mut Commands,
+    config: &AllBeadsConfig,
+    exists: impl Fn(&str) -> bool,
+) -> allbeads::Result<()> {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    for id in bead_id_args(command) {
+        *id = config.resolve_bead_id(id, &cwd, &exists)?;
+    }
+    Ok(())
+}
+
+/// Handle `ab search --save/--load/--list-saved`
+///
+/// Returns `true` if the command is complete. For `--load`, the saved filters
+/// are merged into `command` (command-line values win) and `false` is returned
+/// so the search runs as usual.
+fn handle_saved_search(
+    command: &mut Commands,
+    config: &AllBeadsConfig,
+    config_path: &Option<String>,
+) -> allbeads::Result<bool> {
+    use allbeads::config::SavedSearch;
+
+    let Commands::Search {
+        query,
+        context,
+        status,
+        priority_min,
+        priority_max,
+        issue_type,
+        label,
+        assignee,
+        sort,
+        reverse,
+        limit,
+        fuzzy,
+        save,
+        load,
+        list_saved,
+    } = command
+    else {
+        return Ok(false);
+    };
+
+    if *list_saved {
+        if config.saved_searches.is_empty() {
+            println!("No saved searches. Save one with: ab search --save <name> [query] [filters]");
+        } else {
+            for (name, search) in &config.saved_searches {
+                println!("{}  {}", style::highlight(name), search.to_args());
+            }
+        }
+        return Ok(true);
+    }
+
+    if let Some(name) = save.take() {
+        let search = SavedSearch {
+            query: query.clone(),
+            context: context.clone(),
+            status: status.clone(),
+            priority_min: priority_min.clone(),
+            priority_max: priority_max.clone(),
+            issue_type: issue_type.clone(),
+            labels: label.clone().unwrap_or_default(),
+            assignee: assignee.clone(),
+            sort: sort.clone(),
+            reverse: *reverse,
+            limit: *limit,
+            fuzzy: *fuzzy,
+        };
+        let args = search.to_args();
+
+        let path = config_path
+            .as_ref()
+            .map(PathBuf::from)
+            .unwrap_or_else(AllBeadsConfig::default_path);
+        let mut config = config.clone();
+        let replaced = config.saved_searches.insert(name.clone(), search).is_some();
+        config.save(&path)?;
+
+        println!(
+            "{} search '{}': ab search {}",
+            if replaced { "Updated" } else { "Saved" },
+            name,
+            args
+        );
+        println!("Run it with: ab search --load {}", name);
+        return Ok(true);
+    }
+
+    if let Some(name) = load.take() {
+        let saved = config.saved_searches.get(&name).ok_or_else(|| {
+            let names: Vec<&str> = config.saved_searches.keys().map(String::as_str).collect();
+            allbeads::AllBeadsError::Config(format!(
+                "Saved search '{}' not found. Saved searches: {}",
+                name,
+                if names.is_empty() {
+                    "(none)".to_string()
+                } else {
+                    names.join(", ")
+                }
+            ))
+        })?;
+
+        fn fill<T: Clone>(arg: &mut Option<T>, saved: &Option<T>) {
+            if arg.is_none() {
+                arg.clone_from(saved);
+            }
+        }
+        fill(query, &saved.query);
+        fill(context, &saved.context);
+        fill(status, &saved.status);
+        fill(priority_min, &saved.priority_min);
+        fill(priority_max, &saved.priority_max);
+        fill(issue_type, &saved.issue_type);
+        fill(assignee, &saved.assignee);
+        fill(sort, &saved.sort);
+        fill(limit, &saved.limit);
+        if label.is_none() && !saved.labels.is_empty() {
+            *label = Some(saved.labels.clone());
+        }
+        *reverse |= saved.reverse;
+        *fuzzy |= saved.fuzzy;
+    }
+
+    Ok(false)
+}
+
+/// Best fuzzy match score of `query` against a bead's title, ID and description
+fn fuzzy_score(matcher: &SkimMatcherV2, bead: &allbeads::graph::Bead, query: &str) -> Option<i64> {
+    [
+        Some(bead.title.as_str()),
+        Some(bead.id.as_str()),
+        bead.description.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .filter_map(|text| matcher.fuzzy_match(text, query))
+    .max()
+}
+
+fn parse_priority_arg(s: &str) -> Option<Priority> {
+    parse_priority(s).ok()
+}
+
+fn parse_issue_type(s: &str) -> allbeads::Result<IssueType> {
+    match s.to_lowercase().as_str() {
+        "bug" => Ok(IssueType::Bug),
+        "feature" => Ok(IssueType::Feature),
+        "task" => Ok(IssueType::Task),
+        "epic" => Ok(IssueType::Epic),
+        "chore" => Ok(IssueType::Chore),
+        "merge_request" | "merge-request" | "mr" => Ok(IssueType::MergeRequest),
+        "molecule" => Ok(IssueType::Molecule),
+        "gate" => Ok(IssueType::Gate),
+        _ => Err(allbeads::AllBeadsError::Parse(format!(
+            "Invalid type: {}. Must be one of: bug, feature, task, epic, chore, merge_request, molecule, gate",
+            s
+        ))),
+    }
+}
+
+/// Group bead IDs by the context they belong to
+///
+/// Looks up each bead's `@context` label in the graph, falling back to
+/// matching the ID prefix against the configured contexts.
+fn group_ids_by_context(
+    ids: &[String],
+    graph: &FederatedGraph,
+    config: &AllBeadsConfig,
+) -> std::collections::BTreeMap<String, Vec<String>> {
+    let mut by_context: std::collections::BTreeMap<String, Vec<String>> =
+        std::collections::BTreeMap::new();
+
+    for id in ids {
+        let bead_id = BeadId::from(id.as_str());
+
+        // First try to find in graph
+        if let Some(bead) = graph.beads.get(&bead_id) {
+            if let Some(ctx_name) = bead
+                .labels
+                .iter()
+                .find(|l| l.starts_with('@'))
+                .map(|l| l.trim_start_matches('@').to_string())
+            {
+                by_context.entry(ctx_name).or_default().push(id.clone());
+                continue;
+            }
+        }
+
+        // Fallback: find the context from the ID prefix
+        if let Some(ctx) = config.context_for_bead_id(id) {
+            by_context
+                .entry(ctx.name.clone())
+                .or_default()
+                .push(id.clone());
+            continue;
+        }
+
+        eprintln!("Warning: Could not determine context for bead {}", id);
+    }
+
+    by_context
+}
+
+/// Append an operation to the undo log
+///
+/// A failure to write the log is reported but never fails the command that
+/// already ran.
+fn record_undo(
+    config: &AllBeadsConfig,
+    context: Option<&str>,
+    path: &Path,
+    kind: allbeads::undo::OpKind,
+) {
+    let op = allbeads::undo::Operation::new(context, path, kind);
+    if let Err(e) = allbeads::undo::UndoLog::for_config(config).record(&op) {
+        eprintln!(
+            "  {} Could not record operation for undo: {}",
+            style::warning("⚠"),
+            e
+        );
+    }
+}
+
+/// Read a single key press for `ab triage`, in raw mode only while waiting
+///
+/// Raw mode keeps Ctrl+C from raising SIGINT, so it is returned as an
+/// `Interrupted` error that aborts the triage.
+fn read_triage_key() -> io::Result<crossterm::event::KeyCode> {
+    use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+    use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+
+    enable_raw_mode()?;
+    let key = loop {
+        match event::read() {
+            Ok(Event::Key(key))
+                if key.kind == KeyEventKind::Press
+                    && key.code == KeyCode::Char('c')
+                    && key.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                break Err(io::Error::new(io::ErrorKind::Interrupted, "Triage aborted"))
+            }
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => break Ok(key.code),
+            Ok(_) => continue,
+            Err(e) => break Err(e),
+        }
+    };
+    disable_raw_mode()?;
+    key
+}
+
+/// Walk open beads one at a time and apply single-key triage decisions
+fn handle_triage_command(
+    graph: &FederatedGraph,
+    config: &AllBeadsConfig,
+    bd_flags: &[String],
+    context: Option<&str>,
+    all: bool,
+) -> allbeads::Result<()> {
+    use crossterm::event::KeyCode;
+    use dialoguer::Input;
+
+    if !io::stdin().is_terminal() {
+        return Err(allbeads::AllBeadsError::Other(
+            "ab triage needs an interactive terminal".to_string(),
+        ));
+    }
+
+    let context = context.map(|c| c.trim_start_matches('@'));
+    let mut beads: Vec<&allbeads::graph::Bead> = graph
+        .beads
+        .values()
+        .filter(|b| b.status == Status::Open)
+        .filter(|b| all || (b.priority == Priority::P2 && b.assignee.is_none()))
+        .filter(|b| context.is_none_or(|c| bead_context(b).eq_ignore_ascii_case(c)))
+        .collect();
+    beads.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+
+    if beads.is_empty() {
+        println!("Nothing to triage");
+        return Ok(());
+    }
+
+    let total = beads.len();
+    let mut triaged = 0;
+    let mut skipped = 0;
+    'beads: for (i, bead) in beads.into_iter().enumerate() {
+        let id = bead.id.as_str();
+        let ctx_name = bead_context(bead);
+        let Some(ctx_path) = config.get_context(ctx_name).and_then(|c| c.local_path()) else {
+            eprintln!(
+                "  {} {}: context '{}' has no local path, skipping",
+                style::warning("⚠"),
+                style::issue_id(id),
+                ctx_name
+            );
+            skipped += 1;
+            continue;
+        };
+        let bd = Beads::with_workdir_and_flags(&ctx_path, bd_flags.to_vec());
+
+        println!();
+        println!(
+            "{} [{}/{}] triaged {}, skipped {}",
+            style::header("Triage"),
+            i + 1,
+            total,
+            triaged,
+            skipped
+        );
+        print_bead_summary(bead);
+        let mut changed = false;
+        loop {
+            println!(
+                "  {}",
+                style::dim("0-4 priority · a assign · l label · n/Enter skip · q quit")
+            );
+            match read_triage_key()? {
+                KeyCode::Char(c @ '0'..='4') => {
+                    let priority = c as u8 - b'0';
+                    match bd.update(id, None, Some(priority), None, None, None, None) {
+                        Ok(output) if output.success => {
+                            record_undo(
+                                config,
+                                Some(ctx_name),
+                                &ctx_path,
+                                allbeads::undo::OpKind::Update {
+                                    id: id.to_string(),
+                                    before: allbeads::undo::PriorFields {
+                                        priority: Some(u8::from(bead.priority)),
+                                        ..Default::default()
+                                    },
+                                },
+                            );
+                            println!("  {} Priority P{}", style::success("✓"), priority);
+                            triaged += 1;
+                            continue 'beads;
+                        }
+                        Ok(output) => {
+                            eprintln!("  {} {}", style::error("✗"), output.stderr.trim())
+                        }
+                        Err(e) => eprintln!("  {} {}", style::error("✗"), e),
+                    }
+                }
+                KeyCode::Char('a') => {
+                    let assignee: String = Input::new()
+                        .with_prompt("  Assignee")
+                        .allow_empty(true)
+                        .interact_text()
+                        .unwrap_or_default();
+                    if assignee.is_empty() {
+                        continue;
+                    }
+                    match bd.update(id, None, None, Some(&assignee), None, None, None) {
+                        Ok(output) if output.success => {
+                            record_undo(
+                                config,
+                                Some(ctx_name),
+                                &ctx_path,
+                                allbeads::undo::OpKind::Update {
+                                    id: id.to_string(),
+                                    before: allbeads::undo::PriorFields {
+                                        assignee: Some(bead.assignee.clone().unwrap_or_default()),
+                                        ..Default::default()
+                                    },
+                                },
+                            );
+                            println!("  {} Assigned to {}", style::success("✓"), assignee);
+                            changed = true;
+                        }
+                        Ok(output) => {
+                            eprintln!("  {} {}", style::error("✗"), output.stderr.trim())
+                        }
+                        Err(e) => eprintln!("  {} {}", style::error("✗"), e),
+                    }
+                }
+                KeyCode::Char('l') => {
+                    let label: String = Input::new()
+                        .with_prompt("  Label")
+                        .allow_empty(true)
+                        .interact_text()
+                        .unwrap_or_default();
+                    if label.is_empty() {
+                        continue;
+                    }
+                    match bd.label_add(id, &label) {
+                        Ok(output) if output.success => {
+                            record_undo(
+                                config,
+                                Some(ctx_name),
+                                &ctx_path,
+                                allbeads::undo::OpKind::LabelAdd {
+                                    id: id.to_string(),
+                                    label: label.clone(),
+                                },
+                            );
+                            println!("  {} Added label {}", style::success("✓"), label);
+                            changed = true;
+                        }
+                        Ok(output) => {
+                            eprintln!("  {} {}", style::error("✗"), output.stderr.trim())
+                        }
+                        Err(e) => eprintln!("  {} {}", style::error("✗"), e),
+                    }
+                }
+                KeyCode::Char('n') | KeyCode::Char('s') | KeyCode::Enter | KeyCode::Char(' ') => {
+                    if changed {
+                        triaged += 1;
+                    } else {
+                        skipped += 1;
+                    }
+                    continue 'beads;
+                }
+                KeyCode::Char('q') | KeyCode::Esc => {
+                    if changed {
+                        triaged += 1;
+                    }
+                    break 'beads;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "Triaged {}, skipped {} of {} bead(s)",
+        triaged, skipped, total
+    );
+    Ok(())
+}
+
+/// Open a file at a line in $EDITOR (or $VISUAL)
+///
+/// Uses `--goto file:line` for VS Code-style editors, `file:line` for editors
+/// that take that form, and `+line file` for everything else (vim, nano, emacs).
+fn open_in_editor(path: &Path, line: u32) -> allbeads::Result<()> {
+    let editor = std::env::var("EDITOR")
+        .or_else(|_| std::env::var("VISUAL"))
+        .map_err(|_| allbeads::AllBeadsError::Config("Set $EDITOR or $VISUAL".to_string()))?;
+    let mut words = editor.split_whitespace();
+    let program = words
+        .next()
+        .ok_or_else(|| allbeads::AllBeadsError::Config("$EDITOR is empty".to_string()))?;
+    let name = Path::new(program)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(program);
+
+    let mut cmd = std::process::Command::new(program);
+    cmd.args(words);
+    let target = format!("{}:{}", path.display(), line);
+    match name {
+        "code" | "code-insiders" | "codium" | "cursor" => {
+            cmd.arg("--goto").arg(target);
+        }
+        "subl" | "zed" | "hx" | "helix" => {
+            cmd.arg(target);
+        }
+        _ => {
+            cmd.arg(format!("+{}", line)).arg(path);
+        }
     }
 
-    // Check for configuration files
-    println!("Checking configuration...");
-    if !repo_path.join(".gitignore").exists() {
-        findings.push(JanitorFinding {
-            category: "Configuration",
-            title: "Add .gitignore file".to_string(),
-            description: "Repository is missing a .gitignore file.".to_string(),
-            issue_type: "chore",
-            priority: 3,
-        });
+    let status = cmd.status()?;
+    if !status.success() {
+        return Err(allbeads::AllBeadsError::Other(format!(
+            "{} exited with {}",
+            program, status
+        )));
     }
+    Ok(())
+}
 
-    // Check for security files
-    println!("Checking security...");
-    if !repo_path.join("SECURITY.md").exists() {
-        findings.push(JanitorFinding {
-            category: "Security",
-            title: "Add SECURITY.md policy".to_string(),
-            description: "Repository is missing a security vulnerability reporting policy."
-                .to_string(),
-            issue_type: "chore",
-            priority: 3,
-        });
+/// Print the beads a CRUD command would touch, grouped by context, without running bd
+fn print_dry_run(
+    action: &str,
+    by_context: &std::collections::BTreeMap<String, Vec<String>>,
+    graph: &FederatedGraph,
+) {
+    let total: usize = by_context.values().map(Vec::len).sum();
+    println!(
+        "  {} Dry run - would {} {} bead(s):",
+        style::dim("→"),
+        action,
+        total
+    );
+    for (ctx_name, bead_ids) in by_context {
+        println!();
+        println!("  @{}", ctx_name);
+        for id in bead_ids {
+            let title = graph
+                .beads
+                .get(&BeadId::from(id.as_str()))
+                .map(|b| b.title.as_str())
+                .unwrap_or("(not in graph)");
+            println!("    {} {}", style::issue_id(id), title);
+        }
     }
+}
 
-    // Detect language and check for test directories
-    println!("Checking test coverage...");
-    let detected_langs = detect_project_languages(repo_path);
+/// Replace `old` with `new` on one bead
+///
+/// The new label is added before the old one is removed, so a failure never
+/// leaves the bead with neither. If removing the old label fails, a newly
+/// added label is taken off again.
+fn rename_bead_label(
+    bd: &Beads,
+    id: &str,
+    old: &str,
+    new: &str,
+    has_new: bool,
+) -> Result<(), String> {
+    let check = |result: beads::Result<beads::CommandOutput>| match result {
+        Ok(output) if output.success => Ok(()),
+        Ok(output) => Err(output.stderr.trim().to_string()),
+        Err(e) => Err(e.to_string()),
+    };
 
-    for lang in &detected_langs {
-        if verbose {
-            println!("  Detected language: {}", lang);
+    if !has_new {
+        check(bd.label_add(id, new))?;
+    }
+    if let Err(e) = check(bd.label_remove(id, old)) {
+        if !has_new {
+            let _ = bd.label_remove(id, new);
         }
-        let test_dirs = get_test_directories(lang);
-        let has_tests = test_dirs.iter().any(|d| repo_path.join(d).exists());
+        return Err(e);
+    }
+    Ok(())
+}
 
-        if !has_tests {
-            findings.push(JanitorFinding {
-                category: "Testing",
-                title: format!("Add {} tests", lang),
-                description: format!("No test directory found for {} code.", lang),
-                issue_type: "task",
-                priority: 2,
-            });
+/// Print the aggregation summary, warning about bead IDs shared by contexts
+fn print_load_summary(graph: &FederatedGraph) {
+    if !graph.collisions.is_empty() {
+        eprintln!(
+            "{} {} bead ID(s) exist in more than one context; only the first was loaded:",
+            style::warning("⚠"),
+            graph.collisions.len()
+        );
+        for collision in &graph.collisions {
+            eprintln!(
+                "    {} kept from @{}, skipped from @{}",
+                style::issue_id(collision.id.as_str()),
+                collision.kept,
+                collision.dropped
+            );
         }
+        eprintln!("  Give the contexts distinct prefixes with 'ab rename-prefix'.");
     }
+    allbeads::status!(
+        "✓ Loaded {} beads from {} contexts\n",
+        graph.beads.len(),
+        graph.rigs.len()
+    );
+}
 
-    // Scan for TODO/FIXME comments
-    println!("Scanning for code comments...");
-    let todos = scan_for_todos(repo_path)?;
-
-    for (file, line, text) in todos.iter().take(20) {
-        let title = if text.len() > 50 {
-            format!("{}...", &text[..50])
-        } else {
-            text.clone()
-        };
+/// Print shadow beads whose target bead is missing from every context
+fn print_unresolved_shadows(graph: &FederatedGraph) {
+    let unresolved = graph.unresolved_shadows();
 
-        let is_fixme = text.to_uppercase().contains("FIXME");
-        let is_hack = text.to_uppercase().contains("HACK");
+    println!();
+    println!("{}", style::header("Unresolved Shadow Beads"));
+    println!();
 
-        findings.push(JanitorFinding {
-            category: if is_fixme {
-                "Bug"
-            } else if is_hack {
-                "Tech Debt"
-            } else {
-                "Task"
-            },
-            title,
-            description: format!("Found at {}:{}\n{}", file, line, text),
-            issue_type: if is_fixme { "bug" } else { "task" },
-            priority: if is_fixme { 2 } else { 3 },
-        });
+    if unresolved.is_empty() {
+        println!(
+            "  {} All {} shadow bead(s) resolve to a bead in an aggregated context",
+            style::success("✓"),
+            graph.shadow_beads.len()
+        );
+        println!();
+        return;
     }
 
-    if todos.len() > 20 {
+    for shadow in &unresolved {
         println!(
-            "  Found {} more code comments (showing first 20)",
-            todos.len() - 20
+            "  {} {} → {} {}",
+            style::warning("⚠"),
+            style::issue_id(shadow.id.as_str()),
+            shadow.pointer,
+            style::dim(&format!("(@{})", shadow.context))
         );
-    }
+        println!("      {}", shadow.summary);
 
-    // Check for potential security issues (basic patterns)
-    println!("Scanning for potential issues...");
-    let security_issues = scan_for_security_patterns(repo_path)?;
-    for (file, line, pattern, context) in security_issues.iter().take(10) {
-        findings.push(JanitorFinding {
-            category: "Security",
-            title: format!("Review potential {}", pattern),
-            description: format!("Found at {}:{}\n{}", file, line, context),
-            issue_type: "bug",
-            priority: 1,
-        });
+        let referenced_by: Vec<&str> = graph
+            .get_dependents(&shadow.id)
+            .iter()
+            .map(|b| b.id.as_str())
+            .collect();
+        if !referenced_by.is_empty() {
+            println!(
+                "      {} {}",
+                style::dim("Referenced by:"),
+                referenced_by.join(", ")
+            );
+        }
     }
 
-    // Print summary
     println!();
-    println!("=== Janitor Analysis Summary ===");
+    println!(
+        "{} of {} shadow bead(s) are unresolved. Was a repository removed from the config?",
+        unresolved.len(),
+        graph.shadow_beads.len()
+    );
     println!();
+}
 
-    let mut by_category: std::collections::HashMap<&str, Vec<&JanitorFinding>> =
-        std::collections::HashMap::new();
-    for finding in &findings {
-        by_category
-            .entry(finding.category)
-            .or_default()
-            .push(finding);
-    }
-
-    for (category, items) in &by_category {
-        println!("{} ({} items):", category, items.len());
-        for item in items.iter().take(5) {
-            println!("  [P{}] {}", item.priority, item.title);
-            if verbose {
-                for line in item.description.lines().take(2) {
-                    println!("       {}", line);
-                }
-            }
-        }
-        if items.len() > 5 {
-            println!("  ... and {} more", items.len() - 5);
-        }
-        println!();
-    }
+/// One line of `ab tree` output: status, ID, and title colored by status
+fn format_tree_node(bead: &allbeads::graph::Bead) -> String {
+    let status = format_status(bead.status);
+    format!(
+        "{} {} {} {}",
+        style::status_indicator(status),
+        style::issue_id(bead.id.as_str()),
+        bead.title,
+        style::status_style(&format!("[{}]", status))
+    )
+}
 
-    println!("Total findings: {}", findings.len());
+/// Recursively print the dependency tree below `bead`
+///
+/// Follows `dependencies` (or `blocks` when `up` is set). Edges back into the
+/// current path are marked as cycles; beads already expanded elsewhere in the
+/// tree are printed once more but not expanded again.
+fn print_dep_tree(
+    graph: &FederatedGraph,
+    bead: &allbeads::graph::Bead,
+    up: bool,
+    prefix: &str,
+    path: &mut Vec<BeadId>,
+    expanded: &mut std::collections::HashSet<BeadId>,
+) {
+    let edges = edges_of(bead, up);
 
-    // Create beads if not dry run
-    if !dry_run && !findings.is_empty() {
-        println!();
-        println!("Creating beads...");
+    for (i, child_id) in edges.iter().enumerate() {
+        let last = i + 1 == edges.len();
+        let branch = if last { "└── " } else { "├── " };
+        let child_prefix = format!("{}{}", prefix, if last { "    " } else { "│   " });
 
-        let beads_repo = BeadsRepo::with_workdir(repo_path);
+        let Some(child) = graph.get_bead(child_id) else {
+            println!(
+                "{}{}{} {}",
+                prefix,
+                branch,
+                style::issue_id(child_id.as_str()),
+                style::dim("(not found)")
+            );
+            continue;
+        };
 
-        // Check if beads is initialized
-        if !repo_path.join(".beads").exists() {
-            beads_repo.init()?;
-            println!("  Initialized .beads/ directory");
+        if path.contains(child_id) {
+            println!(
+                "{}{}{} {}",
+                prefix,
+                branch,
+                format_tree_node(child),
+                style::error("↻ cycle")
+            );
+            continue;
         }
 
-        let mut created = 0;
-        for finding in &findings {
-            beads_repo.create(&finding.title, finding.issue_type, Some(finding.priority))?;
-            created += 1;
+        if !expanded.insert(child_id.clone()) {
+            let marker = if edges_of(child, up).is_empty() {
+                String::new()
+            } else {
+                format!(" {}", style::dim("(see above)"))
+            };
+            println!("{}{}{}{}", prefix, branch, format_tree_node(child), marker);
+            continue;
         }
 
-        // Commit findings
-        if created > 0 {
-            let boss_repo = BossRepo::from_local(repo_path)?;
-            boss_repo.add_beads()?;
-            boss_repo.commit(
-                &format!("Janitor: Created {} issues from codebase analysis", created),
-                "AllBeads Janitor",
-                "janitor@allbeads.dev",
-            )?;
-            println!("✓ Created {} beads", created);
-        }
+        println!("{}{}{}", prefix, branch, format_tree_node(child));
+        path.push(child_id.clone());
+        print_dep_tree(graph, child, up, &child_prefix, path, expanded);
+        path.pop();
     }
-
-    Ok(())
 }
 
-/// A finding from janitor analysis
-struct JanitorFinding {
-    category: &'static str,
-    title: String,
-    description: String,
-    issue_type: &'static str,
-    priority: u8,
+/// Tree edges of a bead in the requested direction
+fn edges_of(bead: &allbeads::graph::Bead, up: bool) -> &[BeadId] {
+    if up {
+        &bead.blocks
+    } else {
+        &bead.dependencies
+    }
 }
 
-/// Detect programming languages used in the project
-fn detect_project_languages(repo_path: &std::path::Path) -> Vec<&'static str> {
-    let mut langs = Vec::new();
+/// Filters shared by `ab list` and `ab export`
+struct BeadFilter {
+    status: Option<String>,
+    priority: Option<String>,
+    context: Option<String>,
+    label: Option<String>,
+    issue_type: Option<String>,
+    assignee: Option<String>,
+    /// Only children of this epic
+    parent: Option<String>,
+    ready: bool,
+    all: bool,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+}
 
-    // Rust
-    if repo_path.join("Cargo.toml").exists() {
-        langs.push("Rust");
-    }
+/// Apply list filters to the graph, sorted by priority then status
+fn filter_beads<'a>(
+    graph: &'a FederatedGraph,
+    filter: &BeadFilter,
+) -> allbeads::Result<Vec<&'a allbeads::graph::Bead>> {
+    let mut beads: Vec<_> = graph.beads.values().collect();
 
-    // Python
-    if repo_path.join("pyproject.toml").exists()
-        || repo_path.join("setup.py").exists()
-        || repo_path.join("requirements.txt").exists()
-    {
-        langs.push("Python");
+    // Apply ready filter (open, no open blockers in any context)
+    if filter.ready {
+        beads.retain(|b| b.status == Status::Open && !graph.has_open_blockers(b));
     }
 
-    // JavaScript/TypeScript
-    if repo_path.join("package.json").exists() {
-        if repo_path.join("tsconfig.json").exists() {
-            langs.push("TypeScript");
-        } else {
-            langs.push("JavaScript");
-        }
+    // Apply filters
+    if let Some(status_str) = &filter.status {
+        let status_filter = parse_status(status_str)?;
+        beads.retain(|b| b.status == status_filter);
+    } else if !filter.all && !filter.ready {
+        // Default: exclude closed unless --all or --ready
+        beads.retain(|b| b.status != Status::Closed);
     }
 
-    // Go
-    if repo_path.join("go.mod").exists() {
-        langs.push("Go");
+    if let Some(priority_str) = &filter.priority {
+        let priority_filter = parse_priority(priority_str)?;
+        beads.retain(|b| b.priority == priority_filter);
     }
 
-    // Java
-    if repo_path.join("pom.xml").exists() || repo_path.join("build.gradle").exists() {
-        langs.push("Java");
+    if let Some(context_str) = &filter.context {
+        let context_tag = if context_str.starts_with('@') {
+            context_str.clone()
+        } else {
+            format!("@{}", context_str)
+        };
+        beads.retain(|b| b.labels.contains(&context_tag));
     }
 
-    // Ruby
-    if repo_path.join("Gemfile").exists() {
-        langs.push("Ruby");
+    if let Some(label_str) = &filter.label {
+        beads.retain(|b| b.labels.contains(label_str));
     }
 
-    langs
-}
-
-/// Get expected test directories for a language
-fn get_test_directories(lang: &str) -> Vec<&'static str> {
-    match lang {
-        "Rust" => vec!["tests", "src"], // Rust uses tests/ or inline tests
-        "Python" => vec!["tests", "test"],
-        "JavaScript" | "TypeScript" => vec!["tests", "test", "__tests__", "spec"],
-        "Go" => vec!["."], // Go tests are alongside code
-        "Java" => vec!["src/test"],
-        "Ruby" => vec!["test", "spec"],
-        _ => vec!["tests", "test"],
+    if let Some(type_str) = &filter.issue_type {
+        let type_filter = parse_issue_type(type_str)?;
+        beads.retain(|b| b.issue_type == type_filter);
     }
-}
-
-/// Scan for potential security patterns
-fn scan_for_security_patterns(
-    repo_path: &std::path::Path,
-) -> allbeads::Result<Vec<(String, usize, String, String)>> {
-    let mut results = Vec::new();
-
-    // Patterns that might indicate security issues
-    let patterns = [
-        (
-            "hardcoded secret",
-            r#"(?i)(password|secret|api_key|apikey|token)\s*=\s*["'][^"']+["']"#,
-        ),
-        (
-            "SQL injection risk",
-            r#"(?i)execute\s*\(\s*["'].*\+|format!\s*\([^)]*\{[^}]*\}[^)]*sql"#,
-        ),
-        ("unsafe eval", r#"(?i)\beval\s*\("#),
-    ];
 
-    fn walk_for_security(
-        dir: &std::path::Path,
-        base: &std::path::Path,
-        patterns: &[(&str, &str)],
-        results: &mut Vec<(String, usize, String, String)>,
-    ) -> std::io::Result<()> {
-        if dir.is_dir() {
-            let dir_name = dir.file_name().and_then(|n| n.to_str()).unwrap_or("");
-            if dir_name.starts_with('.')
-                || dir_name == "node_modules"
-                || dir_name == "target"
-                || dir_name == "vendor"
-            {
-                return Ok(());
-            }
+    if let Some(assignee_str) = &filter.assignee {
+        beads.retain(|b| {
+            b.assignee
+                .as_ref()
+                .is_some_and(|a| a.contains(assignee_str.as_str()))
+        });
+    }
 
-            for entry in std::fs::read_dir(dir)? {
-                let entry = entry?;
-                let path = entry.path();
-                if path.is_dir() {
-                    walk_for_security(&path, base, patterns, results)?;
-                } else {
-                    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-                    if matches!(ext, "rs" | "py" | "js" | "ts" | "go" | "java" | "rb") {
-                        if let Ok(content) = std::fs::read_to_string(&path) {
-                            let relative = path
-                                .strip_prefix(base)
-                                .unwrap_or(&path)
-                                .to_string_lossy()
-                                .to_string();
-                            for (line_num, line) in content.lines().enumerate() {
-                                // Skip lines that are pattern definitions (avoid self-detection)
-                                if line.contains("r#\"") || line.contains("name == &\"") {
-                                    continue;
-                                }
-                                for (name, _pattern) in patterns {
-                                    // Simple substring check (regex would be better but adds dependency)
-                                    let line_lower = line.to_lowercase();
-                                    if (name == &"hardcoded secret"
-                                        && (line_lower.contains("password")
-                                            || line_lower.contains("secret")
-                                            || line_lower.contains("api_key"))
-                                        && line.contains("=")
-                                        && (line.contains("\"") || line.contains("'")))
-                                        || (name == &"unsafe eval" && line_lower.contains("eval("))
-                                    {
-                                        results.push((
-                                            relative.clone(),
-                                            line_num + 1,
-                                            name.to_string(),
-                                            line.trim().to_string(),
-                                        ));
-                                        if results.len() >= 20 {
-                                            return Ok(());
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-        Ok(())
+    if let Some(parent_str) = &filter.parent {
+        let parent = BeadId::from(parent_str.as_str());
+        beads.retain(|b| b.parent.as_ref() == Some(&parent));
+    }
+
+    if let Some(cutoff) = filter.since {
+        beads.retain(|b| allbeads::duration::updated_since(&b.updated_at, cutoff));
     }
 
-    walk_for_security(repo_path, repo_path, &patterns, &mut results)
-        .map_err(allbeads::AllBeadsError::Io)?;
+    // Sort by priority then status
+    sort_beads(&mut beads, SortKey::Priority, false);
 
-    Ok(results)
+    Ok(beads)
 }
 
-fn parse_status(s: &str) -> allbeads::Result<Status> {
-    match s.to_lowercase().as_str() {
-        "open" => Ok(Status::Open),
-        "in_progress" | "in-progress" => Ok(Status::InProgress),
-        "blocked" => Ok(Status::Blocked),
-        "deferred" => Ok(Status::Deferred),
-        "closed" => Ok(Status::Closed),
-        "tombstone" => Ok(Status::Tombstone),
-        _ => Err(allbeads::AllBeadsError::Parse(format!(
-            "Invalid status: {}. Must be one of: open, in_progress, blocked, deferred, closed, tombstone",
-            s
-        ))),
-    }
+/// Context name of a bead (from its `@context` label), or empty
+fn bead_context(bead: &allbeads::graph::Bead) -> &str {
+    bead.labels
+        .iter()
+        .find_map(|l| l.strip_prefix('@'))
+        .unwrap_or("")
 }
 
-fn parse_priority(s: &str) -> allbeads::Result<Priority> {
-    match s.to_uppercase().as_str() {
-        "P0" | "0" => Ok(Priority::P0),
-        "P1" | "1" => Ok(Priority::P1),
-        "P2" | "2" => Ok(Priority::P2),
-        "P3" | "3" => Ok(Priority::P3),
-        "P4" | "4" => Ok(Priority::P4),
-        _ => Err(allbeads::AllBeadsError::Parse(format!(
-            "Invalid priority: {}. Must be one of: P0-P4 or 0-4",
-            s
-        ))),
+/// Labels of a bead excluding the `@context` tag, sorted
+fn bead_plain_labels(bead: &allbeads::graph::Bead) -> Vec<&str> {
+    let mut labels: Vec<&str> = bead
+        .labels
+        .iter()
+        .filter(|l| !l.starts_with('@'))
+        .map(|l| l.as_str())
+        .collect();
+    labels.sort_unstable();
+    labels
+}
+
+/// Quote a CSV field if it contains a delimiter, quote, or newline
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
     }
 }
 
-fn parse_priority_arg(s: &str) -> Option<Priority> {
-    parse_priority(s).ok()
+/// Render beads as CSV
+fn export_csv(beads: &[&allbeads::graph::Bead]) -> String {
+    let mut out = String::from("id,title,status,priority,type,assignee,context,labels\n");
+    for bead in beads {
+        let fields = [
+            bead.id.as_str().to_string(),
+            bead.title.clone(),
+            format_status(bead.status).to_string(),
+            format_priority(bead.priority).to_string(),
+            format_issue_type(bead.issue_type).to_string(),
+            bead.assignee.clone().unwrap_or_default(),
+            bead_context(bead).to_string(),
+            bead_plain_labels(bead).join(";"),
+        ];
+        let row: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
 }
 
-fn parse_issue_type(s: &str) -> allbeads::Result<IssueType> {
-    match s.to_lowercase().as_str() {
-        "bug" => Ok(IssueType::Bug),
-        "feature" => Ok(IssueType::Feature),
-        "task" => Ok(IssueType::Task),
-        "epic" => Ok(IssueType::Epic),
-        "chore" => Ok(IssueType::Chore),
-        "merge_request" | "merge-request" | "mr" => Ok(IssueType::MergeRequest),
-        "molecule" => Ok(IssueType::Molecule),
-        "gate" => Ok(IssueType::Gate),
-        _ => Err(allbeads::AllBeadsError::Parse(format!(
-            "Invalid type: {}. Must be one of: bug, feature, task, epic, chore, merge_request, molecule, gate",
-            s
-        ))),
+/// Render beads as Markdown tables, one per context
+fn export_markdown(beads: &[&allbeads::graph::Bead]) -> String {
+    use std::collections::BTreeMap;
+
+    let mut by_context: BTreeMap<&str, Vec<&allbeads::graph::Bead>> = BTreeMap::new();
+    for bead in beads {
+        by_context.entry(bead_context(bead)).or_default().push(bead);
     }
-}
 
-fn status_to_sort_key(status: Status) -> u8 {
-    match status {
-        Status::Open => 0,
-        Status::InProgress => 1,
-        Status::Blocked => 2,
-        Status::Deferred => 3,
-        Status::Closed => 4,
-        Status::Tombstone => 5,
+    let cell = |s: &str| s.replace('|', "\\|").replace('\n', " ");
+    let mut out = String::new();
+    for (context, beads) in by_context {
+        let heading = if context.is_empty() {
+            "(no context)"
+        } else {
+            context
+        };
+        out.push_str(&format!("## {} ({})\n\n", heading, beads.len()));
+        out.push_str("| ID | Title | Status | Priority | Type | Assignee | Labels |\n");
+        out.push_str("|----|-------|--------|----------|------|----------|--------|\n");
+        for bead in beads {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} | {} |\n",
+                bead.id.as_str(),
+                cell(&bead.title),
+                format_status(bead.status),
+                format_priority(bead.priority),
+                format_issue_type(bead.issue_type),
+                cell(bead.assignee.as_deref().unwrap_or("")),
+                cell(&bead_plain_labels(bead).join(", ")),
+            ));
+        }
+        out.push('\n');
     }
+    out
 }
 
 fn print_bead_summary(bead: &allbeads::graph::Bead) {
@@ -3598,17 +6979,29 @@ fn print_bead_summary(bead: &allbeads::graph::Bead) {
     println!();
 }
 
+/// Print a bead for `ab show` in a non-interactive format
+fn print_bead_show(
+    bead: &allbeads::graph::Bead,
+    format: ShowFormat,
+    graph: Option<&FederatedGraph>,
+) -> allbeads::Result<()> {
+    match format {
+        ShowFormat::Text => print_bead_detailed(bead),
+        ShowFormat::Markdown => print!("{}", allbeads::graph::render_bead_markdown(bead, graph)),
+        ShowFormat::Json => println!("{}", serde_json::to_string_pretty(bead)?),
+    }
+    Ok(())
+}
+
 fn print_bead_detailed(bead: &allbeads::graph::Bead) {
-    let priority_num = priority_to_num(bead.priority);
-    let type_str = format_issue_type(bead.issue_type);
-    let status_str = format_status(bead.status);
+    let detail = allbeads::graph::BeadDetail::new(bead);
 
     // Header with ID and title
     println!(
         "{} {} - {}",
         style::issue_id(bead.id.as_str()),
         style::header(&bead.title),
-        style::dim(&format!("({})", type_str))
+        style::dim(&format!("({})", detail.type_str))
     );
     println!();
 
@@ -3616,59 +7009,77 @@ fn print_bead_detailed(bead: &allbeads::graph::Bead) {
     println!(
         "  {} {}  {} {}",
         style::dim("Status:"),
-        style::status_style(status_str),
+        style::status_style(detail.status_str),
         style::dim("Priority:"),
-        style::priority_style(priority_num)
+        style::priority_style(detail.priority)
     );
     println!(
         "  {} {}  {} {}",
         style::dim("Created:"),
-        &bead.created_at[..19], // Trim to date + time
+        detail.created,
         style::dim("by"),
         bead.created_by
     );
-    println!("  {} {}", style::dim("Updated:"), &bead.updated_at[..19]);
+    println!("  {} {}", style::dim("Updated:"), detail.updated);
 
     if let Some(ref assignee) = bead.assignee {
         println!("  {} {}", style::dim("Assignee:"), assignee);
     }
 
-    if !bead.labels.is_empty() {
+    if let Some(ref parent) = bead.parent {
         println!(
             "  {} {}",
-            style::dim("Labels:"),
-            bead.labels
-                .iter()
-                .map(|s| s.as_str())
-                .collect::<Vec<_>>()
-                .join(", ")
+            style::dim("Parent:"),
+            style::issue_id(parent.as_str())
         );
     }
 
-    if !bead.dependencies.is_empty() {
+    if !detail.labels.is_empty() {
+        println!("  {} {}", style::dim("Labels:"), detail.labels.join(", "));
+    }
+
+    if !detail.dependencies.is_empty() {
         println!(
             "  {} {}",
             style::dim("Depends on:"),
-            bead.dependencies
+            detail
+                .dependencies
                 .iter()
-                .map(|id| style::issue_id(id.as_str()).to_string())
+                .map(|id| style::issue_id(id).to_string())
                 .collect::<Vec<_>>()
                 .join(", ")
         );
     }
 
-    if !bead.blocks.is_empty() {
+    if !detail.blocks.is_empty() {
         println!(
             "  {} {}",
             style::dim("Blocks:"),
-            bead.blocks
+            detail
+                .blocks
                 .iter()
-                .map(|id| style::issue_id(id.as_str()).to_string())
+                .map(|id| style::issue_id(id).to_string())
                 .collect::<Vec<_>>()
                 .join(", ")
         );
     }
 
+    for link_type in allbeads::graph::LinkType::ALL {
+        let targets: Vec<String> = detail
+            .links
+            .iter()
+            .filter(|l| l.link_type == link_type)
+            .map(|l| style::issue_id(l.target.as_str()).to_string())
+            .collect();
+        if !targets.is_empty() {
+            println!(
+                "  {} {}",
+                style::dim(&format!("{}:", link_type.display_name())),
+                targets.join(", ")
+            );
+        }
+    }
+
     if let Some(ref description) = bead.description {
         println!();
         println!("{}", style::subheader("Description:"));
@@ -3682,6 +7093,138 @@ fn print_bead_detailed(bead: &allbeads::graph::Bead) {
     }
 }
 
+/// Add or remove a non-blocking link label on `from`
+fn handle_link_command(
+    graph: &FederatedGraph,
+    config: &AllBeadsConfig,
+    from: &str,
+    to: &str,
+    link_type: allbeads::graph::LinkType,
+    remove: bool,
+    bd_flags: &[String],
+) -> allbeads::Result<()> {
+    if from == to {
+        return Err(allbeads::AllBeadsError::Config(
+            "A bead cannot link to itself".to_string(),
+        ));
+    }
+    let bead = graph.get_bead(&BeadId::new(from)).ok_or_else(|| {
+        allbeads::AllBeadsError::IssueNotFound(format!("Bead '{}' not found", from))
+    })?;
+    if !remove && graph.get_bead(&BeadId::new(to)).is_none() {
+        return Err(allbeads::AllBeadsError::IssueNotFound(format!(
+            "Bead '{}' not found",
+            to
+        )));
+    }
+
+    let ctx_name = bead_context(bead);
+    if ctx_name.is_empty() {
+        return Err(allbeads::AllBeadsError::Config(
+            "Bead has no context label".to_string(),
+        ));
+    }
+    let ctx_path = config
+        .contexts
+        .iter()
+        .find(|c| c.name == ctx_name)
+        .and_then(|c| c.local_path())
+        .ok_or_else(|| {
+            allbeads::AllBeadsError::Config(format!("Context '{}' has no local path", ctx_name))
+        })?;
+
+    let label = link_type.label(to);
+    let has_link = bead.labels.contains(&label);
+    if remove != has_link {
+        println!(
+            "{} {} {} {} {}",
+            style::dim("No change:"),
+            from,
+            if remove { "has no" } else { "already has" },
+            link_type.as_str(),
+            to
+        );
+        return Ok(());
+    }
+
+    let bd = Beads::with_workdir_and_flags(&ctx_path, bd_flags.to_vec());
+    let result = if remove {
+        bd.label_remove(from, &label)
+    } else {
+        bd.label_add(from, &label)
+    };
+    match result {
+        Ok(output) if output.success => {}
+        Ok(output) => {
+            return Err(allbeads::AllBeadsError::Other(format!(
+                "bd label failed: {}",
+                output.stderr.trim()
+            )))
+        }
+        Err(e) => return Err(allbeads::AllBeadsError::Other(e.to_string())),
+    }
+
+    let kind = if remove {
+        allbeads::undo::OpKind::LabelRemove {
+            id: from.to_string(),
+            label,
+        }
+    } else {
+        allbeads::undo::OpKind::LabelAdd {
+            id: from.to_string(),
+            label,
+        }
+    };
+    record_undo(config, Some(ctx_name), &ctx_path, kind);
+
+    println!(
+        "{} {} {} {} {}",
+        style::success("✓"),
+        if remove { "Unlinked" } else { "Linked" },
+        style::issue_id(from),
+        style::dim(link_type.as_str()),
+        style::issue_id(to)
+    );
+    Ok(())
+}
+
+/// Offer an existing label in place of a near-duplicate one
+///
+/// Compares against the plain labels used by beads in `context`. Returns
+/// the label to apply: the existing one if the user accepts it, otherwise
+/// `label` unchanged.
+fn confirm_similar_label(
+    graph: &FederatedGraph,
+    context: &str,
+    label: String,
+) -> allbeads::Result<String> {
+    use dialoguer::Confirm;
+
+    let context_label = format!("@{}", context);
+    let existing: std::collections::BTreeSet<&str> = graph
+        .beads
+        .values()
+        .filter(|b| b.labels.contains(&context_label))
+        .flat_map(bead_plain_labels)
+        .collect();
+    let Some(suggestion) = allbeads::graph::similar_labels(&label, existing)
+        .first()
+        .map(|s| s.to_string())
+    else {
+        return Ok(label);
+    };
+
+    let use_existing = Confirm::new()
+        .with_prompt(format!(
+            "Label '{}' is close to existing '{}'. Did you mean '{}'?",
+            label, suggestion, suggestion
+        ))
+        .default(true)
+        .interact()
+        .map_err(|e| allbeads::AllBeadsError::Config(format!("Input error: {}", e)))?;
+    Ok(if use_existing { suggestion } else { label })
+}
+
 /// Show handoff info for a bead that has been handed off to an agent
 fn show_handoff_info(bead_id: &str, bead: &allbeads::graph::Bead) -> allbeads::Result<()> {
     // Try to load comments from the beads crate
@@ -3705,15 +7248,9 @@ fn show_handoff_info(bead_id: &str, bead: &allbeads::graph::Bead) -> allbeads::R
 
     for comment in &comments {
         let content = &comment.content;
-        if content.starts_with("[HANDOFF]") {
-            // Parse: [HANDOFF] Agent: Claude Code, Time: 2026-01-15T10:30:00Z
-            if let Some(agent_part) = content.strip_prefix("[HANDOFF] Agent: ") {
-                if let Some(comma_pos) = agent_part.find(", Time:") {
-                    agent_info = Some(agent_part[..comma_pos].to_string());
-                    let time_part = &agent_part[comma_pos + 7..]; // Skip ", Time:"
-                    handoff_time = Some(time_part.trim().to_string());
-                }
-            }
+        if let Some((agent, time)) = allbeads::handoff::parse_handoff_comment(content) {
+            agent_info = Some(agent.to_string());
+            handoff_time = Some(time.to_string());
         } else if content.starts_with("[TASK_URL]") {
             // Parse: [TASK_URL] https://...
             if let Some(url) = content.strip_prefix("[TASK_URL] ") {
@@ -3816,7 +7353,10 @@ fn calculate_similarity(s1: &str, s2: &str) -> f64 {
 
 // === Distributed Configuration Commands (Phase 4 of PRD-01) ===
 
-fn handle_config_command(cmd: &ConfigCommands) -> allbeads::Result<()> {
+fn handle_config_command(
+    cmd: &ConfigCommands,
+    config_path: &Option<String>,
+) -> allbeads::Result<()> {
     let config_dir = dirs::config_dir()
         .ok_or_else(|| {
             allbeads::AllBeadsError::Config("Could not determine config directory".to_string())
@@ -3843,11 +7383,199 @@ fn handle_config_command(cmd: &ConfigCommands) -> allbeads::Result<()> {
         ConfigCommands::Diff => {
             handle_config_diff(&config_dir)?;
         }
+        ConfigCommands::Resolve {
+            theirs,
+            ours,
+            merge,
+        } => {
+            handle_config_resolve(&config_dir, *theirs, *ours, *merge)?;
+        }
         ConfigCommands::Clone { source, target } => {
             handle_config_clone(source, target.as_deref())?;
         }
+        ConfigCommands::Check => {
+            handle_config_check(config_path)?;
+        }
+        ConfigCommands::Restore { backup } => {
+            handle_config_restore(&config_dir, backup.as_deref())?;
+        }
+    }
+    Ok(())
+}
+
+/// Back up the config files before a forced operation and say where they went
+fn backup_config_files(config_dir: &Path, reason: &str) -> allbeads::Result<()> {
+    if let Some(backup) = allbeads::config::backup_config(config_dir, reason)? {
+        println!(
+            "  {} Backed up config to {} (undo with 'ab config restore {}')",
+            style::success("✓"),
+            style::path(&backup.path.display().to_string()),
+            backup.name
+        );
+    }
+    Ok(())
+}
+
+/// List config backups, or restore one by name
+fn handle_config_restore(config_dir: &Path, backup: Option<&str>) -> allbeads::Result<()> {
+    let Some(name) = backup else {
+        let backups = allbeads::config::list_backups(config_dir)?;
+        if backups.is_empty() {
+            println!("No config backups. They are taken before forced config operations.");
+            return Ok(());
+        }
+        println!();
+        println!("{}", style::header("Config Backups"));
+        println!();
+        for backup in &backups {
+            println!(
+                "  {}  {}",
+                style::highlight(&backup.name),
+                style::dim(&backup.files.join(", "))
+            );
+        }
+        println!();
+        println!("  Restore one with: ab config restore <name>");
+        return Ok(());
+    };
+
+    let safety = allbeads::config::restore_backup(config_dir, name)?;
+    println!(
+        "{} Restored config from backup {}",
+        style::success("✓"),
+        style::highlight(name)
+    );
+    if let Some(safety) = safety {
+        println!(
+            "  Previous config saved as {} (undo with 'ab config restore {}')",
+            style::path(&safety.path.display().to_string()),
+            safety.name
+        );
+    }
+    if config_dir.join(".git").exists() {
+        println!(
+            "  {}",
+            style::dim("Run 'ab config push' to sync the restored config.")
+        );
+    }
+    Ok(())
+}
+
+/// Check the configuration for problems and report them
+///
+/// Returns an error (non-zero exit) if any issue is an error rather than a warning.
+fn handle_config_check(config_path: &Option<String>) -> allbeads::Result<()> {
+    let config_file = if let Some(path) = config_path {
+        PathBuf::from(path)
+    } else {
+        AllBeadsConfig::default_path()
+    };
+    let config = AllBeadsConfig::load(&config_file)?;
+
+    println!(
+        "Checking {}...",
+        style::path(&config_file.display().to_string())
+    );
+    println!();
+
+    let issues = config.validate();
+    if issues.is_empty() {
+        println!(
+            "{} {} contexts, no issues found",
+            style::success("✓"),
+            config.contexts.len()
+        );
+        return Ok(());
+    }
+
+    print_config_issues(&issues);
+
+    let error_count = issues.iter().filter(|i| i.is_error()).count();
+    let warning_count = issues.len() - error_count;
+    println!();
+    println!("{} error(s), {} warning(s)", error_count, warning_count);
+
+    if error_count > 0 {
+        return Err(allbeads::AllBeadsError::Config(format!(
+            "Configuration has {} error(s)",
+            error_count
+        )));
+    }
+    Ok(())
+}
+
+/// Run `ab doctor` health checks and print the report
+///
+/// Returns an error (non-zero exit) if any check fails.
+fn handle_doctor_command(
+    config_path: &Option<String>,
+    contexts: Option<&str>,
+    bd_flags: &[String],
+    json: bool,
+) -> allbeads::Result<()> {
+    use allbeads::doctor::CheckStatus;
+
+    let config = match config_path {
+        Some(path) => AllBeadsConfig::load(path),
+        None => AllBeadsConfig::load_default(),
+    };
+    let contexts: Vec<String> = contexts
+        .map(|c| {
+            c.split(',')
+                .map(|s| s.trim().trim_start_matches('@').to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let report = allbeads::doctor::run(&config, &CacheConfig::default().path, &contexts, bd_flags);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!("{}", style::header("AllBeads Doctor"));
+        println!();
+        for check in &report.checks {
+            let marker = match check.status {
+                CheckStatus::Pass => style::success("✓"),
+                CheckStatus::Warn => style::warning("⚠"),
+                CheckStatus::Fail => style::error("✗"),
+            };
+            let label = match check.context {
+                Some(ref ctx) => format!("{} @{}", check.name, ctx),
+                None => check.name.clone(),
+            };
+            println!("  {} {:<20} {}", marker, label, check.message);
+            if let Some(ref suggestion) = check.suggestion {
+                println!("    {:<20} {}", "", style::dim(suggestion));
+            }
+        }
+        println!();
+        println!(
+            "{} passed, {} warning(s), {} failed",
+            report.count(CheckStatus::Pass),
+            report.count(CheckStatus::Warn),
+            report.count(CheckStatus::Fail)
+        );
+    }
+
+    if report.has_failures() {
+        return Err(allbeads::AllBeadsError::Config(format!(
+            "{} health check(s) failed",
+            report.count(CheckStatus::Fail)
+        )));
+    }
+    Ok(())
+}
+
+/// Print config issues, one per line, with a severity marker
+fn print_config_issues(issues: &[allbeads::config::ConfigIssue]) {
+    for issue in issues {
+        if issue.is_error() {
+            println!("  {} {}", style::error("✗ error:"), issue);
+        } else {
+            println!("  {} {}", style::warning("⚠ warning:"), issue);
+        }
     }
-    Ok(())
 }
 
 /// Initialize distributed config sync
@@ -3944,6 +7672,7 @@ fn handle_config_init(
 
     // Remove existing origin if force
     if force && repo.find_remote("origin").is_ok() {
+        backup_config_files(config_dir, "init")?;
         repo.remote_delete("origin").map_err(|e| {
             allbeads::AllBeadsError::Git(format!("Failed to remove existing remote: {}", e))
         })?;
@@ -4045,22 +7774,15 @@ fn handle_config_pull(config_dir: &Path, force: bool) -> allbeads::Result<()> {
     let remote_url = remote.url().unwrap_or("unknown");
     println!("  Remote: {}", style::path(remote_url));
 
-    // Run git pull
-    let output = std::process::Command::new("git")
-        .args(if force {
-            vec![
-                "-C",
-                config_dir.to_str().unwrap(),
-                "pull",
-                "--force",
-                "origin",
-                "main",
-            ]
-        } else {
-            vec!["-C", config_dir.to_str().unwrap(), "pull", "origin", "main"]
-        })
-        .output()
-        .map_err(|e| allbeads::AllBeadsError::Git(format!("Failed to run git pull: {}", e)))?;
+    // Run git pull, retrying network hiccups
+    let pull_args: &[&str] = if force {
+        backup_config_files(config_dir, "pull")?;
+        &["pull", "--force", "origin", "main"]
+    } else {
+        &["pull", "origin", "main"]
+    };
+    let run = allbeads::git::run_git_with_retry(config_dir, pull_args)?;
+    let output = run.output;
 
     if output.status.success() {
         let stdout = String::from_utf8_lossy(&output.stdout);
@@ -4075,7 +7797,8 @@ fn handle_config_pull(config_dir: &Path, force: bool) -> allbeads::Result<()> {
         let stderr = String::from_utf8_lossy(&output.stderr);
         if stderr.contains("fatal") || stderr.contains("error") {
             return Err(allbeads::AllBeadsError::Git(format!(
-                "Pull failed: {}",
+                "Pull failed{}: {}",
+                attempts_note(run.attempts),
                 stderr
             )));
         }
@@ -4164,32 +7887,14 @@ fn handle_config_push(
         println!("  {} No changes to commit", style::dim("○"));
     }
 
-    // Push to remote
-    let push_args = if force {
-        vec![
-            "-C",
-            config_dir.to_str().unwrap(),
-            "push",
-            "--force",
-            "-u",
-            "origin",
-            "main",
-        ]
+    // Push to remote, retrying network hiccups
+    let push_args: &[&str] = if force {
+        &["push", "--force", "-u", "origin", "main"]
     } else {
-        vec![
-            "-C",
-            config_dir.to_str().unwrap(),
-            "push",
-            "-u",
-            "origin",
-            "main",
-        ]
+        &["push", "-u", "origin", "main"]
     };
-
-    let output = std::process::Command::new("git")
-        .args(&push_args)
-        .output()
-        .map_err(|e| allbeads::AllBeadsError::Git(format!("Failed to push: {}", e)))?;
+    let run = allbeads::git::run_git_with_retry(config_dir, push_args)?;
+    let output = run.output;
 
     if output.status.success() {
         println!("  {} Pushed to remote", style::success("✓"));
@@ -4199,7 +7904,8 @@ fn handle_config_push(
             println!("  {} Already up to date", style::success("✓"));
         } else if stderr.contains("fatal") || stderr.contains("error") {
             return Err(allbeads::AllBeadsError::Git(format!(
-                "Push failed: {}",
+                "Push failed{}: {}",
+                attempts_note(run.attempts),
                 stderr
             )));
         }
@@ -4208,6 +7914,15 @@ fn handle_config_push(
     Ok(())
 }
 
+/// " after N attempts" when a git command was retried
+fn attempts_note(attempts: u32) -> String {
+    if attempts > 1 {
+        format!(" after {} attempts", attempts)
+    } else {
+        String::new()
+    }
+}
+
 /// Show config sync status
 fn handle_config_status(config_dir: &Path) -> allbeads::Result<()> {
     use git2::Repository;
@@ -4270,41 +7985,222 @@ fn handle_config_status(config_dir: &Path) -> allbeads::Result<()> {
     }
 
     // Check ahead/behind
+    if let Some((ahead, behind)) = git_ahead_behind(config_dir, "HEAD...origin/main") {
+        if ahead > 0 && behind > 0 {
+            println!(
+                "  Sync:       {} ahead, {} behind (diverged)",
+                ahead, behind
+            );
+        } else if ahead > 0 {
+            println!("  Sync:       {} commits ahead", ahead);
+        } else if behind > 0 {
+            println!("  Sync:       {} commits behind", behind);
+        } else {
+            println!("  Sync:       {}", style::success("Up to date"));
+        }
+    }
+
+    println!();
+
+    Ok(())
+}
+
+/// Commits ahead of and behind the other side of `range` (e.g. `HEAD...origin/main`)
+///
+/// Uses `git rev-list --left-right --count`; `None` when git fails, for
+/// example because the branch has no upstream.
+fn git_ahead_behind(repo_dir: &Path, range: &str) -> Option<(usize, usize)> {
     let output = std::process::Command::new("git")
-        .args([
-            "-C",
-            config_dir.to_str().unwrap(),
-            "rev-list",
-            "--left-right",
-            "--count",
-            "HEAD...origin/main",
-        ])
-        .output();
+        .arg("-C")
+        .arg(repo_dir)
+        .args(["rev-list", "--left-right", "--count", range])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let counts = String::from_utf8_lossy(&output.stdout);
+    let (ahead, behind) = counts.trim().split_once('\t')?;
+    Some((ahead.parse().ok()?, behind.parse().ok()?))
+}
 
-    if let Ok(output) = output {
-        if output.status.success() {
-            let counts = String::from_utf8_lossy(&output.stdout);
-            let parts: Vec<&str> = counts.trim().split('\t').collect();
-            if parts.len() == 2 {
-                let ahead: i32 = parts[0].parse().unwrap_or(0);
-                let behind: i32 = parts[1].parse().unwrap_or(0);
+/// Guide the user out of a config that diverged from origin/main
+fn handle_config_resolve(
+    config_dir: &Path,
+    theirs: bool,
+    ours: bool,
+    merge: bool,
+) -> allbeads::Result<()> {
+    let git = |args: &[&str]| -> allbeads::Result<std::process::Output> {
+        std::process::Command::new("git")
+            .arg("-C")
+            .arg(config_dir)
+            .args(args)
+            .output()
+            .map_err(|e| {
+                allbeads::AllBeadsError::Git(format!("Failed to run git {}: {}", args[0], e))
+            })
+    };
+    let git_ok = |args: &[&str]| -> allbeads::Result<String> {
+        let output = git(args)?;
+        if !output.status.success() {
+            return Err(allbeads::AllBeadsError::Git(format!(
+                "git {} failed: {}",
+                args[0],
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
 
-                if ahead > 0 && behind > 0 {
-                    println!(
-                        "  Sync:       {} ahead, {} behind (diverged)",
-                        ahead, behind
-                    );
-                } else if ahead > 0 {
-                    println!("  Sync:       {} commits ahead", ahead);
-                } else if behind > 0 {
-                    println!("  Sync:       {} commits behind", behind);
-                } else {
-                    println!("  Sync:       {}", style::success("Up to date"));
-                }
+    if !config_dir.join(".git").exists() {
+        return Err(allbeads::AllBeadsError::Config(
+            "Config sync not initialized. Run 'ab config init --remote=<url>' first.".to_string(),
+        ));
+    }
+
+    println!();
+    println!("{}", style::header("Resolve Config"));
+    println!();
+
+    let fetch = allbeads::git::run_git_with_retry(config_dir, &["fetch", "origin"])?;
+    if !fetch.output.status.success() {
+        return Err(allbeads::AllBeadsError::Git(format!(
+            "Fetch failed{}: {}",
+            attempts_note(fetch.attempts),
+            String::from_utf8_lossy(&fetch.output.stderr).trim()
+        )));
+    }
+
+    let Some((ahead, behind)) = git_ahead_behind(config_dir, "HEAD...origin/main") else {
+        return Err(allbeads::AllBeadsError::Git(
+            "Could not compare with origin/main. Has the config been pushed yet?".to_string(),
+        ));
+    };
+    if ahead == 0 || behind == 0 {
+        let hint = match (ahead, behind) {
+            (0, 0) => "Already in sync with the remote.".to_string(),
+            (0, n) => format!("{} commit(s) behind. Run 'ab config pull'.", n),
+            (n, _) => format!("{} commit(s) ahead. Run 'ab config push'.", n),
+        };
+        println!("  {} Not diverged: {}", style::success("✓"), hint);
+        println!();
+        return Ok(());
+    }
+    println!(
+        "  Local config is {} ahead and {} behind origin/main (diverged)",
+        ahead, behind
+    );
+
+    if !git_ok(&["status", "--porcelain"])?.trim().is_empty() {
+        return Err(allbeads::AllBeadsError::Config(
+            "Config has uncommitted changes. Commit them first (git -C <config dir> commit -am ...)."
+                .to_string(),
+        ));
+    }
+
+    if theirs {
+        let backup = format!(
+            "config-backup-{}",
+            chrono::Utc::now().format("%Y%m%d%H%M%S")
+        );
+        git_ok(&["branch", &backup])?;
+        backup_config_files(config_dir, "resolve")?;
+        git_ok(&["reset", "--hard", "origin/main"])?;
+        println!(
+            "  {} Took the remote config; local commits saved on branch {}",
+            style::success("✓"),
+            style::highlight(&backup)
+        );
+    } else if ours {
+        git_ok(&["merge", "-s", "ours", "--no-edit", "origin/main"])?;
+        println!(
+            "  {} Kept the local config. Run 'ab config push' to publish it.",
+            style::success("✓")
+        );
+    } else if merge {
+        let output = git(&["merge", "--no-ff", "--no-commit", "origin/main"])?;
+        let conflicted = git_ok(&["diff", "--name-only", "--diff-filter=U"])?;
+        if !output.status.success() && conflicted.trim().is_empty() {
+            return Err(allbeads::AllBeadsError::Git(format!(
+                "Merge failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        let mut unresolved = Vec::new();
+        for file in conflicted.lines().filter(|f| !f.is_empty()) {
+            if !(file.ends_with(".yaml") || file.ends_with(".yml")) {
+                println!(
+                    "  {} {}: not YAML, resolve by hand",
+                    style::warning("!"),
+                    file
+                );
+                unresolved.push(file);
+                continue;
+            }
+            // Stages 1-3 of the index hold base, ours and theirs
+            let stage = |n: u8| {
+                git(&["show", &format!(":{}:{}", n, file)])
+                    .ok()
+                    .filter(|o| o.status.success())
+                    .map(|o| String::from_utf8_lossy(&o.stdout).into_owned())
+                    .unwrap_or_default()
+            };
+            let result = allbeads::config::merge_yaml(&stage(1), &stage(2), &stage(3))?;
+            if result.conflicts.is_empty() {
+                std::fs::write(config_dir.join(file), result.to_yaml()?)?;
+                git_ok(&["add", file])?;
+                println!("  {} {}: merged field by field", style::success("✓"), file);
+            } else {
+                println!(
+                    "  {} {}: changed on both sides: {}",
+                    style::warning("!"),
+                    file,
+                    result.conflicts.join(", ")
+                );
+                unresolved.push(file);
             }
         }
-    }
 
+        if unresolved.is_empty() {
+            git_ok(&["commit", "--no-edit"])?;
+            println!(
+                "  {} Merged. Run 'ab config push' to publish.",
+                style::success("✓")
+            );
+        } else {
+            println!();
+            println!(
+                "  Fix the conflict markers in {}, then 'git -C {} commit'.",
+                unresolved.join(", "),
+                config_dir.display()
+            );
+            println!(
+                "  Or start over with 'git -C {} merge --abort'.",
+                config_dir.display()
+            );
+        }
+    } else {
+        println!();
+        print!("{}", git_ok(&["diff", "--stat", "HEAD", "origin/main"])?);
+        println!();
+        print!("{}", git_ok(&["diff", "HEAD", "origin/main"])?);
+        println!();
+        println!("  Choose how to resolve:");
+        println!(
+            "    {}  take the remote config (local commits kept on a backup branch)",
+            style::highlight("ab config resolve --theirs")
+        );
+        println!(
+            "    {}    keep the local config and overwrite the remote on next push",
+            style::highlight("ab config resolve --ours")
+        );
+        println!(
+            "    {}   merge both, field by field for YAML files",
+            style::highlight("ab config resolve --merge")
+        );
+    }
     println!();
 
     Ok(())
@@ -4570,20 +8466,36 @@ fn handle_auth_command(show_token: bool) -> allbeads::Result<()> {
 // Plugin Commands
 // ============================================================================
 
-fn handle_plugin_command(cmd: &PluginCommands) -> allbeads::Result<()> {
+fn handle_plugin_command(
+    cmd: &PluginCommands,
+    config_path: &Option<String>,
+) -> allbeads::Result<()> {
+    // Dev plugins are listed next to the config file, but plugin commands
+    // also work before `ab init` has created one
+    let config = match config_path {
+        Some(cp) => AllBeadsConfig::load(cp)?,
+        None if AllBeadsConfig::default_path().exists() => AllBeadsConfig::load_default()?,
+        None => AllBeadsConfig::new(),
+    };
+
     match cmd {
         PluginCommands::List {
             all,
             category,
             json,
-        } => handle_plugin_list(*all, category.as_deref(), *json),
-        PluginCommands::Info { name } => handle_plugin_info(name),
-        PluginCommands::Status { name } => handle_plugin_status(name.as_deref()),
-        PluginCommands::Detect { path, verbose } => handle_plugin_detect(path, *verbose),
-        PluginCommands::Install { name, yes } => handle_plugin_install(name, *yes),
+        } => handle_plugin_list(&config, *all, category.as_deref(), *json),
+        PluginCommands::Info { name } => handle_plugin_info(&config, name),
+        PluginCommands::Status { name } => handle_plugin_status(&config, name.as_deref()),
+        PluginCommands::Detect { path, verbose } => {
+            handle_plugin_detect(&config, path, *verbose > 0)
+        }
+        PluginCommands::Install { name, yes } => handle_plugin_install(&config, name, *yes),
         PluginCommands::Uninstall { name, yes } => handle_plugin_uninstall(name, *yes),
-        PluginCommands::Onboard { name, path, yes } => handle_plugin_onboard(name, path, *yes),
-        PluginCommands::Recommend { path } => handle_plugin_recommend(path),
+        PluginCommands::Onboard { name, path, yes } => {
+            handle_plugin_onboard(&config, name, path, *yes)
+        }
+        PluginCommands::Dev { path, clear } => handle_plugin_dev(&config, path.as_deref(), *clear),
+        PluginCommands::Recommend { path } => handle_plugin_recommend(&config, path),
         PluginCommands::MarketplaceList { json } => handle_marketplace_list(*json),
         PluginCommands::MarketplaceAdd { source, name } => {
             handle_marketplace_add(source, name.as_deref())
@@ -4592,10 +8504,15 @@ fn handle_plugin_command(cmd: &PluginCommands) -> allbeads::Result<()> {
     }
 }
 
-fn handle_plugin_list(all: bool, category: Option<&str>, json: bool) -> allbeads::Result<()> {
+fn handle_plugin_list(
+    config: &AllBeadsConfig,
+    all: bool,
+    category: Option<&str>,
+    json: bool,
+) -> allbeads::Result<()> {
     use allbeads::plugin::{ClaudePluginState, PluginCategory, PluginRegistry};
 
-    let registry = PluginRegistry::builtin();
+    let registry = PluginRegistry::load(config);
     let claude_state = ClaudePluginState::load();
 
     // Filter by category if specified
@@ -4680,10 +8597,10 @@ fn handle_plugin_list(all: bool, category: Option<&str>, json: bool) -> allbeads
     Ok(())
 }
 
-fn handle_plugin_info(name: &str) -> allbeads::Result<()> {
+fn handle_plugin_info(config: &AllBeadsConfig, name: &str) -> allbeads::Result<()> {
     use allbeads::plugin::{ClaudePluginState, PluginRegistry};
 
-    let registry = PluginRegistry::builtin();
+    let registry = PluginRegistry::load(config);
     let claude_state = ClaudePluginState::load();
 
     let plugin = registry.find(name).ok_or_else(|| {
@@ -4749,10 +8666,10 @@ fn handle_plugin_info(name: &str) -> allbeads::Result<()> {
     Ok(())
 }
 
-fn handle_plugin_status(name: Option<&str>) -> allbeads::Result<()> {
+fn handle_plugin_status(config: &AllBeadsConfig, name: Option<&str>) -> allbeads::Result<()> {
     use allbeads::plugin::{ClaudePluginState, PluginRegistry};
 
-    let registry = PluginRegistry::builtin();
+    let registry = PluginRegistry::load(config);
     let claude_state = ClaudePluginState::load();
 
     println!();
@@ -4817,7 +8734,11 @@ fn handle_plugin_status(name: Option<&str>) -> allbeads::Result<()> {
     Ok(())
 }
 
-fn handle_plugin_detect(path: &str, verbose: bool) -> allbeads::Result<()> {
+fn handle_plugin_detect(
+    config: &AllBeadsConfig,
+    path: &str,
+    verbose: bool,
+) -> allbeads::Result<()> {
     use allbeads::plugin::PluginRegistry;
     use std::path::Path;
 
@@ -4884,7 +8805,7 @@ fn handle_plugin_detect(path: &str, verbose: bool) -> allbeads::Result<()> {
     }
 
     // Get recommendations
-    let registry = PluginRegistry::builtin();
+    let registry = PluginRegistry::load(config);
     let recommended = registry.recommend(&languages, &detected_files);
 
     println!("  Recommended plugins:");
@@ -4904,12 +8825,12 @@ fn handle_plugin_detect(path: &str, verbose: bool) -> allbeads::Result<()> {
     Ok(())
 }
 
-fn handle_plugin_install(name: &str, yes: bool) -> allbeads::Result<()> {
+fn handle_plugin_install(config: &AllBeadsConfig, name: &str, yes: bool) -> allbeads::Result<()> {
     use allbeads::plugin::{
-        check_prerequisites, load_onboarding, OnboardingExecutor, PluginRegistry,
+        check_prerequisites, load_onboarding, ClaudePluginState, OnboardingExecutor, PluginRegistry,
     };
 
-    let registry = PluginRegistry::builtin();
+    let registry = PluginRegistry::load(config);
     let plugin = registry.find(name);
 
     println!();
@@ -4926,6 +8847,43 @@ fn handle_plugin_install(name: &str, yes: bool) -> allbeads::Result<()> {
         }
     };
 
+    // Plugins this one depends on, in install order (the plugin itself is last)
+    let install_order = registry
+        .resolve_install_order(name)
+        .map_err(|e| allbeads::AllBeadsError::Config(e.to_string()))?;
+    let required = &install_order[..install_order.len() - 1];
+    let unknown: Vec<&String> = install_order
+        .iter()
+        .flat_map(|p| &p.depends_on)
+        .filter(|dep| registry.find(dep).is_none())
+        .collect();
+
+    if !required.is_empty() || !unknown.is_empty() {
+        let claude_state = ClaudePluginState::load();
+        println!("  Required plugins (install in this order):");
+        for dep in required {
+            if claude_state.is_installed(&dep.name) {
+                println!("    {} {}", style::success("✓"), dep.name);
+            } else {
+                println!("    {} {}", style::warning("○"), dep.name);
+                if let Some(ref marketplace) = dep.marketplace {
+                    println!("      claude plugin install {}", marketplace);
+                } else {
+                    println!("      ab plugin install {}", dep.name);
+                }
+            }
+        }
+        for dep in unknown {
+            println!(
+                "    {} {} {}",
+                style::error("✗"),
+                dep,
+                style::dim("(not in registry)")
+            );
+        }
+        println!();
+    }
+
     // If plugin has marketplace entry, suggest claude plugin install
     if let Some(ref marketplace) = plugin.marketplace {
         println!("  Step 1: Install via Claude marketplace");
@@ -4939,7 +8897,12 @@ fn handle_plugin_install(name: &str, yes: bool) -> allbeads::Result<()> {
         allbeads::AllBeadsError::Config(format!("Could not get current directory: {}", e))
     })?;
 
-    if let Some(onboarding) = load_onboarding(&current_dir) {
+    // A plugin registered with `ab plugin dev` uses the onboarding from its source
+    let onboarding = registry
+        .dev_path(name)
+        .and_then(load_onboarding)
+        .or_else(|| load_onboarding(&current_dir));
+    if let Some(onboarding) = onboarding {
         println!("  Step 2: Run onboarding for this project");
         println!();
 
@@ -4990,6 +8953,16 @@ fn handle_plugin_install(name: &str, yes: bool) -> allbeads::Result<()> {
                 for err in &result.errors {
                     println!("    - {}", err);
                 }
+                match result.rollback_succeeded {
+                    Some(true) => {
+                        println!("  {} Completed steps were rolled back", style::success("✓"))
+                    }
+                    Some(false) => println!(
+                        "  {} Rollback incomplete; review the project before retrying",
+                        style::warning("!")
+                    ),
+                    None => {}
+                }
             }
         } else {
             println!("  Run with --yes to execute onboarding steps.");
@@ -5055,7 +9028,12 @@ fn handle_plugin_uninstall(name: &str, yes: bool) -> allbeads::Result<()> {
     Ok(())
 }
 
-fn handle_plugin_onboard(name: &str, path: &str, yes: bool) -> allbeads::Result<()> {
+fn handle_plugin_onboard(
+    config: &AllBeadsConfig,
+    name: &str,
+    path: &str,
+    yes: bool,
+) -> allbeads::Result<()> {
     use allbeads::plugin::{
         check_prerequisites, load_onboarding, OnboardingExecutor, PluginRegistry,
     };
@@ -5065,7 +9043,7 @@ fn handle_plugin_onboard(name: &str, path: &str, yes: bool) -> allbeads::Result<
         .canonicalize()
         .map_err(|e| allbeads::AllBeadsError::Config(format!("Invalid path '{}': {}", path, e)))?;
 
-    let registry = PluginRegistry::builtin();
+    let registry = PluginRegistry::load(config);
     let plugin = registry.find(name).ok_or_else(|| {
         allbeads::AllBeadsError::Config(format!("Plugin '{}' not found in registry", name))
     })?;
@@ -5085,8 +9063,12 @@ fn handle_plugin_onboard(name: &str, path: &str, yes: bool) -> allbeads::Result<
         return Ok(());
     }
 
-    // Try to load onboarding from project
-    if let Some(onboarding) = load_onboarding(&project_path) {
+    // Dev plugins use the onboarding from their source; otherwise try the project
+    let onboarding = registry
+        .dev_path(name)
+        .and_then(load_onboarding)
+        .or_else(|| load_onboarding(&project_path));
+    if let Some(onboarding) = onboarding {
         println!("  Found onboarding protocol: {}", onboarding.plugin);
         println!("  Version: {}", onboarding.version);
         println!();
@@ -5150,6 +9132,16 @@ fn handle_plugin_onboard(name: &str, path: &str, yes: bool) -> allbeads::Result<
                 for err in &result.errors {
                     println!("    - {}", err);
                 }
+                match result.rollback_succeeded {
+                    Some(true) => {
+                        println!("  {} Completed steps were rolled back", style::success("✓"))
+                    }
+                    Some(false) => println!(
+                        "  {} Rollback incomplete; review the project before retrying",
+                        style::warning("!")
+                    ),
+                    None => {}
+                }
             }
         } else {
             println!("  Run with --yes to execute these steps.");
@@ -5169,7 +9161,91 @@ fn handle_plugin_onboard(name: &str, path: &str, yes: bool) -> allbeads::Result<
     Ok(())
 }
 
-fn handle_plugin_recommend(path: &str) -> allbeads::Result<()> {
+fn handle_plugin_dev(
+    config: &AllBeadsConfig,
+    path: Option<&str>,
+    clear: bool,
+) -> allbeads::Result<()> {
+    use allbeads::plugin::{
+        dev_plugins_path, load_dev_plugin, load_onboarding, read_dev_plugin_paths,
+        write_dev_plugin_paths,
+    };
+
+    let list_path = dev_plugins_path(config);
+    let mut paths = read_dev_plugin_paths(&list_path);
+
+    println!();
+    println!("{}", style::header("Plugin Dev Mode"));
+    println!();
+
+    if clear {
+        write_dev_plugin_paths(&list_path, &[])?;
+        println!(
+            "  {} Unregistered {} dev plugin(s)",
+            style::success("✓"),
+            paths.len()
+        );
+        return Ok(());
+    }
+
+    let Some(path) = path else {
+        if paths.is_empty() {
+            println!("  No dev plugins registered.");
+            println!("  Register one with: ab plugin dev <path>");
+        }
+        for dir in &paths {
+            match load_dev_plugin(dir) {
+                Ok(plugin) => println!(
+                    "  {} {}",
+                    style::highlight(&plugin.name),
+                    style::path(&dir.display().to_string())
+                ),
+                Err(e) => println!("  {} {}", style::error("✗"), e),
+            }
+        }
+        return Ok(());
+    };
+
+    let dir = std::path::Path::new(path)
+        .canonicalize()
+        .map_err(|e| allbeads::AllBeadsError::Config(format!("Invalid path '{}': {}", path, e)))?;
+    let plugin = load_dev_plugin(&dir)?;
+    let onboarding = load_onboarding(&dir).unwrap_or_default();
+
+    println!("  Plugin:  {}", style::highlight(&plugin.name));
+    println!("  Source:  {}", style::path(&dir.display().to_string()));
+    println!("  Version: {}", onboarding.version);
+    println!("  Prerequisites: {}", onboarding.prerequisites.len());
+    println!("  Onboarding steps: {}", onboarding.onboard.steps.len());
+    if let Some(ref uninstall) = onboarding.uninstall {
+        println!("  Uninstall steps: {}", uninstall.steps.len());
+    }
+    println!();
+
+    // Replace any earlier registration of the same directory or plugin name
+    paths.retain(|p| {
+        p != &dir
+            && load_dev_plugin(p)
+                .map(|other| other.name != plugin.name)
+                .unwrap_or(true)
+    });
+    paths.push(dir);
+    write_dev_plugin_paths(&list_path, &paths)?;
+
+    println!(
+        "  {} Registered until 'ab plugin dev --clear'",
+        style::success("✓")
+    );
+    println!();
+    println!(
+        "  Try it with: ab plugin onboard {} <project-path>",
+        plugin.name
+    );
+
+    Ok(())
+}
+
+fn handle_plugin_recommend(config: &AllBeadsConfig, path: &str) -> allbeads::Result<()> {
     use allbeads::plugin::{analyze_project, recommend_plugins, ClaudePluginState, PluginRegistry};
     use std::path::Path;
 
@@ -5220,7 +9296,7 @@ fn handle_plugin_recommend(path: &str) -> allbeads::Result<()> {
     println!();
 
     // Get recommendations
-    let registry = PluginRegistry::builtin();
+    let registry = PluginRegistry::load(config);
     let claude_state = ClaudePluginState::load();
     let recommendations = recommend_plugins(&project_path, &registry, &claude_state);
 
@@ -6478,9 +10554,8 @@ fn handle_handoff_command(
     dry_run: bool,
     worktree: bool,
     queue: bool,
+    assign: Option<bool>,
 ) -> allbeads::Result<()> {
-    use allbeads::config::AllBeadsConfig;
-    use allbeads::handoff::AgentType;
     use std::process::Command;
 
     // Show available agents
@@ -6505,18 +10580,11 @@ fn handle_handoff_command(
         )
     })?;
 
-    // Parse agent type: explicit > config > prompt
-    let agent_type = if let Some(agent_name) = agent {
-        // Explicit --agent flag
-        agent_name.parse::<AgentType>().map_err(|e| {
-            allbeads::AllBeadsError::Config(format!("Invalid agent '{}': {}", agent_name, e))
-        })?
-    } else if let Some(preferred) = allbeads::handoff::get_preferred_agent() {
-        // Saved preference
-        preferred
-    } else {
+    // Agent: --agent > default_agent in config > saved preference > prompt
+    let agent_type = match resolve_handoff_agent(agent)? {
+        Some(agent_type) => agent_type,
         // First use - prompt user to select
-        prompt_for_agent_selection()?
+        None => prompt_for_agent_selection()?,
     };
 
     // Check if agent is available (skip in dry-run mode)
@@ -6533,78 +10601,19 @@ fn handle_handoff_command(
             "Agent '{}' not found. Is {} installed?",
             agent_type.display_name(),
             agent_cmd
-        )));
-    }
-
-    // Load config to find bead's context
-    let config = AllBeadsConfig::load_default().ok();
-
-    // Helper to find context by prefix
-    fn find_context_path(
-        prefix: &str,
-        config: Option<&AllBeadsConfig>,
-    ) -> Option<std::path::PathBuf> {
-        let config = config?;
-        for ctx in &config.contexts {
-            if let Some(ref ctx_path) = ctx.path {
-                // Check config.yaml for issue-prefix
-                let config_path = ctx_path.join(".beads/config.yaml");
-                if let Ok(content) = std::fs::read_to_string(&config_path) {
-                    for line in content.lines() {
-                        if let Some(value) = line.strip_prefix("issue-prefix:") {
-                            let ctx_prefix = value.trim().trim_matches('"').trim_matches('\'');
-                            if ctx_prefix.eq_ignore_ascii_case(prefix) {
-                                return Some(ctx_path.clone());
-                            }
-                        }
-                    }
-                }
-                // Also check if issues.jsonl has IDs with this prefix
-                let jsonl_path = ctx_path.join(".beads/issues.jsonl");
-                if let Ok(content) = std::fs::read_to_string(&jsonl_path) {
-                    if let Some(first_line) = content.lines().next() {
-                        if let Ok(issue) = serde_json::from_str::<serde_json::Value>(first_line) {
-                            if let Some(id) = issue.get("id").and_then(|v| v.as_str()) {
-                                if let Some(found_prefix) = id.split('-').next() {
-                                    if found_prefix.eq_ignore_ascii_case(prefix) {
-                                        return Some(ctx_path.clone());
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-        None
-    }
-
-    // Extract prefix from bead ID and find context
-    let bead_prefix = bead_id.split('-').next().unwrap_or("");
-    let context_path = find_context_path(bead_prefix, config.as_ref());
-
-    // Load bead from the correct context
-    let beads = if let Some(ref path) = context_path {
-        Beads::with_workdir(path)
-    } else {
-        // Fall back to current directory
-        Beads::new().map_err(|e| {
-            allbeads::AllBeadsError::Config(format!("Failed to initialize beads: {}", e))
-        })?
-    };
-
-    let issue = beads.show(bead_id).map_err(|e| {
-        let hint = if context_path.is_none() {
-            format!(
-                "\n\nHint: Bead '{}' may be in a different context. \
-                 Make sure the repository is added with 'ab context add'.",
-                bead_id
-            )
-        } else {
-            String::new()
-        };
-        allbeads::AllBeadsError::Config(format!("Failed to load bead '{}': {}{}", bead_id, e, hint))
-    })?;
+        )));
+    }
+
+    let HandoffBead {
+        beads,
+        context_path,
+        issue,
+    } = load_handoff_issue(bead_id)?;
+
+    // Assign to the agent: --assign/--no-assign > assign_on_handoff in config
+    let assign =
+        assign.unwrap_or_else(|| AllBeadsConfig::load_default().is_ok_and(|c| c.assign_on_handoff));
+    let assignee = assign.then(|| agent_type.display_name());
 
     // Check if agent is sandboxed (can't do git operations)
     let is_sandboxed = agent_type.is_sandboxed();
@@ -6659,6 +10668,9 @@ fn handle_handoff_command(
             "  {} Would update bead status to in_progress",
             style::dim("→")
         );
+        if let Some(assignee) = assignee {
+            println!("  {} Would assign bead to {}", style::dim("→"), assignee);
+        }
         if worktree {
             println!("  {} Would create worktree for bead", style::dim("→"));
         }
@@ -6764,7 +10776,15 @@ fn handle_handoff_command(
             style::dim("→")
         );
         beads
-            .update(bead_id, Some("in_progress"), None, None, None)
+            .update(
+                bead_id,
+                Some("in_progress"),
+                None,
+                assignee,
+                None,
+                None,
+                None,
+            )
             .map_err(|e| {
                 allbeads::AllBeadsError::Config(format!(
                     "Failed to update bead '{}': {}",
@@ -6816,10 +10836,21 @@ fn handle_handoff_command(
         style::dim("→")
     );
     beads
-        .update(bead_id, Some("in_progress"), None, None, None)
+        .update(
+            bead_id,
+            Some("in_progress"),
+            None,
+            assignee,
+            None,
+            None,
+            None,
+        )
         .map_err(|e| {
             allbeads::AllBeadsError::Config(format!("Failed to update bead '{}': {}", bead_id, e))
         })?;
+    if let Some(assignee) = assignee {
+        println!("  {} Assigned to {}", style::dim("→"), assignee);
+    }
 
     // Add handoff info as a comment
     let handoff_comment = format!(
@@ -7103,6 +11134,182 @@ fn get_git_remote_url() -> Option<String> {
     None
 }
 
+/// List handoff worktrees and remove the ones whose branch has been merged
+///
+/// Only worktrees under `<repo>/.worktrees/` are considered, so worktrees
+/// created by hand elsewhere are never touched.
+fn handle_handoff_prune(merged: bool, yes: bool, dry_run: bool) -> allbeads::Result<()> {
+    use std::process::Command;
+
+    let git = |args: &[&str]| -> allbeads::Result<String> {
+        let output = Command::new("git").args(args).output().map_err(|e| {
+            allbeads::AllBeadsError::Git(format!("Failed to run git {}: {}", args[0], e))
+        })?;
+        if !output.status.success() {
+            return Err(allbeads::AllBeadsError::Git(format!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let repo_root = PathBuf::from(git(&["rev-parse", "--show-toplevel"])?);
+    let worktrees_dir = repo_root.join(".worktrees");
+    let worktrees: Vec<WorktreeInfo> =
+        parse_worktree_list(&git(&["worktree", "list", "--porcelain"])?)
+            .into_iter()
+            .filter(|wt| Path::new(&wt.path).starts_with(&worktrees_dir))
+            .collect();
+
+    println!();
+    println!("{}", style::header("Handoff Worktrees"));
+    println!();
+    if worktrees.is_empty() {
+        println!(
+            "  No handoff worktrees in {}",
+            style::path(&worktrees_dir.display().to_string())
+        );
+        println!();
+        return Ok(());
+    }
+
+    // Default branch: origin's HEAD, else a local main or master
+    let default_branch = git(&["symbolic-ref", "--short", "refs/remotes/origin/HEAD"])
+        .map(|b| b.trim_start_matches("origin/").to_string())
+        .ok()
+        .or_else(|| {
+            ["main", "master"]
+                .into_iter()
+                .find(|b| git(&["rev-parse", "--verify", "--quiet", *b]).is_ok())
+                .map(str::to_string)
+        })
+        .ok_or_else(|| {
+            allbeads::AllBeadsError::Git("Could not determine the default branch".to_string())
+        })?;
+    let merged_branches = git(&[
+        "branch",
+        "--merged",
+        &default_branch,
+        "--format=%(refname:short)",
+    ])?;
+    // A branch with no commits yet still points into the default branch, so
+    // `--merged` lists it. Only count it once it has moved past the commit it
+    // was created at (the oldest reflog entry); without a reflog, keep it.
+    let has_own_commits = |branch: &str| {
+        let reflog = git(&[
+            "reflog",
+            "show",
+            "--format=%H",
+            &format!("refs/heads/{}", branch),
+        ]);
+        let tip = git(&["rev-parse", branch]);
+        match (reflog, tip) {
+            (Ok(reflog), Ok(tip)) => reflog.lines().last().is_some_and(|created| created != tip),
+            _ => false,
+        }
+    };
+    let is_merged = |branch: &str| {
+        merged_branches.lines().any(|b| b.trim() == branch) && has_own_commits(branch)
+    };
+
+    let mut to_remove = Vec::new();
+    for wt in &worktrees {
+        let branch = wt.branch.as_deref().unwrap_or("(detached)");
+        let done = wt.branch.as_deref().is_some_and(is_merged);
+        println!(
+            "  {} {} {}",
+            if done {
+                style::success("✓")
+            } else {
+                style::dim("○")
+            },
+            style::path(&wt.path),
+            style::dim(&format!(
+                "({}{})",
+                branch,
+                if done {
+                    format!(", merged into {}", default_branch)
+                } else {
+                    String::new()
+                }
+            ))
+        );
+        if done {
+            to_remove.push(wt);
+        }
+    }
+    println!();
+
+    if !merged {
+        if !to_remove.is_empty() {
+            println!(
+                "  {} {} merged worktree(s). Run 'ab handoff prune --merged' to remove them.",
+                style::dim("→"),
+                to_remove.len()
+            );
+            println!();
+        }
+        return Ok(());
+    }
+    if to_remove.is_empty() {
+        println!(
+            "  Nothing to prune: no handoff branch is merged into {}.",
+            default_branch
+        );
+        println!();
+        return Ok(());
+    }
+    if dry_run {
+        println!(
+            "  {} Would remove {} worktree(s) and their branches",
+            style::dim("→"),
+            to_remove.len()
+        );
+        println!();
+        return Ok(());
+    }
+    if !yes {
+        print!(
+            "Remove {} worktree(s) and their branches? [y/N] ",
+            to_remove.len()
+        );
+        std::io::stdout().flush().ok();
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input).ok();
+        if !input.trim().eq_ignore_ascii_case("y") {
+            println!("Cancelled.");
+            return Ok(());
+        }
+    }
+
+    let mut removed = 0;
+    for wt in to_remove {
+        // Without --force, git refuses to remove a worktree with local changes
+        if let Err(e) = git(&["worktree", "remove", &wt.path]) {
+            eprintln!("  {} {}: {}", style::warning("⚠"), wt.path, e);
+            continue;
+        }
+        if let Some(ref branch) = wt.branch {
+            if let Err(e) = git(&["branch", "-d", branch]) {
+                eprintln!("  {} {}: {}", style::warning("⚠"), branch, e);
+            }
+        }
+        println!(
+            "  {} Removed {}",
+            style::success("✓"),
+            style::path(&wt.path)
+        );
+        removed += 1;
+    }
+    println!();
+    println!("  {} Pruned {} worktree(s)", style::success("✓"), removed);
+    println!();
+
+    Ok(())
+}
+
 /// Create a git worktree for isolated agent work on a bead
 fn create_handoff_worktree(bead_id: &str) -> allbeads::Result<PathBuf> {
     use std::process::Command;
@@ -7195,6 +11402,124 @@ fn create_handoff_worktree(bead_id: &str) -> allbeads::Result<PathBuf> {
     Ok(worktree_path)
 }
 
+/// A bead loaded for handoff, with the `bd` it was loaded through
+struct HandoffBead {
+    beads: Beads,
+    /// Local path of the context matching the bead's ID prefix, if any
+    context_path: Option<PathBuf>,
+    issue: beads::Issue,
+}
+
+/// Load a bead for handoff from the context matching its ID prefix
+///
+/// Falls back to the current directory when no context matches.
+fn load_handoff_issue(bead_id: &str) -> allbeads::Result<HandoffBead> {
+    let config = AllBeadsConfig::load_default().ok();
+
+    // Find the bead's context from its ID prefix
+    let context_path = config
+        .as_ref()
+        .and_then(|c| c.context_for_bead_id(bead_id))
+        .and_then(|ctx| ctx.local_path());
+
+    // Load bead from the correct context
+    let beads = if let Some(ref path) = context_path {
+        Beads::with_workdir(path)
+    } else {
+        // Fall back to current directory
+        Beads::new().map_err(|e| {
+            allbeads::AllBeadsError::Config(format!("Failed to initialize beads: {}", e))
+        })?
+    };
+
+    let issue = beads.show(bead_id).map_err(|e| {
+        let hint = if context_path.is_none() {
+            format!(
+                "\n\nHint: Bead '{}' may be in a different context. \
+                 Make sure the repository is added with 'ab context add'.",
+                bead_id
+            )
+        } else {
+            String::new()
+        };
+        allbeads::AllBeadsError::Config(format!("Failed to load bead '{}': {}{}", bead_id, e, hint))
+    })?;
+
+    Ok(HandoffBead {
+        beads,
+        context_path,
+        issue,
+    })
+}
+
+/// Pick the handoff agent: `--agent`, then `default_agent` in the AllBeads
+/// config, then the repo's saved preference
+///
+/// Returns `None` when none of them is set.
+fn resolve_handoff_agent(
+    agent: Option<&str>,
+) -> allbeads::Result<Option<allbeads::handoff::AgentType>> {
+    let parse = |name: &str, source: &str| {
+        name.parse::<allbeads::handoff::AgentType>().map_err(|e| {
+            allbeads::AllBeadsError::Config(format!("Invalid {} '{}': {}", source, name, e))
+        })
+    };
+    if let Some(name) = agent {
+        return parse(name, "agent").map(Some);
+    }
+    let configured = AllBeadsConfig::load_default()
+        .ok()
+        .and_then(|c| c.default_agent);
+    if let Some(name) = configured {
+        return parse(&name, "default_agent in config").map(Some);
+    }
+    Ok(allbeads::handoff::get_preferred_agent())
+}
+
+/// Print the handoff prompt for a bead without launching an agent
+///
+/// Read-only: the bead's status and labels are left untouched. The agent
+/// (see [`resolve_handoff_agent`]) only decides whether the sandboxed
+/// workflow is used; with no agent the full git workflow is printed.
+fn handle_handoff_print_prompt(
+    id: Option<&str>,
+    agent: Option<&str>,
+    json: bool,
+) -> allbeads::Result<()> {
+    let bead_id = id.ok_or_else(|| {
+        allbeads::AllBeadsError::Config(
+            "Bead ID required. Usage: ab handoff <bead-id> --print-prompt".to_string(),
+        )
+    })?;
+    let agent_type = resolve_handoff_agent(agent)?;
+    let sandboxed = agent_type.as_ref().is_some_and(|a| a.is_sandboxed());
+
+    let issue = load_handoff_issue(bead_id)?.issue;
+    let prompt = build_handoff_prompt(&issue, sandboxed);
+
+    if json {
+        let output = serde_json::json!({
+            "bead": {
+                "id": issue.id,
+                "title": issue.title,
+                "status": issue.status,
+                "issue_type": issue.issue_type,
+                "priority": issue.priority,
+                "assignee": issue.assignee,
+                "labels": issue.labels,
+                "dependencies": issue.dependencies.iter().map(|d| &d.id).collect::<Vec<_>>(),
+            },
+            "agent": agent_type.as_ref().map(|a| a.display_name()),
+            "sandboxed": sandboxed,
+            "prompt": prompt,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        print!("{}", prompt);
+    }
+    Ok(())
+}
+
 fn build_handoff_prompt(issue: &beads::Issue, is_sandboxed: bool) -> String {
     let mut prompt = format!(
         "You are working on bead {}.\n\n## Title\n{}\n",
@@ -7400,13 +11725,7 @@ fn handle_handoff_agents() -> allbeads::Result<()> {
 fn handle_handoff_ready(agent: Option<&str>) -> allbeads::Result<()> {
     use allbeads::handoff::AgentType;
 
-    let agent_type = if let Some(name) = agent {
-        name.parse::<AgentType>().map_err(|e| {
-            allbeads::AllBeadsError::Config(format!("Invalid agent '{}': {}", name, e))
-        })?
-    } else {
-        AgentType::Claude
-    };
+    let agent_type = resolve_handoff_agent(agent)?.unwrap_or(AgentType::Claude);
 
     println!();
     println!("{}", style::header("Ready Beads"));
@@ -7441,31 +11760,253 @@ fn handle_handoff_ready(agent: Option<&str>) -> allbeads::Result<()> {
         );
     }
 
-    println!();
-    println!(
-        "  Use 'ab handoff <id> --agent {}' to hand off a specific bead.",
-        agent_type.command()
-    );
+    println!();
+    println!(
+        "  Use 'ab handoff <id> --agent {}' to hand off a specific bead.",
+        agent_type.command()
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// Sync Command
+// ============================================================================
+
+/// Git sync state of one context's repository, for `ab sync --status`
+#[derive(Debug, Serialize)]
+struct ContextSyncStatus {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<PathBuf>,
+    remote: bool,
+    has_beads: bool,
+    /// Commits not yet pushed; `None` without a local clone or upstream branch
+    ahead: Option<usize>,
+    /// Commits not yet pulled
+    behind: Option<usize>,
+}
+
+/// Auto-merge conflicted `.beads/issues.jsonl` files for `ab sync --resolve`
+///
+/// Checks the named context, or every context when none is given. Files that
+/// merge cleanly are rewritten and staged; the rest keep conflict markers
+/// around only the beads that were edited differently on both sides.
+fn resolve_jsonl_conflicts(config: &AllBeadsConfig, context: Option<&str>) -> allbeads::Result<()> {
+    let contexts: Vec<_> = config
+        .contexts
+        .iter()
+        .filter(|c| context.is_none_or(|name| c.name == name))
+        .collect();
+    if let Some(name) = context {
+        if contexts.is_empty() {
+            return Err(allbeads::AllBeadsError::Config(format!(
+                "Context '{}' not found",
+                name
+            )));
+        }
+    }
+
+    println!();
+    println!("{}", style::header("Resolve issues.jsonl conflicts"));
+    println!();
+
+    let mut conflicted = 0;
+    let mut unresolved = 0;
+    for ctx in contexts {
+        let Some(repo_path) = ctx.local_path() else {
+            continue;
+        };
+        let jsonl_path = repo_path.join(".beads").join("issues.jsonl");
+        let Ok(content) = std::fs::read_to_string(&jsonl_path) else {
+            continue;
+        };
+        if !allbeads::storage::has_conflict_markers(&content) {
+            continue;
+        }
+        conflicted += 1;
+
+        let merge = match allbeads::storage::merge_conflicted_jsonl(&content) {
+            Ok(merge) => merge,
+            Err(e) => {
+                println!(
+                    "  {} {}: {}",
+                    style::error("✗"),
+                    style::highlight(&ctx.name),
+                    e
+                );
+                unresolved += 1;
+                continue;
+            }
+        };
+        std::fs::write(&jsonl_path, merge.to_jsonl())?;
+
+        if merge.conflicts.is_empty() {
+            let staged = std::process::Command::new("git")
+                .args(["add", ".beads/issues.jsonl"])
+                .current_dir(&repo_path)
+                .status()
+                .is_ok_and(|s| s.success());
+            println!(
+                "  {} {}: merged {} bead(s){}",
+                style::success("✓"),
+                style::highlight(&ctx.name),
+                merge.merged.len(),
+                if staged { ", staged" } else { "" }
+            );
+        } else {
+            unresolved += 1;
+            println!(
+                "  {} {}: merged {} bead(s), {} need a decision",
+                style::warning("!"),
+                style::highlight(&ctx.name),
+                merge.merged.len(),
+                merge.conflicts.len()
+            );
+            for conflict in &merge.conflicts {
+                println!(
+                    "      {} {}",
+                    style::issue_id(&conflict.id),
+                    style::dim(&format!("differs in: {}", conflict.fields.join(", ")))
+                );
+            }
+        }
+    }
+
+    if conflicted == 0 {
+        println!("  {}", style::dim("No conflicted issues.jsonl found"));
+    } else if unresolved > 0 {
+        println!();
+        println!(
+            "  Edit the remaining conflict markers in .beads/issues.jsonl, then 'git add' it."
+        );
+    }
+    Ok(())
+}
+
+/// Show config and per-context sync state without syncing
+fn print_sync_status(
+    config: &AllBeadsConfig,
+    config_dir: &Path,
+    json: bool,
+) -> allbeads::Result<()> {
+    // Uncommitted changes in the config dir; `None` when it isn't a git repo
+    let config_tracked = config_dir.join(".git").exists();
+    let config_changes = config_tracked
+        .then(|| git2::Repository::open(config_dir).ok())
+        .flatten()
+        .map(|repo| repo.statuses(None).map(|s| s.len()).unwrap_or(0));
+
+    let contexts: Vec<ContextSyncStatus> = config
+        .contexts
+        .iter()
+        .map(|ctx| {
+            let path = ctx.local_path();
+            let counts = path
+                .as_deref()
+                .filter(|p| p.join(".git").exists())
+                .and_then(|p| git_ahead_behind(p, "HEAD...@{upstream}"));
+            ContextSyncStatus {
+                name: ctx.name.clone(),
+                has_beads: path.as_ref().is_some_and(|p| p.join(".beads").exists()),
+                path,
+                remote: ctx.is_remote(),
+                ahead: counts.map(|(ahead, _)| ahead),
+                behind: counts.map(|(_, behind)| behind),
+            }
+        })
+        .collect();
+
+    if json {
+        let output = serde_json::json!({
+            "config": {
+                "path": config_dir,
+                "tracked": config_tracked,
+                "changes": config_changes,
+            },
+            "contexts": contexts,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!();
+    println!("{}", style::header("AllBeads Sync"));
+    println!();
+    println!(
+        "  Config directory: {}",
+        style::path(&config_dir.display().to_string())
+    );
+
+    match config_changes {
+        Some(changes) if changes > 0 => println!(
+            "  Config status: {} uncommitted changes",
+            style::warning(&changes.to_string())
+        ),
+        Some(_) => println!("  Config status: {}", style::success("clean")),
+        None if config_tracked => {
+            println!("  Config status: {}", style::dim("not a git repository"))
+        }
+        None => println!("  Config status: {}", style::dim("not tracked in git")),
+    }
+
+    if !contexts.is_empty() {
+        println!();
+        println!("  Contexts:");
+        for ctx in &contexts {
+            let Some(path) = &ctx.path else {
+                let reason = if ctx.remote {
+                    "(remote, not cloned yet - run 'ab sync --all')"
+                } else {
+                    "(no local path)"
+                };
+                println!(
+                    "    {} {} - {}",
+                    style::dim("?"),
+                    style::highlight(&ctx.name),
+                    style::dim(reason)
+                );
+                continue;
+            };
+
+            let status = if ctx.has_beads {
+                style::success("✓ beads")
+            } else {
+                style::dim("no beads")
+            };
+            let sync = match (ctx.ahead, ctx.behind) {
+                (Some(0), Some(0)) => style::success("up to date"),
+                (Some(ahead), Some(behind)) if ahead > 0 && behind > 0 => {
+                    style::warning(&format!("{} ahead, {} behind (diverged)", ahead, behind))
+                }
+                (Some(ahead), Some(0)) => style::warning(&format!("{} to push", ahead)),
+                (Some(_), Some(behind)) => style::warning(&format!("{} to pull", behind)),
+                _ => style::dim("no upstream"),
+            };
+            println!(
+                "    {} {} - {} [{}]",
+                status,
+                style::highlight(&ctx.name),
+                path.display(),
+                sync
+            );
+        }
+    }
 
     Ok(())
 }
 
-// ============================================================================
-// Sync Command
-// ============================================================================
-
+#[allow(clippy::too_many_arguments)]
 fn handle_sync_command(
     all: bool,
     context: Option<&str>,
     message: Option<&str>,
     status: bool,
     web: bool,
+    resolve: bool,
     config_path: &Option<String>,
+    json: bool,
 ) -> allbeads::Result<()> {
-    println!();
-    println!("{}", style::header("AllBeads Sync"));
-    println!();
-
     // Load config
     let config = if let Some(path) = config_path {
         AllBeadsConfig::load(path)?
@@ -7484,68 +12025,17 @@ fn handle_sync_command(
     };
 
     if status {
-        // Show sync status only
-        println!(
-            "  Config directory: {}",
-            style::path(&config_dir.display().to_string())
-        );
-
-        // Check if config dir is a git repo
-        if config_dir.join(".git").exists() {
-            match git2::Repository::open(&config_dir) {
-                Ok(repo) => {
-                    let statuses = repo.statuses(None).ok();
-                    let changes = statuses.map(|s| s.len()).unwrap_or(0);
-                    if changes > 0 {
-                        println!(
-                            "  Config status: {} uncommitted changes",
-                            style::warning(&changes.to_string())
-                        );
-                    } else {
-                        println!("  Config status: {}", style::success("clean"));
-                    }
-                }
-                Err(_) => {
-                    println!("  Config status: {}", style::dim("not a git repository"));
-                }
-            }
-        } else {
-            println!("  Config status: {}", style::dim("not tracked in git"));
-        }
-
-        // Show context status
-        if !config.contexts.is_empty() {
-            println!();
-            println!("  Contexts:");
-            for ctx in &config.contexts {
-                if let Some(ref path) = ctx.path {
-                    let beads_dir = path.join(".beads");
-                    let has_beads = beads_dir.exists();
-                    let status = if has_beads {
-                        style::success("✓ beads")
-                    } else {
-                        style::dim("no beads")
-                    };
-                    println!(
-                        "    {} {} - {}",
-                        status,
-                        style::highlight(&ctx.name),
-                        path.display()
-                    );
-                } else {
-                    println!(
-                        "    {} {} - {}",
-                        style::dim("?"),
-                        style::highlight(&ctx.name),
-                        style::dim("(no local path)")
-                    );
-                }
-            }
-        }
+        return print_sync_status(&config, &config_dir, json);
+    }
 
-        return Ok(());
+    if resolve {
+        return resolve_jsonl_conflicts(&config, context);
     }
 
+    println!();
+    println!("{}", style::header("AllBeads Sync"));
+    println!();
+
     // Sync config directory if it's a git repo
     if config_dir.join(".git").exists() {
         println!("  Syncing config directory...");
@@ -7673,8 +12163,28 @@ fn handle_sync_command(
             for ctx in contexts_to_sync {
                 println!("  Syncing context: {}", style::highlight(&ctx.name));
 
-                let ctx_path = match &ctx.path {
-                    Some(p) => p.clone(),
+                // Remote-only contexts: make sure the managed clone exists and is current
+                if ctx.is_remote() {
+                    let result =
+                        allbeads::git::BossRepo::from_context(ctx.clone()).and_then(|mut repo| {
+                            repo.clone_if_needed()?;
+                            repo.pull()
+                        });
+                    match result {
+                        Ok(()) => println!(
+                            "    {} Updated clone at {}",
+                            style::success("✓"),
+                            style::path(&ctx.remote_cache_path().display().to_string())
+                        ),
+                        Err(e) => {
+                            println!("    {} Could not update clone: {}", style::error("✗"), e);
+                            continue;
+                        }
+                    }
+                }
+
+                let ctx_path = match ctx.local_path() {
+                    Some(p) => p,
                     None => {
                         println!("    {} No local path configured", style::dim("○"));
                         continue;
@@ -7883,7 +12393,15 @@ fn handle_context_command(
             name,
             url,
             auth,
+            group,
+            remote,
         } => {
+            if *remote && url.is_none() {
+                return Err(allbeads::AllBeadsError::Config(
+                    "--remote requires --url <url> to clone from".to_string(),
+                ));
+            }
+
             // Determine if this is a local path or URL-only context
             let (repo_path_opt, remote_url, context_name) = if let Some(url_str) = url {
                 // URL provided - this is the primary mode
@@ -8021,15 +12539,30 @@ fn handle_context_command(
             // Print confirmation
             if let Some(ref path) = repo_path_opt {
                 println!("✓ Added context '{}' from {}", context_name, path.display());
+            } else if *remote {
+                println!("✓ Added remote context '{}'", context_name);
             } else {
                 println!("✓ Added context '{}' (URL-only)", context_name);
             }
             println!("  URL:  {}", remote_url);
             println!("  Auth: {:?}", auth_strategy);
+            if let Some(ref group) = group {
+                println!("  Group: {}", group);
+            }
 
             // Create context
             let mut context = BossContext::new(&context_name, &remote_url, auth_strategy);
             context.path = repo_path_opt;
+            context.group = group.clone();
+            if *remote && context.path.is_none() {
+                context.remote = Some(remote_url.clone());
+                context.remotes_dir = Some(config.remotes_dir());
+                println!(
+                    "  Clone: {} (run 'ab sync {}' to clone)",
+                    context.remote_cache_path().display(),
+                    context_name
+                );
+            }
 
             config.add_context(context);
             config.save(&config_file)?;
@@ -8051,12 +12584,12 @@ fn handle_context_command(
                 .iter()
                 .filter(|ctx| {
                     // --local: only contexts with local paths
-                    if *local && ctx.path.is_none() {
+                    if *local && ctx.local_path().is_none() {
                         return false;
                     }
                     // --beads: only contexts with beads initialized
                     if *beads {
-                        if let Some(ref path) = ctx.path {
+                        if let Some(ref path) = ctx.local_path() {
                             let beads_dir = path.join(".beads");
                             if !beads_dir.exists() {
                                 return false;
@@ -8090,8 +12623,39 @@ fn handle_context_command(
                 println!("    URL:  {}", context.url);
                 if let Some(ref path) = context.path {
                     println!("    Path: {}", path.display());
+                } else if context.is_remote() {
+                    println!("    Clone: {}", context.remote_cache_path().display());
+                }
+                if let Some(prefix) = context.issue_prefix() {
+                    println!("    Prefix: {}", prefix);
                 }
                 println!("    Auth: {:?}", context.auth_strategy);
+                if let Some(ref group) = context.group {
+                    println!("    Group: {}", group);
+                }
+                println!();
+            }
+        }
+
+        ContextCommands::Groups => {
+            let groups = config.groups();
+            if groups.is_empty() {
+                println!("No context groups defined");
+                println!();
+                println!(
+                    "  {} Assign a group with: ab context add <path> --group <name>",
+                    style::dim("→")
+                );
+                return Ok(());
+            }
+
+            println!("Context groups ({}):", groups.len());
+            println!();
+            for (group, members) in &groups {
+                println!("  {} ({})", style::header(group), members.len());
+                for member in members {
+                    println!("    {}", style::path(member));
+                }
                 println!();
             }
         }
@@ -8108,6 +12672,25 @@ fn handle_context_command(
             }
         }
 
+        ContextCommands::Move { name, path } => {
+            let new_path = std::fs::canonicalize(path).map_err(|e| {
+                allbeads::AllBeadsError::Config(format!("Invalid path '{}': {}", path, e))
+            })?;
+            config.move_context(name, new_path.clone())?;
+            config.save(&config_file)?;
+            println!("Moved context '{}' to {}", name, new_path.display());
+        }
+
+        ContextCommands::Rename { old, new } => {
+            config.rename_context(old, new)?;
+            config.save(&config_file)?;
+            println!("Renamed context '{}' to '{}'", old, new);
+            eprintln!(
+                "Note: beads labeled @{} keep that label; relabel them to @{} in the repository if needed",
+                old, new
+            );
+        }
+
         ContextCommands::Uninstall {
             target,
             remove_context,
@@ -8920,26 +13503,12 @@ fn handle_milestones_command(
             description,
             context,
         } => {
-            // Find the target context
-            let ctx_name = context.clone().unwrap_or_else(|| {
-                let cwd = std::env::current_dir().unwrap_or_default();
-                config
-                    .contexts
-                    .iter()
-                    .find(|c| c.path.as_ref().is_some_and(|p| cwd.starts_with(p)))
-                    .map(|c| c.name.clone())
-                    .unwrap_or_else(|| "default".to_string())
-            });
+            // Find the target context: --context, then cwd, then default_context
+            let cwd = std::env::current_dir().unwrap_or_default();
+            let ctx = config.resolve_context(context.as_deref(), &cwd)?;
+            let ctx_name = &ctx.name;
 
-            let ctx = config
-                .contexts
-                .iter()
-                .find(|c| c.name == ctx_name)
-                .ok_or_else(|| {
-                    allbeads::AllBeadsError::Config(format!("Context '{}' not found", ctx_name))
-                })?;
-
-            let ctx_path = ctx.path.as_ref().ok_or_else(|| {
+            let ctx_path = ctx.local_path().ok_or_else(|| {
                 allbeads::AllBeadsError::Config(format!("Context '{}' has no local path", ctx_name))
             })?;
 
@@ -9014,7 +13583,7 @@ fn handle_milestones_command(
                     allbeads::AllBeadsError::Config(format!("Context '{}' not found", ctx_name))
                 })?;
 
-            let ctx_path = ctx.path.as_ref().ok_or_else(|| {
+            let ctx_path = ctx.local_path().ok_or_else(|| {
                 allbeads::AllBeadsError::Config(format!("Context '{}' has no local path", ctx_name))
             })?;
 
@@ -9058,7 +13627,7 @@ fn handle_milestones_command(
                     allbeads::AllBeadsError::Config(format!("Context '{}' not found", ctx_name))
                 })?;
 
-            let ctx_path = ctx.path.as_ref().ok_or_else(|| {
+            let ctx_path = ctx.local_path().ok_or_else(|| {
                 allbeads::AllBeadsError::Config(format!("Context '{}' has no local path", ctx_name))
             })?;
 
@@ -9436,7 +14005,7 @@ fn handle_folder_command(cmd: &FolderCommands) -> allbeads::Result<()> {
 
                 println!();
 
-                if *verbose {
+                if *verbose > 0 {
                     if folder.bead_count > 0 {
                         println!("      Beads: {}", folder.bead_count);
                     }
@@ -11164,6 +15733,7 @@ fn handle_mail_command(cmd: &MailCommands) -> allbeads::Result<()> {
             message,
             message_type,
             from,
+            attach,
         } => {
             // Parse recipient - could be just context name or full address
             let to_address: Address = if to.contains('@') {
@@ -11188,10 +15758,13 @@ fn handle_mail_command(cmd: &MailCommands) -> allbeads::Result<()> {
                 _ => MessageType::Notify(NotifyPayload::new(message).with_severity(Severity::Info)),
             };
 
-            let mail_message = Message::new(from_address.clone(), to_address.clone(), msg_type);
+            let mut mail_message = Message::new(from_address.clone(), to_address.clone(), msg_type);
 
             // Send to remote if authenticated, otherwise local
             if let Some(ref client) = remote_client {
+                if !attach.is_empty() {
+                    eprintln!("Attachments are local-only and won't be sent to remote mail.");
+                }
                 let rt = tokio::runtime::Runtime::new()?;
                 match rt.block_on(client.send(&mail_message)) {
                     Ok(_) => {
@@ -11209,6 +15782,14 @@ fn handle_mail_command(cmd: &MailCommands) -> allbeads::Result<()> {
                     Err(e) => eprintln!("Failed to send: {}", e),
                 }
             } else {
+                for path in attach {
+                    let attachment = allbeads::mail::import_attachment(
+                        std::path::Path::new(path),
+                        postmaster.attachments_dir(),
+                    )
+                    .map_err(allbeads::mail::PostmasterError::from)?;
+                    mail_message = mail_message.with_attachment(attachment);
+                }
                 postmaster.send(mail_message)?;
                 println!(
                     "Sent [{}] to {}: {}",
@@ -11216,10 +15797,32 @@ fn handle_mail_command(cmd: &MailCommands) -> allbeads::Result<()> {
                     to_address,
                     message
                 );
+                if !attach.is_empty() {
+                    println!("Attached {} file(s).", attach.len());
+                }
                 println!("(local only - run 'ab login' to send to remote)");
             }
         }
 
+        MailCommands::Reply { id, message, from } => {
+            if remote_client.is_some() {
+                eprintln!("Replies are local-only and won't be sent to remote mail.");
+            }
+            let from_address = match from {
+                Some(ref name) => Address::new(name.as_str(), &project_id)?,
+                None => Address::human(),
+            };
+            let parent_id = allbeads::mail::MessageId::from_string(id.as_str());
+            let msg_type =
+                MessageType::Notify(NotifyPayload::new(message).with_severity(Severity::Info));
+            postmaster.reply(&parent_id, from_address, msg_type)?;
+            let to_address = postmaster
+                .get_message(&parent_id)?
+                .map(|m| m.message.from.to_string())
+                .unwrap_or_default();
+            println!("Replied to {} ({}): {}", id, to_address, message);
+        }
+
         MailCommands::Test { message } => {
             // Send a variety of test messages
             let human = Address::human();
@@ -11309,13 +15912,18 @@ fn handle_mail_command(cmd: &MailCommands) -> allbeads::Result<()> {
             }
         }
 
-        MailCommands::Inbox => {
+        MailCommands::Inbox { all } => {
             // Use remote if authenticated
             if let Some(ref client) = remote_client {
                 let rt = tokio::runtime::Runtime::new()?;
                 match rt.block_on(client.inbox()) {
-                    Ok(list) => {
-                        if list.mail.is_empty() {
+                    Ok(mut list) => {
+                        if !*all {
+                            list.mail.retain(|msg| !msg.is_read);
+                        }
+                        if list.mail.is_empty() && !*all {
+                            println!("No unread messages (remote).");
+                        } else if list.mail.is_empty() {
                             println!("Remote inbox is empty.");
                             println!("Run 'ab mail test' to send some test messages.");
                         } else {
@@ -11346,11 +15954,11 @@ fn handle_mail_command(cmd: &MailCommands) -> allbeads::Result<()> {
                     Err(e) => {
                         eprintln!("Failed to fetch remote inbox: {}", e);
                         eprintln!("Falling back to local inbox...");
-                        show_local_inbox(&mut postmaster, &project_id)?;
+                        show_local_inbox(&mut postmaster, &project_id, *all)?;
                     }
                 }
             } else {
-                show_local_inbox(&mut postmaster, &project_id)?;
+                show_local_inbox(&mut postmaster, &project_id, *all)?;
             }
         }
 
@@ -11407,8 +16015,14 @@ fn handle_mail_command(cmd: &MailCommands) -> allbeads::Result<()> {
                 } else {
                     eprintln!("Specify a message ID or use --all");
                 }
+            } else if *all {
+                let count = postmaster.mark_all_read(&Address::human())?;
+                println!("Marked {} message(s) as read.", count);
+            } else if let Some(msg_id) = id {
+                postmaster.mark_read(&allbeads::mail::MessageId::from_string(msg_id.as_str()))?;
+                println!("Marked {} as read.", msg_id);
             } else {
-                eprintln!("Remote not available. Run 'ab login' first.");
+                eprintln!("Specify a message ID or use --all");
             }
         }
         MailCommands::Archive { id, all } => {
@@ -11450,18 +16064,29 @@ fn handle_mail_command(cmd: &MailCommands) -> allbeads::Result<()> {
 /// Show local inbox (helper to avoid code duplication)
 fn show_local_inbox(
     postmaster: &mut allbeads::mail::Postmaster,
-    _project_id: &str,
+    project_id: &str,
+    all: bool,
 ) -> allbeads::Result<()> {
     use allbeads::mail::{Address, MessageType};
 
-    let human = Address::human();
-    let messages = postmaster.inbox(&human)?;
+    let messages = if all {
+        postmaster.inbox(&Address::human())?
+    } else {
+        postmaster.unread_in_project(project_id)?
+    };
 
-    if messages.is_empty() {
+    if messages.is_empty() && !all {
+        println!("No unread messages for {}.", project_id);
+        println!("Run 'ab mail inbox --all' to include read messages.");
+    } else if messages.is_empty() {
         println!("Inbox is empty.");
         println!("Run 'ab mail test' to send some test messages.");
     } else {
-        println!("Inbox ({} messages):", messages.len());
+        if all {
+            println!("Inbox ({} messages):", messages.len());
+        } else {
+            println!("Unread for {} ({} messages):", project_id, messages.len());
+        }
         println!();
         for msg in messages {
             let is_unread = msg.status == allbeads::mail::DeliveryStatus::Delivered;
@@ -11561,7 +16186,7 @@ fn handle_jira_command(cmd: &JiraCommands) -> allbeads::Result<()> {
                         priority, status, issue.key, issue.fields.summary
                     );
 
-                    if *verbose {
+                    if *verbose > 0 {
                         if let Some(ref desc) = issue.fields.description {
                             let short_desc = if desc.len() > 100 {
                                 format!("{}...", &desc[..100])
@@ -11670,7 +16295,7 @@ fn handle_github_command(cmd: &GitHubCommands) -> allbeads::Result<()> {
                         labels_str
                     );
 
-                    if *verbose {
+                    if *verbose > 0 {
                         if let Some(ref body) = issue.body {
                             let short_body = if body.len() > 100 {
                                 format!("{}...", &body[..100])
@@ -11716,8 +16341,12 @@ fn handle_github_command(cmd: &GitHubCommands) -> allbeads::Result<()> {
     Ok(())
 }
 
-/// Handle swarm commands by wrapping bd swarm
-fn handle_swarm_command(cmd: &SwarmCommands) -> allbeads::Result<()> {
+/// Handle swarm commands by wrapping bd swarm (status is built from handoffs and mail)
+fn handle_swarm_command(
+    cmd: &SwarmCommands,
+    config_path: &Option<String>,
+    json: bool,
+) -> allbeads::Result<()> {
     use std::process::Command;
 
     match cmd {
@@ -11780,22 +16409,11 @@ fn handle_swarm_command(cmd: &SwarmCommands) -> allbeads::Result<()> {
         }
 
         SwarmCommands::Status => {
-            let output = Command::new("bd")
-                .args(["swarm", "status"])
-                .output()
-                .map_err(|e| {
-                    allbeads::AllBeadsError::Config(format!("Failed to run bd swarm: {}", e))
-                })?;
-
-            if output.status.success() {
-                print!("{}", String::from_utf8_lossy(&output.stdout));
-            } else {
-                let stderr = String::from_utf8_lossy(&output.stderr);
-                return Err(allbeads::AllBeadsError::Config(format!(
-                    "bd swarm status failed: {}",
-                    stderr
-                )));
-            }
+            let config = match config_path {
+                Some(path) => AllBeadsConfig::load(path)?,
+                None => AllBeadsConfig::load_default()?,
+            };
+            handle_swarm_status(&config, json)?;
         }
 
         SwarmCommands::Validate { epic_id } => {
@@ -11821,6 +16439,158 @@ fn handle_swarm_command(cmd: &SwarmCommands) -> allbeads::Result<()> {
     Ok(())
 }
 
+/// One handed-off bead in `ab swarm status`
+#[derive(Debug, Serialize)]
+struct SwarmAgentStatus {
+    bead: String,
+    title: String,
+    context: String,
+    status: String,
+    agent: Option<String>,
+    handed_off_at: Option<String>,
+    /// Handoff worktree, when one exists on disk
+    worktree: Option<PathBuf>,
+    /// Latest of the bead's update, comments and mail about it
+    last_activity: Option<String>,
+    /// Most recent mail mentioning the bead
+    last_message: Option<String>,
+}
+
+/// Show beads handed off to agents across contexts, with agent, worktree and activity
+fn handle_swarm_status(config: &AllBeadsConfig, json: bool) -> allbeads::Result<()> {
+    use allbeads::graph::parse_timestamp;
+    use allbeads::handoff::parse_handoff_comment;
+    use allbeads::mail::{Address, MessageType, Postmaster};
+
+    // Mail about each bead; the local mail store is optional
+    let mail = AllBeadsConfig::default_path()
+        .parent()
+        .map(|p| p.join("mail.db"))
+        .filter(|p| p.exists())
+        .and_then(|p| Postmaster::new(p).ok())
+        .and_then(|pm| pm.inbox(&Address::human()).ok())
+        .unwrap_or_default();
+
+    let mut agents = Vec::new();
+    for ctx in &config.contexts {
+        let Some(path) = ctx.local_path() else {
+            continue;
+        };
+        if !path.join(".beads").exists() {
+            continue;
+        }
+        let bd = Beads::with_workdir(&path);
+        let filter = beads::ListFilter {
+            label: Some("handed-off"),
+            ..Default::default()
+        };
+        let issues = match bd.list_filtered(&filter) {
+            Ok(issues) => issues,
+            Err(e) => {
+                tracing::warn!(context = %ctx.name, error = %e, "Failed to list handed-off beads");
+                continue;
+            }
+        };
+
+        for issue in issues.into_iter().filter(|i| i.status != "closed") {
+            let comments = bd.comments(&issue.id).unwrap_or_default();
+            let handoff = comments
+                .iter()
+                .rev()
+                .find_map(|c| parse_handoff_comment(&c.content));
+
+            let message = mail.iter().find(|m| {
+                matches!(&m.message.message_type,
+                    MessageType::Notify(n) if n.bead_id.as_deref() == Some(issue.id.as_str()))
+            });
+
+            let worktree = path
+                .join(".worktrees")
+                .join(issue.id.replace(['/', '\\', ':'], "-"));
+
+            // Latest timestamp among the bead, its comments and its mail
+            let last_activity = issue
+                .updated_at
+                .iter()
+                .chain(comments.iter().filter_map(|c| c.created_at.as_ref()))
+                .filter_map(|t| parse_timestamp(t))
+                .chain(message.map(|m| m.message.timestamp))
+                .max();
+
+            agents.push(SwarmAgentStatus {
+                bead: issue.id.clone(),
+                title: issue.title.clone(),
+                context: ctx.name.clone(),
+                status: issue.status.clone(),
+                agent: handoff.map(|(agent, _)| agent.to_string()),
+                handed_off_at: handoff.map(|(_, time)| time.to_string()),
+                worktree: worktree.exists().then_some(worktree),
+                last_activity: last_activity.map(|t| t.to_rfc3339()),
+                last_message: message.map(|m| match &m.message.message_type {
+                    MessageType::Notify(n) => n.message.clone(),
+                    _ => String::new(),
+                }),
+            });
+        }
+    }
+    agents.sort_by(|a, b| b.last_activity.cmp(&a.last_activity));
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&agents)?);
+        return Ok(());
+    }
+
+    println!();
+    println!("{}", style::header("Swarm Status"));
+    println!();
+    if agents.is_empty() {
+        println!("  No beads currently handed off to agents.");
+        println!();
+        println!("  Use 'ab handoff <bead-id>' to hand off a bead.");
+        println!();
+        return Ok(());
+    }
+
+    let now = chrono::Utc::now();
+    for a in &agents {
+        println!(
+            "  {} {} {}",
+            style::issue_id(&a.bead),
+            style::status_style(&a.status),
+            a.title
+        );
+        println!(
+            "      {} {}  {} @{}",
+            style::dim("Agent:"),
+            style::highlight(a.agent.as_deref().unwrap_or("unknown")),
+            style::dim("Context:"),
+            a.context
+        );
+        if let Some(ref worktree) = a.worktree {
+            println!(
+                "      {} {}",
+                style::dim("Worktree:"),
+                style::path(&worktree.display().to_string())
+            );
+        }
+        if let Some(t) = a.last_activity.as_deref().and_then(parse_timestamp) {
+            println!(
+                "      {} {}",
+                style::dim("Last activity:"),
+                allbeads::graph::format_relative(t, now)
+            );
+        }
+        if let Some(ref msg) = a.last_message {
+            println!("      {} {}", style::dim("Last mail:"), msg);
+        }
+    }
+    println!();
+    println!("  {} {} agent(s) active", style::dim("→"), agents.len());
+    println!();
+
+    Ok(())
+}
+
 // === Agent Integration Commands (Phase 7) ===
 
 /// Handle the `info` command - show project info and status for AI agents
@@ -11964,7 +16734,7 @@ fn handle_prime_command(graph: &allbeads::graph::FederatedGraph) -> allbeads::Re
         .beads
         .values()
         .filter(|b| {
-            b.status == Status::Blocked || (!b.dependencies.is_empty() && b.status == Status::Open)
+            b.status == Status::Blocked || (b.status == Status::Open && graph.has_open_blockers(b))
         })
         .take(5)
         .collect();
@@ -12361,15 +17131,27 @@ fn handle_check_command(
     pre_commit: bool,
     bead: Option<&str>,
     format: &str,
+    cycles: bool,
 ) -> allbeads::Result<()> {
-    use allbeads::governance::{load_policies_for_context, PolicyChecker};
+    use allbeads::governance::{
+        try_load_policies_for_context, Policy, PolicyChecker, PolicySeverity, PolicyType,
+    };
     use allbeads::graph::FederatedGraph;
-    use allbeads::storage::issue_to_bead;
+    use allbeads::storage::{issue_to_bead, read_beads};
     use beads::Beads;
     use std::process;
 
-    // Load policies from .beads/policies.yaml
-    let policies = load_policies_for_context(".");
+    // --cycles runs a fixed pair of dependency checks; otherwise use .beads/policies.yaml
+    let policies = if cycles {
+        vec![
+            Policy::new("dependency-cycle-check", PolicyType::DependencyCycleCheck)
+                .with_severity(PolicySeverity::Error),
+            Policy::new("valid-dependencies", PolicyType::ValidDependencies)
+                .with_severity(PolicySeverity::Error),
+        ]
+    } else {
+        try_load_policies_for_context(".").map_err(allbeads::AllBeadsError::Config)?
+    };
 
     if policies.is_empty() {
         if !pre_commit {
@@ -12400,16 +17182,26 @@ fn handle_check_command(
         ));
     }
 
-    let bd = Beads::with_workdir(&beads_path);
-    let beads_list = bd
-        .list(None, None)
-        .map_err(|e| allbeads::AllBeadsError::Config(format!("Failed to list beads: {}", e)))?;
+    // The cycle check reads the JSONL directly so hooks stay fast and offline
+    let graph_beads = if cycles {
+        let jsonl_path = beads_path.join("issues.jsonl");
+        if jsonl_path.exists() {
+            read_beads(&jsonl_path)?
+        } else {
+            Vec::new()
+        }
+    } else {
+        let bd = Beads::with_workdir(&beads_path);
+        bd.list(None, None)
+            .map_err(|e| allbeads::AllBeadsError::Config(format!("Failed to list beads: {}", e)))?
+            .into_iter()
+            .map(issue_to_bead)
+            .collect::<allbeads::Result<Vec<_>>>()?
+    };
 
     // Convert to graph for checking
     let mut graph = FederatedGraph::new();
-    for bead_issue in beads_list {
-        let graph_bead = issue_to_bead(bead_issue)?;
-
+    for graph_bead in graph_beads {
         // Filter by specific bead if requested
         if let Some(bead_id) = bead {
             if graph_bead.id.to_string() != bead_id {
@@ -12427,8 +17219,8 @@ fn handle_check_command(
     let violations: Vec<_> = results.iter().filter(|r| !r.passed).collect();
     let has_violations = !violations.is_empty();
 
-    // Output results
-    if pre_commit {
+    // Output results; structured formats apply in pre-commit mode too, for hooks
+    if pre_commit && !matches!(format, "json" | "yaml") {
         // Pre-commit mode: only output if there are violations
         if has_violations {
             eprintln!("Error: Policy violations detected\n");
@@ -12449,19 +17241,23 @@ fn handle_check_command(
         // Normal mode: show all results
         match format {
             "json" | "yaml" => {
-                // Format results for serialization
-                let output: Vec<serde_json::Value> = results
-                    .iter()
-                    .map(|r| {
-                        serde_json::json!({
-                            "policy_name": r.policy_name,
-                            "passed": r.passed,
-                            "message": r.message,
-                            "affected_beads": r.affected_beads,
-                            "timestamp": r.timestamp,
+                // One entry per violated policy and bead, plus per-policy results
+                let output = serde_json::json!({
+                    "violations": checker.violations(&results),
+                    "summary": PolicyChecker::summarize(&results),
+                    "results": results
+                        .iter()
+                        .map(|r| {
+                            serde_json::json!({
+                                "policy_name": r.policy_name,
+                                "passed": r.passed,
+                                "message": r.message,
+                                "affected_beads": r.affected_beads,
+                                "timestamp": r.timestamp,
+                            })
                         })
-                    })
-                    .collect();
+                        .collect::<Vec<_>>(),
+                });
 
                 if format == "json" {
                     let json = serde_json::to_string_pretty(&output)?;
@@ -12502,8 +17298,8 @@ fn handle_check_command(
             }
         }
 
-        // Exit with non-zero if strict mode and violations exist
-        if strict && has_violations {
+        // Exit with non-zero if strict (or pre-commit) mode and violations exist
+        if (strict || pre_commit) && has_violations {
             process::exit(1);
         }
     }
@@ -12524,8 +17320,15 @@ fn handle_hooks_command(cmd: &HooksCommands) -> allbeads::Result<()> {
     }
 
     match cmd {
-        HooksCommands::Install { hook, all, dry_run } => {
-            let hooks_to_install = if *all {
+        HooksCommands::Install {
+            hook,
+            all,
+            dry_run,
+            cycles,
+        } => {
+            let hooks_to_install = if *cycles {
+                vec![CYCLES_HOOK]
+            } else if *all {
                 vec!["pre-commit", "commit-msg", "post-commit", "pre-push"]
             } else if let Some(h) = hook {
                 vec![h.as_str()]
@@ -12536,7 +17339,8 @@ fn handle_hooks_command(cmd: &HooksCommands) -> allbeads::Result<()> {
             println!("Installing git hooks...\n");
 
             for hook_name in hooks_to_install {
-                let hook_path = git_hooks_dir.join(hook_name);
+                // The cycles hook is a pre-commit hook with a different template
+                let hook_path = git_hooks_dir.join(hook_file_name(hook_name));
                 let hook_content = get_hook_template(hook_name);
 
                 if *dry_run {
@@ -12567,14 +17371,26 @@ fn handle_hooks_command(cmd: &HooksCommands) -> allbeads::Result<()> {
             Ok(())
         }
 
-        HooksCommands::Uninstall { hook, all } => {
+        HooksCommands::Uninstall { hook, all, cycles } => {
+            if *cycles {
+                let hook_path = git_hooks_dir.join(hook_file_name(CYCLES_HOOK));
+                let content = fs::read_to_string(&hook_path).unwrap_or_default();
+                if !content.contains(CYCLES_HOOK_MARKER) {
+                    println!("  ⊗ Dependency cycle hook not installed");
+                    return Ok(());
+                }
+                fs::remove_file(&hook_path)?;
+                println!("  ✓ Removed {}", hook_path.display());
+                return Ok(());
+            }
+
             let hooks_to_remove = if *all {
                 vec!["pre-commit", "commit-msg", "post-commit", "pre-push"]
             } else if let Some(h) = hook {
                 vec![h.as_str()]
             } else {
                 return Err(allbeads::AllBeadsError::Config(
-                    "Specify --hook=<name> or --all".to_string(),
+                    "Specify --hook=<name>, --cycles or --all".to_string(),
                 ));
             };
 
@@ -12604,7 +17420,12 @@ fn handle_hooks_command(cmd: &HooksCommands) -> allbeads::Result<()> {
             for hook_name in all_hooks {
                 let hook_path = git_hooks_dir.join(hook_name);
                 if hook_path.exists() {
-                    println!("  ✓ {}", hook_name);
+                    let content = fs::read_to_string(&hook_path).unwrap_or_default();
+                    if content.contains(CYCLES_HOOK_MARKER) {
+                        println!("  ✓ {} (dependency cycles)", hook_name);
+                    } else {
+                        println!("  ✓ {}", hook_name);
+                    }
                     found_any = true;
                 }
             }
@@ -12855,8 +17676,48 @@ fn handle_aiki_command(cmd: &AikiCommands) -> allbeads::Result<()> {
     }
 }
 
+/// Install name for the dependency-cycle pre-commit hook
+const CYCLES_HOOK: &str = "pre-commit-cycles";
+
+/// Line identifying an installed dependency-cycle hook
+const CYCLES_HOOK_MARKER: &str = "# AllBeads dependency cycle hook";
+
+/// File under .git/hooks that a hook name installs to
+fn hook_file_name(hook_name: &str) -> &str {
+    if hook_name == CYCLES_HOOK {
+        "pre-commit"
+    } else {
+        hook_name
+    }
+}
+
 fn get_hook_template(hook_name: &str) -> String {
     match hook_name {
+        CYCLES_HOOK => r#"#!/bin/sh
+# AllBeads dependency cycle hook
+# Auto-generated by ab hooks install --cycles
+# Remove with: ab hooks uninstall --cycles
+
+# Only check when the beads file is part of this commit
+git diff --cached --name-only | grep -q '^\.beads/issues\.jsonl$' || exit 0
+
+# Prefer an installed binary so the hook stays fast
+if command -v ab >/dev/null 2>&1; then
+    ALLBEADS="ab"
+elif command -v allbeads >/dev/null 2>&1; then
+    ALLBEADS="allbeads"
+else
+    echo "Error: AllBeads not found. Install with 'cargo install allbeads'."
+    exit 1
+fi
+
+# Local-only check: reads .beads/issues.jsonl, no network
+$ALLBEADS check --cycles --strict --pre-commit
+
+exit $?
+"#
+        .to_string(),
+
         "pre-commit" => r#"#!/bin/sh
 # AllBeads pre-commit hook for policy enforcement
 # Auto-generated by ab hooks install
@@ -13628,6 +18489,10 @@ fn output_scan_result(
         format_scan_result_csv_with_fields, format_scan_result_junit, print_scan_result,
     };
 
+    if result.cancelled && !matches!(format, commands::OutputFormat::Text) {
+        eprintln!("Scan cancelled: output contains partial results");
+    }
+
     match format {
         commands::OutputFormat::Text => print_scan_result(result, show_all),
         commands::OutputFormat::Json => {
@@ -13702,6 +18567,15 @@ async fn handle_scan_command(
         ..Default::default()
     };
 
+    // First Ctrl+C stops the scan; what was found so far is still reported
+    let cancel = scan_options.cancel.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            eprintln!("\nCancelling scan after in-flight requests finish...");
+            cancel.cancel();
+        }
+    });
+
     // Get GitHub token from environment
     let token = std::env::var("GITHUB_TOKEN").ok();
 
@@ -13922,7 +18796,9 @@ async fn handle_scan_command(
                 .await;
 
             match result {
-                Ok(scan_result) if !scan_result.repositories.is_empty() => {
+                Ok(scan_result)
+                    if !scan_result.repositories.is_empty() || scan_result.cancelled =>
+                {
                     output_scan_result(
                         &scan_result,
                         &effective_format(format),
@@ -20,5 +20,8 @@
 //! ```
 
 mod parser;
+mod validate;
+mod writer;
 
 pub use parser::{Annotation, Manifest, ManifestDefault, Project, Remote};
+pub use validate::{validate, validate_str, ManifestError};
@@ -84,6 +84,11 @@ impl Project {
         self.get_annotation("allbeads.prefix")
     }
 
+    /// Get the AllBeads context this project was generated from
+    pub fn context(&self) -> Option<&str> {
+        self.get_annotation("allbeads.context")
+    }
+
     /// Get the JIRA project key for this project
     pub fn jira_project(&self) -> Option<&str> {
         self.get_annotation("allbeads.jira-project")
@@ -94,6 +99,18 @@ impl Project {
         self.get_annotation("allbeads.github-repo")
     }
 
+    /// Get the Sheriff poll interval override for this project (e.g. `30s`, `5m`)
+    ///
+    /// Invalid or zero values are ignored so the global interval applies.
+    pub fn poll_interval(&self) -> Option<std::time::Duration> {
+        let value = self.get_annotation("allbeads.poll-interval")?;
+        crate::duration::parse_duration(value)
+            .ok()?
+            .to_std()
+            .ok()
+            .filter(|d| !d.is_zero())
+    }
+
     /// Get an annotation by key
     pub fn get_annotation(&self, key: &str) -> Option<&str> {
         self.annotations
@@ -293,6 +310,7 @@ mod tests {
                 <annotation key="allbeads.persona" value="security-specialist" />
                 <annotation key="allbeads.prefix" value="auth" />
                 <annotation key="allbeads.jira-project" value="SEC" />
+                <annotation key="allbeads.poll-interval" value="30s" />
             </project>
 
             <project path="services/api" name="backend/api-gateway" revision="develop">
@@ -330,9 +348,14 @@ mod tests {
         assert_eq!(auth.persona(), Some("security-specialist"));
         assert_eq!(auth.prefix(), Some("auth"));
         assert_eq!(auth.jira_project(), Some("SEC"));
+        assert_eq!(
+            auth.poll_interval(),
+            Some(std::time::Duration::from_secs(30))
+        );
 
         let api = &manifest.projects[1];
         assert_eq!(api.revision, Some("develop".to_string()));
+        assert_eq!(api.poll_interval(), None);
 
         let web = &manifest.projects[2];
         assert_eq!(web.remote, Some("backup".to_string()));
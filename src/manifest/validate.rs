@@ -0,0 +1,358 @@
+//! Manifest validation with source locations
+//!
+//! Unlike [`Manifest::parse`](super::Manifest::parse), which stops at the
+//! first problem, validation collects every problem it finds and reports
+//! each one with the line and column of the offending element.
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+/// Elements understood by the manifest parser
+const KNOWN_ELEMENTS: &[&str] = &["manifest", "remote", "default", "project", "annotation"];
+
+/// A problem found in a manifest, with its 1-based source location
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+/// Validate a manifest file, reporting every problem found
+pub fn validate(path: &Path) -> Result<(), Vec<ManifestError>> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        vec![ManifestError {
+            line: 0,
+            column: 0,
+            message: format!("Cannot read {}: {}", path.display(), e),
+        }]
+    })?;
+    validate_str(&content)
+}
+
+/// Validate manifest XML content, reporting every problem found
+pub fn validate_str(xml: &str) -> Result<(), Vec<ManifestError>> {
+    let mut validator = Validator::new(xml);
+    validator.run();
+    if validator.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(validator.errors)
+    }
+}
+
+/// A `<project>` whose rig ID is known only after its annotations are read
+struct OpenProject {
+    offset: usize,
+    path: Option<String>,
+    prefix: Option<String>,
+}
+
+struct Validator<'a> {
+    xml: &'a str,
+    errors: Vec<ManifestError>,
+    depth: usize,
+    project: Option<OpenProject>,
+    remotes: HashMap<String, usize>,
+    rigs: HashMap<String, usize>,
+}
+
+impl<'a> Validator<'a> {
+    fn new(xml: &'a str) -> Self {
+        Self {
+            xml,
+            errors: Vec::new(),
+            depth: 0,
+            project: None,
+            remotes: HashMap::new(),
+            rigs: HashMap::new(),
+        }
+    }
+
+    fn run(&mut self) {
+        let mut reader = Reader::from_str(self.xml);
+        reader.config_mut().trim_text(true);
+        let mut saw_root = false;
+
+        loop {
+            let before = reader.buffer_position() as usize;
+            match reader.read_event() {
+                Ok(Event::Start(ref e)) => {
+                    let offset = self.tag_start(before);
+                    self.element(e, offset, &mut saw_root, false);
+                    self.depth += 1;
+                }
+                Ok(Event::Empty(ref e)) => {
+                    let offset = self.tag_start(before);
+                    self.element(e, offset, &mut saw_root, true);
+                }
+                Ok(Event::End(ref e)) => {
+                    self.depth = self.depth.saturating_sub(1);
+                    if e.name().as_ref() == b"project" {
+                        self.close_project();
+                    }
+                }
+                Ok(Event::Eof) => break,
+                Err(e) => {
+                    let offset = reader.error_position() as usize;
+                    self.error(offset, format!("Malformed XML: {}", e));
+                    break;
+                }
+                _ => {}
+            }
+        }
+
+        if !saw_root && self.errors.is_empty() {
+            self.error(0, "Missing <manifest> root element".to_string());
+        }
+    }
+
+    fn element(&mut self, e: &BytesStart, offset: usize, saw_root: &mut bool, empty: bool) {
+        let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+
+        if self.depth == 0 {
+            if name != "manifest" {
+                self.error(
+                    offset,
+                    format!("Root element must be <manifest>, found <{}>", name),
+                );
+            }
+            *saw_root = true;
+            return;
+        }
+
+        if !KNOWN_ELEMENTS.contains(&name.as_str()) {
+            self.error(offset, format!("Unknown element <{}>", name));
+            return;
+        }
+
+        let attrs = self.attributes(e, offset);
+        let require = |validator: &mut Self, attr: &str| -> Option<String> {
+            let value = attrs.get(attr).cloned();
+            if value.is_none() {
+                validator.error(
+                    offset,
+                    format!("<{}> is missing required attribute '{}'", name, attr),
+                );
+            }
+            value
+        };
+
+        match name.as_str() {
+            "manifest" => {
+                self.error(offset, "Nested <manifest> element".to_string());
+            }
+            "remote" => {
+                let remote = require(self, "name");
+                require(self, "fetch");
+                if let Some(remote) = remote {
+                    if let Some(first) = self.remotes.get(&remote) {
+                        let message = format!(
+                            "Duplicate remote '{}' (first defined on line {})",
+                            remote,
+                            self.position(*first).0
+                        );
+                        self.error(offset, message);
+                    } else {
+                        self.remotes.insert(remote, offset);
+                    }
+                }
+            }
+            "default" => {
+                require(self, "revision");
+                require(self, "remote");
+            }
+            "project" => {
+                if self.project.is_some() {
+                    self.error(offset, "<project> cannot be nested".to_string());
+                    return;
+                }
+                let path = require(self, "path");
+                require(self, "name");
+                self.project = Some(OpenProject {
+                    offset,
+                    path,
+                    prefix: None,
+                });
+                if empty {
+                    self.close_project();
+                }
+            }
+            "annotation" => {
+                let key = require(self, "key");
+                let value = require(self, "value");
+                match self.project {
+                    Some(ref mut project) => match key.as_deref() {
+                        Some("allbeads.prefix") => project.prefix = value,
+                        Some("allbeads.poll-interval") => {
+                            let valid = value.as_deref().is_some_and(|v| {
+                                crate::duration::parse_duration(v)
+                                    .is_ok_and(|d| d > chrono::Duration::zero())
+                            });
+                            if !valid {
+                                self.error(
+                                    offset,
+                                    format!(
+                                        "Invalid allbeads.poll-interval '{}' (use e.g. 30s, 5m)",
+                                        value.unwrap_or_default()
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {}
+                    },
+                    None => self.error(
+                        offset,
+                        "<annotation> must be inside a <project>".to_string(),
+                    ),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Record the rig ID of the finished project, flagging duplicates
+    fn close_project(&mut self) {
+        let Some(project) = self.project.take() else {
+            return;
+        };
+        // Same ID the Sheriff derives: the bead prefix, falling back to path
+        let Some(rig_id) = project.prefix.or(project.path) else {
+            return;
+        };
+        if let Some(first) = self.rigs.get(&rig_id) {
+            let message = format!(
+                "Duplicate rig id '{}' (first defined on line {})",
+                rig_id,
+                self.position(*first).0
+            );
+            self.error(project.offset, message);
+        } else {
+            self.rigs.insert(rig_id, project.offset);
+        }
+    }
+
+    fn attributes(&mut self, e: &BytesStart, offset: usize) -> HashMap<String, String> {
+        let mut attrs = HashMap::new();
+        for attr in e.attributes() {
+            match attr {
+                Ok(attr) => {
+                    let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
+                    match attr.unescape_value() {
+                        Ok(value) => {
+                            attrs.insert(key, value.to_string());
+                        }
+                        Err(err) => self.error(
+                            offset,
+                            format!("Invalid value for attribute '{}': {}", key, err),
+                        ),
+                    }
+                }
+                Err(err) => self.error(offset, format!("Invalid attribute: {}", err)),
+            }
+        }
+        attrs
+    }
+
+    /// Skip leading whitespace to find the `<` of the element just read
+    fn tag_start(&self, from: usize) -> usize {
+        self.xml[from..].find('<').map(|i| from + i).unwrap_or(from)
+    }
+
+    /// Convert a byte offset to a 1-based (line, column)
+    fn position(&self, offset: usize) -> (usize, usize) {
+        let offset = offset.min(self.xml.len());
+        let before = &self.xml[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rfind('\n')
+            .map(|nl| before[nl + 1..].chars().count())
+            .unwrap_or_else(|| before.chars().count())
+            + 1;
+        (line, column)
+    }
+
+    fn error(&mut self, offset: usize, message: String) {
+        let (line, column) = self.position(offset);
+        self.errors.push(ManifestError {
+            line,
+            column,
+            message,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_manifest() {
+        let xml = r#"<manifest>
+  <remote name="origin" fetch="https://github.com/org" />
+  <default revision="main" remote="origin" />
+  <project path="services/auth" name="backend/auth">
+    <annotation key="allbeads.prefix" value="auth" />
+  </project>
+  <project path="services/api" name="backend/api" />
+</manifest>"#;
+        assert_eq!(validate_str(xml), Ok(()));
+    }
+
+    #[test]
+    fn test_reports_locations() {
+        let xml = r#"<manifest>
+  <remote name="origin" />
+  <projet path="x" name="y" />
+  <project path="a" name="a">
+    <annotation key="allbeads.prefix" value="dup" />
+  </project>
+  <project path="b" name="b">
+    <annotation key="allbeads.prefix" value="dup" />
+  </project>
+  <annotation key="stray" value="1" />
+</manifest>"#;
+        let errors = validate_str(xml).unwrap_err();
+        let summary: Vec<(usize, usize)> = errors.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(summary, vec![(2, 3), (3, 3), (7, 3), (10, 3)]);
+        assert!(errors[0].message.contains("'fetch'"));
+        assert!(errors[1].message.contains("Unknown element <projet>"));
+        assert!(errors[2].message.contains("Duplicate rig id 'dup'"));
+        assert!(errors[2].message.contains("line 4"));
+        assert!(errors[3].message.contains("inside a <project>"));
+    }
+
+    #[test]
+    fn test_poll_interval_annotation() {
+        let xml = r#"<manifest>
+  <project path="a" name="a">
+    <annotation key="allbeads.poll-interval" value="5m" />
+  </project>
+  <project path="b" name="b">
+    <annotation key="allbeads.poll-interval" value="soon" />
+  </project>
+</manifest>"#;
+        let errors = validate_str(xml).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!((errors[0].line, errors[0].column), (6, 5));
+        assert!(errors[0].message.contains("'soon'"));
+    }
+
+    #[test]
+    fn test_malformed_xml() {
+        let xml = "<manifest>\n  <project path=\"a\" name=\"a\">\n</manifest>";
+        let errors = validate_str(xml).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.starts_with("Malformed XML"));
+        assert_eq!(errors[0].line, 3);
+    }
+}
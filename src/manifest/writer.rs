@@ -0,0 +1,277 @@
+//! XML manifest generation
+//!
+//! Builds manifests from the AllBeads configuration and serializes them in
+//! the same schema the parser reads.
+
+use super::{Annotation, Manifest, Project};
+use crate::config::AllBeadsConfig;
+use crate::graph::Rig;
+use crate::{AllBeadsError, Result};
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, Event};
+use quick_xml::Writer;
+
+impl Manifest {
+    /// Build a manifest with one project per configured context and rig
+    ///
+    /// Projects use the context's local path (or managed clone) and its full
+    /// repository URL, so no `<remote>` entries are needed. The bead prefix is
+    /// read from the context's `.beads/config.yaml` when available. Each of a
+    /// context's member rigs follows it as its own project.
+    pub fn from_config(config: &AllBeadsConfig) -> Self {
+        let projects = config
+            .contexts
+            .iter()
+            .flat_map(|ctx| {
+                let mut annotations = vec![Annotation {
+                    key: "allbeads.context".to_string(),
+                    value: ctx.name.clone(),
+                }];
+                if let Some(prefix) = ctx.issue_prefix() {
+                    annotations.push(Annotation {
+                        key: "allbeads.prefix".to_string(),
+                        value: prefix.to_string(),
+                    });
+                }
+                if let Some(ref jira) = ctx.integrations.jira {
+                    annotations.push(Annotation {
+                        key: "allbeads.jira-project".to_string(),
+                        value: jira.project.clone(),
+                    });
+                }
+
+                let project = Project {
+                    path: ctx.get_path().to_string_lossy().to_string(),
+                    name: ctx.clone_url().to_string(),
+                    revision: None,
+                    remote: None,
+                    annotations,
+                };
+                std::iter::once(project).chain(ctx.rigs.iter().map(rig_project))
+            })
+            .collect();
+
+        Manifest {
+            remotes: Vec::new(),
+            default: None,
+            projects,
+        }
+    }
+
+    /// Serialize the manifest to XML
+    pub fn to_xml(&self) -> Result<String> {
+        let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+
+        write(
+            &mut writer,
+            Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)),
+        )?;
+        write(&mut writer, Event::Start(BytesStart::new("manifest")))?;
+
+        for remote in &self.remotes {
+            let mut elem = BytesStart::new("remote");
+            elem.push_attribute(("name", remote.name.as_str()));
+            elem.push_attribute(("fetch", remote.fetch.as_str()));
+            if let Some(ref review) = remote.review {
+                elem.push_attribute(("review", review.as_str()));
+            }
+            write(&mut writer, Event::Empty(elem))?;
+        }
+
+        if let Some(ref default) = self.default {
+            let mut elem = BytesStart::new("default");
+            elem.push_attribute(("revision", default.revision.as_str()));
+            elem.push_attribute(("remote", default.remote.as_str()));
+            let sync_j = default.sync_j.map(|j| j.to_string());
+            if let Some(ref sync_j) = sync_j {
+                elem.push_attribute(("sync-j", sync_j.as_str()));
+            }
+            write(&mut writer, Event::Empty(elem))?;
+        }
+
+        for project in &self.projects {
+            let mut elem = BytesStart::new("project");
+            elem.push_attribute(("path", project.path.as_str()));
+            elem.push_attribute(("name", project.name.as_str()));
+            if let Some(ref revision) = project.revision {
+                elem.push_attribute(("revision", revision.as_str()));
+            }
+            if let Some(ref remote) = project.remote {
+                elem.push_attribute(("remote", remote.as_str()));
+            }
+
+            if project.annotations.is_empty() {
+                write(&mut writer, Event::Empty(elem))?;
+                continue;
+            }
+
+            write(&mut writer, Event::Start(elem))?;
+            for annotation in &project.annotations {
+                let mut elem = BytesStart::new("annotation");
+                elem.push_attribute(("key", annotation.key.as_str()));
+                elem.push_attribute(("value", annotation.value.as_str()));
+                write(&mut writer, Event::Empty(elem))?;
+            }
+            write(&mut writer, Event::End(BytesEnd::new("project")))?;
+        }
+
+        write(&mut writer, Event::End(BytesEnd::new("manifest")))?;
+
+        let mut xml = String::from_utf8(writer.into_inner())
+            .map_err(|e| AllBeadsError::Parse(format!("Manifest is not valid UTF-8: {}", e)))?;
+        xml.push('\n');
+        Ok(xml)
+    }
+}
+
+/// Manifest project for a context's member rig
+fn rig_project(rig: &Rig) -> Project {
+    let annotation = |key: &str, value: &str| Annotation {
+        key: key.to_string(),
+        value: value.to_string(),
+    };
+    let mut annotations = vec![
+        annotation("allbeads.context", &rig.context),
+        annotation("allbeads.prefix", &rig.prefix),
+    ];
+    if let Some(ref persona) = rig.persona {
+        annotations.push(annotation("allbeads.persona", persona));
+    }
+    if let Some(ref jira) = rig.jira_project {
+        annotations.push(annotation("allbeads.jira-project", jira));
+    }
+
+    Project {
+        path: rig.path.to_string_lossy().to_string(),
+        name: rig.remote.clone(),
+        revision: Some(rig.branch.clone()),
+        remote: None,
+        annotations,
+    }
+}
+
+fn write(writer: &mut Writer<Vec<u8>>, event: Event<'_>) -> Result<()> {
+    writer
+        .write_event(event)
+        .map_err(|e| AllBeadsError::Parse(format!("Error writing manifest XML: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{AuthStrategy, BossContext};
+    use crate::manifest::{ManifestDefault, Remote};
+
+    #[test]
+    fn test_to_xml_round_trip() {
+        let manifest = Manifest {
+            remotes: vec![Remote {
+                name: "origin".to_string(),
+                fetch: "https://github.com/org".to_string(),
+                review: None,
+            }],
+            default: Some(ManifestDefault {
+                revision: "main".to_string(),
+                remote: "origin".to_string(),
+                sync_j: Some(4),
+            }),
+            projects: vec![
+                Project {
+                    path: "services/auth".to_string(),
+                    name: "backend/auth & co".to_string(),
+                    revision: Some("develop".to_string()),
+                    remote: None,
+                    annotations: vec![Annotation {
+                        key: "allbeads.prefix".to_string(),
+                        value: "auth".to_string(),
+                    }],
+                },
+                Project {
+                    path: "frontend/web".to_string(),
+                    name: "frontend/web-app".to_string(),
+                    revision: None,
+                    remote: Some("origin".to_string()),
+                    annotations: vec![],
+                },
+            ],
+        };
+
+        let parsed = Manifest::parse(&manifest.to_xml().unwrap()).unwrap();
+
+        assert_eq!(parsed.remotes.len(), 1);
+        assert_eq!(parsed.remotes[0].fetch, "https://github.com/org");
+        assert_eq!(parsed.default.as_ref().unwrap().sync_j, Some(4));
+        assert_eq!(parsed.projects.len(), 2);
+        assert_eq!(parsed.projects[0].name, "backend/auth & co");
+        assert_eq!(parsed.projects[0].revision.as_deref(), Some("develop"));
+        assert_eq!(parsed.projects[0].prefix(), Some("auth"));
+        assert_eq!(parsed.projects[1].remote.as_deref(), Some("origin"));
+        assert!(parsed.projects[1].annotations.is_empty());
+    }
+
+    #[test]
+    fn test_from_config_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join(".beads")).unwrap();
+        std::fs::write(
+            dir.path().join(".beads/config.yaml"),
+            "issue-prefix: \"wk\"\n",
+        )
+        .unwrap();
+
+        let mut work = BossContext::new(
+            "work",
+            "https://github.com/org/work.git",
+            AuthStrategy::SshAgent,
+        )
+        .with_path(dir.path());
+        work.add_rig(
+            Rig::builder()
+                .id("auth")
+                .path("services/auth")
+                .remote("git@github.com:org/auth.git")
+                .branch("develop")
+                .auth_strategy(crate::graph::RigAuthStrategy::SshAgent)
+                .persona("security-specialist")
+                .prefix("auth")
+                .jira_project("SEC")
+                .context("work")
+                .build()
+                .unwrap(),
+        );
+
+        let mut config = AllBeadsConfig::new();
+        config.add_context(work);
+        config.add_context(
+            BossContext::new(
+                "personal",
+                "https://github.com/me/personal.git",
+                AuthStrategy::SshAgent,
+            )
+            .with_path("/tmp/personal"),
+        );
+
+        let manifest = Manifest::from_config(&config);
+        let parsed = Manifest::parse(&manifest.to_xml().unwrap()).unwrap();
+
+        let rigs = |m: &Manifest| -> Vec<(String, String)> {
+            m.projects
+                .iter()
+                .map(|p| (p.prefix().unwrap_or(&p.path).to_string(), p.name.clone()))
+                .collect()
+        };
+        assert_eq!(rigs(&parsed), rigs(&manifest));
+        assert_eq!(parsed.projects.len(), 3);
+        assert_eq!(parsed.projects[0].prefix(), Some("wk"));
+
+        let rig = &parsed.projects[1];
+        assert_eq!(rig.path, "services/auth");
+        assert_eq!(rig.name, "git@github.com:org/auth.git");
+        assert_eq!(rig.revision.as_deref(), Some("develop"));
+        assert_eq!(rig.context(), Some("work"));
+        assert_eq!(rig.prefix(), Some("auth"));
+        assert_eq!(rig.persona(), Some("security-specialist"));
+        assert_eq!(rig.jira_project(), Some("SEC"));
+
+        assert_eq!(parsed.projects[2].context(), Some("personal"));
+    }
+}
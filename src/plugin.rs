@@ -187,6 +187,9 @@ pub enum OnboardingStep {
         cwd: Option<String>,
         #[serde(default)]
         skip_if: Option<DetectionConfig>,
+        /// Command that undoes this step if a later step fails
+        #[serde(default)]
+        rollback: Option<String>,
     },
     Interactive {
         id: String,
@@ -250,12 +253,26 @@ pub struct CuratedPlugin {
     pub has_onboarding: bool,
     #[serde(default)]
     pub relevance: PluginRelevance,
+    /// Registry plugins that must be installed before this one
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+}
+
+/// A cycle in plugin `depends_on` declarations
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Plugin dependency cycle: {}", .cycle.join(" -> "))]
+pub struct CycleError {
+    /// Plugins forming the cycle, starting and ending with the same name
+    pub cycle: Vec<String>,
 }
 
 /// Plugin registry containing curated plugins
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PluginRegistry {
     pub plugins: Vec<CuratedPlugin>,
+    /// Source directories of plugins registered with `ab plugin dev`
+    #[serde(skip)]
+    pub dev_paths: HashMap<String, PathBuf>,
 }
 
 impl PluginRegistry {
@@ -274,6 +291,7 @@ impl PluginRegistry {
                         always_suggest: true,
                         ..Default::default()
                     },
+                    depends_on: Vec::new(),
                 },
                 CuratedPlugin {
                     name: "prose".to_string(),
@@ -283,6 +301,7 @@ impl PluginRegistry {
                     repository: Some("https://github.com/openprose/prose".to_string()),
                     has_onboarding: true,
                     relevance: PluginRelevance::default(),
+                    depends_on: Vec::new(),
                 },
                 CuratedPlugin {
                     name: "mcp-github".to_string(),
@@ -295,6 +314,7 @@ impl PluginRegistry {
                         files: vec![".github".to_string()],
                         ..Default::default()
                     },
+                    depends_on: Vec::new(),
                 },
                 CuratedPlugin {
                     name: "mcp-filesystem".to_string(),
@@ -307,6 +327,7 @@ impl PluginRegistry {
                         always_suggest: true,
                         ..Default::default()
                     },
+                    depends_on: Vec::new(),
                 },
                 CuratedPlugin {
                     name: "prettier".to_string(),
@@ -320,6 +341,7 @@ impl PluginRegistry {
                         files: vec![".prettierrc".to_string(), "prettier.config.js".to_string()],
                         ..Default::default()
                     },
+                    depends_on: Vec::new(),
                 },
                 CuratedPlugin {
                     name: "eslint".to_string(),
@@ -337,6 +359,7 @@ impl PluginRegistry {
                         ],
                         ..Default::default()
                     },
+                    depends_on: Vec::new(),
                 },
                 CuratedPlugin {
                     name: "jest".to_string(),
@@ -350,6 +373,7 @@ impl PluginRegistry {
                         files: vec!["jest.config.js".to_string(), "jest.config.ts".to_string()],
                         ..Default::default()
                     },
+                    depends_on: Vec::new(),
                 },
                 CuratedPlugin {
                     name: "pytest".to_string(),
@@ -363,9 +387,39 @@ impl PluginRegistry {
                         files: vec!["pytest.ini".to_string(), "pyproject.toml".to_string()],
                         ..Default::default()
                     },
+                    depends_on: Vec::new(),
                 },
             ],
+            dev_paths: HashMap::new(),
+        }
+    }
+
+    /// Built-in plugins plus local plugins registered with `ab plugin dev`
+    ///
+    /// Dev plugins are re-read from disk each time, so edits to their
+    /// onboarding YAML show up immediately. A dev plugin replaces a built-in
+    /// plugin with the same name.
+    pub fn load(config: &crate::config::AllBeadsConfig) -> Self {
+        let mut registry = Self::builtin();
+        for path in read_dev_plugin_paths(&dev_plugins_path(config)) {
+            match load_dev_plugin(&path) {
+                Ok(plugin) => registry.register_dev(plugin, path),
+                Err(e) => tracing::warn!("Skipping dev plugin at {}: {}", path.display(), e),
+            }
         }
+        registry
+    }
+
+    /// Add a local plugin, replacing any plugin with the same name
+    pub fn register_dev(&mut self, plugin: CuratedPlugin, path: PathBuf) {
+        self.plugins.retain(|p| p.name != plugin.name);
+        self.dev_paths.insert(plugin.name.clone(), path);
+        self.plugins.push(plugin);
+    }
+
+    /// Source directory of a plugin registered with `ab plugin dev`
+    pub fn dev_path(&self, name: &str) -> Option<&Path> {
+        self.dev_paths.get(name).map(PathBuf::as_path)
     }
 
     /// Find a plugin by name
@@ -373,6 +427,47 @@ impl PluginRegistry {
         self.plugins.iter().find(|p| p.name == name)
     }
 
+    /// Order in which to install a plugin and its dependencies
+    ///
+    /// Dependencies come first and the requested plugin last. Names that
+    /// aren't in the registry are skipped, so callers should compare against
+    /// `depends_on` to warn about them.
+    pub fn resolve_install_order(
+        &self,
+        name: &str,
+    ) -> std::result::Result<Vec<&CuratedPlugin>, CycleError> {
+        let mut order = Vec::new();
+        let mut path = Vec::new();
+        self.visit(name, &mut path, &mut order)?;
+        Ok(order)
+    }
+
+    fn visit<'a>(
+        &'a self,
+        name: &str,
+        path: &mut Vec<String>,
+        order: &mut Vec<&'a CuratedPlugin>,
+    ) -> std::result::Result<(), CycleError> {
+        if let Some(pos) = path.iter().position(|p| p == name) {
+            let mut cycle = path[pos..].to_vec();
+            cycle.push(name.to_string());
+            return Err(CycleError { cycle });
+        }
+        let Some(plugin) = self.find(name) else {
+            return Ok(());
+        };
+        if order.iter().any(|p| p.name == name) {
+            return Ok(());
+        }
+        path.push(name.to_string());
+        for dep in &plugin.depends_on {
+            self.visit(dep, path, order)?;
+        }
+        path.pop();
+        order.push(plugin);
+        Ok(())
+    }
+
     /// Get plugins relevant to given languages and files
     pub fn recommend(&self, languages: &[String], files: &[String]) -> Vec<&CuratedPlugin> {
         self.plugins
@@ -403,6 +498,72 @@ impl PluginRegistry {
     }
 }
 
+/// File in the config directory listing `ab plugin dev` source directories
+pub const DEV_PLUGINS_FILE: &str = "dev-plugins.json";
+
+/// Location of the dev plugin list, next to the config file
+pub fn dev_plugins_path(config: &crate::config::AllBeadsConfig) -> PathBuf {
+    config.config_dir().join(DEV_PLUGINS_FILE)
+}
+
+/// Read registered dev plugin directories; a missing file means none
+pub fn read_dev_plugin_paths(file: &Path) -> Vec<PathBuf> {
+    std::fs::read_to_string(file)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Replace the registered dev plugin directories
+pub fn write_dev_plugin_paths(file: &Path, paths: &[PathBuf]) -> crate::Result<()> {
+    if let Some(parent) = file.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(file, serde_json::to_string_pretty(paths)?)?;
+    Ok(())
+}
+
+/// Build a registry entry from a local plugin directory
+///
+/// Requires `.claude-plugin/allbeads-onboarding.yaml`. Name and description
+/// come from `.claude-plugin/plugin.json` when present, otherwise from the
+/// onboarding protocol. Unlike [`load_onboarding`], parse errors are returned
+/// so plugin authors can see them.
+pub fn load_dev_plugin(path: &Path) -> crate::Result<CuratedPlugin> {
+    let plugin_dir = path.join(".claude-plugin");
+    let onboarding_path = plugin_dir.join("allbeads-onboarding.yaml");
+    let content = std::fs::read_to_string(&onboarding_path).map_err(|e| {
+        crate::AllBeadsError::Config(format!("{}: {}", onboarding_path.display(), e))
+    })?;
+    let onboarding: PluginOnboarding = serde_yaml::from_str(&content).map_err(|e| {
+        crate::AllBeadsError::Parse(format!("{}: {}", onboarding_path.display(), e))
+    })?;
+
+    let manifest: Option<serde_json::Value> =
+        std::fs::read_to_string(plugin_dir.join("plugin.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok());
+    let manifest_str = |key: &str| {
+        manifest
+            .as_ref()
+            .and_then(|m| m.get(key))
+            .and_then(|v| v.as_str())
+            .map(String::from)
+    };
+
+    Ok(CuratedPlugin {
+        name: manifest_str("name").unwrap_or_else(|| onboarding.plugin.clone()),
+        description: manifest_str("description")
+            .unwrap_or_else(|| format!("Local plugin at {}", path.display())),
+        category: PluginCategory::Other,
+        marketplace: None,
+        repository: None,
+        has_onboarding: true,
+        relevance: onboarding.relevance,
+        depends_on: Vec::new(),
+    })
+}
+
 /// Claude plugin state from ~/.claude/plugins/
 #[derive(Debug, Clone, Default)]
 pub struct ClaudePluginState {
@@ -616,6 +777,26 @@ pub struct OnboardingResult {
     pub steps_skipped: usize,
     pub errors: Vec<String>,
     pub prompt_responses: StdHashMap<String, String>,
+    /// Whether undoing completed steps after a failure worked; `None` if no rollback ran
+    pub rollback_succeeded: Option<bool>,
+}
+
+/// How to undo a completed onboarding step
+#[derive(Debug, Clone)]
+enum StepUndo {
+    /// Put a file back as it was; `None` means it didn't exist
+    RestoreFile {
+        path: PathBuf,
+        original: Option<String>,
+    },
+    /// Run the step's declared rollback command
+    Command {
+        id: String,
+        command: String,
+        cwd: PathBuf,
+    },
+    /// A command step that declared no rollback
+    Irreversible { id: String },
 }
 
 /// Execute onboarding steps for a plugin
@@ -624,6 +805,8 @@ pub struct OnboardingExecutor {
     dry_run: bool,
     auto_yes: bool,
     prompt_responses: StdHashMap<String, String>,
+    /// Undo actions for completed steps, in execution order
+    journal: Vec<StepUndo>,
 }
 
 impl OnboardingExecutor {
@@ -633,6 +816,7 @@ impl OnboardingExecutor {
             dry_run: false,
             auto_yes: false,
             prompt_responses: StdHashMap::new(),
+            journal: Vec::new(),
         }
     }
 
@@ -647,8 +831,13 @@ impl OnboardingExecutor {
     }
 
     /// Execute all onboarding steps
+    ///
+    /// Stops at the first failing step and undoes the steps completed before
+    /// it in reverse order: written files are restored from backups and
+    /// commands run their declared `rollback`.
     pub fn execute(&mut self, onboarding: &PluginOnboarding) -> OnboardingResult {
         let mut result = OnboardingResult::default();
+        self.journal.clear();
 
         for step in &onboarding.onboard.steps {
             match self.execute_step(step) {
@@ -661,7 +850,10 @@ impl OnboardingExecutor {
                 }
                 Err(e) => {
                     result.errors.push(e);
-                    // Continue with other steps unless critical
+                    let rollback_errors = self.rollback();
+                    result.rollback_succeeded = Some(rollback_errors.is_empty());
+                    result.errors.extend(rollback_errors);
+                    break;
                 }
             }
         }
@@ -671,6 +863,62 @@ impl OnboardingExecutor {
         result
     }
 
+    /// Undo journaled steps in reverse order, returning any failures
+    fn rollback(&mut self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if self.journal.is_empty() {
+            return errors;
+        }
+
+        println!("  Rolling back {} completed step(s)...", self.journal.len());
+        while let Some(undo) = self.journal.pop() {
+            match undo {
+                StepUndo::RestoreFile { path, original } => {
+                    let restored = match original {
+                        Some(content) => std::fs::write(&path, content),
+                        None => std::fs::remove_file(&path),
+                    };
+                    match restored {
+                        Ok(()) => println!("    ✓ Restored {}", path.display()),
+                        Err(e) => errors.push(format!(
+                            "Rollback: failed to restore '{}': {}",
+                            path.display(),
+                            e
+                        )),
+                    }
+                }
+                StepUndo::Command { id, command, cwd } => {
+                    let output = std::process::Command::new("sh")
+                        .arg("-c")
+                        .arg(&command)
+                        .current_dir(&cwd)
+                        .output();
+                    match output {
+                        Ok(out) if out.status.success() => {
+                            println!("    ✓ Rolled back '{}'", id)
+                        }
+                        Ok(out) => errors.push(format!(
+                            "Rollback: command for '{}' failed: {}",
+                            id,
+                            String::from_utf8_lossy(&out.stderr)
+                        )),
+                        Err(e) => errors.push(format!(
+                            "Rollback: failed to run command for '{}': {}",
+                            id, e
+                        )),
+                    }
+                }
+                StepUndo::Irreversible { id } => {
+                    errors.push(format!(
+                        "Rollback: step '{}' declares no rollback command",
+                        id
+                    ));
+                }
+            }
+        }
+        errors
+    }
+
     /// Execute a single step, returns Ok(true) if skipped
     fn execute_step(&mut self, step: &OnboardingStep) -> Result<bool, String> {
         match step {
@@ -681,6 +929,7 @@ impl OnboardingExecutor {
                 command,
                 cwd,
                 skip_if,
+                rollback,
             } => {
                 println!("  Step: {}", name);
                 println!("    {}", description);
@@ -713,6 +962,14 @@ impl OnboardingExecutor {
                     .map_err(|e| format!("Failed to run command '{}': {}", id, e))?;
 
                 if output.status.success() {
+                    self.journal.push(match rollback {
+                        Some(command) => StepUndo::Command {
+                            id: id.clone(),
+                            command: command.clone(),
+                            cwd: work_dir,
+                        },
+                        None => StepUndo::Irreversible { id: id.clone() },
+                    });
                     println!("    ✓ Completed");
                     Ok(false)
                 } else {
@@ -770,8 +1027,13 @@ impl OnboardingExecutor {
                         .map_err(|e| format!("Failed to create directory: {}", e))?;
                 }
 
+                let original = std::fs::read_to_string(&dest_path).ok();
                 std::fs::write(&dest_path, rendered)
                     .map_err(|e| format!("Failed to write '{}': {}", id, e))?;
+                self.journal.push(StepUndo::RestoreFile {
+                    path: dest_path,
+                    original,
+                });
 
                 println!("    ✓ Created {}", dest);
                 Ok(false)
@@ -795,7 +1057,8 @@ impl OnboardingExecutor {
                 }
 
                 // Read existing content
-                let existing = std::fs::read_to_string(&dest_path).unwrap_or_default();
+                let original = std::fs::read_to_string(&dest_path).ok();
+                let existing = original.clone().unwrap_or_default();
 
                 // Check if content already exists
                 let rendered = self.render_template(content);
@@ -813,6 +1076,10 @@ impl OnboardingExecutor {
 
                 writeln!(file, "{}", rendered)
                     .map_err(|e| format!("Failed to append to '{}': {}", id, e))?;
+                self.journal.push(StepUndo::RestoreFile {
+                    path: dest_path,
+                    original,
+                });
 
                 println!("    ✓ Appended to {}", dest);
                 Ok(false)
@@ -1360,6 +1627,175 @@ mod tests {
         assert!(registry.find("beads").is_some());
     }
 
+    fn plugin(name: &str, depends_on: &[&str]) -> CuratedPlugin {
+        CuratedPlugin {
+            name: name.to_string(),
+            description: String::new(),
+            category: PluginCategory::Other,
+            marketplace: None,
+            repository: None,
+            has_onboarding: false,
+            relevance: PluginRelevance::default(),
+            depends_on: depends_on.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_install_order() {
+        let registry = PluginRegistry {
+            plugins: vec![
+                plugin("app", &["lint", "test"]),
+                plugin("lint", &["core"]),
+                plugin("test", &["core", "missing"]),
+                plugin("core", &[]),
+            ],
+            ..Default::default()
+        };
+        let order: Vec<&str> = registry
+            .resolve_install_order("app")
+            .unwrap()
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        assert_eq!(order, vec!["core", "lint", "test", "app"]);
+        assert!(registry
+            .resolve_install_order("unknown")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_resolve_install_order_cycle() {
+        let registry = PluginRegistry {
+            plugins: vec![
+                plugin("a", &["b"]),
+                plugin("b", &["c"]),
+                plugin("c", &["b"]),
+            ],
+            ..Default::default()
+        };
+        let err = registry.resolve_install_order("a").unwrap_err();
+        assert_eq!(err.cycle, vec!["b", "c", "b"]);
+        assert_eq!(err.to_string(), "Plugin dependency cycle: b -> c -> b");
+    }
+
+    #[test]
+    fn test_dev_plugin_registration() {
+        let dir = tempfile::tempdir().unwrap();
+        let plugin_dir = dir.path().join("my-plugin");
+        std::fs::create_dir_all(plugin_dir.join(".claude-plugin")).unwrap();
+        std::fs::write(
+            plugin_dir.join(".claude-plugin/allbeads-onboarding.yaml"),
+            "schema_version: \"1.0\"\nplugin: beads\nversion: \"0.1.0\"\n",
+        )
+        .unwrap();
+
+        let plugin = load_dev_plugin(&plugin_dir).unwrap();
+        assert_eq!(plugin.name, "beads");
+        assert!(plugin.has_onboarding);
+
+        let mut registry = PluginRegistry::builtin();
+        let builtin_count = registry.plugins.len();
+        registry.register_dev(plugin, plugin_dir.clone());
+        assert_eq!(registry.plugins.len(), builtin_count);
+        assert_eq!(registry.dev_path("beads"), Some(plugin_dir.as_path()));
+        assert!(registry.dev_path("prose").is_none());
+
+        let config_path = dir.path().join("config.yaml");
+        crate::config::AllBeadsConfig::new()
+            .save(&config_path)
+            .unwrap();
+        let config = crate::config::AllBeadsConfig::load(&config_path).unwrap();
+        let list = dev_plugins_path(&config);
+        assert_eq!(list, dir.path().join("dev-plugins.json"));
+        assert!(read_dev_plugin_paths(&list).is_empty());
+        write_dev_plugin_paths(&list, std::slice::from_ref(&plugin_dir)).unwrap();
+        assert_eq!(read_dev_plugin_paths(&list), vec![plugin_dir.clone()]);
+        assert_eq!(
+            PluginRegistry::load(&config).dev_path("beads"),
+            Some(plugin_dir.as_path())
+        );
+
+        assert!(load_dev_plugin(dir.path()).is_err());
+    }
+
+    fn onboarding_with_steps(yaml_steps: &str) -> PluginOnboarding {
+        serde_yaml::from_str(&format!(
+            "schema_version: \"1.0\"\nplugin: test\nversion: \"0.1.0\"\nonboard:\n  steps:\n{}",
+            yaml_steps
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_onboarding_rollback_restores_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("existing.md"), "keep\n").unwrap();
+        let onboarding = onboarding_with_steps(
+            r#"    - type: template
+      id: new
+      name: New file
+      description: Create a file
+      template: "hello"
+      dest: new.md
+    - type: append
+      id: append
+      name: Append
+      description: Append to a file
+      dest: existing.md
+      content: "added"
+    - type: command
+      id: marker
+      name: Marker
+      description: Create a marker
+      command: touch marker
+      rollback: rm marker
+    - type: command
+      id: fail
+      name: Fail
+      description: Always fails
+      command: "false"
+"#,
+        );
+
+        let mut executor = OnboardingExecutor::new(dir.path().to_path_buf()).auto_yes(true);
+        let result = executor.execute(&onboarding);
+
+        assert!(!result.success);
+        assert_eq!(result.steps_completed, 3);
+        assert_eq!(result.rollback_succeeded, Some(true));
+        assert!(!dir.path().join("new.md").exists());
+        assert!(!dir.path().join("marker").exists());
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("existing.md")).unwrap(),
+            "keep\n"
+        );
+    }
+
+    #[test]
+    fn test_onboarding_rollback_requires_declared_command() {
+        let dir = tempfile::tempdir().unwrap();
+        let onboarding = onboarding_with_steps(
+            r#"    - type: command
+      id: setup
+      name: Setup
+      description: No rollback declared
+      command: "true"
+    - type: command
+      id: fail
+      name: Fail
+      description: Always fails
+      command: "false"
+"#,
+        );
+
+        let mut executor = OnboardingExecutor::new(dir.path().to_path_buf()).auto_yes(true);
+        let result = executor.execute(&onboarding);
+
+        assert_eq!(result.rollback_succeeded, Some(false));
+        assert!(result.errors.iter().any(|e| e.contains("'setup'")));
+    }
+
     #[test]
     fn test_recommend_plugins() {
         let registry = PluginRegistry::builtin();
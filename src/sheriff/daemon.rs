@@ -1,7 +1,11 @@
 //! Sheriff daemon implementation
 //!
 //! Background synchronization daemon that federates beads across repositories.
-//! Runs as a tokio async event loop with configurable poll intervals.
+//! Runs as a tokio async event loop with configurable poll intervals. Each rig
+//! is scheduled independently, so a manifest can poll a fast local rig more
+//! often than a slow remote one (`allbeads.poll-interval` annotation). Rigs
+//! that fall due together sync in parallel, up to
+//! [`SheriffConfig::max_concurrent_polls`] at a time.
 
 use super::metrics;
 use crate::governance::checker::CheckSummary;
@@ -16,13 +20,17 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::{broadcast, mpsc, Mutex};
+use tokio::sync::{broadcast, mpsc, Mutex, Semaphore};
+use tokio::time::Instant;
 
 use super::{sync_rig_to_shadows, SyncResult};
 
 /// Default poll interval (5 seconds)
 pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
 
+/// Default number of rigs synced at the same time
+pub const DEFAULT_MAX_CONCURRENT_POLLS: usize = 4;
+
 /// Sheriff daemon configuration
 #[derive(Debug, Clone)]
 pub struct SheriffConfig {
@@ -33,8 +41,15 @@ pub struct SheriffConfig {
     pub manifest_path: Option<PathBuf>,
 
     /// Poll interval for checking Rig repositories
+    ///
+    /// Rigs with their own interval (see [`Sheriff::set_rig_poll_interval`])
+    /// ignore this. Housekeeping (lock cleanup, policy checks) runs once per
+    /// this interval.
     pub poll_interval: Duration,
 
+    /// Most rigs synced at the same time when several are due
+    pub max_concurrent_polls: usize,
+
     /// Enable verbose logging
     pub verbose: bool,
 
@@ -66,6 +81,7 @@ impl Default for SheriffConfig {
             boss_repo_path: PathBuf::from("."),
             manifest_path: None,
             poll_interval: DEFAULT_POLL_INTERVAL,
+            max_concurrent_polls: DEFAULT_MAX_CONCURRENT_POLLS,
             verbose: false,
             db_path: PathBuf::from(".beads/mail.db"),
             project_id: "boss".to_string(),
@@ -97,6 +113,12 @@ impl SheriffConfig {
         self
     }
 
+    /// Set how many rigs may sync at the same time
+    pub fn with_max_concurrent_polls(mut self, max: usize) -> Self {
+        self.max_concurrent_polls = max;
+        self
+    }
+
     /// Set verbose logging
     pub fn with_verbose(mut self, verbose: bool) -> Self {
         self.verbose = verbose;
@@ -137,14 +159,14 @@ pub enum SheriffEvent {
     /// Daemon stopped
     Stopped,
 
-    /// Poll cycle started
+    /// Poll cycle started for the rigs that are due
     PollStarted,
 
-    /// Poll cycle completed
+    /// Poll completed for a single rig
     PollCompleted {
-        /// Number of rigs polled
-        rigs_polled: usize,
-        /// Total sync changes
+        /// Rig identifier
+        rig_id: RigId,
+        /// Sync changes for this rig
         changes: usize,
     },
 
@@ -235,6 +257,12 @@ struct RigState {
 
     /// Last sync result
     last_sync: Option<SyncResult>,
+
+    /// Poll interval override (falls back to the global poll interval)
+    poll_interval: Option<Duration>,
+
+    /// When this rig is next due to be polled
+    next_poll: Instant,
 }
 
 /// Sheriff daemon
@@ -271,6 +299,9 @@ pub struct Sheriff {
     /// Command sender (for cloning)
     command_tx: mpsc::Sender<SheriffCommand>,
 
+    /// When lock cleanup and policy checks are next due
+    next_housekeeping: Instant,
+
     /// Running flag
     running: bool,
 }
@@ -304,6 +335,7 @@ impl Sheriff {
             event_tx,
             command_rx: Some(command_rx),
             command_tx,
+            next_housekeeping: Instant::now(),
             running: false,
         })
     }
@@ -394,6 +426,8 @@ impl Sheriff {
                 context: self.config.project_id.clone(),
                 shadows: Vec::new(),
                 last_sync: None,
+                poll_interval: project.poll_interval(),
+                next_poll: Instant::now(),
             };
 
             self.rigs.insert(rig_id, state);
@@ -416,17 +450,57 @@ impl Sheriff {
             context: context.into(),
             shadows: Vec::new(),
             last_sync: None,
+            poll_interval: None,
+            next_poll: Instant::now(),
         };
         self.rigs.insert(id, state);
     }
 
+    /// Poll a rig on its own interval instead of the global one
+    ///
+    /// Returns `false` if no rig with that ID is tracked.
+    pub fn set_rig_poll_interval(&mut self, id: &str, interval: Duration) -> bool {
+        match self.rigs.get_mut(id) {
+            Some(state) => {
+                state.poll_interval = Some(interval);
+                state.next_poll = Instant::now() + interval;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Effective poll interval of a rig
+    fn rig_interval(&self, state: &RigState) -> Duration {
+        state.poll_interval.unwrap_or(self.config.poll_interval)
+    }
+
+    /// When the next rig or housekeeping run is due
+    fn next_poll_at(&self) -> Instant {
+        self.rigs
+            .values()
+            .map(|state| state.next_poll)
+            .fold(self.next_housekeeping, Instant::min)
+    }
+
+    /// IDs of rigs whose next poll time has passed, in a stable order
+    fn due_rigs(&self, now: Instant) -> Vec<String> {
+        let mut due: Vec<String> = self
+            .rigs
+            .iter()
+            .filter(|(_, state)| state.next_poll <= now)
+            .map(|(id, _)| id.clone())
+            .collect();
+        due.sort();
+        due
+    }
+
     /// Run the daemon event loop with graceful shutdown on SIGTERM/SIGINT
     pub async fn run(&mut self) -> Result<()> {
         self.running = true;
         metrics::set_health_status(true);
         self.send_event(SheriffEvent::Started);
 
-        let mut interval = tokio::time::interval(self.config.poll_interval);
         let mut mail_interval = tokio::time::interval(self.config.mail_poll_interval);
         let mail_poll_enabled = self.config.mail_poll;
 
@@ -438,24 +512,14 @@ impl Sheriff {
         // Use platform-specific event loop
         #[cfg(unix)]
         {
-            self.run_with_signals(
-                &mut interval,
-                &mut mail_interval,
-                mail_poll_enabled,
-                &mut command_rx,
-            )
-            .await?;
+            self.run_with_signals(&mut mail_interval, mail_poll_enabled, &mut command_rx)
+                .await?;
         }
 
         #[cfg(not(unix))]
         {
-            self.run_without_signals(
-                &mut interval,
-                &mut mail_interval,
-                mail_poll_enabled,
-                &mut command_rx,
-            )
-            .await?;
+            self.run_without_signals(&mut mail_interval, mail_poll_enabled, &mut command_rx)
+                .await?;
         }
 
         // Cleanup before exit
@@ -471,7 +535,6 @@ impl Sheriff {
     #[cfg(unix)]
     async fn run_with_signals(
         &mut self,
-        interval: &mut tokio::time::Interval,
         mail_interval: &mut tokio::time::Interval,
         mail_poll_enabled: bool,
         command_rx: &mut mpsc::Receiver<SheriffCommand>,
@@ -487,9 +550,9 @@ impl Sheriff {
 
         loop {
             tokio::select! {
-                _ = interval.tick() => {
+                _ = tokio::time::sleep_until(self.next_poll_at()) => {
                     if self.running {
-                        self.poll_cycle().await;
+                        self.poll_due().await;
                     }
                 }
                 _ = mail_interval.tick(), if mail_poll_enabled => {
@@ -498,7 +561,7 @@ impl Sheriff {
                     }
                 }
                 Some(cmd) = command_rx.recv() => {
-                    match self.handle_command_async(cmd, interval).await {
+                    match self.handle_command_async(cmd).await {
                         CommandResult::Continue => {}
                         CommandResult::Stop => break,
                     }
@@ -522,16 +585,15 @@ impl Sheriff {
     #[cfg(not(unix))]
     async fn run_without_signals(
         &mut self,
-        interval: &mut tokio::time::Interval,
         mail_interval: &mut tokio::time::Interval,
         mail_poll_enabled: bool,
         command_rx: &mut mpsc::Receiver<SheriffCommand>,
     ) -> Result<()> {
         loop {
             tokio::select! {
-                _ = interval.tick() => {
+                _ = tokio::time::sleep_until(self.next_poll_at()) => {
                     if self.running {
-                        self.poll_cycle().await;
+                        self.poll_due().await;
                     }
                 }
                 _ = mail_interval.tick(), if mail_poll_enabled => {
@@ -540,7 +602,7 @@ impl Sheriff {
                     }
                 }
                 Some(cmd) = command_rx.recv() => {
-                    match self.handle_command_async(cmd, interval).await {
+                    match self.handle_command_async(cmd).await {
                         CommandResult::Continue => {}
                         CommandResult::Stop => break,
                     }
@@ -551,14 +613,13 @@ impl Sheriff {
     }
 
     /// Handle a command asynchronously
-    async fn handle_command_async(
-        &mut self,
-        cmd: SheriffCommand,
-        interval: &mut tokio::time::Interval,
-    ) -> CommandResult {
+    async fn handle_command_async(&mut self, cmd: SheriffCommand) -> CommandResult {
         match cmd {
             SheriffCommand::SyncNow => {
-                self.poll_cycle().await;
+                let mut all: Vec<String> = self.rigs.keys().cloned().collect();
+                all.sort();
+                self.poll_rigs(all).await;
+                self.housekeeping();
             }
             SheriffCommand::Shutdown => {
                 tracing::info!("Received shutdown command");
@@ -573,8 +634,15 @@ impl Sheriff {
                 }
             }
             SheriffCommand::SetPollInterval(duration) => {
-                *interval = tokio::time::interval(duration);
+                // Reschedule rigs that follow the global interval
                 self.config.poll_interval = duration;
+                let next = Instant::now() + duration;
+                self.next_housekeeping = next;
+                for state in self.rigs.values_mut() {
+                    if state.poll_interval.is_none() {
+                        state.next_poll = next;
+                    }
+                }
             }
             SheriffCommand::ReloadPolicies => {
                 self.reload_policies();
@@ -606,56 +674,73 @@ impl Sheriff {
         );
     }
 
-    /// Execute a single poll cycle
-    async fn poll_cycle(&mut self) {
-        self.send_event(SheriffEvent::PollStarted);
-
-        let mut total_changes = 0;
-        let rigs_count = self.rigs.len();
+    /// Poll the rigs that are due, then run housekeeping if it is due too
+    async fn poll_due(&mut self) {
+        let now = Instant::now();
+        let due = self.due_rigs(now);
+        self.poll_rigs(due).await;
+        if self.next_housekeeping <= now {
+            self.housekeeping();
+        }
+    }
 
-        // Collect rig IDs to avoid borrow issues
-        let rig_ids: Vec<String> = self.rigs.keys().cloned().collect();
+    /// Poll the given rigs and schedule each one's next poll
+    ///
+    /// Rigs sync on blocking threads, at most
+    /// [`SheriffConfig::max_concurrent_polls`] at a time.
+    async fn poll_rigs(&mut self, rig_ids: Vec<String>) {
+        if rig_ids.is_empty() {
+            return;
+        }
+        self.send_event(SheriffEvent::PollStarted);
 
+        let limit = Arc::new(Semaphore::new(self.config.max_concurrent_polls.max(1)));
+        let mut syncs = Vec::new();
         for rig_id in rig_ids {
-            let start = std::time::Instant::now();
-            match self.sync_rig(&rig_id) {
-                Ok(result) => {
-                    // Record sync duration metric
-                    let duration = start.elapsed().as_secs_f64();
-                    metrics::record_sync_duration(&rig_id, duration);
-
-                    total_changes += result.change_count();
-
-                    // Get rig_id_typed from the state
-                    if let Some(state) = self.rigs.get(&rig_id) {
-                        // Record shadow count metric
-                        metrics::set_shadows_count(&rig_id, state.shadows.len() as i64);
-
-                        self.send_event(SheriffEvent::RigSynced {
-                            rig_id: state.id.clone(),
-                            result,
-                        });
-                    }
-                }
+            let (path, context, existing) = match self.take_rig_for_sync(&rig_id) {
+                Ok(parts) => parts,
                 Err(e) => {
-                    // Record error metric
-                    metrics::record_api_error("sync_error", &rig_id);
+                    self.rig_sync_failed(&rig_id, e);
+                    continue;
+                }
+            };
+            let limit = Arc::clone(&limit);
+            let id = rig_id.clone();
+            let handle = tokio::spawn(async move {
+                // The semaphore is never closed, so this always gets a permit
+                let _permit = limit.acquire_owned().await;
+                let start = std::time::Instant::now();
+                let result = tokio::task::spawn_blocking(move || {
+                    sync_rig_to_shadows(&path, &id, &context, existing)
+                })
+                .await;
+                (result, start.elapsed())
+            });
+            syncs.push((rig_id, handle));
+        }
 
-                    self.send_event(SheriffEvent::Error {
-                        message: format!("Failed to sync rig {}: {}", rig_id, e),
-                    });
+        for (rig_id, handle) in syncs {
+            match handle.await {
+                Ok((Ok(Ok((result, new_shadows))), elapsed)) => {
+                    metrics::record_sync_duration(&rig_id, elapsed.as_secs_f64());
+                    self.finish_sync(&rig_id, result, new_shadows);
                 }
+                Ok((Ok(Err(e)), _)) => self.rig_sync_failed(&rig_id, e),
+                Ok((Err(e), _)) | Err(e) => self.rig_sync_failed(
+                    &rig_id,
+                    crate::AllBeadsError::Other(format!("Sync task failed: {}", e)),
+                ),
             }
         }
+    }
 
-        // Record sync cycle completion
+    /// Record the sync cycle, clean up expired locks and run policy checks
+    ///
+    /// Runs once per global poll interval, however many rigs were polled in
+    /// between, so a Sheriff without rigs still ticks.
+    fn housekeeping(&mut self) {
         metrics::record_sync_cycle("success");
 
-        self.send_event(SheriffEvent::PollCompleted {
-            rigs_polled: rigs_count,
-            changes: total_changes,
-        });
-
         // Cleanup expired locks in postmaster
         if let Some(ref postmaster) = self.postmaster {
             if let Ok(mut pm) = postmaster.try_lock() {
@@ -663,8 +748,8 @@ impl Sheriff {
             }
         }
 
-        // Run policy checks
         self.run_policy_checks();
+        self.next_housekeeping = Instant::now() + self.config.poll_interval;
     }
 
     /// Poll mail inbox and process messages
@@ -796,6 +881,7 @@ impl Sheriff {
                 notes: None,
                 aiki_tasks: Vec::new(),
                 handoff: None,
+                parent: None,
             };
 
             graph.beads.insert(bead.id.clone(), bead);
@@ -813,9 +899,8 @@ impl Sheriff {
         }
     }
 
-    /// Sync a single rig
-    fn sync_rig(&mut self, rig_id: &str) -> Result<SyncResult> {
-        // Get mutable access to take shadows and read path/context
+    /// Take a rig's path, context and shadows for a sync off the main task
+    fn take_rig_for_sync(&mut self, rig_id: &str) -> Result<(PathBuf, String, Vec<ShadowBead>)> {
         let state = self
             .rigs
             .get_mut(rig_id)
@@ -829,28 +914,54 @@ impl Sheriff {
             )));
         }
 
-        // Take ownership of existing shadows instead of cloning (saves one full Vec clone)
+        // Take ownership of existing shadows instead of cloning
         let existing_shadows = std::mem::take(&mut state.shadows);
-        // Copy path and context for use after releasing borrow
-        let rig_path = state.path.clone();
-        let context = state.context.clone();
-
-        // Sync rig to shadows (borrow of self.rigs released)
-        let (result, new_shadows) =
-            sync_rig_to_shadows(&rig_path, rig_id, &context, existing_shadows)?;
+        Ok((state.path.clone(), state.context.clone(), existing_shadows))
+    }
 
-        // Update state - clone shadows for rig state, move into global list
-        let shadows_for_rig = new_shadows.clone();
-        if let Some(state) = self.rigs.get_mut(rig_id) {
-            state.shadows = shadows_for_rig;
-            // Clone result for storing (original returned to caller)
-            state.last_sync = Some(result.clone());
-        }
+    /// Store a rig's sync result, emit its events and schedule its next poll
+    fn finish_sync(&mut self, rig_id: &str, result: SyncResult, new_shadows: Vec<ShadowBead>) {
+        let Some(state) = self.rigs.get_mut(rig_id) else {
+            return;
+        };
+        let changes = result.change_count();
+        state.shadows = new_shadows.clone();
+        state.last_sync = Some(result.clone());
+        let id = state.id.clone();
+        metrics::set_shadows_count(rig_id, state.shadows.len() as i64);
 
         // Update global shadows list (takes ownership of new_shadows)
         self.update_shadows(rig_id, new_shadows);
 
-        Ok(result)
+        self.send_event(SheriffEvent::RigSynced {
+            rig_id: id.clone(),
+            result,
+        });
+        self.schedule_next_poll(rig_id);
+        self.send_event(SheriffEvent::PollCompleted {
+            rig_id: id,
+            changes,
+        });
+    }
+
+    /// Report a failed rig sync and schedule its next poll
+    fn rig_sync_failed(&mut self, rig_id: &str, error: crate::AllBeadsError) {
+        metrics::record_api_error("sync_error", rig_id);
+        self.send_event(SheriffEvent::Error {
+            message: format!("Failed to sync rig {}: {}", rig_id, error),
+        });
+        self.schedule_next_poll(rig_id);
+    }
+
+    /// Schedule a rig's next poll from now, so a slow sync can't cause
+    /// back-to-back polls
+    fn schedule_next_poll(&mut self, rig_id: &str) {
+        let Some(interval) = self.rigs.get(rig_id).map(|state| self.rig_interval(state)) else {
+            return;
+        };
+        if let Some(state) = self.rigs.get_mut(rig_id) {
+            state.next_poll = Instant::now() + interval;
+        }
     }
 
     /// Update global shadows list after a rig sync
@@ -958,6 +1069,12 @@ impl SheriffBuilder {
         self
     }
 
+    /// Set how many rigs may sync at the same time
+    pub fn max_concurrent_polls(mut self, max: usize) -> Self {
+        self.config.max_concurrent_polls = max;
+        self
+    }
+
     /// Set verbose logging
     pub fn verbose(mut self, verbose: bool) -> Self {
         self.config.verbose = verbose;
@@ -1011,10 +1128,12 @@ mod tests {
             .poll_interval(Duration::from_secs(10))
             .verbose(true)
             .project_id("test")
+            .max_concurrent_polls(2)
             .build()
             .unwrap();
 
         assert_eq!(sheriff.config.poll_interval, Duration::from_secs(10));
+        assert_eq!(sheriff.config.max_concurrent_polls, 2);
     }
 
     #[test]
@@ -1025,6 +1144,59 @@ mod tests {
         assert!(sheriff.rigs.contains_key("auth-service"));
     }
 
+    #[test]
+    fn test_rig_poll_schedule() {
+        let config = SheriffConfig::default().with_poll_interval(Duration::from_secs(60));
+        let mut sheriff = Sheriff::new(config).unwrap();
+        sheriff.add_rig("fast", "/path/to/fast", "work");
+        sheriff.add_rig("slow", "/path/to/slow", "work");
+        assert!(!sheriff.set_rig_poll_interval("missing", Duration::from_secs(1)));
+        assert!(sheriff.set_rig_poll_interval("slow", Duration::from_secs(600)));
+
+        let now = Instant::now();
+        assert_eq!(sheriff.due_rigs(now), vec!["fast".to_string()]);
+        assert!(sheriff.next_poll_at() <= now);
+
+        let fast = &sheriff.rigs["fast"];
+        let slow = &sheriff.rigs["slow"];
+        assert_eq!(sheriff.rig_interval(fast), Duration::from_secs(60));
+        assert_eq!(sheriff.rig_interval(slow), Duration::from_secs(600));
+        assert_eq!(
+            sheriff.due_rigs(now + Duration::from_secs(601)),
+            vec!["fast".to_string(), "slow".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_poll_due_reschedules_rigs_and_housekeeping_once() {
+        let config = SheriffConfig::default()
+            .with_poll_interval(Duration::from_secs(60))
+            .with_max_concurrent_polls(1);
+        let mut sheriff = Sheriff::new(config).unwrap();
+        sheriff.add_rig("a", "/nonexistent/allbeads/a", "work");
+        sheriff.add_rig("b", "/nonexistent/allbeads/b", "work");
+        let mut events = sheriff.subscribe();
+
+        let before = Instant::now();
+        sheriff.poll_due().await;
+
+        assert!(sheriff.due_rigs(Instant::now()).is_empty());
+        assert!(sheriff.next_housekeeping > before);
+        assert!(sheriff.next_poll_at() > before);
+
+        let mut errors = 0;
+        let mut policy_checks = 0;
+        while let Ok(event) = events.try_recv() {
+            match event {
+                SheriffEvent::Error { .. } => errors += 1,
+                SheriffEvent::PolicyChecked { .. } => policy_checks += 1,
+                _ => {}
+            }
+        }
+        assert_eq!(errors, 2);
+        assert_eq!(policy_checks, 1);
+    }
+
     #[test]
     fn test_stats() {
         let sheriff = Sheriff::new(SheriffConfig::default()).unwrap();
@@ -58,7 +58,7 @@ mod sync;
 
 pub use daemon::{
     Sheriff, SheriffBuilder, SheriffCommand, SheriffConfig, SheriffEvent, SheriffStats,
-    DEFAULT_POLL_INTERVAL,
+    DEFAULT_MAX_CONCURRENT_POLLS, DEFAULT_POLL_INTERVAL,
 };
 pub use external_sync::{
     ExternalSyncConfig, ExternalSyncEvent, ExternalSyncResult, ExternalSyncer,
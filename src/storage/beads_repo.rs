@@ -148,6 +148,28 @@ impl BeadsRepo {
         Ok(())
     }
 
+    /// Create a new bead with a description
+    pub fn create_with_description(
+        &self,
+        title: &str,
+        issue_type: &str,
+        priority: Option<u8>,
+        description: &str,
+    ) -> Result<()> {
+        self.bd
+            .create_full(
+                title,
+                issue_type,
+                priority,
+                Some(description),
+                None,
+                None,
+                None,
+            )
+            .map_err(|e| crate::AllBeadsError::Storage(e.to_string()))?;
+        Ok(())
+    }
+
     /// Update a bead's status
     pub fn update_status(&self, id: &BeadId, status: Status) -> Result<()> {
         let status_str = match status {
@@ -107,16 +107,9 @@ pub fn issue_to_bead(issue: beads::Issue) -> Result<Bead> {
     let issue_type = parse_issue_type(&issue.issue_type)?;
 
     let priority = issue
-        .priority
-        .and_then(|p| match p {
-            0 => Some(Priority::P0),
-            1 => Some(Priority::P1),
-            2 => Some(Priority::P2),
-            3 => Some(Priority::P3),
-            4 => Some(Priority::P4),
-            _ => None,
-        })
-        .unwrap_or(Priority::P2);
+        .priority_typed()
+        .map(|p| Priority::from(p.as_u8()))
+        .unwrap_or_default();
 
     let bead = Bead {
         id: BeadId::new(issue.id),
@@ -134,6 +127,7 @@ pub fn issue_to_bead(issue: beads::Issue) -> Result<Bead> {
         created_by: "unknown".to_string(), // beads::Issue doesn't track creator
         assignee: issue.assignee,
         dependencies: issue.depends_on.into_iter().map(BeadId::new).collect(),
+        parent: issue.parent.map(BeadId::new),
         blocks: issue
             .blocks
             .into_iter()
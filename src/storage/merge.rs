@@ -0,0 +1,233 @@
+//! Merge git conflicts in `issues.jsonl`
+//!
+//! When two machines edit beads and both `bd sync`, git may leave conflict
+//! markers in `.beads/issues.jsonl`. Each line is a whole bead keyed by `id`,
+//! so most of these conflicts are just different beads landing in the same
+//! hunk. [`merge_conflicted_jsonl`] keys both sides by bead ID, takes every
+//! bead that only one side has or both sides agree on, and reports beads with
+//! the same ID but different fields as genuine conflicts.
+
+use crate::{AllBeadsError, Result};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// A bead edited differently on both sides of a merge
+#[derive(Debug, Clone, PartialEq)]
+pub struct BeadConflict {
+    pub id: String,
+    /// Top-level fields whose values differ, sorted
+    pub fields: Vec<String>,
+    /// Our line, as written in the file
+    pub ours: String,
+    /// Their line, as written in the file
+    pub theirs: String,
+}
+
+/// Result of merging a conflicted `issues.jsonl`
+#[derive(Debug, Clone, Default)]
+pub struct JsonlMerge {
+    /// Beads both sides agree on, or that only one side has, sorted by ID
+    pub merged: Vec<String>,
+    /// Beads that need a human decision, sorted by ID
+    pub conflicts: Vec<BeadConflict>,
+}
+
+impl JsonlMerge {
+    /// Render the merged file
+    ///
+    /// Conflicting beads are written back between conflict markers so the
+    /// file stays conflicted on exactly those lines.
+    pub fn to_jsonl(&self) -> String {
+        let mut lines: Vec<(&str, String)> = self
+            .merged
+            .iter()
+            .map(|line| (line_id(line).unwrap_or_default(), format!("{}\n", line)))
+            .collect();
+        for conflict in &self.conflicts {
+            lines.push((
+                conflict.id.as_str(),
+                format!(
+                    "<<<<<<< ours\n{}\n=======\n{}\n>>>>>>> theirs\n",
+                    conflict.ours, conflict.theirs
+                ),
+            ));
+        }
+        lines.sort_by(|a, b| a.0.cmp(b.0));
+        lines.into_iter().map(|(_, text)| text).collect()
+    }
+}
+
+/// Whether file content contains git conflict markers
+pub fn has_conflict_markers(content: &str) -> bool {
+    content.lines().any(|l| l.starts_with("<<<<<<<"))
+}
+
+/// Merge both sides of a conflicted `issues.jsonl`
+///
+/// Lines outside conflict hunks belong to both sides. The base section of
+/// diff3-style hunks (`|||||||`) is ignored.
+pub fn merge_conflicted_jsonl(content: &str) -> Result<JsonlMerge> {
+    #[derive(PartialEq)]
+    enum Section {
+        Common,
+        Ours,
+        Base,
+        Theirs,
+    }
+
+    let mut ours: Vec<&str> = Vec::new();
+    let mut theirs: Vec<&str> = Vec::new();
+    let mut section = Section::Common;
+    for (n, line) in content.lines().enumerate() {
+        let unexpected =
+            || AllBeadsError::Parse(format!("Unexpected conflict marker on line {}", n + 1));
+        if line.starts_with("<<<<<<<") {
+            if section != Section::Common {
+                return Err(unexpected());
+            }
+            section = Section::Ours;
+        } else if line.starts_with("|||||||") {
+            if section != Section::Ours {
+                return Err(unexpected());
+            }
+            section = Section::Base;
+        } else if line.starts_with("=======") {
+            if section != Section::Ours && section != Section::Base {
+                return Err(unexpected());
+            }
+            section = Section::Theirs;
+        } else if line.starts_with(">>>>>>>") {
+            if section != Section::Theirs {
+                return Err(unexpected());
+            }
+            section = Section::Common;
+        } else if !line.trim().is_empty() {
+            match section {
+                Section::Common => {
+                    ours.push(line);
+                    theirs.push(line);
+                }
+                Section::Ours => ours.push(line),
+                Section::Base => {}
+                Section::Theirs => theirs.push(line),
+            }
+        }
+    }
+    if section != Section::Common {
+        return Err(AllBeadsError::Parse(
+            "Unterminated conflict hunk".to_string(),
+        ));
+    }
+
+    let ours = index_by_id(&ours)?;
+    let theirs = index_by_id(&theirs)?;
+
+    let mut merge = JsonlMerge::default();
+    let ids: std::collections::BTreeSet<&String> = ours.keys().chain(theirs.keys()).collect();
+    for id in ids {
+        match (ours.get(id), theirs.get(id)) {
+            (Some((line, _)), None) | (None, Some((line, _))) => {
+                merge.merged.push(line.to_string())
+            }
+            (Some((our_line, our_value)), Some((their_line, their_value))) => {
+                if our_value == their_value {
+                    merge.merged.push(our_line.to_string());
+                } else {
+                    merge.conflicts.push(BeadConflict {
+                        id: id.clone(),
+                        fields: differing_fields(our_value, their_value),
+                        ours: our_line.to_string(),
+                        theirs: their_line.to_string(),
+                    });
+                }
+            }
+            (None, None) => unreachable!("id came from one of the maps"),
+        }
+    }
+    Ok(merge)
+}
+
+/// Parse lines into `id -> (line, value)`; a later line for the same ID wins
+fn index_by_id<'a>(lines: &[&'a str]) -> Result<BTreeMap<String, (&'a str, Value)>> {
+    let mut by_id = BTreeMap::new();
+    for line in lines {
+        let value: Value = serde_json::from_str(line)?;
+        let id = value
+            .get("id")
+            .and_then(Value::as_str)
+            .ok_or_else(|| AllBeadsError::Parse(format!("Bead without an id: {}", line)))?
+            .to_string();
+        by_id.insert(id, (*line, value));
+    }
+    Ok(by_id)
+}
+
+fn line_id(line: &str) -> Option<&str> {
+    // Lines in `merged` were parsed already, so only the id needs extracting
+    let start = line.find("\"id\"")? + 4;
+    let rest = line[start..].trim_start().strip_prefix(':')?.trim_start();
+    let rest = rest.strip_prefix('"')?;
+    rest.find('"').map(|end| &rest[..end])
+}
+
+fn differing_fields(ours: &Value, theirs: &Value) -> Vec<String> {
+    let (Some(ours), Some(theirs)) = (ours.as_object(), theirs.as_object()) else {
+        return Vec::new();
+    };
+    let keys: std::collections::BTreeSet<&String> = ours.keys().chain(theirs.keys()).collect();
+    keys.into_iter()
+        .filter(|k| ours.get(*k) != theirs.get(*k))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFLICTED: &str = r#"{"id":"ab-1","title":"Shared","status":"open"}
+<<<<<<< HEAD
+{"id":"ab-2","title":"Mine","status":"open"}
+{"id":"ab-4","title":"Edited","status":"closed"}
+=======
+{"id":"ab-3","title":"Theirs","status":"open"}
+{"id":"ab-4","title":"Edited","status":"in_progress"}
+>>>>>>> origin/main
+{"id":"ab-5","title":"Tail","status":"open"}
+"#;
+
+    #[test]
+    fn test_merge_keeps_non_conflicting_beads() {
+        assert!(has_conflict_markers(CONFLICTED));
+        let merge = merge_conflicted_jsonl(CONFLICTED).unwrap();
+
+        let ids: Vec<&str> = merge.merged.iter().filter_map(|l| line_id(l)).collect();
+        assert_eq!(ids, vec!["ab-1", "ab-2", "ab-3", "ab-5"]);
+        assert_eq!(merge.conflicts.len(), 1);
+        assert_eq!(merge.conflicts[0].id, "ab-4");
+        assert_eq!(merge.conflicts[0].fields, vec!["status"]);
+    }
+
+    #[test]
+    fn test_to_jsonl_reconflicts_only_genuine_conflicts() {
+        let merge = merge_conflicted_jsonl(CONFLICTED).unwrap();
+        let output = merge.to_jsonl();
+        assert_eq!(output.matches("<<<<<<<").count(), 1);
+        assert!(output.contains("=======\n{\"id\":\"ab-4\""));
+
+        let clean = merge_conflicted_jsonl(
+            "<<<<<<< a\n{\"id\":\"x-2\"}\n||||||| base\n=======\n{\"id\":\"x-1\"}\n>>>>>>> b\n",
+        )
+        .unwrap();
+        assert!(clean.conflicts.is_empty());
+        assert_eq!(clean.to_jsonl(), "{\"id\":\"x-1\"}\n{\"id\":\"x-2\"}\n");
+        assert!(!has_conflict_markers(&clean.to_jsonl()));
+    }
+
+    #[test]
+    fn test_malformed_conflict() {
+        assert!(merge_conflicted_jsonl("<<<<<<< a\n{\"id\":\"x-1\"}\n").is_err());
+        assert!(merge_conflicted_jsonl("=======\n").is_err());
+        assert!(merge_conflicted_jsonl("not json\n").is_err());
+    }
+}
@@ -6,7 +6,9 @@
 mod beads_repo;
 mod conversions;
 mod jsonl;
+mod merge;
 
 pub use beads_repo::BeadsRepo;
 pub use conversions::{issue_to_bead, issues_to_beads, parse_issue_type, parse_status};
 pub use jsonl::{read_beads, write_beads, JsonlReader, JsonlWriter};
+pub use merge::{has_conflict_markers, merge_conflicted_jsonl, BeadConflict, JsonlMerge};
@@ -2,8 +2,68 @@
 //!
 //! Provides consistent color scheme matching bd's output style.
 //! Uses crossterm for cross-platform terminal colors.
+//!
+//! Colors are on when stdout is a terminal and `NO_COLOR` is unset, unless
+//! overridden with `--color` (see [`init_color`]).
+
+use crossterm::style::{ContentStyle, StyledContent, Stylize};
+use std::fmt::Display;
+use std::io::IsTerminal;
+use std::sync::OnceLock;
+
+/// Resolved color mode, set once per process
+static COLOR_ENABLED: OnceLock<bool> = OnceLock::new();
+
+/// When to emit ANSI styling (`--color`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ColorChoice {
+    /// Color when stdout is a terminal and NO_COLOR is unset
+    #[default]
+    Auto,
+    /// Always color, even when piped
+    Always,
+    /// Never color
+    Never,
+}
+
+impl ColorChoice {
+    /// Decide whether to color given the environment
+    pub fn resolve(self, no_color: bool, is_terminal: bool) -> bool {
+        match self {
+            ColorChoice::Auto => !no_color && is_terminal,
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
 
-use crossterm::style::{StyledContent, Stylize};
+fn detect(choice: ColorChoice) -> bool {
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    choice.resolve(no_color, std::io::stdout().is_terminal())
+}
+
+/// Resolve the color mode for this process
+///
+/// Only the first call (or first styled output) decides; later calls return
+/// the mode already in effect. Also applies it to raw crossterm styling.
+pub fn init_color(choice: ColorChoice) -> bool {
+    let enabled = *COLOR_ENABLED.get_or_init(|| detect(choice));
+    crossterm::style::force_color_output(enabled);
+    enabled
+}
+
+/// Whether styled output includes ANSI escapes
+pub fn color_enabled() -> bool {
+    *COLOR_ENABLED.get_or_init(|| detect(ColorChoice::Auto))
+}
+
+/// Drop all styling when color is disabled
+fn paint<D: Display>(mut content: StyledContent<D>) -> StyledContent<D> {
+    if !color_enabled() {
+        *content.style_mut() = ContentStyle::new();
+    }
+    content
+}
 
 /// Priority colors (matches bd)
 /// - P0/P1: Red/Orange (urgent)
@@ -11,14 +71,14 @@ use crossterm::style::{StyledContent, Stylize};
 /// - P3/P4: Dim (low/backlog)
 pub fn priority_style(priority: u8) -> StyledContent<String> {
     let label = format!("P{}", priority);
-    match priority {
+    paint(match priority {
         0 => label.red().bold(),
         1 => label.dark_yellow().bold(), // Orange-ish
         2 => label.yellow(),
         3 => label.dark_grey(),
         4 => label.dark_grey(),
         _ => label.white(),
-    }
+    })
 }
 
 /// Issue type colors (matches bd)
@@ -29,7 +89,7 @@ pub fn priority_style(priority: u8) -> StyledContent<String> {
 /// - chore: Grey
 pub fn type_style(issue_type: &str) -> StyledContent<String> {
     let label = format!("[{}]", issue_type);
-    match issue_type.to_lowercase().as_str() {
+    paint(match issue_type.to_lowercase().as_str() {
         "epic" => label.magenta(),
         "feature" => label.green(),
         "bug" => label.red(),
@@ -37,7 +97,7 @@ pub fn type_style(issue_type: &str) -> StyledContent<String> {
         "chore" => label.dark_grey(),
         "gate" => label.blue(),
         _ => label.white(),
-    }
+    })
 }
 
 /// Status colors (matches bd)
@@ -46,24 +106,24 @@ pub fn type_style(issue_type: &str) -> StyledContent<String> {
 /// - blocked: Red
 /// - closed: Dim grey
 pub fn status_style(status: &str) -> StyledContent<String> {
-    match status.to_lowercase().as_str() {
+    paint(match status.to_lowercase().as_str() {
         "open" => status.to_string().white(),
         "in_progress" => status.to_string().yellow(),
         "blocked" => status.to_string().red(),
         "closed" => status.to_string().dark_grey(),
         _ => status.to_string().white(),
-    }
+    })
 }
 
 /// Status indicator (circle)
 pub fn status_indicator(status: &str) -> StyledContent<&'static str> {
-    match status.to_lowercase().as_str() {
+    paint(match status.to_lowercase().as_str() {
         "open" => "○".white(),
         "in_progress" => "◐".yellow(),
         "blocked" => "●".red(),
         "closed" => "✓".dark_grey(),
         _ => "○".white(),
-    }
+    })
 }
 
 /// Count styling based on context
@@ -72,94 +132,94 @@ pub fn status_indicator(status: &str) -> StyledContent<&'static str> {
 /// - Warning: Yellow
 /// - Error: Red (for blocked)
 pub fn count_ready(n: usize) -> StyledContent<String> {
-    if n == 0 {
+    paint(if n == 0 {
         n.to_string().dark_grey()
     } else {
         n.to_string().green()
-    }
+    })
 }
 
 pub fn count_blocked(n: usize) -> StyledContent<String> {
-    if n == 0 {
+    paint(if n == 0 {
         n.to_string().dark_grey()
     } else {
         n.to_string().red()
-    }
+    })
 }
 
 pub fn count_in_progress(n: usize) -> StyledContent<String> {
-    if n == 0 {
+    paint(if n == 0 {
         n.to_string().dark_grey()
     } else {
         n.to_string().yellow()
-    }
+    })
 }
 
 pub fn count_normal(n: usize) -> StyledContent<String> {
-    n.to_string().white()
+    paint(n.to_string().white())
 }
 
 /// Section headers
 pub fn header(text: &str) -> StyledContent<String> {
-    text.to_string().bold()
+    paint(text.to_string().bold())
 }
 
 /// Subheaders
 pub fn subheader(text: &str) -> StyledContent<String> {
-    text.to_string().underlined()
+    paint(text.to_string().underlined())
 }
 
 /// Dim/muted text
 pub fn dim(text: &str) -> StyledContent<String> {
-    text.to_string().dark_grey()
+    paint(text.to_string().dark_grey())
 }
 
 /// Success text
 pub fn success(text: &str) -> StyledContent<String> {
-    text.to_string().green()
+    paint(text.to_string().green())
 }
 
 /// Info text (cyan arrow for progress indicators)
 pub fn info(text: &str) -> StyledContent<String> {
-    text.to_string().cyan()
+    paint(text.to_string().cyan())
 }
 
 /// Warning text
 pub fn warning(text: &str) -> StyledContent<String> {
-    text.to_string().yellow()
+    paint(text.to_string().yellow())
 }
 
 /// Error text
 pub fn error(text: &str) -> StyledContent<String> {
-    text.to_string().red()
+    paint(text.to_string().red())
 }
 
 /// ID styling (matches bd's issue ID style)
 pub fn issue_id(id: &str) -> StyledContent<String> {
-    id.to_string().cyan()
+    paint(id.to_string().cyan())
 }
 
 /// Highlight important text (yellow)
 pub fn highlight(text: &str) -> StyledContent<String> {
-    text.to_string().yellow()
+    paint(text.to_string().yellow())
 }
 
 /// Path styling
 pub fn path(p: &str) -> StyledContent<String> {
-    p.to_string().blue()
+    paint(p.to_string().blue())
 }
 
 /// Context/folder status colors
 /// Matches Dry→Wet progression
 pub fn folder_status(status: &str) -> StyledContent<String> {
-    match status.to_lowercase().as_str() {
+    paint(match status.to_lowercase().as_str() {
         "dry" => status.to_string().dark_grey(),
         "git" => status.to_string().blue(),
         "beads" => status.to_string().cyan(),
         "configured" | "config" => status.to_string().yellow(),
         "wet" => status.to_string().green(),
         _ => status.to_string().white(),
-    }
+    })
 }
 
 /// Folder status indicator with emoji (minimal use)
@@ -178,6 +238,15 @@ pub fn folder_status_indicator(status: &str) -> &'static str {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_color_choice_resolve() {
+        assert!(ColorChoice::Auto.resolve(false, true));
+        assert!(!ColorChoice::Auto.resolve(true, true));
+        assert!(!ColorChoice::Auto.resolve(false, false));
+        assert!(ColorChoice::Always.resolve(true, false));
+        assert!(!ColorChoice::Never.resolve(false, true));
+    }
+
     #[test]
     fn test_priority_colors() {
         // Just ensure they don't panic
@@ -10,7 +10,8 @@ use super::stats_view::StatsView;
 use super::swarm_view::SwarmView;
 use super::timeline_view::TimelineView;
 use crate::graph::{Bead, FederatedGraph, Status};
-use crate::mail::{Address, Postmaster};
+use crate::mail::{Address, Message, MessageType, NotifyPayload, Postmaster};
+use ratatui::layout::{Position, Rect};
 use ratatui::widgets::ListState;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
@@ -65,7 +66,13 @@ pub struct App {
     pub current_column: Column,
     pub list_state: ListState,
     pub show_detail: bool,
+    /// Lines scrolled down in the bead detail pane
+    pub detail_scroll: u16,
+    /// Screen areas of the Kanban columns from the last draw, for mouse clicks
+    pub column_areas: [Rect; 3],
     pub current_tab: Tab,
+    /// Key binding help overlay is open
+    pub show_help: bool,
     pub mail_view: MailView,
     pub graph_view: GraphView,
     pub stats_view: StatsView,
@@ -100,7 +107,10 @@ impl App {
             current_column: Column::Open,
             list_state,
             show_detail: false,
+            detail_scroll: 0,
+            column_areas: [Rect::default(); 3],
             current_tab: Tab::Kanban,
+            show_help: false,
             mail_view: MailView::new(),
             graph_view,
             stats_view,
@@ -240,6 +250,18 @@ impl App {
         self.contexts_view.request_refresh();
     }
 
+    /// Toggle the selected message's detail view, marking it read when opened
+    pub fn toggle_mail_detail(&mut self) {
+        self.mail_view.toggle_detail();
+        let is_unread = self
+            .mail_view
+            .selected_message()
+            .is_some_and(|m| m.status == crate::mail::DeliveryStatus::Delivered);
+        if self.mail_view.is_showing_detail() && is_unread {
+            self.mark_message_read();
+        }
+    }
+
     /// Mark selected message as read
     pub fn mark_message_read(&mut self) {
         if let Some(ref postmaster) = self.postmaster {
@@ -252,6 +274,59 @@ impl App {
         }
     }
 
+    /// Open the mail compose form with the project's known agents
+    pub fn start_mail_compose(&mut self) {
+        let Some(ref postmaster) = self.postmaster else {
+            return;
+        };
+        let known_agents = postmaster
+            .lock()
+            .ok()
+            .and_then(|pm| pm.known_agents().ok())
+            .unwrap_or_default();
+        self.mail_view.start_compose(known_agents);
+    }
+
+    /// Validate the draft and ask for confirmation
+    pub fn request_mail_send(&mut self) {
+        let Some(ref postmaster) = self.postmaster else {
+            return;
+        };
+        let project_id = match postmaster.lock() {
+            Ok(pm) => pm.project_id().to_string(),
+            Err(_) => return,
+        };
+        if let Some(compose) = self.mail_view.compose_mut() {
+            compose.request_send(&project_id);
+        }
+    }
+
+    /// Send the confirmed draft through the postmaster
+    pub fn send_mail_compose(&mut self) {
+        let Some(ref postmaster) = self.postmaster else {
+            return;
+        };
+        let Some(compose) = self.mail_view.compose_mut() else {
+            return;
+        };
+        let Some(to) = compose.confirm_to.take() else {
+            return;
+        };
+        let message = Message::new(
+            self.inbox_address.clone(),
+            to.clone(),
+            MessageType::Notify(NotifyPayload::new(compose.text())),
+        );
+        let result = match postmaster.lock() {
+            Ok(mut pm) => pm.send(message).map_err(|e| e.to_string()),
+            Err(_) => Err("Mail database is unavailable".to_string()),
+        };
+        match result {
+            Ok(_) => self.mail_view.finish_compose(format!("Sent to {}", to)),
+            Err(e) => compose.error = Some(format!("Failed to send: {}", e)),
+        }
+    }
+
     /// Get the currently selected index
     pub fn selected_index(&self) -> usize {
         self.list_state.selected().unwrap_or(0)
@@ -336,9 +411,46 @@ impl App {
 
     pub fn toggle_detail(&mut self) {
         self.show_detail = !self.show_detail;
+        self.detail_scroll = 0;
     }
 
     pub fn close_detail(&mut self) {
         self.show_detail = false;
+        self.detail_scroll = 0;
+    }
+
+    pub fn scroll_detail_down(&mut self) {
+        self.detail_scroll = self.detail_scroll.saturating_add(1);
+    }
+
+    pub fn scroll_detail_up(&mut self) {
+        self.detail_scroll = self.detail_scroll.saturating_sub(1);
+    }
+
+    /// Select the bead under a mouse click, switching columns if needed
+    pub fn click_bead(&mut self, x: u16, y: u16) {
+        let Some(index) = self
+            .column_areas
+            .iter()
+            .position(|area| area.contains(Position { x, y }))
+        else {
+            return;
+        };
+        let area = self.column_areas[index];
+        let column = Column::all()[index];
+        if column != self.current_column {
+            self.current_column = column;
+            self.list_state.select(Some(0));
+            *self.list_state.offset_mut() = 0;
+        }
+
+        // Rows inside the border map to list items, shifted by the scroll offset
+        if y <= area.y || y >= area.bottom().saturating_sub(1) {
+            return;
+        }
+        let row = self.list_state.offset() + (y - area.y - 1) as usize;
+        if row < self.current_beads().len() {
+            self.list_state.select(Some(row));
+        }
     }
 }
@@ -0,0 +1,327 @@
+//! TUI key bindings
+//!
+//! Every binding lives in one table per tab. The event loop looks actions up
+//! here and the `?` help overlay renders the same tables, so the help can't
+//! drift from what the keys actually do. Text entry (the GitHub search box
+//! and mail compose form) takes raw keys and is not listed.
+
+use super::app::Tab;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// What a key press does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    NextTab,
+    ToggleHelp,
+    Down,
+    Up,
+    Left,
+    Right,
+    ToggleDetail,
+    CloseDetail,
+    MarkRead,
+    Compose,
+    CycleFilter,
+    ZoomOut,
+    ZoomIn,
+    NextSection,
+    Pause,
+    Resume,
+    Kill,
+    Refresh,
+    CycleSort,
+    CycleOrgFilter,
+    ToggleMode,
+    StartSearch,
+    RunSearch,
+    ToggleMark,
+    Onboard,
+}
+
+/// A key with the modifiers it needs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Key {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+const fn key(c: char) -> Key {
+    code(KeyCode::Char(c))
+}
+
+const fn ctrl(c: char) -> Key {
+    Key {
+        code: KeyCode::Char(c),
+        modifiers: KeyModifiers::CONTROL,
+    }
+}
+
+const fn code(code: KeyCode) -> Key {
+    Key {
+        code,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+impl Key {
+    /// Whether a key press triggers this key
+    ///
+    /// Extra modifiers are ignored so that e.g. `?` matches with Shift held.
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        event.code == self.code && event.modifiers.contains(self.modifiers)
+    }
+
+    /// Label shown in the help overlay
+    pub fn label(&self) -> String {
+        let name = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::Enter => "Enter".to_string(),
+            KeyCode::Esc => "Esc".to_string(),
+            KeyCode::Tab => "Tab".to_string(),
+            other => format!("{:?}", other),
+        };
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            format!("Ctrl+{}", name)
+        } else {
+            name
+        }
+    }
+}
+
+/// Keys bound to one action, with the text shown in the help overlay
+#[derive(Clone, Copy)]
+pub struct Binding {
+    pub keys: &'static [Key],
+    pub action: Action,
+    pub description: &'static str,
+}
+
+const fn bind(keys: &'static [Key], action: Action, description: &'static str) -> Binding {
+    Binding {
+        keys,
+        action,
+        description,
+    }
+}
+
+/// Bindings active on every tab, checked before the tab's own
+pub const GLOBAL: &[Binding] = &[
+    bind(&[key('q'), ctrl('c')], Action::Quit, "Quit"),
+    bind(&[code(KeyCode::Tab)], Action::NextTab, "Switch view"),
+    bind(&[key('?')], Action::ToggleHelp, "Toggle this help"),
+];
+
+const NAVIGATE: [Binding; 2] = [
+    bind(&[key('j'), code(KeyCode::Down)], Action::Down, "Move down"),
+    bind(&[key('k'), code(KeyCode::Up)], Action::Up, "Move up"),
+];
+
+const DETAIL: [Binding; 2] = [
+    bind(
+        &[code(KeyCode::Enter)],
+        Action::ToggleDetail,
+        "Toggle details",
+    ),
+    bind(&[code(KeyCode::Esc)], Action::CloseDetail, "Close details"),
+];
+
+const KANBAN: &[Binding] = &[
+    NAVIGATE[0],
+    NAVIGATE[1],
+    bind(
+        &[key('h'), code(KeyCode::Left)],
+        Action::Left,
+        "Previous column",
+    ),
+    bind(
+        &[key('l'), code(KeyCode::Right)],
+        Action::Right,
+        "Next column",
+    ),
+    DETAIL[0],
+    DETAIL[1],
+];
+
+const MAIL: &[Binding] = &[
+    NAVIGATE[0],
+    NAVIGATE[1],
+    bind(
+        &[code(KeyCode::Enter)],
+        Action::ToggleDetail,
+        "Open message (marks it read)",
+    ),
+    DETAIL[1],
+    bind(&[key('r')], Action::MarkRead, "Mark read"),
+    bind(&[key('c')], Action::Compose, "Compose message"),
+];
+
+const GRAPH: &[Binding] = &[
+    NAVIGATE[0],
+    NAVIGATE[1],
+    DETAIL[0],
+    DETAIL[1],
+    bind(&[key('f')], Action::CycleFilter, "Cycle filter"),
+];
+
+const TIMELINE: &[Binding] = &[
+    NAVIGATE[0],
+    NAVIGATE[1],
+    DETAIL[0],
+    DETAIL[1],
+    bind(&[key('+'), key('=')], Action::ZoomOut, "Zoom out"),
+    bind(&[key('-')], Action::ZoomIn, "Zoom in"),
+];
+
+const GOVERNANCE: &[Binding] = &[
+    NAVIGATE[0],
+    NAVIGATE[1],
+    bind(
+        &[
+            key('h'),
+            code(KeyCode::Left),
+            key('l'),
+            code(KeyCode::Right),
+        ],
+        Action::NextSection,
+        "Switch section",
+    ),
+    DETAIL[0],
+    DETAIL[1],
+];
+
+const SWARM: &[Binding] = &[
+    NAVIGATE[0],
+    NAVIGATE[1],
+    DETAIL[0],
+    DETAIL[1],
+    bind(&[key('p')], Action::Pause, "Pause agent"),
+    bind(&[key('r')], Action::Resume, "Resume agent"),
+    bind(&[key('x')], Action::Kill, "Kill agent"),
+];
+
+const AIKI: &[Binding] = &[
+    NAVIGATE[0],
+    NAVIGATE[1],
+    bind(&[key('r')], Action::Refresh, "Refresh"),
+];
+
+const CONTEXTS: &[Binding] = &[
+    NAVIGATE[0],
+    NAVIGATE[1],
+    bind(&[key('r')], Action::Refresh, "Refresh"),
+    bind(&[key('s')], Action::CycleSort, "Cycle sort"),
+    bind(&[key('o')], Action::CycleOrgFilter, "Cycle org filter"),
+    DETAIL[0],
+    DETAIL[1],
+];
+
+const GITHUB_PICKER: &[Binding] = &[
+    NAVIGATE[0],
+    NAVIGATE[1],
+    bind(&[key('m')], Action::ToggleMode, "Toggle user/search mode"),
+    bind(&[key('/')], Action::StartSearch, "Edit search query"),
+    bind(&[key('s')], Action::RunSearch, "Run search again"),
+    bind(&[key(' ')], Action::ToggleMark, "Mark repo for onboarding"),
+    bind(
+        &[code(KeyCode::Enter)],
+        Action::ToggleDetail,
+        "Details (in details: mark and close)",
+    ),
+    DETAIL[1],
+    bind(
+        &[key('o')],
+        Action::Onboard,
+        "Onboard marked or selected repos",
+    ),
+];
+
+/// Bindings specific to a tab
+pub fn tab_bindings(tab: Tab) -> &'static [Binding] {
+    match tab {
+        Tab::Kanban => KANBAN,
+        Tab::Mail => MAIL,
+        Tab::Graph => GRAPH,
+        Tab::Stats => &[],
+        Tab::Timeline => TIMELINE,
+        Tab::Governance => GOVERNANCE,
+        Tab::Swarm => SWARM,
+        Tab::Aiki => AIKI,
+        Tab::Contexts => CONTEXTS,
+        Tab::GitHubPicker => GITHUB_PICKER,
+    }
+}
+
+/// Find the action bound to a key press
+pub fn lookup(bindings: &[Binding], event: &KeyEvent) -> Option<Action> {
+    bindings
+        .iter()
+        .find(|b| b.keys.iter().any(|k| k.matches(event)))
+        .map(|b| b.action)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TABS: [Tab; 10] = [
+        Tab::Kanban,
+        Tab::Mail,
+        Tab::Graph,
+        Tab::Stats,
+        Tab::Timeline,
+        Tab::Governance,
+        Tab::Swarm,
+        Tab::Aiki,
+        Tab::Contexts,
+        Tab::GitHubPicker,
+    ];
+
+    #[test]
+    fn test_lookup() {
+        let press = |code, modifiers| KeyEvent::new(code, modifiers);
+        assert_eq!(
+            lookup(GLOBAL, &press(KeyCode::Char('?'), KeyModifiers::SHIFT)),
+            Some(Action::ToggleHelp)
+        );
+        assert_eq!(
+            lookup(GLOBAL, &press(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            Some(Action::Quit)
+        );
+        assert_eq!(
+            lookup(GLOBAL, &press(KeyCode::Char('c'), KeyModifiers::NONE)),
+            None
+        );
+        assert_eq!(
+            lookup(
+                tab_bindings(Tab::Mail),
+                &press(KeyCode::Char('c'), KeyModifiers::NONE)
+            ),
+            Some(Action::Compose)
+        );
+    }
+
+    #[test]
+    fn test_no_key_bound_twice() {
+        for tab in TABS {
+            let keys: Vec<Key> = GLOBAL
+                .iter()
+                .chain(tab_bindings(tab))
+                .flat_map(|b| b.keys.iter().copied())
+                .collect();
+            for (i, key) in keys.iter().enumerate() {
+                assert!(
+                    !keys[i + 1..].contains(key),
+                    "{} bound twice on {:?}",
+                    key.label(),
+                    tab
+                );
+            }
+        }
+    }
+}
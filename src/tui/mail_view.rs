@@ -1,8 +1,9 @@
 //! Mail inbox view for the TUI
 //!
-//! Displays Agent Mail messages with actions.
+//! Displays Agent Mail messages with actions, and a compose form for
+//! sending new ones.
 
-use crate::mail::{MessageType, Postmaster, StoredMessage};
+use crate::mail::{format_size, Address, MessageType, Postmaster, StoredMessage};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -21,6 +22,124 @@ pub struct MailView {
     show_detail: bool,
     /// Unread count
     unread_count: usize,
+    /// Message being composed, if the compose form is open
+    compose: Option<ComposeState>,
+    /// Result of the last send, shown in the inbox title
+    status_message: Option<String>,
+}
+
+/// Field with focus in the compose form
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ComposeField {
+    #[default]
+    To,
+    Subject,
+    Body,
+}
+
+/// Draft message in the compose form
+#[derive(Debug, Default)]
+pub struct ComposeState {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+    pub field: ComposeField,
+    /// Agents the recipient is validated against
+    pub known_agents: Vec<Address>,
+    /// Validated recipient, set while waiting for the user to confirm sending
+    pub confirm_to: Option<Address>,
+    /// Validation or send error shown below the form
+    pub error: Option<String>,
+}
+
+impl ComposeState {
+    /// Start a new draft addressed to one of `known_agents`
+    pub fn new(known_agents: Vec<Address>) -> Self {
+        Self {
+            known_agents,
+            ..Default::default()
+        }
+    }
+
+    fn current_field_mut(&mut self) -> &mut String {
+        match self.field {
+            ComposeField::To => &mut self.to,
+            ComposeField::Subject => &mut self.subject,
+            ComposeField::Body => &mut self.body,
+        }
+    }
+
+    /// Type a character into the focused field
+    pub fn push_char(&mut self, c: char) {
+        self.current_field_mut().push(c);
+        self.error = None;
+    }
+
+    /// Delete the last character of the focused field
+    pub fn pop_char(&mut self) {
+        self.current_field_mut().pop();
+        self.error = None;
+    }
+
+    /// Validate the draft and ask for confirmation before sending
+    pub fn request_send(&mut self, project_id: &str) {
+        if self.text().is_empty() {
+            self.error = Some("Subject or body is required".to_string());
+            return;
+        }
+        match self.recipient(project_id) {
+            Ok(to) => self.confirm_to = Some(to),
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    /// Move focus to the next field
+    pub fn next_field(&mut self) {
+        self.field = match self.field {
+            ComposeField::To => ComposeField::Subject,
+            ComposeField::Subject => ComposeField::Body,
+            ComposeField::Body => ComposeField::To,
+        };
+    }
+
+    /// Move focus to the previous field
+    pub fn previous_field(&mut self) {
+        self.field = match self.field {
+            ComposeField::To => ComposeField::Body,
+            ComposeField::Subject => ComposeField::To,
+            ComposeField::Body => ComposeField::Subject,
+        };
+    }
+
+    /// Resolve the recipient against known agents
+    ///
+    /// A bare name like `worker` is looked up in `project_id`.
+    pub fn recipient(&self, project_id: &str) -> Result<Address, String> {
+        let to = self.to.trim();
+        if to.is_empty() {
+            return Err("Recipient is required".to_string());
+        }
+        let address = if to.contains('@') {
+            to.parse::<Address>().map_err(|e| e.to_string())?
+        } else {
+            Address::new(to, project_id).map_err(|e| e.to_string())?
+        };
+        if !self.known_agents.contains(&address) {
+            return Err(format!("Unknown agent: {}", address));
+        }
+        Ok(address)
+    }
+
+    /// Message text: the subject line, then the body
+    pub fn text(&self) -> String {
+        let subject = self.subject.trim();
+        let body = self.body.trim();
+        match (subject.is_empty(), body.is_empty()) {
+            (_, true) => subject.to_string(),
+            (true, false) => body.to_string(),
+            (false, false) => format!("{}\n\n{}", subject, body),
+        }
+    }
 }
 
 impl MailView {
@@ -33,6 +152,8 @@ impl MailView {
             list_state,
             show_detail: false,
             unread_count: 0,
+            compose: None,
+            status_message: None,
         }
     }
 
@@ -113,6 +234,33 @@ impl MailView {
     pub fn selected_message_id(&self) -> Option<&crate::mail::MessageId> {
         self.selected_message().map(|m| &m.message.id)
     }
+
+    /// Open the compose form
+    pub fn start_compose(&mut self, known_agents: Vec<Address>) {
+        self.compose = Some(ComposeState::new(known_agents));
+        self.status_message = None;
+    }
+
+    /// Close the compose form, discarding the draft
+    pub fn cancel_compose(&mut self) {
+        self.compose = None;
+    }
+
+    /// Check if the compose form is open
+    pub fn is_composing(&self) -> bool {
+        self.compose.is_some()
+    }
+
+    /// Get the draft being composed
+    pub fn compose_mut(&mut self) -> Option<&mut ComposeState> {
+        self.compose.as_mut()
+    }
+
+    /// Close the compose form and show `message` in the inbox title
+    pub fn finish_compose(&mut self, message: String) {
+        self.compose = None;
+        self.status_message = Some(message);
+    }
 }
 
 impl Default for MailView {
@@ -123,7 +271,9 @@ impl Default for MailView {
 
 /// Draw the mail view
 pub fn draw(f: &mut Frame, mail_view: &mut MailView, area: Rect) {
-    if mail_view.show_detail {
+    if let Some(ref compose) = mail_view.compose {
+        draw_compose_view(f, compose, area);
+    } else if mail_view.show_detail {
         draw_detail_view(f, mail_view, area);
     } else {
         draw_inbox_view(f, mail_view, area);
@@ -142,11 +292,14 @@ fn draw_inbox_view(f: &mut Frame, mail_view: &mut MailView, area: Rect) {
 
     // Title with unread count
     let unread = mail_view.unread_count;
-    let title_text = if unread > 0 {
+    let mut title_text = if unread > 0 {
         format!("Agent Mail Inbox ({} unread)", unread)
     } else {
         "Agent Mail Inbox".to_string()
     };
+    if let Some(ref status) = mail_view.status_message {
+        title_text.push_str(&format!(" - {}", status));
+    }
     let title = Paragraph::new(title_text)
         .style(
             Style::default()
@@ -185,6 +338,8 @@ fn draw_inbox_view(f: &mut Frame, mail_view: &mut MailView, area: Rect) {
         Span::raw("View Message  "),
         Span::styled("r: ", Style::default().add_modifier(Modifier::BOLD)),
         Span::raw("Mark Read  "),
+        Span::styled("c: ", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw("Compose  "),
         Span::styled("Tab: ", Style::default().add_modifier(Modifier::BOLD)),
         Span::raw("Switch View  "),
         Span::styled("q: ", Style::default().add_modifier(Modifier::BOLD)),
@@ -196,6 +351,94 @@ fn draw_inbox_view(f: &mut Frame, mail_view: &mut MailView, area: Rect) {
     f.render_widget(help, chunks[2]);
 }
 
+fn draw_compose_view(f: &mut Frame, compose: &ComposeState, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Length(3), // To
+            Constraint::Length(3), // Subject
+            Constraint::Min(0),    // Body
+            Constraint::Length(3), // Known agents / error
+            Constraint::Length(3), // Help
+        ])
+        .split(area);
+
+    let title = Paragraph::new("Compose Message")
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(title, chunks[0]);
+
+    let field_block = |name: &'static str, field: ComposeField| {
+        let border = if compose.field == field && compose.confirm_to.is_none() {
+            Color::Yellow
+        } else {
+            Color::DarkGray
+        };
+        Block::default()
+            .title(name)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border))
+    };
+    f.render_widget(
+        Paragraph::new(compose.to.as_str()).block(field_block("To", ComposeField::To)),
+        chunks[1],
+    );
+    f.render_widget(
+        Paragraph::new(compose.subject.as_str())
+            .block(field_block("Subject", ComposeField::Subject)),
+        chunks[2],
+    );
+    f.render_widget(
+        Paragraph::new(compose.body.as_str())
+            .block(field_block("Body", ComposeField::Body))
+            .wrap(Wrap { trim: false }),
+        chunks[3],
+    );
+
+    let status = if let Some(ref error) = compose.error {
+        Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red)))
+    } else if let Some(ref to) = compose.confirm_to {
+        Line::from(Span::styled(
+            format!("Send to {}? (y/n)", to),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ))
+    } else if compose.known_agents.is_empty() {
+        Line::from(Span::styled(
+            "No known agents yet",
+            Style::default().fg(Color::DarkGray),
+        ))
+    } else {
+        let names: Vec<String> = compose.known_agents.iter().map(|a| a.to_string()).collect();
+        Line::from(Span::styled(
+            names.join(", "),
+            Style::default().fg(Color::DarkGray),
+        ))
+    };
+    let status =
+        Paragraph::new(status).block(Block::default().borders(Borders::ALL).title("Known Agents"));
+    f.render_widget(status, chunks[4]);
+
+    let help_text = vec![Line::from(vec![
+        Span::styled("Tab/↑/↓: ", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw("Switch Field  "),
+        Span::styled("Ctrl+S: ", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw("Send  "),
+        Span::styled("Esc: ", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw("Cancel"),
+    ])];
+    let help = Paragraph::new(help_text)
+        .style(Style::default().fg(Color::White))
+        .block(Block::default().borders(Borders::ALL).title("Help"));
+    f.render_widget(help, chunks[5]);
+}
+
 fn create_message_list_item(msg: &StoredMessage) -> ListItem<'static> {
     let is_unread = msg.status == crate::mail::DeliveryStatus::Delivered;
 
@@ -409,6 +652,21 @@ fn draw_detail_view(f: &mut Frame, mail_view: &mut MailView, area: Rect) {
             }
         }
 
+        if !msg.message.attachments.is_empty() {
+            text.push(Line::raw(""));
+            text.push(Line::from(Span::styled(
+                "Attachments:",
+                Style::default().add_modifier(Modifier::BOLD),
+            )));
+            for attachment in &msg.message.attachments {
+                let size = attachment
+                    .size()
+                    .map(format_size)
+                    .unwrap_or_else(|| "missing".to_string());
+                text.push(Line::raw(format!("  - {} ({})", attachment.name, size)));
+            }
+        }
+
         let content = Paragraph::new(text)
             .block(Block::default().borders(Borders::ALL))
             .wrap(Wrap { trim: true });
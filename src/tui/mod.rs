@@ -8,6 +8,7 @@ pub mod contexts_view;
 pub mod github_picker_view;
 pub mod governance_view;
 pub mod graph_view;
+pub mod keymap;
 pub mod mail_view;
 pub mod stats_view;
 pub mod swarm_view;
@@ -27,17 +28,22 @@ pub use timeline_view::TimelineView;
 use crate::graph::FederatedGraph;
 use crate::Result;
 use crossterm::{
-    event::{self, Event, KeyCode, KeyModifiers},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers,
+        MouseButton, MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use keymap::Action;
+use mail_view::ComposeField;
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
 use std::path::PathBuf;
 
 /// Run the TUI application (without mail support)
 pub fn run(graph: FederatedGraph) -> Result<TuiResult> {
-    run_with_mail(graph, None, "default")
+    run_with_mail(graph, None, "default", false)
 }
 
 /// Result of running the TUI - may request onboarding
@@ -48,15 +54,22 @@ pub struct TuiResult {
 
 /// Run the TUI application with optional mail support
 /// Returns repos to onboard if the user pressed 'o' in GitHub picker
+///
+/// With `mouse`, mouse events are captured for click-to-select and wheel
+/// scrolling, at the cost of the terminal's own text selection.
 pub fn run_with_mail(
     graph: FederatedGraph,
     mail_db_path: Option<PathBuf>,
     project_id: &str,
+    mouse: bool,
 ) -> Result<TuiResult> {
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
+    if mouse {
+        execute!(stdout, EnableMouseCapture)?;
+    }
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
@@ -71,6 +84,9 @@ pub fn run_with_mail(
 
     // Restore terminal
     disable_raw_mode()?;
+    if mouse {
+        execute!(terminal.backend_mut(), DisableMouseCapture)?;
+    }
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
     terminal.show_cursor()?;
 
@@ -102,159 +118,237 @@ fn run_app<B: ratatui::backend::Backend>(
         app.github_picker_view.poll_results();
 
         if event::poll(std::time::Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                // Global keys
-                match key.code {
-                    KeyCode::Char('q') => return Ok(()),
-                    KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                        return Ok(())
+            let event = event::read()?;
+            if let Event::Mouse(mouse) = event {
+                handle_mouse(app, mouse);
+                continue;
+            }
+            if let Event::Key(key) = event {
+                // Ctrl+C quits from anywhere, text entry included
+                if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                    return Ok(());
+                }
+
+                // Text entry captures all other keys, including q, Tab and ?
+                if app.current_tab == Tab::Mail && app.mail_view.is_composing() {
+                    handle_compose_key(app, key);
+                    continue;
+                }
+                if app.current_tab == Tab::GitHubPicker && app.github_picker_view.input_mode {
+                    handle_search_input_key(app, key);
+                    continue;
+                }
+
+                // The help overlay swallows everything but its own dismissal
+                if app.show_help {
+                    if matches!(key.code, KeyCode::Esc)
+                        || keymap::lookup(keymap::GLOBAL, &key) == Some(Action::ToggleHelp)
+                    {
+                        app.show_help = false;
                     }
-                    KeyCode::Tab => {
+                    continue;
+                }
+
+                // Global keys
+                match keymap::lookup(keymap::GLOBAL, &key) {
+                    Some(Action::Quit) => return Ok(()),
+                    Some(Action::NextTab) => {
                         app.next_tab();
                         continue;
                     }
+                    Some(Action::ToggleHelp) => {
+                        app.show_help = true;
+                        continue;
+                    }
                     _ => {}
                 }
 
                 // Tab-specific keys
-                match app.current_tab {
-                    Tab::Kanban => match key.code {
-                        KeyCode::Char('j') | KeyCode::Down => app.next(),
-                        KeyCode::Char('k') | KeyCode::Up => app.previous(),
-                        KeyCode::Char('h') | KeyCode::Left => app.previous_column(),
-                        KeyCode::Char('l') | KeyCode::Right => app.next_column(),
-                        KeyCode::Enter => app.toggle_detail(),
-                        KeyCode::Esc => app.close_detail(),
-                        _ => {}
-                    },
-                    Tab::Mail => match key.code {
-                        KeyCode::Char('j') | KeyCode::Down => app.mail_view.next(),
-                        KeyCode::Char('k') | KeyCode::Up => app.mail_view.previous(),
-                        KeyCode::Enter => app.mail_view.toggle_detail(),
-                        KeyCode::Esc => app.mail_view.close_detail(),
-                        KeyCode::Char('r') => app.mark_message_read(),
-                        _ => {}
-                    },
-                    Tab::Graph => match key.code {
-                        KeyCode::Char('j') | KeyCode::Down => app.graph_view.next(),
-                        KeyCode::Char('k') | KeyCode::Up => app.graph_view.previous(),
-                        KeyCode::Enter => app.graph_view.toggle_detail(),
-                        KeyCode::Esc => app.graph_view.close_detail(),
-                        KeyCode::Char('f') => app.graph_view.cycle_filter(),
-                        _ => {}
-                    },
-                    Tab::Stats => {
-                        // Stats is a read-only view, no special keys needed
-                    }
-                    Tab::Timeline => match key.code {
-                        KeyCode::Char('j') | KeyCode::Down => app.timeline_view.next(),
-                        KeyCode::Char('k') | KeyCode::Up => app.timeline_view.previous(),
-                        KeyCode::Enter => app.timeline_view.toggle_detail(),
-                        KeyCode::Esc => app.timeline_view.close_detail(),
-                        KeyCode::Char('+') | KeyCode::Char('=') => app.timeline_view.zoom_out(),
-                        KeyCode::Char('-') => app.timeline_view.zoom_in(),
-                        _ => {}
-                    },
-                    Tab::Governance => match key.code {
-                        KeyCode::Char('j') | KeyCode::Down => app.governance_view.next(),
-                        KeyCode::Char('k') | KeyCode::Up => app.governance_view.previous(),
-                        KeyCode::Char('h') | KeyCode::Left => app.governance_view.next_section(),
-                        KeyCode::Char('l') | KeyCode::Right => app.governance_view.next_section(),
-                        KeyCode::Enter => app.governance_view.toggle_detail(),
-                        KeyCode::Esc => app.governance_view.close_detail(),
-                        _ => {}
-                    },
-                    Tab::Swarm => match key.code {
-                        KeyCode::Char('j') | KeyCode::Down => app.swarm_view.next(),
-                        KeyCode::Char('k') | KeyCode::Up => app.swarm_view.previous(),
-                        KeyCode::Enter => app.swarm_view.toggle_detail(),
-                        KeyCode::Esc => app.swarm_view.close_detail(),
-                        KeyCode::Char('p') => app.swarm_view.pause_selected(),
-                        KeyCode::Char('r') => app.swarm_view.resume_selected(),
-                        KeyCode::Char('x') => app.swarm_view.kill_selected(),
-                        _ => {}
-                    },
-                    Tab::Aiki => match key.code {
-                        KeyCode::Char('j') | KeyCode::Down => app.aiki_view.next(),
-                        KeyCode::Char('k') | KeyCode::Up => app.aiki_view.previous(),
-                        KeyCode::Char('r') => app.refresh_aiki_view(),
-                        _ => {}
-                    },
-                    Tab::Contexts => match key.code {
-                        KeyCode::Char('j') | KeyCode::Down => app.contexts_view.next(),
-                        KeyCode::Char('k') | KeyCode::Up => app.contexts_view.previous(),
-                        KeyCode::Char('r') => app.force_refresh_contexts_view(),
-                        KeyCode::Char('s') => app.contexts_view.cycle_sort(),
-                        KeyCode::Char('o') => app.contexts_view.cycle_org_filter(),
-                        KeyCode::Enter => app.contexts_view.toggle_detail(),
-                        KeyCode::Esc => app.contexts_view.close_detail(),
-                        _ => {}
-                    },
-                    Tab::GitHubPicker => {
-                        if app.github_picker_view.input_mode {
-                            // Input mode - capture characters for search query
-                            match key.code {
-                                KeyCode::Enter => {
-                                    // Execute search and exit input mode
-                                    app.github_picker_view.toggle_input_mode();
-                                    app.github_picker_view.execute_search();
-                                }
-                                KeyCode::Esc => app.github_picker_view.toggle_input_mode(),
-                                KeyCode::Backspace => app.github_picker_view.pop_char(),
-                                KeyCode::Char(c) => app.github_picker_view.push_char(c),
-                                _ => {}
-                            }
-                        } else {
-                            // Navigation mode
-                            match key.code {
-                                KeyCode::Char('j') | KeyCode::Down => app.github_picker_view.next(),
-                                KeyCode::Char('k') | KeyCode::Up => {
-                                    app.github_picker_view.previous()
-                                }
-                                KeyCode::Char('m') => app.github_picker_view.toggle_mode(),
-                                KeyCode::Char('/') => app.github_picker_view.toggle_input_mode(),
-                                KeyCode::Char(' ') => {
-                                    // Toggle mark on current repo
-                                    app.github_picker_view.toggle_mark();
-                                }
-                                KeyCode::Enter => {
-                                    // Toggle detail view (or toggle mark in detail view)
-                                    if app.github_picker_view.show_detail {
-                                        // In detail view, toggle mark and close
-                                        app.github_picker_view.toggle_mark();
-                                        app.github_picker_view.close_detail();
-                                    } else {
-                                        app.github_picker_view.toggle_detail();
-                                    }
-                                }
-                                KeyCode::Char('o') => {
-                                    // Trigger onboarding - marked repos or current selection
-                                    if !app.github_picker_view.marked_repos.is_empty() {
-                                        app.onboard_requested = true;
-                                        return Ok(());
-                                    } else if let Some(repo) =
-                                        app.github_picker_view.selected_repo()
-                                    {
-                                        // No marked repos - onboard current selection if not managed
-                                        if !app.github_picker_view.is_managed(&repo.name) {
-                                            let url = repo.clone_url.clone();
-                                            app.github_picker_view.marked_repos.push(url);
-                                            app.onboard_requested = true;
-                                            return Ok(());
-                                        }
-                                    }
-                                }
-                                KeyCode::Esc => app.github_picker_view.close_detail(),
-                                KeyCode::Char('s') => {
-                                    // Re-execute search
-                                    app.github_picker_view.execute_search();
-                                }
-                                _ => {}
-                            }
-                        }
+                let Some(action) = keymap::lookup(keymap::tab_bindings(app.current_tab), &key)
+                else {
+                    continue;
+                };
+                if handle_tab_action(app, action) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+/// Run a tab-specific action; returns true if the TUI should exit
+fn handle_tab_action(app: &mut App, action: Action) -> bool {
+    match app.current_tab {
+        Tab::Kanban => match action {
+            Action::Down => app.next(),
+            Action::Up => app.previous(),
+            Action::Left => app.previous_column(),
+            Action::Right => app.next_column(),
+            Action::ToggleDetail => app.toggle_detail(),
+            Action::CloseDetail => app.close_detail(),
+            _ => {}
+        },
+        Tab::Mail => match action {
+            Action::Down => app.mail_view.next(),
+            Action::Up => app.mail_view.previous(),
+            Action::ToggleDetail => app.toggle_mail_detail(),
+            Action::CloseDetail => app.mail_view.close_detail(),
+            Action::MarkRead => app.mark_message_read(),
+            Action::Compose => app.start_mail_compose(),
+            _ => {}
+        },
+        Tab::Graph => match action {
+            Action::Down => app.graph_view.next(),
+            Action::Up => app.graph_view.previous(),
+            Action::ToggleDetail => app.graph_view.toggle_detail(),
+            Action::CloseDetail => app.graph_view.close_detail(),
+            Action::CycleFilter => app.graph_view.cycle_filter(),
+            _ => {}
+        },
+        Tab::Stats => {
+            // Stats is a read-only view, no special keys needed
+        }
+        Tab::Timeline => match action {
+            Action::Down => app.timeline_view.next(),
+            Action::Up => app.timeline_view.previous(),
+            Action::ToggleDetail => app.timeline_view.toggle_detail(),
+            Action::CloseDetail => app.timeline_view.close_detail(),
+            Action::ZoomOut => app.timeline_view.zoom_out(),
+            Action::ZoomIn => app.timeline_view.zoom_in(),
+            _ => {}
+        },
+        Tab::Governance => match action {
+            Action::Down => app.governance_view.next(),
+            Action::Up => app.governance_view.previous(),
+            Action::NextSection => app.governance_view.next_section(),
+            Action::ToggleDetail => app.governance_view.toggle_detail(),
+            Action::CloseDetail => app.governance_view.close_detail(),
+            _ => {}
+        },
+        Tab::Swarm => match action {
+            Action::Down => app.swarm_view.next(),
+            Action::Up => app.swarm_view.previous(),
+            Action::ToggleDetail => app.swarm_view.toggle_detail(),
+            Action::CloseDetail => app.swarm_view.close_detail(),
+            Action::Pause => app.swarm_view.pause_selected(),
+            Action::Resume => app.swarm_view.resume_selected(),
+            Action::Kill => app.swarm_view.kill_selected(),
+            _ => {}
+        },
+        Tab::Aiki => match action {
+            Action::Down => app.aiki_view.next(),
+            Action::Up => app.aiki_view.previous(),
+            Action::Refresh => app.refresh_aiki_view(),
+            _ => {}
+        },
+        Tab::Contexts => match action {
+            Action::Down => app.contexts_view.next(),
+            Action::Up => app.contexts_view.previous(),
+            Action::Refresh => app.force_refresh_contexts_view(),
+            Action::CycleSort => app.contexts_view.cycle_sort(),
+            Action::CycleOrgFilter => app.contexts_view.cycle_org_filter(),
+            Action::ToggleDetail => app.contexts_view.toggle_detail(),
+            Action::CloseDetail => app.contexts_view.close_detail(),
+            _ => {}
+        },
+        Tab::GitHubPicker => match action {
+            Action::Down => app.github_picker_view.next(),
+            Action::Up => app.github_picker_view.previous(),
+            Action::ToggleMode => app.github_picker_view.toggle_mode(),
+            Action::StartSearch => app.github_picker_view.toggle_input_mode(),
+            Action::ToggleMark => app.github_picker_view.toggle_mark(),
+            Action::ToggleDetail => {
+                // Toggle detail view (or toggle mark in detail view)
+                if app.github_picker_view.show_detail {
+                    // In detail view, toggle mark and close
+                    app.github_picker_view.toggle_mark();
+                    app.github_picker_view.close_detail();
+                } else {
+                    app.github_picker_view.toggle_detail();
+                }
+            }
+            Action::Onboard => {
+                // Trigger onboarding - marked repos or current selection
+                if !app.github_picker_view.marked_repos.is_empty() {
+                    app.onboard_requested = true;
+                    return true;
+                } else if let Some(repo) = app.github_picker_view.selected_repo() {
+                    // No marked repos - onboard current selection if not managed
+                    if !app.github_picker_view.is_managed(&repo.name) {
+                        let url = repo.clone_url.clone();
+                        app.github_picker_view.marked_repos.push(url);
+                        app.onboard_requested = true;
+                        return true;
                     }
                 }
             }
+            Action::CloseDetail => app.github_picker_view.close_detail(),
+            Action::RunSearch => app.github_picker_view.execute_search(),
+            _ => {}
+        },
+    }
+    false
+}
+
+/// Handle a key press while typing a GitHub search query
+fn handle_search_input_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Enter => {
+            // Execute search and exit input mode
+            app.github_picker_view.toggle_input_mode();
+            app.github_picker_view.execute_search();
+        }
+        KeyCode::Esc => app.github_picker_view.toggle_input_mode(),
+        KeyCode::Backspace => app.github_picker_view.pop_char(),
+        KeyCode::Char(c) => app.github_picker_view.push_char(c),
+        _ => {}
+    }
+}
+
+/// Handle a key press while the mail compose form is open
+fn handle_compose_key(app: &mut App, key: KeyEvent) {
+    let Some(compose) = app.mail_view.compose_mut() else {
+        return;
+    };
+
+    if compose.confirm_to.is_some() {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Enter => app.send_mail_compose(),
+            KeyCode::Char('n') | KeyCode::Esc => compose.confirm_to = None,
+            _ => {}
+        }
+        return;
+    }
+
+    match key.code {
+        KeyCode::Esc => app.mail_view.cancel_compose(),
+        KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.request_mail_send()
+        }
+        KeyCode::Tab | KeyCode::Down => compose.next_field(),
+        KeyCode::BackTab | KeyCode::Up => compose.previous_field(),
+        KeyCode::Enter if compose.field == ComposeField::Body => compose.push_char('\n'),
+        KeyCode::Enter => compose.next_field(),
+        KeyCode::Backspace => compose.pop_char(),
+        // Other control chords are not text
+        KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => compose.push_char(c),
+        _ => {}
+    }
+}
+
+/// Handle a mouse event (only delivered when mouse capture is enabled)
+fn handle_mouse(app: &mut App, mouse: MouseEvent) {
+    if app.current_tab != Tab::Kanban {
+        return;
+    }
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) if !app.show_detail => {
+            app.click_bead(mouse.column, mouse.row)
         }
+        MouseEventKind::ScrollDown if app.show_detail => app.scroll_detail_down(),
+        MouseEventKind::ScrollUp if app.show_detail => app.scroll_detail_up(),
+        MouseEventKind::ScrollDown => app.next(),
+        MouseEventKind::ScrollUp => app.previous(),
+        _ => {}
     }
 }
@@ -2,7 +2,7 @@
 //!
 //! Displays beads in a Gantt-style timeline visualization.
 
-use crate::graph::{BeadId, FederatedGraph, Priority, Status};
+use crate::graph::{parse_timestamp, BeadId, FederatedGraph, Priority, Status};
 use chrono::{DateTime, NaiveDate, Utc};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -64,13 +64,8 @@ impl TimelineView {
 
         for bead in graph.beads.values() {
             // Parse created_at timestamp
-            let created_at = DateTime::parse_from_rfc3339(&bead.created_at)
-                .map(|dt| dt.with_timezone(&Utc))
-                .unwrap_or(now);
-
-            let updated_at = DateTime::parse_from_rfc3339(&bead.updated_at)
-                .map(|dt| dt.with_timezone(&Utc))
-                .unwrap_or(now);
+            let created_at = parse_timestamp(&bead.created_at).unwrap_or(now);
+            let updated_at = parse_timestamp(&bead.updated_at).unwrap_or(now);
 
             let days_open = if bead.status == Status::Closed {
                 (updated_at - created_at).num_days()
@@ -4,6 +4,7 @@ use super::app::{App, Column, Tab};
 use super::contexts_view;
 use super::governance_view;
 use super::graph_view;
+use super::keymap;
 use super::mail_view;
 use super::stats_view;
 use super::swarm_view;
@@ -13,7 +14,7 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Tabs, Wrap},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Tabs, Wrap},
     Frame,
 };
 
@@ -54,6 +55,59 @@ pub fn draw(f: &mut Frame, app: &mut App) {
             draw_github_picker_tab(f, app);
         }
     }
+
+    if app.show_help {
+        draw_help_overlay(f, app.current_tab);
+    }
+}
+
+/// Centered overlay listing the global and current tab's key bindings
+fn draw_help_overlay(f: &mut Frame, tab: Tab) {
+    let section = |title: &str, bindings: &[keymap::Binding]| {
+        let mut lines = vec![Line::from(Span::styled(
+            title.to_string(),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))];
+        for binding in bindings {
+            let keys: Vec<String> = binding.keys.iter().map(|k| k.label()).collect();
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<16}", keys.join(" / ")),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(binding.description),
+            ]));
+        }
+        lines
+    };
+
+    let mut text = section("Global", keymap::GLOBAL);
+    let tab_bindings = keymap::tab_bindings(tab);
+    if !tab_bindings.is_empty() {
+        text.push(Line::raw(""));
+        text.extend(section(&format!("{:?}", tab), tab_bindings));
+    }
+
+    let area = f.area();
+    let width = 60.min(area.width);
+    let height = (text.len() as u16 + 2).min(area.height);
+    let overlay = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    let help = Paragraph::new(text).block(
+        Block::default()
+            .title("Keys (? or Esc to close)")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow)),
+    );
+    f.render_widget(Clear, overlay);
+    f.render_widget(help, overlay);
 }
 
 fn draw_mail_tab(f: &mut Frame, app: &mut App) {
@@ -284,6 +338,7 @@ fn draw_kanban_view(f: &mut Frame, app: &mut App) {
         ])
         .split(chunks[1]);
 
+    app.column_areas = [board_chunks[0], board_chunks[1], board_chunks[2]];
     let columns = Column::all();
     draw_column(f, app, columns[0], board_chunks[0]);
     draw_column(f, app, columns[1], board_chunks[1]);
@@ -302,6 +357,11 @@ fn draw_kanban_view(f: &mut Frame, app: &mut App) {
         ));
         help_spans.push(Span::raw("Switch View  "));
     }
+    help_spans.push(Span::styled(
+        "?: ",
+        Style::default().add_modifier(Modifier::BOLD),
+    ));
+    help_spans.push(Span::raw("Help  "));
     help_spans.push(Span::styled(
         "q: ",
         Style::default().add_modifier(Modifier::BOLD),
@@ -547,7 +607,8 @@ fn draw_detail_view(f: &mut Frame, app: &mut App) {
 
         let content = Paragraph::new(text)
             .block(Block::default().borders(Borders::ALL))
-            .wrap(Wrap { trim: true });
+            .wrap(Wrap { trim: true })
+            .scroll((app.detail_scroll, 0));
         f.render_widget(content, chunks[1]);
 
         // Help
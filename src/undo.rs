@@ -0,0 +1,297 @@
+//! Undo log for CRUD commands
+//!
+//! Every mutating `ab` command appends an [`Operation`] to an append-only
+//! JSONL file in the config directory, recording enough to reverse it
+//! (close → reopen, label add → remove, ...). `ab undo` pops the last entry
+//! and applies its inverse. Deletes can't be reversed; they are logged as
+//! irreversible so `ab undo` can refuse them with a clear message instead of
+//! silently skipping past them.
+
+use crate::config::AllBeadsConfig;
+use crate::{AllBeadsError, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+/// File name of the undo log, next to config.yaml
+pub const UNDO_LOG_FILE: &str = "undo.jsonl";
+
+/// Previous field values of a bead before `ab update`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriorFields {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+/// A mutating command, described well enough to reverse it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum OpKind {
+    Close {
+        ids: Vec<String>,
+    },
+    Reopen {
+        ids: Vec<String>,
+    },
+    LabelAdd {
+        id: String,
+        label: String,
+    },
+    LabelRemove {
+        id: String,
+        label: String,
+    },
+    LabelRename {
+        ids: Vec<String>,
+        old: String,
+        new: String,
+    },
+    Update {
+        id: String,
+        before: PriorFields,
+    },
+    RenamePrefix {
+        old: String,
+        new: String,
+    },
+    Delete {
+        ids: Vec<String>,
+    },
+}
+
+impl OpKind {
+    /// Whether the operation can be undone
+    pub fn is_reversible(&self) -> bool {
+        !matches!(self, OpKind::Delete { .. })
+    }
+
+    /// One-line description for `ab undo` output
+    pub fn describe(&self) -> String {
+        match self {
+            OpKind::Close { ids } => format!("close {}", ids.join(", ")),
+            OpKind::Reopen { ids } => format!("reopen {}", ids.join(", ")),
+            OpKind::LabelAdd { id, label } => format!("add label '{}' to {}", label, id),
+            OpKind::LabelRemove { id, label } => format!("remove label '{}' from {}", label, id),
+            OpKind::LabelRename { ids, old, new } => format!(
+                "rename label '{}' to '{}' on {} bead(s)",
+                old,
+                new,
+                ids.len()
+            ),
+            OpKind::Update { id, .. } => format!("update {}", id),
+            OpKind::RenamePrefix { old, new } => format!("rename prefix '{}' to '{}'", old, new),
+            OpKind::Delete { ids } => format!("delete {}", ids.join(", ")),
+        }
+    }
+}
+
+/// One entry in the undo log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Operation {
+    pub timestamp: DateTime<Utc>,
+    /// Context the operation ran in, for display
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    /// Repository `bd` ran in; the inverse runs there too
+    pub path: PathBuf,
+    #[serde(flatten)]
+    pub kind: OpKind,
+}
+
+impl Operation {
+    /// Create an operation timestamped now
+    pub fn new(context: Option<&str>, path: impl Into<PathBuf>, kind: OpKind) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            context: context.map(str::to_string),
+            path: path.into(),
+            kind,
+        }
+    }
+}
+
+/// Append-only log of operations
+#[derive(Debug, Clone)]
+pub struct UndoLog {
+    path: PathBuf,
+}
+
+impl UndoLog {
+    /// Open the log at a specific path (created on first write)
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Open the log next to the config file (`undo.jsonl`)
+    pub fn for_config(config: &AllBeadsConfig) -> Self {
+        Self::new(config.config_dir().join(UNDO_LOG_FILE))
+    }
+
+    /// Append an operation
+    pub fn record(&self, op: &Operation) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(op)?)?;
+        Ok(())
+    }
+
+    /// All operations, oldest first; unreadable lines are skipped
+    pub fn entries(&self) -> Result<Vec<Operation>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = fs::read_to_string(&self.path)?;
+        Ok(content
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .filter_map(|l| serde_json::from_str(l).ok())
+            .collect())
+    }
+
+    /// The most recent operation, if any
+    pub fn last(&self) -> Result<Option<Operation>> {
+        Ok(self.entries()?.pop())
+    }
+
+    /// Remove and return the most recent operation
+    ///
+    /// Irreversible operations are left in place and reported as an error,
+    /// so older entries are never undone out of order.
+    pub fn pop(&self) -> Result<Option<Operation>> {
+        let mut entries = self.entries()?;
+        let Some(op) = entries.pop() else {
+            return Ok(None);
+        };
+        if !op.kind.is_reversible() {
+            return Err(AllBeadsError::Other(format!(
+                "Last operation ({}) can't be undone",
+                op.kind.describe()
+            )));
+        }
+
+        self.write_entries(&entries)?;
+        Ok(Some(op))
+    }
+
+    /// Drop the most recent operation without undoing it
+    ///
+    /// Used to get past an irreversible entry.
+    pub fn discard_last(&self) -> Result<Option<Operation>> {
+        let mut entries = self.entries()?;
+        let op = entries.pop();
+        self.write_entries(&entries)?;
+        Ok(op)
+    }
+
+    fn write_entries(&self, entries: &[Operation]) -> Result<()> {
+        let mut content = String::new();
+        for entry in entries {
+            content.push_str(&serde_json::to_string(entry)?);
+            content.push('\n');
+        }
+        fs::write(&self.path, content)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_record_and_pop() {
+        let dir = TempDir::new().unwrap();
+        let log = UndoLog::new(dir.path().join(UNDO_LOG_FILE));
+        assert!(log.pop().unwrap().is_none());
+
+        let close = Operation::new(
+            Some("work"),
+            "/repo",
+            OpKind::Close {
+                ids: vec!["ab-1".to_string()],
+            },
+        );
+        let label = Operation::new(
+            Some("work"),
+            "/repo",
+            OpKind::LabelAdd {
+                id: "ab-2".to_string(),
+                label: "backend".to_string(),
+            },
+        );
+        log.record(&close).unwrap();
+        log.record(&label).unwrap();
+
+        assert_eq!(log.pop().unwrap(), Some(label));
+        assert_eq!(log.entries().unwrap(), vec![close.clone()]);
+        assert_eq!(log.pop().unwrap(), Some(close));
+        assert!(log.entries().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_irreversible() {
+        let dir = TempDir::new().unwrap();
+        let log = UndoLog::new(dir.path().join(UNDO_LOG_FILE));
+        let delete = Operation::new(
+            None,
+            "/repo",
+            OpKind::Delete {
+                ids: vec!["ab-1".to_string()],
+            },
+        );
+        log.record(&delete).unwrap();
+
+        let err = log.pop().unwrap_err();
+        assert!(err.to_string().contains("can't be undone"));
+        assert_eq!(log.entries().unwrap().len(), 1);
+
+        assert_eq!(log.discard_last().unwrap(), Some(delete));
+        assert!(log.entries().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_log_next_to_loaded_config() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.yaml");
+        AllBeadsConfig::new().save(&config_path).unwrap();
+
+        let config = AllBeadsConfig::load(&config_path).unwrap();
+        let log = UndoLog::for_config(&config);
+        assert_eq!(log.path, dir.path().join(UNDO_LOG_FILE));
+    }
+
+    #[test]
+    fn test_serialization() {
+        let op = Operation::new(
+            None,
+            "/repo",
+            OpKind::Update {
+                id: "ab-1".to_string(),
+                before: PriorFields {
+                    status: Some("open".to_string()),
+                    ..Default::default()
+                },
+            },
+        );
+        let json = serde_json::to_value(&op).unwrap();
+        assert_eq!(json["op"], "update");
+        assert_eq!(json["before"]["status"], "open");
+        assert!(json.get("context").is_none());
+    }
+}
@@ -27,6 +27,7 @@ fn create_test_bead(id: &str, title: &str, status: Status, priority: Priority) -
         created_by: "test".to_string(),
         assignee: None,
         labels,
+        parent: None,
         dependencies: vec![],
         blocks: vec![],
         notes: None,
@@ -165,6 +166,7 @@ mod cache_tests {
             path: temp_dir.path().join("cache.db"),
             ttl: Duration::from_secs(300),
             wal_mode: false,
+            compress: false,
         };
 
         let cache = Cache::new(cache_config).unwrap();
@@ -202,6 +204,7 @@ mod cache_tests {
             path: temp_dir.path().join("cache.db"),
             ttl: Duration::from_secs(300),
             wal_mode: false,
+            compress: false,
         };
 
         let cache = Cache::new(cache_config).unwrap();
@@ -228,6 +231,7 @@ mod cache_tests {
             path: temp_dir.path().join("cache.db"),
             ttl: Duration::from_secs(300),
             wal_mode: false,
+            compress: false,
         };
 
         let cache = Cache::new(cache_config).unwrap();